@@ -1,6 +1,7 @@
 import 'dart:async';
 import 'dart:typed_data';
 
+import '../../src/rust/transport/options.dart';
 import '../../src/rust/transport/quic.dart';
 import '../../src/rust/frb_generated.dart';
 
@@ -113,7 +114,7 @@ class RustTransport implements TransportInterface {
   Future<void> _ensureInitialized() async {
     if (_quicTransport == null) {
       await RustLib.init();
-      _quicTransport = QuicTransport();
+      _quicTransport = QuicTransport(options: TransportOptions.default_());
       print('[RustTransport] Initialized QuicTransport');
     }
   }
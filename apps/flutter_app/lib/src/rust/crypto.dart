@@ -15,6 +15,12 @@ Uint8List generateKey() => RustLib.instance.api.crateCryptoGenerateKey();
 Uint8List encryptText({required String plaintext, required List<int> key}) =>
     RustLib.instance.api.crateCryptoEncryptText(plaintext: plaintext, key: key);
 
+/// Encrypt binary data, such as an image, using AES-256-GCM
+///
+/// Returns: nonce (12 bytes) || ciphertext || tag (16 bytes)
+Uint8List encryptBytes({required List<int> plaintext, required List<int> key}) =>
+    RustLib.instance.api.crateCryptoEncryptBytes(plaintext: plaintext, key: key);
+
 /// Generate an X25519 keypair for device pairing
 ///
 /// Returns: (secret_key, public_key) as 32-byte vectors
@@ -32,3 +38,9 @@ Uint8List deriveSharedSecret({required List<int> mySecret, required List<int> th
 /// Expects: nonce (12 bytes) || ciphertext || tag (16 bytes)
 String decryptText({required List<int> ciphertext, required List<int> key}) =>
     RustLib.instance.api.crateCryptoDecryptText(ciphertext: ciphertext, key: key);
+
+/// Decrypt binary data encrypted with [`encrypt_bytes`]
+///
+/// Expects: nonce (12 bytes) || ciphertext || tag (16 bytes)
+Uint8List decryptBytes({required List<int> ciphertext, required List<int> key}) =>
+    RustLib.instance.api.crateCryptoDecryptBytes(ciphertext: ciphertext, key: key);
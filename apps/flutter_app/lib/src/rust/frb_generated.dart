@@ -11,7 +11,22 @@ import 'frb_generated.dart';
 import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'signaling/client.dart';
+import 'transport/address.dart';
+import 'transport/broadcast.dart';
+import 'transport/datagram.dart';
+import 'transport/delivery.dart';
+import 'transport/events.dart';
+import 'transport/identity.dart';
+import 'transport/options.dart';
+import 'transport/protocol.dart';
 import 'transport/quic.dart';
+import 'transport/receiver.dart';
+import 'transport/stats.dart';
+import 'transport/stun.dart';
+import 'transport/supervisor.dart';
+import 'transport/transfer.dart';
+import 'transport/trust.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -67,7 +82,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1189968051;
+  int get rustContentHash => -2083088441;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'rust_core',
@@ -77,6 +92,24 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Uint8List crateTransportIdentityDeviceIdentityCertificate({required DeviceIdentity that});
+
+  String crateTransportIdentityDeviceIdentityDeviceId({required DeviceIdentity that});
+
+  String crateTransportIdentityDeviceIdentityFingerprint({required DeviceIdentity that});
+
+  DeviceIdentity crateTransportIdentityDeviceIdentityFromBytes({required List<int> bytes});
+
+  DeviceIdentity crateTransportIdentityDeviceIdentityGenerate({required String deviceId});
+
+  DeviceIdentity crateTransportIdentityDeviceIdentityLoad({required String path});
+
+  DeviceIdentity crateTransportIdentityDeviceIdentityLoadOrGenerate({required String path, required String deviceId});
+
+  void crateTransportIdentityDeviceIdentitySave({required DeviceIdentity that, required String path});
+
+  Uint8List crateTransportIdentityDeviceIdentityToBytes({required DeviceIdentity that});
+
   Future<List<PeerInfo>> crateDiscoveryMdnsMdnsDiscoveryGetDiscoveredPeers({required MdnsDiscovery that});
 
   MdnsDiscovery crateDiscoveryMdnsMdnsDiscoveryNew({required String deviceId, required String deviceName});
@@ -89,32 +122,176 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateTransportQuicQuicTransportAcceptConnection({required QuicTransport that});
 
+  bool crateTransportQuicQuicTransportAcknowledgeItem(
+      {required QuicTransport that, required String peerId, required BigInt itemId});
+
+  Future<Map<String, BroadcastOutcome>> crateTransportQuicQuicTransportBroadcast(
+      {required QuicTransport that, required List<int> data, required BroadcastFilter filter});
+
+  bool crateTransportQuicQuicTransportCancelTransfer({required QuicTransport that, required BigInt transferId});
+
   Future<void> crateTransportQuicQuicTransportClose({required QuicTransport that});
 
+  Future<String> crateTransportQuicQuicTransportConnectToDevice(
+      {required QuicTransport that, required String deviceId, required String addr, required int port});
+
   Future<String> crateTransportQuicQuicTransportConnectToPeer(
       {required QuicTransport that, required String addr, required int port});
 
+  Future<String> crateTransportQuicQuicTransportConnectToPeerInfo(
+      {required QuicTransport that, required PeerInfo peer});
+
+  Future<String> crateTransportQuicQuicTransportConnectViaRelay(
+      {required QuicTransport that, required String deviceId});
+
+  Future<String> crateTransportQuicQuicTransportConnectViaRendezvous(
+      {required QuicTransport that, required String deviceId});
+
+  Future<String> crateTransportQuicQuicTransportConnectViaSignaling(
+      {required QuicTransport that, required String deviceId});
+
+  Stream<ConnectionEvent> crateTransportQuicQuicTransportConnectionEvents({required QuicTransport that});
+
   QuicTransport crateTransportQuicQuicTransportDefault();
 
   Future<void> crateTransportQuicQuicTransportDisconnect({required QuicTransport that, required String peerId});
 
+  Future<NatReport> crateTransportQuicQuicTransportDiscoverNat(
+      {required QuicTransport that, required List<String> stunServers});
+
+  bool crateTransportQuicQuicTransportForgetFingerprint({required QuicTransport that, required String deviceId});
+
+  bool crateTransportQuicQuicTransportForgetPeerKey({required QuicTransport that, required String deviceId});
+
   Future<List<String>> crateTransportQuicQuicTransportGetConnectedPeers({required QuicTransport that});
 
+  LinkQuality? crateTransportQuicQuicTransportGetLinkQuality({required QuicTransport that, required String peerId});
+
+  Future<String> crateTransportQuicQuicTransportGetPeerAddress({required QuicTransport that, required String peerId});
+
+  Future<String> crateTransportQuicQuicTransportGetPeerDeviceId({required QuicTransport that, required String peerId});
+
+  Future<int> crateTransportQuicQuicTransportGetPeerProtocolVersion(
+      {required QuicTransport that, required String peerId});
+
+  Future<PeerStats> crateTransportQuicQuicTransportGetPeerStats({required QuicTransport that, required String peerId});
+
+  List<PendingTransfer> crateTransportQuicQuicTransportGetPendingTransfers({required QuicTransport that});
+
+  String? crateTransportQuicQuicTransportGetPreferredAddress({required QuicTransport that, required String deviceId});
+
+  List<String> crateTransportQuicQuicTransportGetSupervisedPeers({required QuicTransport that});
+
+  Future<bool> crateTransportQuicQuicTransportIsPeerRelayed({required QuicTransport that, required String peerId});
+
   bool crateTransportQuicQuicTransportIsRunning({required QuicTransport that});
 
-  QuicTransport crateTransportQuicQuicTransportNew();
+  Future<void> crateTransportQuicQuicTransportKeepConnected(
+      {required QuicTransport that, required String deviceId, required List<String> addresses, required int port});
+
+  Stream<LinkEvent> crateTransportQuicQuicTransportLinkEvents({required QuicTransport that});
+
+  List<PinnedFingerprint> crateTransportQuicQuicTransportListPinnedFingerprints({required QuicTransport that});
+
+  int? crateTransportQuicQuicTransportLocalPort({required QuicTransport that});
+
+  QuicTransport crateTransportQuicQuicTransportNew({required TransportOptions options});
+
+  Stream<List<PeerStats>> crateTransportQuicQuicTransportPeerStats(
+      {required QuicTransport that, required BigInt intervalMs});
+
+  void crateTransportQuicQuicTransportPinFingerprint(
+      {required QuicTransport that, required String deviceId, required String fingerprint});
+
+  Stream<ReceivedItem> crateTransportQuicQuicTransportReceiveItems({required QuicTransport that});
+
+  Future<String> crateTransportQuicQuicTransportRegisterRendezvous(
+      {required QuicTransport that, required String server});
+
+  bool crateTransportQuicQuicTransportRejectItem(
+      {required QuicTransport that, required String peerId, required BigInt itemId, required String reason});
 
   Future<void> crateTransportQuicQuicTransportSendData(
       {required QuicTransport that, required String peerId, required List<int> data});
 
+  Future<DeliveryResult> crateTransportQuicQuicTransportSendDataAcked(
+      {required QuicTransport that, required String peerId, required List<int> data, required BigInt timeoutMs});
+
+  Future<BigInt> crateTransportQuicQuicTransportSendFile(
+      {required QuicTransport that, required String peerId, required String path, required String contentType});
+
+  Future<BigInt> crateTransportQuicQuicTransportSendLargeData(
+      {required QuicTransport that,
+      required String peerId,
+      required String name,
+      required String contentType,
+      required List<int> data});
+
+  Future<void> crateTransportQuicQuicTransportSendMessage(
+      {required QuicTransport that, required String peerId, required SyncMessage message});
+
+  Future<DeliveryResult> crateTransportQuicQuicTransportSendMessageAcked(
+      {required QuicTransport that, required String peerId, required SyncMessage message, required BigInt timeoutMs});
+
+  Future<void> crateTransportQuicQuicTransportSetActivity({required QuicTransport that, required Activity activity});
+
+  void crateTransportQuicQuicTransportSetIdentity({required QuicTransport that, required DeviceIdentity identity});
+
+  void crateTransportQuicQuicTransportSetPeerKey(
+      {required QuicTransport that, required String deviceId, required List<int> key});
+
+  void crateTransportQuicQuicTransportSetPeerPublicKey(
+      {required QuicTransport that, required String deviceId, required List<int> publicKey});
+
+  void crateTransportQuicQuicTransportSetSealingKey({required QuicTransport that, required List<int> secretKey});
+
+  Future<void> crateTransportQuicQuicTransportShutdown({required QuicTransport that, required BigInt timeoutMs});
+
   Future<void> crateTransportQuicQuicTransportStartServer({required QuicTransport that, required int port});
 
+  bool crateTransportQuicQuicTransportStopKeepingConnected({required QuicTransport that, required String deviceId});
+
+  Stream<TransferEvent> crateTransportQuicQuicTransportTransferEvents({required QuicTransport that});
+
+  Future<bool> crateTransportQuicQuicTransportUnpair({required QuicTransport that, required String deviceId});
+
+  void crateTransportQuicQuicTransportUseDiscovery({required QuicTransport that, required MdnsDiscovery discovery});
+
+  Future<void> crateTransportQuicQuicTransportUseRelay({required QuicTransport that, required String server});
+
+  Future<void> crateTransportQuicQuicTransportUseSignaling(
+      {required QuicTransport that, required SignalingClient signaling});
+
+  QuicTransport crateTransportQuicQuicTransportWithIdentity({required DeviceIdentity identity});
+
+  Future<void> crateSignalingClientSignalingClientClose({required SignalingClient that});
+
+  Future<SignalingClient> crateSignalingClientSignalingClientConnect({required String url, required String token});
+
+  String crateSignalingClientSignalingClientDeviceId({required SignalingClient that});
+
+  List<String> crateSignalingClientSignalingClientOnlinePeers({required SignalingClient that});
+
+  Stream<SignalingEvent> crateSignalingClientSignalingClientSignalingEvents({required SignalingClient that});
+
+  Activity crateTransportDatagramActivityDefault();
+
+  BroadcastFilter crateTransportBroadcastBroadcastFilterDefault();
+
+  String crateTransportTrustCertificateFingerprint({required List<int> certDer});
+
+  Uint8List crateCryptoDecryptBytes({required List<int> ciphertext, required List<int> key});
+
   String crateCryptoDecryptText({required List<int> ciphertext, required List<int> key});
 
   Uint8List crateCryptoDeriveSharedSecret({required List<int> mySecret, required List<int> theirPublic});
 
+  Uint8List crateCryptoEncryptBytes({required List<int> plaintext, required List<int> key});
+
   Uint8List crateCryptoEncryptText({required String plaintext, required List<int> key});
 
+  String crateTransportAddressFormatPeerAddress({required String addr, required int port});
+
   Uint8List crateCryptoGenerateKey();
 
   (Uint8List, Uint8List) crateCryptoGenerateKeypair();
@@ -123,6 +300,18 @@ abstract class RustLibApi extends BaseApi {
 
   void crateInit();
 
+  TransportOptions crateTransportOptionsTransportOptionsDefault();
+
+  TransportOptions crateTransportOptionsTransportOptionsDesktop();
+
+  TransportOptions crateTransportOptionsTransportOptionsMobile();
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DeviceIdentity;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DeviceIdentity;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DeviceIdentityPtr;
+
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MdnsDiscovery;
 
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MdnsDiscovery;
@@ -134,6 +323,12 @@ abstract class RustLibApi extends BaseApi {
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_QuicTransport;
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_QuicTransportPtr;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SignalingClient;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SignalingClient;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SignalingClientPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -145,1062 +340,4916 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<List<PeerInfo>> crateDiscoveryMdnsMdnsDiscoveryGetDiscoveredPeers({required MdnsDiscovery that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  Uint8List crateTransportIdentityDeviceIdentityCertificate({required DeviceIdentity that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
             that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_peer_info,
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
         decodeErrorData: null,
       ),
-      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryGetDiscoveredPeersConstMeta,
+      constMeta: kCrateTransportIdentityDeviceIdentityCertificateConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryGetDiscoveredPeersConstMeta => const TaskConstMeta(
-        debugName: "MdnsDiscovery_get_discovered_peers",
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityCertificateConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_certificate",
         argNames: ["that"],
       );
 
   @override
-  MdnsDiscovery crateDiscoveryMdnsMdnsDiscoveryNew({required String deviceId, required String deviceName}) {
+  String crateTransportIdentityDeviceIdentityDeviceId({required DeviceIdentity that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(deviceId, serializer);
-        sse_encode_String(deviceName, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+            that, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
       },
       codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery,
-        decodeErrorData: sse_decode_discovery_error,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryNewConstMeta,
-      argValues: [deviceId, deviceName],
+      constMeta: kCrateTransportIdentityDeviceIdentityDeviceIdConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryNewConstMeta => const TaskConstMeta(
-        debugName: "MdnsDiscovery_new",
-        argNames: ["deviceId", "deviceName"],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityDeviceIdConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_device_id",
+        argNames: ["that"],
       );
 
   @override
-  void crateDiscoveryMdnsMdnsDiscoveryRegister({required MdnsDiscovery that, required int port}) {
+  String crateTransportIdentityDeviceIdentityFingerprint({required DeviceIdentity that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
             that, serializer);
-        sse_encode_u_16(port, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_discovery_error,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryRegisterConstMeta,
-      argValues: [that, port],
+      constMeta: kCrateTransportIdentityDeviceIdentityFingerprintConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryRegisterConstMeta => const TaskConstMeta(
-        debugName: "MdnsDiscovery_register",
-        argNames: ["that", "port"],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityFingerprintConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_fingerprint",
+        argNames: ["that"],
       );
 
   @override
-  void crateDiscoveryMdnsMdnsDiscoveryStartBrowsing({required MdnsDiscovery that}) {
+  DeviceIdentity crateTransportIdentityDeviceIdentityFromBytes({required List<int> bytes}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-            that, serializer);
+        sse_encode_list_prim_u_8_loose(bytes, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_discovery_error,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity,
+        decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryStartBrowsingConstMeta,
-      argValues: [that],
+      constMeta: kCrateTransportIdentityDeviceIdentityFromBytesConstMeta,
+      argValues: [bytes],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryStartBrowsingConstMeta => const TaskConstMeta(
-        debugName: "MdnsDiscovery_start_browsing",
-        argNames: ["that"],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityFromBytesConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_from_bytes",
+        argNames: ["bytes"],
       );
 
   @override
-  void crateDiscoveryMdnsMdnsDiscoveryStop({required MdnsDiscovery that}) {
+  DeviceIdentity crateTransportIdentityDeviceIdentityGenerate({required String deviceId}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-            that, serializer);
+        sse_encode_String(deviceId, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_discovery_error,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity,
+        decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryStopConstMeta,
-      argValues: [that],
+      constMeta: kCrateTransportIdentityDeviceIdentityGenerateConstMeta,
+      argValues: [deviceId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryStopConstMeta => const TaskConstMeta(
-        debugName: "MdnsDiscovery_stop",
-        argNames: ["that"],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityGenerateConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_generate",
+        argNames: ["deviceId"],
       );
 
   @override
-  Future<String> crateTransportQuicQuicTransportAcceptConnection({required QuicTransport that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  DeviceIdentity crateTransportIdentityDeviceIdentityLoad({required String path}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+        sse_encode_String(path, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity,
         decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportAcceptConnectionConstMeta,
-      argValues: [that],
+      constMeta: kCrateTransportIdentityDeviceIdentityLoadConstMeta,
+      argValues: [path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportAcceptConnectionConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_accept_connection",
-        argNames: ["that"],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityLoadConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_load",
+        argNames: ["path"],
       );
 
   @override
-  Future<void> crateTransportQuicQuicTransportClose({required QuicTransport that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  DeviceIdentity crateTransportIdentityDeviceIdentityLoadOrGenerate({required String path, required String deviceId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+        sse_encode_String(path, serializer);
+        sse_encode_String(deviceId, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity,
+        decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportCloseConstMeta,
-      argValues: [that],
+      constMeta: kCrateTransportIdentityDeviceIdentityLoadOrGenerateConstMeta,
+      argValues: [path, deviceId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportCloseConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_close",
-        argNames: ["that"],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityLoadOrGenerateConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_load_or_generate",
+        argNames: ["path", "deviceId"],
       );
 
   @override
-  Future<String> crateTransportQuicQuicTransportConnectToPeer(
-      {required QuicTransport that, required String addr, required int port}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateTransportIdentityDeviceIdentitySave({required DeviceIdentity that, required String path}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
             that, serializer);
-        sse_encode_String(addr, serializer);
-        sse_encode_u_16(port, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+        sse_encode_String(path, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportConnectToPeerConstMeta,
-      argValues: [that, addr, port],
+      constMeta: kCrateTransportIdentityDeviceIdentitySaveConstMeta,
+      argValues: [that, path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportConnectToPeerConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_connect_to_peer",
-        argNames: ["that", "addr", "port"],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentitySaveConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_save",
+        argNames: ["that", "path"],
       );
 
   @override
-  QuicTransport crateTransportQuicQuicTransportDefault() {
+  Uint8List crateTransportIdentityDeviceIdentityToBytes({required DeviceIdentity that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+            that, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
       },
       codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport,
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
         decodeErrorData: null,
       ),
-      constMeta: kCrateTransportQuicQuicTransportDefaultConstMeta,
-      argValues: [],
+      constMeta: kCrateTransportIdentityDeviceIdentityToBytesConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportDefaultConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_default",
-        argNames: [],
+  TaskConstMeta get kCrateTransportIdentityDeviceIdentityToBytesConstMeta => const TaskConstMeta(
+        debugName: "DeviceIdentity_to_bytes",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateTransportQuicQuicTransportDisconnect({required QuicTransport that, required String peerId}) {
+  Future<List<PeerInfo>> crateDiscoveryMdnsMdnsDiscoveryGetDiscoveredPeers({required MdnsDiscovery that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
             that, serializer);
-        sse_encode_String(peerId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_transport_error,
+        decodeSuccessData: sse_decode_list_peer_info,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateTransportQuicQuicTransportDisconnectConstMeta,
-      argValues: [that, peerId],
+      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryGetDiscoveredPeersConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportDisconnectConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_disconnect",
-        argNames: ["that", "peerId"],
+  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryGetDiscoveredPeersConstMeta => const TaskConstMeta(
+        debugName: "MdnsDiscovery_get_discovered_peers",
+        argNames: ["that"],
       );
 
   @override
-  Future<List<String>> crateTransportQuicQuicTransportGetConnectedPeers({required QuicTransport that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  MdnsDiscovery crateDiscoveryMdnsMdnsDiscoveryNew({required String deviceId, required String deviceName}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_String(deviceName, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: null,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery,
+        decodeErrorData: sse_decode_discovery_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportGetConnectedPeersConstMeta,
-      argValues: [that],
+      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryNewConstMeta,
+      argValues: [deviceId, deviceName],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportGetConnectedPeersConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_get_connected_peers",
-        argNames: ["that"],
+  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryNewConstMeta => const TaskConstMeta(
+        debugName: "MdnsDiscovery_new",
+        argNames: ["deviceId", "deviceName"],
       );
 
   @override
-  bool crateTransportQuicQuicTransportIsRunning({required QuicTransport that}) {
+  void crateDiscoveryMdnsMdnsDiscoveryRegister({required MdnsDiscovery that, required int port}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
             that, serializer);
+        sse_encode_u_16(port, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_discovery_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportIsRunningConstMeta,
-      argValues: [that],
+      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryRegisterConstMeta,
+      argValues: [that, port],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportIsRunningConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_is_running",
-        argNames: ["that"],
+  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryRegisterConstMeta => const TaskConstMeta(
+        debugName: "MdnsDiscovery_register",
+        argNames: ["that", "port"],
       );
 
   @override
-  QuicTransport crateTransportQuicQuicTransportNew() {
+  void crateDiscoveryMdnsMdnsDiscoveryStartBrowsing({required MdnsDiscovery that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+            that, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
       },
       codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_discovery_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportNewConstMeta,
-      argValues: [],
+      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryStartBrowsingConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportNewConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_new",
-        argNames: [],
+  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryStartBrowsingConstMeta => const TaskConstMeta(
+        debugName: "MdnsDiscovery_start_browsing",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateTransportQuicQuicTransportSendData(
-      {required QuicTransport that, required String peerId, required List<int> data}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateDiscoveryMdnsMdnsDiscoveryStop({required MdnsDiscovery that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
             that, serializer);
-        sse_encode_String(peerId, serializer);
-        sse_encode_list_prim_u_8_loose(data, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_transport_error,
+        decodeErrorData: sse_decode_discovery_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportSendDataConstMeta,
-      argValues: [that, peerId, data],
+      constMeta: kCrateDiscoveryMdnsMdnsDiscoveryStopConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportSendDataConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_send_data",
-        argNames: ["that", "peerId", "data"],
+  TaskConstMeta get kCrateDiscoveryMdnsMdnsDiscoveryStopConstMeta => const TaskConstMeta(
+        debugName: "MdnsDiscovery_stop",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateTransportQuicQuicTransportStartServer({required QuicTransport that, required int port}) {
+  Future<String> crateTransportQuicQuicTransportAcceptConnection({required QuicTransport that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
             that, serializer);
-        sse_encode_u_16(port, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateTransportQuicQuicTransportStartServerConstMeta,
-      argValues: [that, port],
+      constMeta: kCrateTransportQuicQuicTransportAcceptConnectionConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicQuicTransportStartServerConstMeta => const TaskConstMeta(
-        debugName: "QuicTransport_start_server",
-        argNames: ["that", "port"],
+  TaskConstMeta get kCrateTransportQuicQuicTransportAcceptConnectionConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_accept_connection",
+        argNames: ["that"],
       );
 
   @override
-  String crateCryptoDecryptText({required List<int> ciphertext, required List<int> key}) {
+  bool crateTransportQuicQuicTransportAcknowledgeItem(
+      {required QuicTransport that, required String peerId, required BigInt itemId}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(ciphertext, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_u_64(itemId, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateCryptoDecryptTextConstMeta,
-      argValues: [ciphertext, key],
+      constMeta: kCrateTransportQuicQuicTransportAcknowledgeItemConstMeta,
+      argValues: [that, peerId, itemId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateCryptoDecryptTextConstMeta => const TaskConstMeta(
-        debugName: "decrypt_text",
-        argNames: ["ciphertext", "key"],
+  TaskConstMeta get kCrateTransportQuicQuicTransportAcknowledgeItemConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_acknowledge_item",
+        argNames: ["that", "peerId", "itemId"],
       );
 
   @override
-  Uint8List crateCryptoDeriveSharedSecret({required List<int> mySecret, required List<int> theirPublic}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<Map<String, BroadcastOutcome>> crateTransportQuicQuicTransportBroadcast(
+      {required QuicTransport that, required List<int> data, required BroadcastFilter filter}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(mySecret, serializer);
-        sse_encode_list_prim_u_8_loose(theirPublic, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_box_autoadd_broadcast_filter(filter, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_u_8_strict,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_Map_String_broadcast_outcome_None,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateCryptoDeriveSharedSecretConstMeta,
-      argValues: [mySecret, theirPublic],
+      constMeta: kCrateTransportQuicQuicTransportBroadcastConstMeta,
+      argValues: [that, data, filter],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateCryptoDeriveSharedSecretConstMeta => const TaskConstMeta(
-        debugName: "derive_shared_secret",
-        argNames: ["mySecret", "theirPublic"],
+  TaskConstMeta get kCrateTransportQuicQuicTransportBroadcastConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_broadcast",
+        argNames: ["that", "data", "filter"],
       );
 
   @override
-  Uint8List crateCryptoEncryptText({required String plaintext, required List<int> key}) {
+  bool crateTransportQuicQuicTransportCancelTransfer({required QuicTransport that, required BigInt transferId}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(plaintext, serializer);
-        sse_encode_list_prim_u_8_loose(key, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_u_64(transferId, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_u_8_strict,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateCryptoEncryptTextConstMeta,
-      argValues: [plaintext, key],
+      constMeta: kCrateTransportQuicQuicTransportCancelTransferConstMeta,
+      argValues: [that, transferId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateCryptoEncryptTextConstMeta => const TaskConstMeta(
-        debugName: "encrypt_text",
-        argNames: ["plaintext", "key"],
+  TaskConstMeta get kCrateTransportQuicQuicTransportCancelTransferConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_cancel_transfer",
+        argNames: ["that", "transferId"],
       );
 
   @override
-  Uint8List crateCryptoGenerateKey() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<void> crateTransportQuicQuicTransportClose({required QuicTransport that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateCryptoGenerateKeyConstMeta,
-      argValues: [],
+      constMeta: kCrateTransportQuicQuicTransportCloseConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateCryptoGenerateKeyConstMeta => const TaskConstMeta(
-        debugName: "generate_key",
-        argNames: [],
+  TaskConstMeta get kCrateTransportQuicQuicTransportCloseConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_close",
+        argNames: ["that"],
       );
 
   @override
-  (Uint8List, Uint8List) crateCryptoGenerateKeypair() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<String> crateTransportQuicQuicTransportConnectToDevice(
+      {required QuicTransport that, required String deviceId, required String addr, required int port}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_String(addr, serializer);
+        sse_encode_u_16(port, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateCryptoGenerateKeypairConstMeta,
-      argValues: [],
+      constMeta: kCrateTransportQuicQuicTransportConnectToDeviceConstMeta,
+      argValues: [that, deviceId, addr, port],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateCryptoGenerateKeypairConstMeta => const TaskConstMeta(
-        debugName: "generate_keypair",
-        argNames: [],
+  TaskConstMeta get kCrateTransportQuicQuicTransportConnectToDeviceConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_connect_to_device",
+        argNames: ["that", "deviceId", "addr", "port"],
       );
 
   @override
-  (Uint8List, Uint8List) crateTransportQuicGenerateSelfSignedCert() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<String> crateTransportQuicQuicTransportConnectToPeer(
+      {required QuicTransport that, required String addr, required int port}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(addr, serializer);
+        sse_encode_u_16(port, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateTransportQuicGenerateSelfSignedCertConstMeta,
-      argValues: [],
+      constMeta: kCrateTransportQuicQuicTransportConnectToPeerConstMeta,
+      argValues: [that, addr, port],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateTransportQuicGenerateSelfSignedCertConstMeta => const TaskConstMeta(
-        debugName: "generate_self_signed_cert",
-        argNames: [],
+  TaskConstMeta get kCrateTransportQuicQuicTransportConnectToPeerConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_connect_to_peer",
+        argNames: ["that", "addr", "port"],
       );
 
   @override
-  void crateInit() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<String> crateTransportQuicQuicTransportConnectToPeerInfo(
+      {required QuicTransport that, required PeerInfo peer}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_box_autoadd_peer_info(peer, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
       ),
-      constMeta: kCrateInitConstMeta,
-      argValues: [],
+      constMeta: kCrateTransportQuicQuicTransportConnectToPeerInfoConstMeta,
+      argValues: [that, peer],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateInitConstMeta => const TaskConstMeta(
-        debugName: "init",
-        argNames: [],
+  TaskConstMeta get kCrateTransportQuicQuicTransportConnectToPeerInfoConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_connect_to_peer_info",
+        argNames: ["that", "peer"],
       );
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MdnsDiscovery =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MdnsDiscovery =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_QuicTransport =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_QuicTransport =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport;
-
-  @protected
-  MdnsDiscovery dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MdnsDiscoveryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  @override
+  Future<String> crateTransportQuicQuicTransportConnectViaRelay(
+      {required QuicTransport that, required String deviceId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportConnectViaRelayConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  QuicTransport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
+  TaskConstMeta get kCrateTransportQuicQuicTransportConnectViaRelayConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_connect_via_relay",
+        argNames: ["that", "deviceId"],
+      );
 
-  @protected
-  QuicTransport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  @override
+  Future<String> crateTransportQuicQuicTransportConnectViaRendezvous(
+      {required QuicTransport that, required String deviceId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportConnectViaRendezvousConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  MdnsDiscovery dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MdnsDiscoveryImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
+  TaskConstMeta get kCrateTransportQuicQuicTransportConnectViaRendezvousConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_connect_via_rendezvous",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  Future<String> crateTransportQuicQuicTransportConnectViaSignaling(
+      {required QuicTransport that, required String deviceId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportConnectViaSignalingConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportConnectViaSignalingConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_connect_via_signaling",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  Stream<ConnectionEvent> crateTransportQuicQuicTransportConnectionEvents({required QuicTransport that}) {
+    final sink = RustStreamSink<ConnectionEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_StreamSink_connection_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportConnectionEventsConstMeta,
+      argValues: [that, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportConnectionEventsConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_connection_events",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  QuicTransport crateTransportQuicQuicTransportDefault() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportDefaultConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_default",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportDisconnect({required QuicTransport that, required String peerId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportDisconnectConstMeta,
+      argValues: [that, peerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportDisconnectConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_disconnect",
+        argNames: ["that", "peerId"],
+      );
+
+  @override
+  Future<NatReport> crateTransportQuicQuicTransportDiscoverNat(
+      {required QuicTransport that, required List<String> stunServers}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_list_String(stunServers, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_nat_report,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportDiscoverNatConstMeta,
+      argValues: [that, stunServers],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportDiscoverNatConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_discover_nat",
+        argNames: ["that", "stunServers"],
+      );
+
+  @override
+  bool crateTransportQuicQuicTransportForgetFingerprint({required QuicTransport that, required String deviceId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportForgetFingerprintConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportForgetFingerprintConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_forget_fingerprint",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  bool crateTransportQuicQuicTransportForgetPeerKey({required QuicTransport that, required String deviceId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportForgetPeerKeyConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportForgetPeerKeyConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_forget_peer_key",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  Future<List<String>> crateTransportQuicQuicTransportGetConnectedPeers({required QuicTransport that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetConnectedPeersConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetConnectedPeersConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_connected_peers",
+        argNames: ["that"],
+      );
+
+  @override
+  LinkQuality? crateTransportQuicQuicTransportGetLinkQuality({required QuicTransport that, required String peerId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_link_quality,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetLinkQualityConstMeta,
+      argValues: [that, peerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetLinkQualityConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_link_quality",
+        argNames: ["that", "peerId"],
+      );
+
+  @override
+  Future<String> crateTransportQuicQuicTransportGetPeerAddress({required QuicTransport that, required String peerId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetPeerAddressConstMeta,
+      argValues: [that, peerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetPeerAddressConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_peer_address",
+        argNames: ["that", "peerId"],
+      );
+
+  @override
+  Future<String> crateTransportQuicQuicTransportGetPeerDeviceId({required QuicTransport that, required String peerId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetPeerDeviceIdConstMeta,
+      argValues: [that, peerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetPeerDeviceIdConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_peer_device_id",
+        argNames: ["that", "peerId"],
+      );
+
+  @override
+  Future<int> crateTransportQuicQuicTransportGetPeerProtocolVersion(
+      {required QuicTransport that, required String peerId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_16,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetPeerProtocolVersionConstMeta,
+      argValues: [that, peerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetPeerProtocolVersionConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_peer_protocol_version",
+        argNames: ["that", "peerId"],
+      );
+
+  @override
+  Future<PeerStats> crateTransportQuicQuicTransportGetPeerStats({required QuicTransport that, required String peerId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_peer_stats,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetPeerStatsConstMeta,
+      argValues: [that, peerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetPeerStatsConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_peer_stats",
+        argNames: ["that", "peerId"],
+      );
+
+  @override
+  List<PendingTransfer> crateTransportQuicQuicTransportGetPendingTransfers({required QuicTransport that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pending_transfer,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetPendingTransfersConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetPendingTransfersConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_pending_transfers",
+        argNames: ["that"],
+      );
+
+  @override
+  String? crateTransportQuicQuicTransportGetPreferredAddress({required QuicTransport that, required String deviceId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetPreferredAddressConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetPreferredAddressConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_preferred_address",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  List<String> crateTransportQuicQuicTransportGetSupervisedPeers({required QuicTransport that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportGetSupervisedPeersConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportGetSupervisedPeersConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_get_supervised_peers",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<bool> crateTransportQuicQuicTransportIsPeerRelayed({required QuicTransport that, required String peerId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportIsPeerRelayedConstMeta,
+      argValues: [that, peerId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportIsPeerRelayedConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_is_peer_relayed",
+        argNames: ["that", "peerId"],
+      );
+
+  @override
+  bool crateTransportQuicQuicTransportIsRunning({required QuicTransport that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportIsRunningConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportIsRunningConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_is_running",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportKeepConnected(
+      {required QuicTransport that, required String deviceId, required List<String> addresses, required int port}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_list_String(addresses, serializer);
+        sse_encode_u_16(port, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportKeepConnectedConstMeta,
+      argValues: [that, deviceId, addresses, port],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportKeepConnectedConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_keep_connected",
+        argNames: ["that", "deviceId", "addresses", "port"],
+      );
+
+  @override
+  Stream<LinkEvent> crateTransportQuicQuicTransportLinkEvents({required QuicTransport that}) {
+    final sink = RustStreamSink<LinkEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_StreamSink_link_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportLinkEventsConstMeta,
+      argValues: [that, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportLinkEventsConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_link_events",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  List<PinnedFingerprint> crateTransportQuicQuicTransportListPinnedFingerprints({required QuicTransport that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_pinned_fingerprint,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportListPinnedFingerprintsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportListPinnedFingerprintsConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_list_pinned_fingerprints",
+        argNames: ["that"],
+      );
+
+  @override
+  int? crateTransportQuicQuicTransportLocalPort({required QuicTransport that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_16,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportLocalPortConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportLocalPortConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_local_port",
+        argNames: ["that"],
+      );
+
+  @override
+  QuicTransport crateTransportQuicQuicTransportNew({required TransportOptions options}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_transport_options(options, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportNewConstMeta,
+      argValues: [options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportNewConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_new",
+        argNames: ["options"],
+      );
+
+  @override
+  Stream<List<PeerStats>> crateTransportQuicQuicTransportPeerStats(
+      {required QuicTransport that, required BigInt intervalMs}) {
+    final sink = RustStreamSink<List<PeerStats>>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_StreamSink_list_peer_stats_Sse(sink, serializer);
+        sse_encode_u_64(intervalMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportPeerStatsConstMeta,
+      argValues: [that, sink, intervalMs],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportPeerStatsConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_peer_stats",
+        argNames: ["that", "sink", "intervalMs"],
+      );
+
+  @override
+  void crateTransportQuicQuicTransportPinFingerprint(
+      {required QuicTransport that, required String deviceId, required String fingerprint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_String(fingerprint, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportPinFingerprintConstMeta,
+      argValues: [that, deviceId, fingerprint],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportPinFingerprintConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_pin_fingerprint",
+        argNames: ["that", "deviceId", "fingerprint"],
+      );
+
+  @override
+  Stream<ReceivedItem> crateTransportQuicQuicTransportReceiveItems({required QuicTransport that}) {
+    final sink = RustStreamSink<ReceivedItem>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_StreamSink_received_item_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportReceiveItemsConstMeta,
+      argValues: [that, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportReceiveItemsConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_receive_items",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<String> crateTransportQuicQuicTransportRegisterRendezvous(
+      {required QuicTransport that, required String server}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(server, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportRegisterRendezvousConstMeta,
+      argValues: [that, server],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportRegisterRendezvousConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_register_rendezvous",
+        argNames: ["that", "server"],
+      );
+
+  @override
+  bool crateTransportQuicQuicTransportRejectItem(
+      {required QuicTransport that, required String peerId, required BigInt itemId, required String reason}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_u_64(itemId, serializer);
+        sse_encode_String(reason, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportRejectItemConstMeta,
+      argValues: [that, peerId, itemId, reason],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportRejectItemConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_reject_item",
+        argNames: ["that", "peerId", "itemId", "reason"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportSendData(
+      {required QuicTransport that, required String peerId, required List<int> data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSendDataConstMeta,
+      argValues: [that, peerId, data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSendDataConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_send_data",
+        argNames: ["that", "peerId", "data"],
+      );
+
+  @override
+  Future<DeliveryResult> crateTransportQuicQuicTransportSendDataAcked(
+      {required QuicTransport that, required String peerId, required List<int> data, required BigInt timeoutMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_u_64(timeoutMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_delivery_result,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSendDataAckedConstMeta,
+      argValues: [that, peerId, data, timeoutMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSendDataAckedConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_send_data_acked",
+        argNames: ["that", "peerId", "data", "timeoutMs"],
+      );
+
+  @override
+  Future<BigInt> crateTransportQuicQuicTransportSendFile(
+      {required QuicTransport that, required String peerId, required String path, required String contentType}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(contentType, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSendFileConstMeta,
+      argValues: [that, peerId, path, contentType],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSendFileConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_send_file",
+        argNames: ["that", "peerId", "path", "contentType"],
+      );
+
+  @override
+  Future<BigInt> crateTransportQuicQuicTransportSendLargeData(
+      {required QuicTransport that,
+      required String peerId,
+      required String name,
+      required String contentType,
+      required List<int> data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_String(contentType, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSendLargeDataConstMeta,
+      argValues: [that, peerId, name, contentType, data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSendLargeDataConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_send_large_data",
+        argNames: ["that", "peerId", "name", "contentType", "data"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportSendMessage(
+      {required QuicTransport that, required String peerId, required SyncMessage message}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_box_autoadd_sync_message(message, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSendMessageConstMeta,
+      argValues: [that, peerId, message],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSendMessageConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_send_message",
+        argNames: ["that", "peerId", "message"],
+      );
+
+  @override
+  Future<DeliveryResult> crateTransportQuicQuicTransportSendMessageAcked(
+      {required QuicTransport that, required String peerId, required SyncMessage message, required BigInt timeoutMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_box_autoadd_sync_message(message, serializer);
+        sse_encode_u_64(timeoutMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_delivery_result,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSendMessageAckedConstMeta,
+      argValues: [that, peerId, message, timeoutMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSendMessageAckedConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_send_message_acked",
+        argNames: ["that", "peerId", "message", "timeoutMs"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportSetActivity({required QuicTransport that, required Activity activity}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_activity(activity, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSetActivityConstMeta,
+      argValues: [that, activity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSetActivityConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_set_activity",
+        argNames: ["that", "activity"],
+      );
+
+  @override
+  void crateTransportQuicQuicTransportSetIdentity({required QuicTransport that, required DeviceIdentity identity}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+            identity, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSetIdentityConstMeta,
+      argValues: [that, identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSetIdentityConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_set_identity",
+        argNames: ["that", "identity"],
+      );
+
+  @override
+  void crateTransportQuicQuicTransportSetPeerKey(
+      {required QuicTransport that, required String deviceId, required List<int> key}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSetPeerKeyConstMeta,
+      argValues: [that, deviceId, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSetPeerKeyConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_set_peer_key",
+        argNames: ["that", "deviceId", "key"],
+      );
+
+  @override
+  void crateTransportQuicQuicTransportSetPeerPublicKey(
+      {required QuicTransport that, required String deviceId, required List<int> publicKey}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_list_prim_u_8_loose(publicKey, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSetPeerPublicKeyConstMeta,
+      argValues: [that, deviceId, publicKey],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSetPeerPublicKeyConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_set_peer_public_key",
+        argNames: ["that", "deviceId", "publicKey"],
+      );
+
+  @override
+  void crateTransportQuicQuicTransportSetSealingKey({required QuicTransport that, required List<int> secretKey}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_list_prim_u_8_loose(secretKey, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportSetSealingKeyConstMeta,
+      argValues: [that, secretKey],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportSetSealingKeyConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_set_sealing_key",
+        argNames: ["that", "secretKey"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportShutdown({required QuicTransport that, required BigInt timeoutMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_u_64(timeoutMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportShutdownConstMeta,
+      argValues: [that, timeoutMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportShutdownConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_shutdown",
+        argNames: ["that", "timeoutMs"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportStartServer({required QuicTransport that, required int port}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_u_16(port, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportStartServerConstMeta,
+      argValues: [that, port],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportStartServerConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_start_server",
+        argNames: ["that", "port"],
+      );
+
+  @override
+  bool crateTransportQuicQuicTransportStopKeepingConnected({required QuicTransport that, required String deviceId}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportStopKeepingConnectedConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportStopKeepingConnectedConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_stop_keeping_connected",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  Stream<TransferEvent> crateTransportQuicQuicTransportTransferEvents({required QuicTransport that}) {
+    final sink = RustStreamSink<TransferEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_StreamSink_transfer_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportTransferEventsConstMeta,
+      argValues: [that, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportTransferEventsConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_transfer_events",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<bool> crateTransportQuicQuicTransportUnpair({required QuicTransport that, required String deviceId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(deviceId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportUnpairConstMeta,
+      argValues: [that, deviceId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportUnpairConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_unpair",
+        argNames: ["that", "deviceId"],
+      );
+
+  @override
+  void crateTransportQuicQuicTransportUseDiscovery({required QuicTransport that, required MdnsDiscovery discovery}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+            discovery, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportUseDiscoveryConstMeta,
+      argValues: [that, discovery],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportUseDiscoveryConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_use_discovery",
+        argNames: ["that", "discovery"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportUseRelay({required QuicTransport that, required String server}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_String(server, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportUseRelayConstMeta,
+      argValues: [that, server],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportUseRelayConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_use_relay",
+        argNames: ["that", "server"],
+      );
+
+  @override
+  Future<void> crateTransportQuicQuicTransportUseSignaling(
+      {required QuicTransport that, required SignalingClient signaling}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+            that, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+            signaling, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportUseSignalingConstMeta,
+      argValues: [that, signaling],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportUseSignalingConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_use_signaling",
+        argNames: ["that", "signaling"],
+      );
+
+  @override
+  QuicTransport crateTransportQuicQuicTransportWithIdentity({required DeviceIdentity identity}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+            identity, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportQuicQuicTransportWithIdentityConstMeta,
+      argValues: [identity],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicQuicTransportWithIdentityConstMeta => const TaskConstMeta(
+        debugName: "QuicTransport_with_identity",
+        argNames: ["identity"],
+      );
+
+  @override
+  Future<void> crateSignalingClientSignalingClientClose({required SignalingClient that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+            that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateSignalingClientSignalingClientCloseConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateSignalingClientSignalingClientCloseConstMeta => const TaskConstMeta(
+        debugName: "SignalingClient_close",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<SignalingClient> crateSignalingClientSignalingClientConnect({required String url, required String token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(url, serializer);
+        sse_encode_String(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient,
+        decodeErrorData: sse_decode_signaling_error,
+      ),
+      constMeta: kCrateSignalingClientSignalingClientConnectConstMeta,
+      argValues: [url, token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateSignalingClientSignalingClientConnectConstMeta => const TaskConstMeta(
+        debugName: "SignalingClient_connect",
+        argNames: ["url", "token"],
+      );
+
+  @override
+  String crateSignalingClientSignalingClientDeviceId({required SignalingClient that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateSignalingClientSignalingClientDeviceIdConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateSignalingClientSignalingClientDeviceIdConstMeta => const TaskConstMeta(
+        debugName: "SignalingClient_device_id",
+        argNames: ["that"],
+      );
+
+  @override
+  List<String> crateSignalingClientSignalingClientOnlinePeers({required SignalingClient that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateSignalingClientSignalingClientOnlinePeersConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateSignalingClientSignalingClientOnlinePeersConstMeta => const TaskConstMeta(
+        debugName: "SignalingClient_online_peers",
+        argNames: ["that"],
+      );
+
+  @override
+  Stream<SignalingEvent> crateSignalingClientSignalingClientSignalingEvents({required SignalingClient that}) {
+    final sink = RustStreamSink<SignalingEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+            that, serializer);
+        sse_encode_StreamSink_signaling_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateSignalingClientSignalingClientSignalingEventsConstMeta,
+      argValues: [that, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateSignalingClientSignalingClientSignalingEventsConstMeta => const TaskConstMeta(
+        debugName: "SignalingClient_signaling_events",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Activity crateTransportDatagramActivityDefault() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_activity,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportDatagramActivityDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportDatagramActivityDefaultConstMeta => const TaskConstMeta(
+        debugName: "activity_default",
+        argNames: [],
+      );
+
+  @override
+  BroadcastFilter crateTransportBroadcastBroadcastFilterDefault() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_broadcast_filter,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportBroadcastBroadcastFilterDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportBroadcastBroadcastFilterDefaultConstMeta => const TaskConstMeta(
+        debugName: "broadcast_filter_default",
+        argNames: [],
+      );
+
+  @override
+  String crateTransportTrustCertificateFingerprint({required List<int> certDer}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(certDer, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportTrustCertificateFingerprintConstMeta,
+      argValues: [certDer],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportTrustCertificateFingerprintConstMeta => const TaskConstMeta(
+        debugName: "certificate_fingerprint",
+        argNames: ["certDer"],
+      );
+
+  @override
+  Uint8List crateCryptoDecryptBytes({required List<int> ciphertext, required List<int> key}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateCryptoDecryptBytesConstMeta,
+      argValues: [ciphertext, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateCryptoDecryptBytesConstMeta => const TaskConstMeta(
+        debugName: "decrypt_bytes",
+        argNames: ["ciphertext", "key"],
+      );
+
+  @override
+  String crateCryptoDecryptText({required List<int> ciphertext, required List<int> key}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateCryptoDecryptTextConstMeta,
+      argValues: [ciphertext, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateCryptoDecryptTextConstMeta => const TaskConstMeta(
+        debugName: "decrypt_text",
+        argNames: ["ciphertext", "key"],
+      );
+
+  @override
+  Uint8List crateCryptoDeriveSharedSecret({required List<int> mySecret, required List<int> theirPublic}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(mySecret, serializer);
+        sse_encode_list_prim_u_8_loose(theirPublic, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateCryptoDeriveSharedSecretConstMeta,
+      argValues: [mySecret, theirPublic],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateCryptoDeriveSharedSecretConstMeta => const TaskConstMeta(
+        debugName: "derive_shared_secret",
+        argNames: ["mySecret", "theirPublic"],
+      );
+
+  @override
+  Uint8List crateCryptoEncryptBytes({required List<int> plaintext, required List<int> key}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(plaintext, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateCryptoEncryptBytesConstMeta,
+      argValues: [plaintext, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateCryptoEncryptBytesConstMeta => const TaskConstMeta(
+        debugName: "encrypt_bytes",
+        argNames: ["plaintext", "key"],
+      );
+
+  @override
+  Uint8List crateCryptoEncryptText({required String plaintext, required List<int> key}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(plaintext, serializer);
+        sse_encode_list_prim_u_8_loose(key, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateCryptoEncryptTextConstMeta,
+      argValues: [plaintext, key],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateCryptoEncryptTextConstMeta => const TaskConstMeta(
+        debugName: "encrypt_text",
+        argNames: ["plaintext", "key"],
+      );
+
+  @override
+  String crateTransportAddressFormatPeerAddress({required String addr, required int port}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(addr, serializer);
+        sse_encode_u_16(port, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportAddressFormatPeerAddressConstMeta,
+      argValues: [addr, port],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportAddressFormatPeerAddressConstMeta => const TaskConstMeta(
+        debugName: "format_peer_address",
+        argNames: ["addr", "port"],
+      );
+
+  @override
+  Uint8List crateCryptoGenerateKey() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateCryptoGenerateKeyConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateCryptoGenerateKeyConstMeta => const TaskConstMeta(
+        debugName: "generate_key",
+        argNames: [],
+      );
+
+  @override
+  (Uint8List, Uint8List) crateCryptoGenerateKeypair() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateCryptoGenerateKeypairConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateCryptoGenerateKeypairConstMeta => const TaskConstMeta(
+        debugName: "generate_keypair",
+        argNames: [],
+      );
+
+  @override
+  (Uint8List, Uint8List) crateTransportQuicGenerateSelfSignedCert() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_transport_error,
+      ),
+      constMeta: kCrateTransportQuicGenerateSelfSignedCertConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportQuicGenerateSelfSignedCertConstMeta => const TaskConstMeta(
+        debugName: "generate_self_signed_cert",
+        argNames: [],
+      );
+
+  @override
+  void crateInit() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateInitConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateInitConstMeta => const TaskConstMeta(
+        debugName: "init",
+        argNames: [],
+      );
+
+  @override
+  TransportOptions crateTransportOptionsTransportOptionsDefault() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_transport_options,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportOptionsTransportOptionsDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportOptionsTransportOptionsDefaultConstMeta => const TaskConstMeta(
+        debugName: "transport_options_default",
+        argNames: [],
+      );
+
+  @override
+  TransportOptions crateTransportOptionsTransportOptionsDesktop() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_transport_options,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportOptionsTransportOptionsDesktopConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportOptionsTransportOptionsDesktopConstMeta => const TaskConstMeta(
+        debugName: "transport_options_desktop",
+        argNames: [],
+      );
+
+  @override
+  TransportOptions crateTransportOptionsTransportOptionsMobile() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_transport_options,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateTransportOptionsTransportOptionsMobileConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateTransportOptionsTransportOptionsMobileConstMeta => const TaskConstMeta(
+        debugName: "transport_options_mobile",
+        argNames: [],
+      );
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DeviceIdentity =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DeviceIdentity =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MdnsDiscovery =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MdnsDiscovery =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_QuicTransport =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_QuicTransport =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SignalingClient => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SignalingClient => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  DeviceIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MdnsDiscovery dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MdnsDiscoveryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicTransport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SignalingClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SignalingClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicTransport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  DeviceIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MdnsDiscovery dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MdnsDiscoveryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicTransport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SignalingClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SignalingClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Map<String, BroadcastOutcome> dco_decode_Map_String_broadcast_outcome_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(dco_decode_list_record_string_broadcast_outcome(raw).map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  DeviceIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DeviceIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MdnsDiscovery dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MdnsDiscoveryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicTransport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SignalingClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SignalingClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<ConnectionEvent> dco_decode_StreamSink_connection_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LinkEvent> dco_decode_StreamSink_link_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<List<PeerStats>> dco_decode_StreamSink_list_peer_stats_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ReceivedItem> dco_decode_StreamSink_received_item_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<SignalingEvent> dco_decode_StreamSink_signaling_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<TransferEvent> dco_decode_StreamSink_transfer_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  Activity dco_decode_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Activity.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BroadcastFilter dco_decode_box_autoadd_broadcast_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_broadcast_filter(raw);
+  }
+
+  @protected
+  DisconnectReason dco_decode_box_autoadd_disconnect_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_disconnect_reason(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  LinkQuality dco_decode_box_autoadd_link_quality(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_link_quality(raw);
+  }
+
+  @protected
+  PeerInfo dco_decode_box_autoadd_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_peer_info(raw);
+  }
+
+  @protected
+  SyncMessage dco_decode_box_autoadd_sync_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_message(raw);
+  }
+
+  @protected
+  TransportOptions dco_decode_box_autoadd_transport_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_transport_options(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BroadcastFilter dco_decode_broadcast_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BroadcastFilter(
+      include: dco_decode_list_String(arr[0]),
+      exclude: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  BroadcastOutcome dco_decode_broadcast_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return const BroadcastOutcome_Sent();
+      case 1:
+        return const BroadcastOutcome_NotConnected();
+      case 2:
+        return const BroadcastOutcome_NoKey();
+      case 3:
+        return const BroadcastOutcome_Deposited();
+      case 4:
+        return BroadcastOutcome_Failed(
+          error: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CongestionController dco_decode_congestion_controller(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CongestionController.values[raw as int];
+  }
+
+  @protected
+  ConnectionEvent dco_decode_connection_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ConnectionEvent_PeerConnected(
+          peerId: dco_decode_String(raw[1]),
+          address: dco_decode_String(raw[2]),
+          outbound: dco_decode_bool(raw[3]),
+        );
+      case 1:
+        return ConnectionEvent_PeerDisconnected(
+          peerId: dco_decode_String(raw[1]),
+          reason: dco_decode_box_autoadd_disconnect_reason(raw[2]),
+          byPeer: dco_decode_bool(raw[3]),
+        );
+      case 2:
+        return ConnectionEvent_HandshakeFailed(
+          address: dco_decode_String(raw[1]),
+          error: dco_decode_String(raw[2]),
+        );
+      case 3:
+        return ConnectionEvent_ConnectionMigrated(
+          peerId: dco_decode_String(raw[1]),
+          oldAddress: dco_decode_String(raw[2]),
+          newAddress: dco_decode_String(raw[3]),
+        );
+      case 4:
+        return ConnectionEvent_PeerStateChanged(
+          peerId: dco_decode_String(raw[1]),
+          state: dco_decode_peer_state(raw[2]),
+          attempt: dco_decode_u_32(raw[3]),
+          retryInMs: dco_decode_u_64(raw[4]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DeliveryResult dco_decode_delivery_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DeliveryResult_Delivered(
+          itemId: dco_decode_u_64(raw[1]),
+        );
+      case 1:
+        return DeliveryResult_Rejected(
+          itemId: dco_decode_u_64(raw[1]),
+          reason: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return DeliveryResult_TimedOut(
+          itemId: dco_decode_u_64(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DisconnectReason dco_decode_disconnect_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return const DisconnectReason_UserDisconnect();
+      case 1:
+        return const DisconnectReason_Unpaired();
+      case 2:
+        return const DisconnectReason_VersionMismatch();
+      case 3:
+        return const DisconnectReason_ShuttingDown();
+      case 4:
+        return const DisconnectReason_RateLimited();
+      case 5:
+        return const DisconnectReason_Replaced();
+      case 6:
+        return const DisconnectReason_ProtocolViolation();
+      case 7:
+        return const DisconnectReason_TimedOut();
+      case 8:
+        return DisconnectReason_ConnectionLost(
+          error: dco_decode_String(raw[1]),
+        );
+      case 9:
+        return DisconnectReason_Unknown(
+          code: dco_decode_u_64(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DiscoveryError dco_decode_discovery_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DiscoveryError_Registration(
+          dco_decode_String(raw[1]),
+        );
+      case 1:
+        return DiscoveryError_Browse(
+          dco_decode_String(raw[1]),
+        );
+      case 2:
+        return DiscoveryError_Parse(
+          dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  LinkEvent dco_decode_link_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return LinkEvent_QualityChanged(
+          quality: dco_decode_box_autoadd_link_quality(raw[1]),
+        );
+      case 1:
+        return LinkEvent_ActivityChanged(
+          peerId: dco_decode_String(raw[1]),
+          activity: dco_decode_activity(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  LinkQuality dco_decode_link_quality(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LinkQuality(
+      peerId: dco_decode_String(arr[0]),
+      rttMs: dco_decode_opt_box_autoadd_f_64(arr[1]),
+      loss: dco_decode_f_64(arr[2]),
+      lastHeardMs: dco_decode_u_64(arr[3]),
+      activity: dco_decode_activity(arr[4]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<PeerInfo> dco_decode_list_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_info).toList();
+  }
+
+  @protected
+  List<PeerStats> dco_decode_list_peer_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_peer_stats).toList();
+  }
+
+  @protected
+  List<PendingTransfer> dco_decode_list_pending_transfer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pending_transfer).toList();
+  }
+
+  @protected
+  List<PinnedFingerprint> dco_decode_list_pinned_fingerprint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pinned_fingerprint).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, BroadcastOutcome)> dco_decode_list_record_string_broadcast_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_broadcast_outcome).toList();
+  }
+
+  @protected
+  NatReport dco_decode_nat_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NatReport(
+      mappedAddress: dco_decode_String(arr[0]),
+      natType: dco_decode_nat_type(arr[1]),
+    );
+  }
+
+  @protected
+  NatType dco_decode_nat_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NatType.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  LinkQuality? dco_decode_opt_box_autoadd_link_quality(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_link_quality(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  PeerInfo dco_decode_peer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PeerInfo(
+      deviceId: dco_decode_String(arr[0]),
+      deviceName: dco_decode_String(arr[1]),
+      addresses: dco_decode_list_String(arr[2]),
+      port: dco_decode_u_16(arr[3]),
+      discoveredAt: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  PeerState dco_decode_peer_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PeerState.values[raw as int];
+  }
+
+  @protected
+  PeerStats dco_decode_peer_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return PeerStats(
+      peerId: dco_decode_String(arr[0]),
+      address: dco_decode_String(arr[1]),
+      rttMs: dco_decode_f_64(arr[2]),
+      congestionWindow: dco_decode_u_64(arr[3]),
+      bytesSent: dco_decode_u_64(arr[4]),
+      bytesReceived: dco_decode_u_64(arr[5]),
+      packetsSent: dco_decode_u_64(arr[6]),
+      packetsReceived: dco_decode_u_64(arr[7]),
+      lostPackets: dco_decode_u_64(arr[8]),
+      congestionEvents: dco_decode_u_64(arr[9]),
+      mtu: dco_decode_u_16(arr[10]),
+      connectedMs: dco_decode_u_64(arr[11]),
+    );
+  }
+
+  @protected
+  PendingTransfer dco_decode_pending_transfer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PendingTransfer(
+      transferId: dco_decode_u_64(arr[0]),
+      peerId: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+      transferred: dco_decode_u_64(arr[4]),
+      outbound: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  PinnedFingerprint dco_decode_pinned_fingerprint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PinnedFingerprint(
+      deviceId: dco_decode_String(arr[0]),
+      fingerprint: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ReceivedItem dco_decode_received_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ReceivedItem(
+      peerId: dco_decode_String(arr[0]),
+      itemId: dco_decode_u_64(arr[1]),
+      contentType: dco_decode_String(arr[2]),
+      timestamp: dco_decode_u_64(arr[3]),
+      payload: dco_decode_list_prim_u_8_strict(arr[4]),
+      requiresAck: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  (Uint8List, Uint8List) dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_list_prim_u_8_strict(arr[0]),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (String, BroadcastOutcome) dco_decode_record_string_broadcast_outcome(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_broadcast_outcome(arr[1]),
+    );
+  }
+
+  @protected
+  SignalingError dco_decode_signaling_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SignalingError_Connection(
+          dco_decode_String(raw[1]),
+        );
+      case 1:
+        return SignalingError_Unauthorized(
+          dco_decode_String(raw[1]),
+        );
+      case 2:
+        return SignalingError_Protocol(
+          dco_decode_String(raw[1]),
+        );
+      case 3:
+        return const SignalingError_Closed();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SignalingEvent dco_decode_signaling_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SignalingEvent_PeerOnline(
+          deviceId: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return SignalingEvent_PeerOffline(
+          deviceId: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return SignalingEvent_Disconnected(
+          error: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SyncMessage dco_decode_sync_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SyncMessage_Hello(
+          deviceId: dco_decode_String(raw[1]),
+          minVersion: dco_decode_u_16(raw[2]),
+          maxVersion: dco_decode_u_16(raw[3]),
+        );
+      case 1:
+        return SyncMessage_ClipboardItem(
+          itemId: dco_decode_u_64(raw[1]),
+          contentType: dco_decode_String(raw[2]),
+          timestamp: dco_decode_u_64(raw[3]),
+          payload: dco_decode_list_prim_u_8_strict(raw[4]),
+        );
+      case 2:
+        return SyncMessage_Ack(
+          itemId: dco_decode_u_64(raw[1]),
+        );
+      case 3:
+        return SyncMessage_Ping(
+          nonce: dco_decode_u_64(raw[1]),
+        );
+      case 4:
+        return SyncMessage_Pong(
+          nonce: dco_decode_u_64(raw[1]),
+        );
+      case 5:
+        return SyncMessage_HistoryRequest(
+          since: dco_decode_u_64(raw[1]),
+          limit: dco_decode_u_32(raw[2]),
+        );
+      case 6:
+        return SyncMessage_Error(
+          code: dco_decode_u_16(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      case 7:
+        return SyncMessage_TransferOffer(
+          transferId: dco_decode_u_64(raw[1]),
+          name: dco_decode_String(raw[2]),
+          contentType: dco_decode_String(raw[3]),
+          size: dco_decode_u_64(raw[4]),
+        );
+      case 8:
+        return SyncMessage_TransferAccept(
+          transferId: dco_decode_u_64(raw[1]),
+          offset: dco_decode_u_64(raw[2]),
+        );
+      case 9:
+        return SyncMessage_TransferChunk(
+          offset: dco_decode_u_64(raw[1]),
+          data: dco_decode_list_prim_u_8_strict(raw[2]),
+        );
+      case 10:
+        return SyncMessage_TransferEnd(
+          sha256: dco_decode_list_prim_u_8_strict(raw[1]),
+        );
+      case 11:
+        return SyncMessage_TransferAck(
+          transferId: dco_decode_u_64(raw[1]),
+          offset: dco_decode_u_64(raw[2]),
+        );
+      case 12:
+        return SyncMessage_Presence(
+          activity: dco_decode_activity(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TransferEvent dco_decode_transfer_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TransferEvent_Started(
+          transferId: dco_decode_u_64(raw[1]),
+          peerId: dco_decode_String(raw[2]),
+          name: dco_decode_String(raw[3]),
+          size: dco_decode_u_64(raw[4]),
+          outbound: dco_decode_bool(raw[5]),
+          offset: dco_decode_u_64(raw[6]),
+        );
+      case 1:
+        return TransferEvent_Progress(
+          transferId: dco_decode_u_64(raw[1]),
+          peerId: dco_decode_String(raw[2]),
+          transferred: dco_decode_u_64(raw[3]),
+          size: dco_decode_u_64(raw[4]),
+        );
+      case 2:
+        return TransferEvent_Completed(
+          transferId: dco_decode_u_64(raw[1]),
+          peerId: dco_decode_String(raw[2]),
+          path: dco_decode_String(raw[3]),
+          sha256: dco_decode_String(raw[4]),
+        );
+      case 3:
+        return TransferEvent_Failed(
+          transferId: dco_decode_u_64(raw[1]),
+          peerId: dco_decode_String(raw[2]),
+          error: dco_decode_String(raw[3]),
+        );
+      case 4:
+        return TransferEvent_Cancelled(
+          transferId: dco_decode_u_64(raw[1]),
+          peerId: dco_decode_String(raw[2]),
+          byPeer: dco_decode_bool(raw[3]),
+        );
+      case 5:
+        return TransferEvent_Interrupted(
+          transferId: dco_decode_u_64(raw[1]),
+          peerId: dco_decode_String(raw[2]),
+          transferred: dco_decode_u_64(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TransportError dco_decode_transport_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return TransportError_Connection(
+          dco_decode_String(raw[1]),
+        );
+      case 1:
+        return TransportError_Io(
+          dco_decode_String(raw[1]),
+        );
+      case 2:
+        return TransportError_Tls(
+          dco_decode_String(raw[1]),
+        );
+      case 3:
+        return const TransportError_NotConnected();
+      case 4:
+        return TransportError_PeerNotFound(
+          dco_decode_String(raw[1]),
+        );
+      case 5:
+        return TransportError_FingerprintMismatch(
+          deviceId: dco_decode_String(raw[1]),
+          expected: dco_decode_String(raw[2]),
+          actual: dco_decode_String(raw[3]),
+        );
+      case 6:
+        return TransportError_Protocol(
+          dco_decode_String(raw[1]),
+        );
+      case 7:
+        return TransportError_Cancelled(
+          dco_decode_String(raw[1]),
+        );
+      case 8:
+        return TransportError_Rejected(
+          dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TransportOptions dco_decode_transport_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return TransportOptions(
+      bindAddress: dco_decode_String(arr[0]),
+      dualStack: dco_decode_bool(arr[1]),
+      keepAliveIntervalMs: dco_decode_u_64(arr[2]),
+      idleTimeoutMs: dco_decode_u_64(arr[3]),
+      maxConcurrentStreams: dco_decode_u_32(arr[4]),
+      maxMessageSize: dco_decode_u_64(arr[5]),
+      receiveWindow: dco_decode_u_64(arr[6]),
+      congestionController: dco_decode_congestion_controller(arr[7]),
+      maxTransferSize: dco_decode_u_64(arr[8]),
+      transferDirectory: dco_decode_String(arr[9]),
+      spoolDirectory: dco_decode_String(arr[10]),
+      heartbeatIntervalMs: dco_decode_u_64(arr[11]),
+      directUpgradeIntervalMs: dco_decode_u_64(arr[12]),
+      mailboxTtlMs: dco_decode_u_64(arr[13]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  DeviceIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DeviceIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  MdnsDiscovery sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MdnsDiscoveryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  QuicTransport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SignalingClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SignalingClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  QuicTransport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  DeviceIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DeviceIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  MdnsDiscovery sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MdnsDiscoveryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  QuicTransport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SignalingClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SignalingClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Map<String, BroadcastOutcome> sse_decode_Map_String_broadcast_outcome_None(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_broadcast_outcome(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  DeviceIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return DeviceIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  MdnsDiscovery sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MdnsDiscoveryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  QuicTransport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SignalingClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SignalingClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RustStreamSink<ConnectionEvent> sse_decode_StreamSink_connection_event_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LinkEvent> sse_decode_StreamSink_link_event_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<List<PeerStats>> sse_decode_StreamSink_list_peer_stats_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ReceivedItem> sse_decode_StreamSink_received_item_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<SignalingEvent> sse_decode_StreamSink_signaling_event_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<TransferEvent> sse_decode_StreamSink_transfer_event_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  Activity sse_decode_activity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Activity.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BroadcastFilter sse_decode_box_autoadd_broadcast_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_broadcast_filter(deserializer));
+  }
+
+  @protected
+  DisconnectReason sse_decode_box_autoadd_disconnect_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_disconnect_reason(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  LinkQuality sse_decode_box_autoadd_link_quality(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_link_quality(deserializer));
+  }
+
+  @protected
+  PeerInfo sse_decode_box_autoadd_peer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_peer_info(deserializer));
+  }
+
+  @protected
+  SyncMessage sse_decode_box_autoadd_sync_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_message(deserializer));
+  }
+
+  @protected
+  TransportOptions sse_decode_box_autoadd_transport_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_transport_options(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  BroadcastFilter sse_decode_broadcast_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_include = sse_decode_list_String(deserializer);
+    var var_exclude = sse_decode_list_String(deserializer);
+    return BroadcastFilter(include: var_include, exclude: var_exclude);
+  }
+
+  @protected
+  BroadcastOutcome sse_decode_broadcast_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return const BroadcastOutcome_Sent();
+      case 1:
+        return const BroadcastOutcome_NotConnected();
+      case 2:
+        return const BroadcastOutcome_NoKey();
+      case 3:
+        return const BroadcastOutcome_Deposited();
+      case 4:
+        var var_error = sse_decode_String(deserializer);
+        return BroadcastOutcome_Failed(error: var_error);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CongestionController sse_decode_congestion_controller(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CongestionController.values[inner];
+  }
+
+  @protected
+  ConnectionEvent sse_decode_connection_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_peerId = sse_decode_String(deserializer);
+        var var_address = sse_decode_String(deserializer);
+        var var_outbound = sse_decode_bool(deserializer);
+        return ConnectionEvent_PeerConnected(peerId: var_peerId, address: var_address, outbound: var_outbound);
+      case 1:
+        var var_peerId = sse_decode_String(deserializer);
+        var var_reason = sse_decode_box_autoadd_disconnect_reason(deserializer);
+        var var_byPeer = sse_decode_bool(deserializer);
+        return ConnectionEvent_PeerDisconnected(peerId: var_peerId, reason: var_reason, byPeer: var_byPeer);
+      case 2:
+        var var_address = sse_decode_String(deserializer);
+        var var_error = sse_decode_String(deserializer);
+        return ConnectionEvent_HandshakeFailed(address: var_address, error: var_error);
+      case 3:
+        var var_peerId = sse_decode_String(deserializer);
+        var var_oldAddress = sse_decode_String(deserializer);
+        var var_newAddress = sse_decode_String(deserializer);
+        return ConnectionEvent_ConnectionMigrated(
+            peerId: var_peerId, oldAddress: var_oldAddress, newAddress: var_newAddress);
+      case 4:
+        var var_peerId = sse_decode_String(deserializer);
+        var var_state = sse_decode_peer_state(deserializer);
+        var var_attempt = sse_decode_u_32(deserializer);
+        var var_retryInMs = sse_decode_u_64(deserializer);
+        return ConnectionEvent_PeerStateChanged(
+            peerId: var_peerId, state: var_state, attempt: var_attempt, retryInMs: var_retryInMs);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DeliveryResult sse_decode_delivery_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_itemId = sse_decode_u_64(deserializer);
+        return DeliveryResult_Delivered(itemId: var_itemId);
+      case 1:
+        var var_itemId = sse_decode_u_64(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return DeliveryResult_Rejected(itemId: var_itemId, reason: var_reason);
+      case 2:
+        var var_itemId = sse_decode_u_64(deserializer);
+        return DeliveryResult_TimedOut(itemId: var_itemId);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DisconnectReason sse_decode_disconnect_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return const DisconnectReason_UserDisconnect();
+      case 1:
+        return const DisconnectReason_Unpaired();
+      case 2:
+        return const DisconnectReason_VersionMismatch();
+      case 3:
+        return const DisconnectReason_ShuttingDown();
+      case 4:
+        return const DisconnectReason_RateLimited();
+      case 5:
+        return const DisconnectReason_Replaced();
+      case 6:
+        return const DisconnectReason_ProtocolViolation();
+      case 7:
+        return const DisconnectReason_TimedOut();
+      case 8:
+        var var_error = sse_decode_String(deserializer);
+        return DisconnectReason_ConnectionLost(error: var_error);
+      case 9:
+        var var_code = sse_decode_u_64(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return DisconnectReason_Unknown(code: var_code, message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DiscoveryError sse_decode_discovery_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return DiscoveryError_Registration(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return DiscoveryError_Browse(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return DiscoveryError_Parse(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  LinkEvent sse_decode_link_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_quality = sse_decode_box_autoadd_link_quality(deserializer);
+        return LinkEvent_QualityChanged(quality: var_quality);
+      case 1:
+        var var_peerId = sse_decode_String(deserializer);
+        var var_activity = sse_decode_activity(deserializer);
+        return LinkEvent_ActivityChanged(peerId: var_peerId, activity: var_activity);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  LinkQuality sse_decode_link_quality(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_String(deserializer);
+    var var_rttMs = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_loss = sse_decode_f_64(deserializer);
+    var var_lastHeardMs = sse_decode_u_64(deserializer);
+    var var_activity = sse_decode_activity(deserializer);
+    return LinkQuality(
+        peerId: var_peerId, rttMs: var_rttMs, loss: var_loss, lastHeardMs: var_lastHeardMs, activity: var_activity);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PeerStats> sse_decode_list_peer_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PeerStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_peer_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PendingTransfer> sse_decode_list_pending_transfer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PendingTransfer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pending_transfer(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PinnedFingerprint> sse_decode_list_pinned_fingerprint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PinnedFingerprint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pinned_fingerprint(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, BroadcastOutcome)> sse_decode_list_record_string_broadcast_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, BroadcastOutcome)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_broadcast_outcome(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  NatReport sse_decode_nat_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mappedAddress = sse_decode_String(deserializer);
+    var var_natType = sse_decode_nat_type(deserializer);
+    return NatReport(mappedAddress: var_mappedAddress, natType: var_natType);
+  }
+
+  @protected
+  NatType sse_decode_nat_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NatType.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LinkQuality? sse_decode_opt_box_autoadd_link_quality(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_link_quality(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PeerInfo sse_decode_peer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deviceId = sse_decode_String(deserializer);
+    var var_deviceName = sse_decode_String(deserializer);
+    var var_addresses = sse_decode_list_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_discoveredAt = sse_decode_u_64(deserializer);
+    return PeerInfo(
+        deviceId: var_deviceId,
+        deviceName: var_deviceName,
+        addresses: var_addresses,
+        port: var_port,
+        discoveredAt: var_discoveredAt);
+  }
+
+  @protected
+  PeerState sse_decode_peer_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PeerState.values[inner];
+  }
+
+  @protected
+  PeerStats sse_decode_peer_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_String(deserializer);
+    var var_address = sse_decode_String(deserializer);
+    var var_rttMs = sse_decode_f_64(deserializer);
+    var var_congestionWindow = sse_decode_u_64(deserializer);
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_packetsSent = sse_decode_u_64(deserializer);
+    var var_packetsReceived = sse_decode_u_64(deserializer);
+    var var_lostPackets = sse_decode_u_64(deserializer);
+    var var_congestionEvents = sse_decode_u_64(deserializer);
+    var var_mtu = sse_decode_u_16(deserializer);
+    var var_connectedMs = sse_decode_u_64(deserializer);
+    return PeerStats(
+        peerId: var_peerId,
+        address: var_address,
+        rttMs: var_rttMs,
+        congestionWindow: var_congestionWindow,
+        bytesSent: var_bytesSent,
+        bytesReceived: var_bytesReceived,
+        packetsSent: var_packetsSent,
+        packetsReceived: var_packetsReceived,
+        lostPackets: var_lostPackets,
+        congestionEvents: var_congestionEvents,
+        mtu: var_mtu,
+        connectedMs: var_connectedMs);
+  }
+
+  @protected
+  PendingTransfer sse_decode_pending_transfer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_transferId = sse_decode_u_64(deserializer);
+    var var_peerId = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_transferred = sse_decode_u_64(deserializer);
+    var var_outbound = sse_decode_bool(deserializer);
+    return PendingTransfer(
+        transferId: var_transferId,
+        peerId: var_peerId,
+        name: var_name,
+        size: var_size,
+        transferred: var_transferred,
+        outbound: var_outbound);
+  }
+
+  @protected
+  PinnedFingerprint sse_decode_pinned_fingerprint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deviceId = sse_decode_String(deserializer);
+    var var_fingerprint = sse_decode_String(deserializer);
+    return PinnedFingerprint(deviceId: var_deviceId, fingerprint: var_fingerprint);
+  }
+
+  @protected
+  ReceivedItem sse_decode_received_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_peerId = sse_decode_String(deserializer);
+    var var_itemId = sse_decode_u_64(deserializer);
+    var var_contentType = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_requiresAck = sse_decode_bool(deserializer);
+    return ReceivedItem(
+        peerId: var_peerId,
+        itemId: var_itemId,
+        contentType: var_contentType,
+        timestamp: var_timestamp,
+        payload: var_payload,
+        requiresAck: var_requiresAck);
+  }
+
+  @protected
+  (Uint8List, Uint8List) sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (String, BroadcastOutcome) sse_decode_record_string_broadcast_outcome(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_broadcast_outcome(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  SignalingError sse_decode_signaling_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return SignalingError_Connection(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return SignalingError_Unauthorized(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return SignalingError_Protocol(var_field0);
+      case 3:
+        return const SignalingError_Closed();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SignalingEvent sse_decode_signaling_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_deviceId = sse_decode_String(deserializer);
+        return SignalingEvent_PeerOnline(deviceId: var_deviceId);
+      case 1:
+        var var_deviceId = sse_decode_String(deserializer);
+        return SignalingEvent_PeerOffline(deviceId: var_deviceId);
+      case 2:
+        var var_error = sse_decode_String(deserializer);
+        return SignalingEvent_Disconnected(error: var_error);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SyncMessage sse_decode_sync_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_deviceId = sse_decode_String(deserializer);
+        var var_minVersion = sse_decode_u_16(deserializer);
+        var var_maxVersion = sse_decode_u_16(deserializer);
+        return SyncMessage_Hello(deviceId: var_deviceId, minVersion: var_minVersion, maxVersion: var_maxVersion);
+      case 1:
+        var var_itemId = sse_decode_u_64(deserializer);
+        var var_contentType = sse_decode_String(deserializer);
+        var var_timestamp = sse_decode_u_64(deserializer);
+        var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+        return SyncMessage_ClipboardItem(
+            itemId: var_itemId, contentType: var_contentType, timestamp: var_timestamp, payload: var_payload);
+      case 2:
+        var var_itemId = sse_decode_u_64(deserializer);
+        return SyncMessage_Ack(itemId: var_itemId);
+      case 3:
+        var var_nonce = sse_decode_u_64(deserializer);
+        return SyncMessage_Ping(nonce: var_nonce);
+      case 4:
+        var var_nonce = sse_decode_u_64(deserializer);
+        return SyncMessage_Pong(nonce: var_nonce);
+      case 5:
+        var var_since = sse_decode_u_64(deserializer);
+        var var_limit = sse_decode_u_32(deserializer);
+        return SyncMessage_HistoryRequest(since: var_since, limit: var_limit);
+      case 6:
+        var var_code = sse_decode_u_16(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return SyncMessage_Error(code: var_code, message: var_message);
+      case 7:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_name = sse_decode_String(deserializer);
+        var var_contentType = sse_decode_String(deserializer);
+        var var_size = sse_decode_u_64(deserializer);
+        return SyncMessage_TransferOffer(
+            transferId: var_transferId, name: var_name, contentType: var_contentType, size: var_size);
+      case 8:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_offset = sse_decode_u_64(deserializer);
+        return SyncMessage_TransferAccept(transferId: var_transferId, offset: var_offset);
+      case 9:
+        var var_offset = sse_decode_u_64(deserializer);
+        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+        return SyncMessage_TransferChunk(offset: var_offset, data: var_data);
+      case 10:
+        var var_sha256 = sse_decode_list_prim_u_8_strict(deserializer);
+        return SyncMessage_TransferEnd(sha256: var_sha256);
+      case 11:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_offset = sse_decode_u_64(deserializer);
+        return SyncMessage_TransferAck(transferId: var_transferId, offset: var_offset);
+      case 12:
+        var var_activity = sse_decode_activity(deserializer);
+        return SyncMessage_Presence(activity: var_activity);
+      default:
+        throw UnimplementedError('');
+    }
+  }
 
   @protected
-  QuicTransport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  TransferEvent sse_decode_transfer_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_peerId = sse_decode_String(deserializer);
+        var var_name = sse_decode_String(deserializer);
+        var var_size = sse_decode_u_64(deserializer);
+        var var_outbound = sse_decode_bool(deserializer);
+        var var_offset = sse_decode_u_64(deserializer);
+        return TransferEvent_Started(
+            transferId: var_transferId,
+            peerId: var_peerId,
+            name: var_name,
+            size: var_size,
+            outbound: var_outbound,
+            offset: var_offset);
+      case 1:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_peerId = sse_decode_String(deserializer);
+        var var_transferred = sse_decode_u_64(deserializer);
+        var var_size = sse_decode_u_64(deserializer);
+        return TransferEvent_Progress(
+            transferId: var_transferId, peerId: var_peerId, transferred: var_transferred, size: var_size);
+      case 2:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_peerId = sse_decode_String(deserializer);
+        var var_path = sse_decode_String(deserializer);
+        var var_sha256 = sse_decode_String(deserializer);
+        return TransferEvent_Completed(
+            transferId: var_transferId, peerId: var_peerId, path: var_path, sha256: var_sha256);
+      case 3:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_peerId = sse_decode_String(deserializer);
+        var var_error = sse_decode_String(deserializer);
+        return TransferEvent_Failed(transferId: var_transferId, peerId: var_peerId, error: var_error);
+      case 4:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_peerId = sse_decode_String(deserializer);
+        var var_byPeer = sse_decode_bool(deserializer);
+        return TransferEvent_Cancelled(transferId: var_transferId, peerId: var_peerId, byPeer: var_byPeer);
+      case 5:
+        var var_transferId = sse_decode_u_64(deserializer);
+        var var_peerId = sse_decode_String(deserializer);
+        var var_transferred = sse_decode_u_64(deserializer);
+        return TransferEvent_Interrupted(transferId: var_transferId, peerId: var_peerId, transferred: var_transferred);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  MdnsDiscovery dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MdnsDiscoveryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  TransportError sse_decode_transport_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return TransportError_Connection(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return TransportError_Io(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return TransportError_Tls(var_field0);
+      case 3:
+        return const TransportError_NotConnected();
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return TransportError_PeerNotFound(var_field0);
+      case 5:
+        var var_deviceId = sse_decode_String(deserializer);
+        var var_expected = sse_decode_String(deserializer);
+        var var_actual = sse_decode_String(deserializer);
+        return TransportError_FingerprintMismatch(deviceId: var_deviceId, expected: var_expected, actual: var_actual);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return TransportError_Protocol(var_field0);
+      case 7:
+        var var_field0 = sse_decode_String(deserializer);
+        return TransportError_Cancelled(var_field0);
+      case 8:
+        var var_field0 = sse_decode_String(deserializer);
+        return TransportError_Rejected(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  QuicTransport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  TransportOptions sse_decode_transport_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bindAddress = sse_decode_String(deserializer);
+    var var_dualStack = sse_decode_bool(deserializer);
+    var var_keepAliveIntervalMs = sse_decode_u_64(deserializer);
+    var var_idleTimeoutMs = sse_decode_u_64(deserializer);
+    var var_maxConcurrentStreams = sse_decode_u_32(deserializer);
+    var var_maxMessageSize = sse_decode_u_64(deserializer);
+    var var_receiveWindow = sse_decode_u_64(deserializer);
+    var var_congestionController = sse_decode_congestion_controller(deserializer);
+    var var_maxTransferSize = sse_decode_u_64(deserializer);
+    var var_transferDirectory = sse_decode_String(deserializer);
+    var var_spoolDirectory = sse_decode_String(deserializer);
+    var var_heartbeatIntervalMs = sse_decode_u_64(deserializer);
+    var var_directUpgradeIntervalMs = sse_decode_u_64(deserializer);
+    var var_mailboxTtlMs = sse_decode_u_64(deserializer);
+    return TransportOptions(
+        bindAddress: var_bindAddress,
+        dualStack: var_dualStack,
+        keepAliveIntervalMs: var_keepAliveIntervalMs,
+        idleTimeoutMs: var_idleTimeoutMs,
+        maxConcurrentStreams: var_maxConcurrentStreams,
+        maxMessageSize: var_maxMessageSize,
+        receiveWindow: var_receiveWindow,
+        congestionController: var_congestionController,
+        maxTransferSize: var_maxTransferSize,
+        transferDirectory: var_transferDirectory,
+        spoolDirectory: var_spoolDirectory,
+        heartbeatIntervalMs: var_heartbeatIntervalMs,
+        directUpgradeIntervalMs: var_directUpgradeIntervalMs,
+        mailboxTtlMs: var_mailboxTtlMs);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  DiscoveryError dco_decode_discovery_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return DiscoveryError_Registration(
-          dco_decode_String(raw[1]),
-        );
-      case 1:
-        return DiscoveryError_Browse(
-          dco_decode_String(raw[1]),
-        );
-      case 2:
-        return DiscoveryError_Parse(
-          dco_decode_String(raw[1]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  List<PeerInfo> dco_decode_list_peer_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_peer_info).toList();
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  PeerInfo dco_decode_peer_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return PeerInfo(
-      deviceId: dco_decode_String(arr[0]),
-      deviceName: dco_decode_String(arr[1]),
-      addresses: dco_decode_list_String(arr[2]),
-      port: dco_decode_u_16(arr[3]),
-      discoveredAt: dco_decode_u_64(arr[4]),
-    );
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      DeviceIdentity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as DeviceIdentityImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  (Uint8List, Uint8List) dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_list_prim_u_8_strict(arr[0]),
-      dco_decode_list_prim_u_8_strict(arr[1]),
-    );
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      MdnsDiscovery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as MdnsDiscoveryImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  TransportError dco_decode_transport_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return TransportError_Connection(
-          dco_decode_String(raw[1]),
-        );
-      case 1:
-        return TransportError_Io(
-          dco_decode_String(raw[1]),
-        );
-      case 2:
-        return TransportError_Tls(
-          dco_decode_String(raw[1]),
-        );
-      case 3:
-        return const TransportError_NotConnected();
-      case 4:
-        return TransportError_PeerNotFound(
-          dco_decode_String(raw[1]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      QuicTransport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SignalingClient self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as SignalingClientImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      QuicTransport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      DeviceIdentity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as DeviceIdentityImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      MdnsDiscovery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as MdnsDiscoveryImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      QuicTransport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SignalingClient self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as SignalingClientImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_broadcast_outcome_None(Map < String, BroadcastOutcome > self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_broadcast_outcome(self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      DeviceIdentity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as DeviceIdentityImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
+      MdnsDiscovery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as MdnsDiscoveryImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
+      QuicTransport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SignalingClient self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as SignalingClientImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_connection_event_Sse(RustStreamSink<ConnectionEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+          decodeSuccessData: sse_decode_connection_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_link_event_Sse(RustStreamSink<LinkEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+          decodeSuccessData: sse_decode_link_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_peer_stats_Sse(RustStreamSink<List<PeerStats>> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+          decodeSuccessData: sse_decode_list_peer_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_received_item_Sse(RustStreamSink<ReceivedItem> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+          decodeSuccessData: sse_decode_received_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        )), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_signaling_event_Sse(RustStreamSink<SignalingEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+          decodeSuccessData: sse_decode_signaling_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_transfer_event_Sse(RustStreamSink<TransferEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+          decodeSuccessData: sse_decode_transfer_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )), serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_activity(Activity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_broadcast_filter(BroadcastFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_broadcast_filter(self, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_box_autoadd_disconnect_reason(DisconnectReason self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_disconnect_reason(self, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_box_autoadd_link_quality(LinkQuality self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_link_quality(self, serializer);
   }
 
   @protected
-  MdnsDiscovery sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_peer_info(PeerInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return MdnsDiscoveryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_peer_info(self, serializer);
   }
 
   @protected
-  QuicTransport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_sync_message(SyncMessage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_sync_message(self, serializer);
   }
 
   @protected
-  QuicTransport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_transport_options(TransportOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_transport_options(self, serializer);
   }
 
   @protected
-  MdnsDiscovery sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return MdnsDiscoveryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_u_16(self, serializer);
   }
 
   @protected
-  QuicTransport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      SseDeserializer deserializer) {
+  void sse_encode_broadcast_filter(BroadcastFilter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_list_String(self.include, serializer);
+    sse_encode_list_String(self.exclude, serializer);
   }
 
   @protected
-  MdnsDiscovery sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      SseDeserializer deserializer) {
+  void sse_encode_broadcast_outcome(BroadcastOutcome self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return MdnsDiscoveryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    switch (self) {
+      case BroadcastOutcome_Sent():
+        sse_encode_i_32(0, serializer);
+      case BroadcastOutcome_NotConnected():
+        sse_encode_i_32(1, serializer);
+      case BroadcastOutcome_NoKey():
+        sse_encode_i_32(2, serializer);
+      case BroadcastOutcome_Deposited():
+        sse_encode_i_32(3, serializer);
+      case BroadcastOutcome_Failed(error: final error):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(error, serializer);
+    }
   }
 
   @protected
-  QuicTransport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      SseDeserializer deserializer) {
+  void sse_encode_congestion_controller(CongestionController self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicTransportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_connection_event(ConnectionEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    switch (self) {
+      case ConnectionEvent_PeerConnected(peerId: final peerId, address: final address, outbound: final outbound):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_String(address, serializer);
+        sse_encode_bool(outbound, serializer);
+      case ConnectionEvent_PeerDisconnected(peerId: final peerId, reason: final reason, byPeer: final byPeer):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_box_autoadd_disconnect_reason(reason, serializer);
+        sse_encode_bool(byPeer, serializer);
+      case ConnectionEvent_HandshakeFailed(address: final address, error: final error):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(address, serializer);
+        sse_encode_String(error, serializer);
+      case ConnectionEvent_ConnectionMigrated(
+          peerId: final peerId,
+          oldAddress: final oldAddress,
+          newAddress: final newAddress
+        ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_String(oldAddress, serializer);
+        sse_encode_String(newAddress, serializer);
+      case ConnectionEvent_PeerStateChanged(
+          peerId: final peerId,
+          state: final state,
+          attempt: final attempt,
+          retryInMs: final retryInMs
+        ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_peer_state(state, serializer);
+        sse_encode_u_32(attempt, serializer);
+        sse_encode_u_64(retryInMs, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_delivery_result(DeliveryResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    switch (self) {
+      case DeliveryResult_Delivered(itemId: final itemId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(itemId, serializer);
+      case DeliveryResult_Rejected(itemId: final itemId, reason: final reason):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_64(itemId, serializer);
+        sse_encode_String(reason, serializer);
+      case DeliveryResult_TimedOut(itemId: final itemId):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_64(itemId, serializer);
+    }
   }
 
   @protected
-  DiscoveryError sse_decode_discovery_error(SseDeserializer deserializer) {
+  void sse_encode_disconnect_reason(DisconnectReason self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DisconnectReason_UserDisconnect():
+        sse_encode_i_32(0, serializer);
+      case DisconnectReason_Unpaired():
+        sse_encode_i_32(1, serializer);
+      case DisconnectReason_VersionMismatch():
+        sse_encode_i_32(2, serializer);
+      case DisconnectReason_ShuttingDown():
+        sse_encode_i_32(3, serializer);
+      case DisconnectReason_RateLimited():
+        sse_encode_i_32(4, serializer);
+      case DisconnectReason_Replaced():
+        sse_encode_i_32(5, serializer);
+      case DisconnectReason_ProtocolViolation():
+        sse_encode_i_32(6, serializer);
+      case DisconnectReason_TimedOut():
+        sse_encode_i_32(7, serializer);
+      case DisconnectReason_ConnectionLost(error: final error):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(error, serializer);
+      case DisconnectReason_Unknown(code: final code, message: final message):
+        sse_encode_i_32(9, serializer);
+        sse_encode_u_64(code, serializer);
+        sse_encode_String(message, serializer);
+    }
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return DiscoveryError_Registration(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return DiscoveryError_Browse(var_field0);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return DiscoveryError_Parse(var_field0);
-      default:
-        throw UnimplementedError('');
+  @protected
+  void sse_encode_discovery_error(DiscoveryError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DiscoveryError_Registration(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case DiscoveryError_Browse(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case DiscoveryError_Parse(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(field0, serializer);
     }
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_link_event(LinkEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case LinkEvent_QualityChanged(quality: final quality):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_link_quality(quality, serializer);
+      case LinkEvent_ActivityChanged(peerId: final peerId, activity: final activity):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_activity(activity, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer) {
+  void sse_encode_link_quality(LinkQuality self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.peerId, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.rttMs, serializer);
+    sse_encode_f_64(self.loss, serializer);
+    sse_encode_u_64(self.lastHeardMs, serializer);
+    sse_encode_activity(self.activity, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PeerInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_peer_info(deserializer));
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_info(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_peer_stats(List<PeerStats> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_peer_stats(item, serializer);
+    }
   }
 
   @protected
-  PeerInfo sse_decode_peer_info(SseDeserializer deserializer) {
+  void sse_encode_list_pending_transfer(List<PendingTransfer> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_deviceId = sse_decode_String(deserializer);
-    var var_deviceName = sse_decode_String(deserializer);
-    var var_addresses = sse_decode_list_String(deserializer);
-    var var_port = sse_decode_u_16(deserializer);
-    var var_discoveredAt = sse_decode_u_64(deserializer);
-    return PeerInfo(
-        deviceId: var_deviceId,
-        deviceName: var_deviceName,
-        addresses: var_addresses,
-        port: var_port,
-        discoveredAt: var_discoveredAt);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pending_transfer(item, serializer);
+    }
   }
 
   @protected
-  (Uint8List, Uint8List) sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_pinned_fingerprint(List<PinnedFingerprint> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pinned_fingerprint(item, serializer);
+    }
   }
 
   @protected
-  TransportError sse_decode_transport_error(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return TransportError_Connection(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return TransportError_Io(var_field0);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return TransportError_Tls(var_field0);
-      case 3:
-        return const TransportError_NotConnected();
-      case 4:
-        var var_field0 = sse_decode_String(deserializer);
-        return TransportError_PeerNotFound(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
+  void sse_encode_list_record_string_broadcast_outcome(
+      List<(String, BroadcastOutcome)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_broadcast_outcome(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_nat_report(NatReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_String(self.mappedAddress, serializer);
+    sse_encode_nat_type(self.natType, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_nat_type(NatType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_link_quality(LinkQuality? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_link_quality(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      MdnsDiscovery self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as MdnsDiscoveryImpl).frbInternalSseEncode(move: true), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_16(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      QuicTransport self, SseSerializer serializer) {
+  void sse_encode_peer_info(PeerInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: true), serializer);
+    sse_encode_String(self.deviceId, serializer);
+    sse_encode_String(self.deviceName, serializer);
+    sse_encode_list_String(self.addresses, serializer);
+    sse_encode_u_16(self.port, serializer);
+    sse_encode_u_64(self.discoveredAt, serializer);
   }
 
   @protected
-  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      QuicTransport self, SseSerializer serializer) {
+  void sse_encode_peer_state(PeerState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      MdnsDiscovery self, SseSerializer serializer) {
+  void sse_encode_peer_stats(PeerStats self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as MdnsDiscoveryImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_String(self.peerId, serializer);
+    sse_encode_String(self.address, serializer);
+    sse_encode_f_64(self.rttMs, serializer);
+    sse_encode_u_64(self.congestionWindow, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_u_64(self.packetsSent, serializer);
+    sse_encode_u_64(self.packetsReceived, serializer);
+    sse_encode_u_64(self.lostPackets, serializer);
+    sse_encode_u_64(self.congestionEvents, serializer);
+    sse_encode_u_16(self.mtu, serializer);
+    sse_encode_u_64(self.connectedMs, serializer);
   }
 
   @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      QuicTransport self, SseSerializer serializer) {
+  void sse_encode_pending_transfer(PendingTransfer self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_u_64(self.transferId, serializer);
+    sse_encode_String(self.peerId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_u_64(self.transferred, serializer);
+    sse_encode_bool(self.outbound, serializer);
   }
 
   @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
-      MdnsDiscovery self, SseSerializer serializer) {
+  void sse_encode_pinned_fingerprint(PinnedFingerprint self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as MdnsDiscoveryImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_String(self.deviceId, serializer);
+    sse_encode_String(self.fingerprint, serializer);
   }
 
   @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
-      QuicTransport self, SseSerializer serializer) {
+  void sse_encode_received_item(ReceivedItem self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as QuicTransportImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_String(self.peerId, serializer);
+    sse_encode_u_64(self.itemId, serializer);
+    sse_encode_String(self.contentType, serializer);
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+    sse_encode_bool(self.requiresAck, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict(
+      (Uint8List, Uint8List) self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_list_prim_u_8_strict(self.$1, serializer);
+    sse_encode_list_prim_u_8_strict(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_record_string_broadcast_outcome((String, BroadcastOutcome) self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_broadcast_outcome(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_discovery_error(DiscoveryError self, SseSerializer serializer) {
+  void sse_encode_signaling_error(SignalingError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     switch (self) {
-      case DiscoveryError_Registration(field0: final field0):
+      case SignalingError_Connection(field0: final field0):
         sse_encode_i_32(0, serializer);
         sse_encode_String(field0, serializer);
-      case DiscoveryError_Browse(field0: final field0):
+      case SignalingError_Unauthorized(field0: final field0):
         sse_encode_i_32(1, serializer);
         sse_encode_String(field0, serializer);
-      case DiscoveryError_Parse(field0: final field0):
+      case SignalingError_Protocol(field0: final field0):
         sse_encode_i_32(2, serializer);
         sse_encode_String(field0, serializer);
+      case SignalingError_Closed():
+        sse_encode_i_32(3, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_signaling_event(SignalingEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
+    switch (self) {
+      case SignalingEvent_PeerOnline(deviceId: final deviceId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(deviceId, serializer);
+      case SignalingEvent_PeerOffline(deviceId: final deviceId):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(deviceId, serializer);
+      case SignalingEvent_Disconnected(error: final error):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(error, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer) {
+  void sse_encode_sync_message(SyncMessage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_peer_info(item, serializer);
+    switch (self) {
+      case SyncMessage_Hello(deviceId: final deviceId, minVersion: final minVersion, maxVersion: final maxVersion):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_u_16(minVersion, serializer);
+        sse_encode_u_16(maxVersion, serializer);
+      case SyncMessage_ClipboardItem(
+          itemId: final itemId,
+          contentType: final contentType,
+          timestamp: final timestamp,
+          payload: final payload
+        ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_64(itemId, serializer);
+        sse_encode_String(contentType, serializer);
+        sse_encode_u_64(timestamp, serializer);
+        sse_encode_list_prim_u_8_strict(payload, serializer);
+      case SyncMessage_Ack(itemId: final itemId):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_64(itemId, serializer);
+      case SyncMessage_Ping(nonce: final nonce):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_64(nonce, serializer);
+      case SyncMessage_Pong(nonce: final nonce):
+        sse_encode_i_32(4, serializer);
+        sse_encode_u_64(nonce, serializer);
+      case SyncMessage_HistoryRequest(since: final since, limit: final limit):
+        sse_encode_i_32(5, serializer);
+        sse_encode_u_64(since, serializer);
+        sse_encode_u_32(limit, serializer);
+      case SyncMessage_Error(code: final code, message: final message):
+        sse_encode_i_32(6, serializer);
+        sse_encode_u_16(code, serializer);
+        sse_encode_String(message, serializer);
+      case SyncMessage_TransferOffer(
+          transferId: final transferId,
+          name: final name,
+          contentType: final contentType,
+          size: final size
+        ):
+        sse_encode_i_32(7, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_String(contentType, serializer);
+        sse_encode_u_64(size, serializer);
+      case SyncMessage_TransferAccept(transferId: final transferId, offset: final offset):
+        sse_encode_i_32(8, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_u_64(offset, serializer);
+      case SyncMessage_TransferChunk(offset: final offset, data: final data):
+        sse_encode_i_32(9, serializer);
+        sse_encode_u_64(offset, serializer);
+        sse_encode_list_prim_u_8_strict(data, serializer);
+      case SyncMessage_TransferEnd(sha256: final sha256):
+        sse_encode_i_32(10, serializer);
+        sse_encode_list_prim_u_8_strict(sha256, serializer);
+      case SyncMessage_TransferAck(transferId: final transferId, offset: final offset):
+        sse_encode_i_32(11, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_u_64(offset, serializer);
+      case SyncMessage_Presence(activity: final activity):
+        sse_encode_i_32(12, serializer);
+        sse_encode_activity(activity, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_peer_info(PeerInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.deviceId, serializer);
-    sse_encode_String(self.deviceName, serializer);
-    sse_encode_list_String(self.addresses, serializer);
-    sse_encode_u_16(self.port, serializer);
-    sse_encode_u_64(self.discoveredAt, serializer);
-  }
-
-  @protected
-  void sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict(
-      (Uint8List, Uint8List) self, SseSerializer serializer) {
+  void sse_encode_transfer_event(TransferEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.$1, serializer);
-    sse_encode_list_prim_u_8_strict(self.$2, serializer);
+    switch (self) {
+      case TransferEvent_Started(
+          transferId: final transferId,
+          peerId: final peerId,
+          name: final name,
+          size: final size,
+          outbound: final outbound,
+          offset: final offset
+        ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_u_64(size, serializer);
+        sse_encode_bool(outbound, serializer);
+        sse_encode_u_64(offset, serializer);
+      case TransferEvent_Progress(
+          transferId: final transferId,
+          peerId: final peerId,
+          transferred: final transferred,
+          size: final size
+        ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_u_64(transferred, serializer);
+        sse_encode_u_64(size, serializer);
+      case TransferEvent_Completed(
+          transferId: final transferId,
+          peerId: final peerId,
+          path: final path,
+          sha256: final sha256
+        ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(sha256, serializer);
+      case TransferEvent_Failed(transferId: final transferId, peerId: final peerId, error: final error):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_String(error, serializer);
+      case TransferEvent_Cancelled(transferId: final transferId, peerId: final peerId, byPeer: final byPeer):
+        sse_encode_i_32(4, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_bool(byPeer, serializer);
+      case TransferEvent_Interrupted(
+          transferId: final transferId,
+          peerId: final peerId,
+          transferred: final transferred
+        ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_u_64(transferId, serializer);
+        sse_encode_String(peerId, serializer);
+        sse_encode_u_64(transferred, serializer);
+    }
   }
 
   @protected
@@ -1221,15 +5270,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case TransportError_PeerNotFound(field0: final field0):
         sse_encode_i_32(4, serializer);
         sse_encode_String(field0, serializer);
+      case TransportError_FingerprintMismatch(deviceId: final deviceId, expected: final expected, actual: final actual):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(deviceId, serializer);
+        sse_encode_String(expected, serializer);
+        sse_encode_String(actual, serializer);
+      case TransportError_Protocol(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case TransportError_Cancelled(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(field0, serializer);
+      case TransportError_Rejected(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(field0, serializer);
     }
   }
 
+  @protected
+  void sse_encode_transport_options(TransportOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.bindAddress, serializer);
+    sse_encode_bool(self.dualStack, serializer);
+    sse_encode_u_64(self.keepAliveIntervalMs, serializer);
+    sse_encode_u_64(self.idleTimeoutMs, serializer);
+    sse_encode_u_32(self.maxConcurrentStreams, serializer);
+    sse_encode_u_64(self.maxMessageSize, serializer);
+    sse_encode_u_64(self.receiveWindow, serializer);
+    sse_encode_congestion_controller(self.congestionController, serializer);
+    sse_encode_u_64(self.maxTransferSize, serializer);
+    sse_encode_String(self.transferDirectory, serializer);
+    sse_encode_String(self.spoolDirectory, serializer);
+    sse_encode_u_64(self.heartbeatIntervalMs, serializer);
+    sse_encode_u_64(self.directUpgradeIntervalMs, serializer);
+    sse_encode_u_64(self.mailboxTtlMs, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint16(self);
   }
 
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1252,12 +5340,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
+}
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
+@sealed
+class DeviceIdentityImpl extends RustOpaque implements DeviceIdentity {
+  // Not to be used by end users
+  DeviceIdentityImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  DeviceIdentityImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_DeviceIdentity,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_DeviceIdentity,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_DeviceIdentityPtr,
+  );
+
+  /// DER encoded certificate
+  Uint8List get certificate => RustLib.instance.api.crateTransportIdentityDeviceIdentityCertificate(
+        that: this,
+      );
+
+  /// Device id this identity was issued for
+  String get deviceId => RustLib.instance.api.crateTransportIdentityDeviceIdentityDeviceId(
+        that: this,
+      );
+
+  /// SHA-256 fingerprint of the certificate, as shared in pairing payloads
+  String get fingerprint => RustLib.instance.api.crateTransportIdentityDeviceIdentityFingerprint(
+        that: this,
+      );
+
+  /// Write the identity to `path`
+  ///
+  /// The file contains the private key and is created readable by the owner only.
+  void save({required String path}) =>
+      RustLib.instance.api.crateTransportIdentityDeviceIdentitySave(that: this, path: path);
+
+  /// Serialize the identity (including the private key) to a byte blob
+  Uint8List toBytes() => RustLib.instance.api.crateTransportIdentityDeviceIdentityToBytes(
+        that: this,
+      );
 }
 
 @sealed
@@ -1311,50 +5435,496 @@ class QuicTransportImpl extends RustOpaque implements QuicTransport {
     rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_QuicTransportPtr,
   );
 
-  /// Accept an incoming connection (server only)
+  /// Wait for the next incoming connection
+  ///
+  /// Connections are accepted by the background accept loop; this resolves
+  /// with the peer id of the next one to complete its handshake, or the error
+  /// of the next failed handshake. Clients must present a certificate for a
+  /// paired device; pin their fingerprints with [`QuicTransport::pin_fingerprint`]
+  /// beforehand.
   Future<String> acceptConnection() => RustLib.instance.api.crateTransportQuicQuicTransportAcceptConnection(
         that: this,
       );
 
+  /// Confirm that a received item with `requires_ack` was applied
+  ///
+  /// Returns `false` if the sender is no longer waiting for it.
+  bool acknowledgeItem({required String peerId, required BigInt itemId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportAcknowledgeItem(that: this, peerId: peerId, itemId: itemId);
+
+  /// Send data to every connected device, or those matching `filter`, at once
+  ///
+  /// The data is encrypted with each device's key from
+  /// [`QuicTransport::set_peer_key`], once per distinct key, and sent as the
+  /// same [`SyncMessage::ClipboardItem`] to all of them. Returns what happened
+  /// for each device; one failing doesn't affect the others.
+  ///
+  /// With [`QuicTransport::use_signaling`], paired devices that aren't
+  /// connected but have a key from [`QuicTransport::set_peer_public_key`] get
+  /// the item sealed and left at the hub, see [`BroadcastOutcome::Deposited`].
+  ///
+  /// # Arguments
+  /// * `data` - Plaintext to send
+  /// * `filter` - Devices to include or leave out
+  Future<Map<String, BroadcastOutcome>> broadcast({required List<int> data, required BroadcastFilter filter}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportBroadcast(that: this, data: data, filter: filter);
+
+  /// Cancel a transfer in either direction
+  ///
+  /// A running transfer is cancelled for the peer too. An interrupted one is
+  /// removed from the spool; for incoming transfers the sender may still
+  /// offer it again. Returns `false` if there is no such transfer.
+  bool cancelTransfer({required BigInt transferId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportCancelTransfer(that: this, transferId: transferId);
+
   /// Close the transport and all connections
+  ///
+  /// Anything not yet delivered is dropped; use [`QuicTransport::shutdown`]
+  /// to deliver it first.
   Future<void> close() => RustLib.instance.api.crateTransportQuicQuicTransportClose(
         that: this,
       );
 
-  /// Connect to a peer (client mode)
+  /// Connect to a known device, pinning its certificate fingerprint
+  ///
+  /// The first successful connection records the fingerprint of the device's
+  /// certificate; later connections presenting a different certificate fail
+  /// with [`TransportError::FingerprintMismatch`].
+  ///
+  /// # Arguments
+  /// * `device_id` - Device id of the peer (from mDNS or pairing)
+  /// * `addr` - IPv4 or IPv6 address of the peer, optionally with a zone (`fe80::1%eth0`)
+  /// * `port` - Port number of the peer
+  Future<String> connectToDevice({required String deviceId, required String addr, required int port}) =>
+      RustLib.instance.api
+          .crateTransportQuicQuicTransportConnectToDevice(that: this, deviceId: deviceId, addr: addr, port: port);
+
+  /// Connect to a peer
+  ///
+  /// The peer's certificate is not pinned because its device id is unknown;
+  /// prefer [`QuicTransport::connect_to_device`] when it is.
   ///
   /// # Arguments
-  /// * `addr` - IP address or hostname of the peer
+  /// * `addr` - IPv4 or IPv6 address of the peer, optionally with a zone (`fe80::1%eth0`)
   /// * `port` - Port number of the peer
   Future<String> connectToPeer({required String addr, required int port}) =>
       RustLib.instance.api.crateTransportQuicQuicTransportConnectToPeer(that: this, addr: addr, port: port);
 
+  /// Connect to a peer found by mDNS, racing its addresses
+  ///
+  /// Attempts start 250ms apart, local-network and IPv6 addresses first, and
+  /// the first handshake to complete wins; the others are cancelled. The
+  /// winning address is tried first next time. The peer's certificate
+  /// fingerprint is pinned as in [`QuicTransport::connect_to_device`].
+  Future<String> connectToPeerInfo({required PeerInfo peer}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportConnectToPeerInfo(that: this, peer: peer);
+
+  /// Connect to a device through the relay set with [`QuicTransport::use_relay`]
+  ///
+  /// The device must connect to us through the same relay at about the same
+  /// time. When both are registered with a rendezvous service, the relayed
+  /// connection is replaced by a direct one once hole punching succeeds,
+  /// tried every [`TransportOptions::direct_upgrade_interval_ms`].
+  Future<String> connectViaRelay({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportConnectViaRelay(that: this, deviceId: deviceId);
+
+  /// Connect to a device through the rendezvous service, punching through NATs
+  ///
+  /// Both devices must be registered with the same service with
+  /// [`QuicTransport::register_rendezvous`] and have each other's
+  /// fingerprints pinned.
+  Future<String> connectViaRendezvous({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportConnectViaRendezvous(that: this, deviceId: deviceId);
+
+  /// Connect to a device at the signaling hub, punching through NATs
+  ///
+  /// The device must be online at the hub set with
+  /// [`QuicTransport::use_signaling`] and have our fingerprint pinned.
+  Future<String> connectViaSignaling({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportConnectViaSignaling(that: this, deviceId: deviceId);
+
+  /// Forward connection events to a Dart stream
+  ///
+  /// Events published before the stream is opened are not replayed; call
+  /// this before starting the server or dialing peers. A listener that falls
+  /// far behind skips the oldest events.
+  Stream<ConnectionEvent> connectionEvents() => RustLib.instance.api.crateTransportQuicQuicTransportConnectionEvents(
+        that: this,
+      );
+
   /// Disconnect from a peer
+  ///
+  /// Also stops reconnecting to it if it was kept connected.
   Future<void> disconnect({required String peerId}) =>
       RustLib.instance.api.crateTransportQuicQuicTransportDisconnect(that: this, peerId: peerId);
 
+  /// Learn our public address and NAT behaviour from STUN servers
+  ///
+  /// Binding requests are sent from the QUIC socket to every server at once,
+  /// so the mapped address is the one peers can reach QUIC at. At least two
+  /// servers on different addresses are needed to tell an
+  /// endpoint-independent NAT from a symmetric one.
+  ///
+  /// # Arguments
+  /// * `stun_servers` - Server addresses such as `stun.example.com:3478`
+  Future<NatReport> discoverNat({required List<String> stunServers}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportDiscoverNat(that: this, stunServers: stunServers);
+
+  /// Forget a device's pinned fingerprint
+  ///
+  /// Returns `true` if a fingerprint was pinned for the device.
+  bool forgetFingerprint({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportForgetFingerprint(that: this, deviceId: deviceId);
+
+  /// Forget the key shared with a device, e.g. after unpairing
+  bool forgetPeerKey({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportForgetPeerKey(that: this, deviceId: deviceId);
+
   /// Get list of connected peers
   Future<List<String>> getConnectedPeers() => RustLib.instance.api.crateTransportQuicQuicTransportGetConnectedPeers(
         that: this,
       );
 
+  /// Round-trip time, loss and activity of a connected peer, measured with datagram heartbeats
+  LinkQuality? getLinkQuality({required String peerId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportGetLinkQuality(that: this, peerId: peerId);
+
+  /// Get the current network address of a connected peer
+  ///
+  /// Reflects QUIC connection migration and reconnects from new addresses.
+  Future<String> getPeerAddress({required String peerId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportGetPeerAddress(that: this, peerId: peerId);
+
+  /// Get the device id proven by a peer's certificate during the handshake
+  Future<String> getPeerDeviceId({required String peerId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportGetPeerDeviceId(that: this, peerId: peerId);
+
+  /// Get the wire protocol version negotiated with a connected peer
+  Future<int> getPeerProtocolVersion({required String peerId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportGetPeerProtocolVersion(that: this, peerId: peerId);
+
+  /// RTT, congestion window, traffic counters, path address and age of a peer's connection
+  Future<PeerStats> getPeerStats({required String peerId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportGetPeerStats(that: this, peerId: peerId);
+
+  /// Transfers that are running or waiting in the spool for their peer to reconnect
+  List<PendingTransfer> getPendingTransfers() =>
+      RustLib.instance.api.crateTransportQuicQuicTransportGetPendingTransfers(
+        that: this,
+      );
+
+  /// Address that won the last [`QuicTransport::connect_to_peer_info`] race for a device
+  String? getPreferredAddress({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportGetPreferredAddress(that: this, deviceId: deviceId);
+
+  /// Devices kept connected with [`QuicTransport::keep_connected`]
+  ///
+  /// Devices whose supervision ended on its own, because they unpaired us or
+  /// share no protocol version, are not included.
+  List<String> getSupervisedPeers() => RustLib.instance.api.crateTransportQuicQuicTransportGetSupervisedPeers(
+        that: this,
+      );
+
+  /// Whether a peer is reached through a relay rather than directly
+  Future<bool> isPeerRelayed({required String peerId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportIsPeerRelayed(that: this, peerId: peerId);
+
   /// Check if transport is running
   bool isRunning() => RustLib.instance.api.crateTransportQuicQuicTransportIsRunning(
         that: this,
       );
 
+  /// Keep a connection to a device open, reconnecting whenever it drops
+  ///
+  /// Dials the device now if it isn't connected. After a disconnect the last
+  /// address that worked is tried first, then addresses from mDNS (see
+  /// [`QuicTransport::use_discovery`]), then `addresses`, with jittered
+  /// exponential backoff between rounds. Progress is reported as
+  /// [`ConnectionEvent::PeerStateChanged`] events. Supervision stops by itself
+  /// when the device unpairs us or shares no protocol version with us.
+  ///
+  /// # Arguments
+  /// * `device_id` - Device id of the peer
+  /// * `addresses` - Known IP addresses of the peer, IPv6 optionally with a zone
+  /// * `port` - Port number of the peer
+  Future<void> keepConnected({required String deviceId, required List<String> addresses, required int port}) =>
+      RustLib.instance.api
+          .crateTransportQuicQuicTransportKeepConnected(
+              that: this, deviceId: deviceId, addresses: addresses, port: port);
+
+  /// Forward link quality and activity updates to a Dart stream
+  ///
+  /// A quality update arrives for each peer every heartbeat interval, see
+  /// [`TransportOptions::heartbeat_interval_ms`].
+  Stream<LinkEvent> linkEvents() => RustLib.instance.api.crateTransportQuicQuicTransportLinkEvents(
+        that: this,
+      );
+
+  /// List all pinned certificate fingerprints
+  List<PinnedFingerprint> listPinnedFingerprints() =>
+      RustLib.instance.api.crateTransportQuicQuicTransportListPinnedFingerprints(
+        that: this,
+      );
+
+  /// Port the endpoint accepts and dials from, `None` before it is created
+  int? localPort() => RustLib.instance.api.crateTransportQuicQuicTransportLocalPort(
+        that: this,
+      );
+
+  /// Send statistics of all connected peers to a Dart stream every `interval_ms`
+  ///
+  /// Each update lists every peer connected at the time, ordered by peer id.
+  /// Intervals below 100ms are raised to 100ms. Stops when the Dart stream is
+  /// cancelled.
+  Stream<List<PeerStats>> peerStats({required BigInt intervalMs}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportPeerStats(that: this, intervalMs: intervalMs);
+
+  /// Pin a device's certificate fingerprint ahead of the first connection
+  ///
+  /// Used with the fingerprint carried in a QR pairing payload. Accepts hex
+  /// with or without `:` separators and replaces any existing pin. This pairs
+  /// the device, which lets it connect to us; fingerprints pinned on first
+  /// use when dialing don't.
+  void pinFingerprint({required String deviceId, required String fingerprint}) => RustLib.instance.api
+      .crateTransportQuicQuicTransportPinFingerprint(that: this, deviceId: deviceId, fingerprint: fingerprint);
+
+  /// Forward clipboard items received from all peers to a Dart stream
+  ///
+  /// Each item carries the id of the peer that sent it. Forwarding stops when
+  /// the Dart stream is cancelled.
+  Stream<ReceivedItem> receiveItems() => RustLib.instance.api.crateTransportQuicQuicTransportReceiveItems(
+        that: this,
+      );
+
+  /// Register with a rendezvous service so devices behind NATs can reach us
+  ///
+  /// Registration is sent from the QUIC socket, so the service sees the
+  /// public address our NAT maps it to, and is refreshed in the background.
+  /// Devices the service introduces are connected to by hole punching.
+  /// Returns our public address as the service saw it.
+  ///
+  /// # Arguments
+  /// * `server` - Address of the service, e.g. `rendezvous.example.com:3478`
+  Future<String> registerRendezvous({required String server}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportRegisterRendezvous(that: this, server: server);
+
+  /// Tell the sender of a received item with `requires_ack` why it wasn't applied
+  ///
+  /// Returns `false` if the sender is no longer waiting for it.
+  bool rejectItem({required String peerId, required BigInt itemId, required String reason}) => RustLib.instance.api
+      .crateTransportQuicQuicTransportRejectItem(that: this, peerId: peerId, itemId: itemId, reason: reason);
+
   /// Send data to a specific peer
   ///
+  /// The data is wrapped in a [`SyncMessage::ClipboardItem`].
+  ///
   /// # Arguments
-  /// * `peer_id` - The peer identifier (address:port)
+  /// * `peer_id` - The peer identifier (device id)
   /// * `data` - Data to send
   Future<void> sendData({required String peerId, required List<int> data}) =>
       RustLib.instance.api.crateTransportQuicQuicTransportSendData(that: this, peerId: peerId, data: data);
 
-  /// Start the QUIC server on the specified port
+  /// Send data to a peer and wait until it confirms applying it
+  ///
+  /// Like [`QuicTransport::send_data`], but the item goes on a bidirectional
+  /// stream and the peer's app answers it with
+  /// [`QuicTransport::acknowledge_item`] or [`QuicTransport::reject_item`].
+  ///
+  /// # Arguments
+  /// * `peer_id` - The peer identifier (device id)
+  /// * `data` - Data to send
+  /// * `timeout_ms` - How long to wait for the answer, including sending the data
+  Future<DeliveryResult> sendDataAcked({required String peerId, required List<int> data, required BigInt timeoutMs}) =>
+      RustLib.instance.api
+          .crateTransportQuicQuicTransportSendDataAcked(that: this, peerId: peerId, data: data, timeoutMs: timeoutMs);
+
+  /// Send a file to a peer in chunks
+  ///
+  /// Completes once the peer has stored the file and verified its SHA-256
+  /// hash, returning the transfer id. Progress is reported as
+  /// [`TransferEvent`]s, see [`QuicTransport::transfer_events`]. If the
+  /// connection drops, this fails but the transfer is kept in the spool and
+  /// continues from the last acknowledged offset when the peer reconnects.
+  ///
+  /// # Arguments
+  /// * `peer_id` - The peer identifier (device id)
+  /// * `path` - File to send; the peer saves it under the same file name
+  /// * `content_type` - MIME type of the file
+  Future<BigInt> sendFile({required String peerId, required String path, required String contentType}) =>
+      RustLib.instance.api
+          .crateTransportQuicQuicTransportSendFile(that: this, peerId: peerId, path: path, contentType: contentType);
+
+  /// Send a payload too large for [`QuicTransport::send_data`], such as a clipboard image, in chunks
+  ///
+  /// Behaves like [`QuicTransport::send_file`]; the peer saves the payload as
+  /// `name`. A copy is kept in the spool until the peer has all of it.
+  Future<BigInt> sendLargeData(
+          {required String peerId, required String name, required String contentType, required List<int> data}) =>
+      RustLib.instance.api
+          .crateTransportQuicQuicTransportSendLargeData(
+              that: this, peerId: peerId, name: name, contentType: contentType, data: data);
+
+  /// Send a protocol message to a specific peer on its own stream
+  Future<void> sendMessage({required String peerId, required SyncMessage message}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportSendMessage(that: this, peerId: peerId, message: message);
+
+  /// Send a [`SyncMessage::ClipboardItem`] and wait until the peer confirms applying it
+  ///
+  /// The item id identifies the answer, so reuse it when resending an item.
+  /// Fails only if the item could not be sent; a peer that doesn't answer in
+  /// time gives [`DeliveryResult::TimedOut`].
+  Future<DeliveryResult> sendMessageAcked(
+          {required String peerId, required SyncMessage message, required BigInt timeoutMs}) =>
+      RustLib.instance.api
+          .crateTransportQuicQuicTransportSendMessageAcked(
+              that: this, peerId: peerId, message: message, timeoutMs: timeoutMs);
+
+  /// Tell all connected peers what the user is doing
+  ///
+  /// Sent as a datagram, so it costs no stream; peers connecting later are
+  /// told when they connect.
+  Future<void> setActivity({required Activity activity}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportSetActivity(that: this, activity: activity);
+
+  /// Set the device identity used for subsequent servers and connections
+  void setIdentity({required DeviceIdentity identity}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportSetIdentity(that: this, identity: identity);
+
+  /// Set the key shared with a device, see [`crate::crypto::derive_shared_secret`]
+  ///
+  /// [`QuicTransport::broadcast`] encrypts items for the device with this key.
+  void setPeerKey({required String deviceId, required List<int> key}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportSetPeerKey(that: this, deviceId: deviceId, key: key);
+
+  /// Set a paired device's X25519 public key, used to seal items left for it while offline
+  void setPeerPublicKey({required String deviceId, required List<int> publicKey}) => RustLib.instance.api
+      .crateTransportQuicQuicTransportSetPeerPublicKey(that: this, deviceId: deviceId, publicKey: publicKey);
+
+  /// Set our X25519 secret key, the one whose public key paired devices seal items with
+  ///
+  /// Use the key pair from [`crate::crypto::generate_keypair`] exchanged
+  /// during pairing. Set it before [`QuicTransport::use_signaling`], which
+  /// only fetches our mailbox when it can open what it gets.
+  void setSealingKey({required List<int> secretKey}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportSetSealingKey(that: this, secretKey: secretKey);
+
+  /// Shut down gracefully, delivering what was already sent
+  ///
+  /// New sends and incoming connections are refused while sends in flight,
+  /// including chunked transfers, get up to `timeout_ms` to be acknowledged
+  /// by their peers. Transfers still running then are interrupted and resume
+  /// from the spool later. Connections are closed with a code peers report
+  /// as a shutdown rather than a lost connection.
+  ///
+  /// Takes `&self` so sends already waiting on the transport are not blocked
+  /// behind it. The transport can be started again afterwards.
+  Future<void> shutdown({required BigInt timeoutMs}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportShutdown(that: this, timeoutMs: timeoutMs);
+
+  /// Start accepting connections on the specified port
+  ///
+  /// The transport has a single endpoint that both accepts and dials with
+  /// the same identity. If it already exists because a peer was dialed, it
+  /// moves to `port` unless `port` is 0; connections it dialed follow it, but
+  /// ones it accepted can't change their server address and are lost. Incoming
+  /// connections are accepted in the background, with handshakes running
+  /// concurrently; subscribe with [`QuicTransport::connection_events`] to
+  /// learn about new peers.
   ///
   /// # Arguments
-  /// * `port` - Port number to listen on
+  /// * `port` - Port number to listen on, 0 for any
   Future<void> startServer({required int port}) =>
       RustLib.instance.api.crateTransportQuicQuicTransportStartServer(that: this, port: port);
+
+  /// Stop reconnecting to a device; an open connection is left as is
+  ///
+  /// Returns `true` if the device was supervised.
+  bool stopKeepingConnected({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportStopKeepingConnected(that: this, deviceId: deviceId);
+
+  /// Forward transfer progress in both directions to a Dart stream
+  ///
+  /// Like [`QuicTransport::connection_events`], only events published after
+  /// the stream is opened are delivered.
+  Stream<TransferEvent> transferEvents() => RustLib.instance.api.crateTransportQuicQuicTransportTransferEvents(
+        that: this,
+      );
+
+  /// Unpair a device, dropping its pinned fingerprint and key and closing any connection to it
+  ///
+  /// The device sees the close as [`DisconnectReason::Unpaired`]. Returns
+  /// `true` if the device was pinned, keyed, supervised or connected.
+  Future<bool> unpair({required String deviceId}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportUnpair(that: this, deviceId: deviceId);
+
+  /// Use peers found by an mDNS browser as fresh addresses when reconnecting
+  ///
+  /// Applies to peers supervised after this call.
+  void useDiscovery({required MdnsDiscovery discovery}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportUseDiscovery(that: this, discovery: discovery);
+
+  /// Use a relay server when no direct path to a device works
+  ///
+  /// Devices registered with a rendezvous service fall back to the relay
+  /// when hole punching fails. QUIC runs end to end through the relay, so it
+  /// only sees ciphertext.
+  ///
+  /// # Arguments
+  /// * `server` - Address of a `syncmist-relay` server, e.g. `relay.example.com:3479`
+  Future<void> useRelay({required String server}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportUseRelay(that: this, server: server);
+
+  /// Exchange connection candidates with paired devices through a signaling hub
+  ///
+  /// Paired devices that are, or come, online at the hub are connected to by
+  /// hole punching. Candidates include the public address learned with
+  /// [`QuicTransport::discover_nat`] or [`QuicTransport::register_rendezvous`],
+  /// so call one of those first when behind a NAT.
+  ///
+  /// The hub also becomes our mailbox: items left for us while we were
+  /// offline are fetched, verified and opened with the key from
+  /// [`QuicTransport::set_sealing_key`], and arrive like items from connected
+  /// peers. From then on [`QuicTransport::broadcast`] leaves items for offline
+  /// paired devices there.
+  ///
+  /// # Arguments
+  /// * `signaling` - Client connected to the hub as this device
+  Future<void> useSignaling({required SignalingClient signaling}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportUseSignaling(that: this, signaling: signaling);
+}
+
+@sealed
+class SignalingClientImpl extends RustOpaque implements SignalingClient {
+  // Not to be used by end users
+  SignalingClientImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  SignalingClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SignalingClient,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SignalingClient,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SignalingClientPtr,
+  );
+
+  /// Tell other devices we are leaving and close the connection to the hub
+  Future<void> close() => RustLib.instance.api.crateSignalingClientSignalingClientClose(
+        that: this,
+      );
+
+  /// Device id the token was issued for
+  String deviceId() => RustLib.instance.api.crateSignalingClientSignalingClientDeviceId(
+        that: this,
+      );
+
+  /// Devices at the hub that announced themselves since we connected, ordered by id
+  List<String> onlinePeers() => RustLib.instance.api.crateSignalingClientSignalingClientOnlinePeers(
+        that: this,
+      );
+
+  /// Forward presence changes to a Dart stream
+  Stream<SignalingEvent> signalingEvents() => RustLib.instance.api.crateSignalingClientSignalingClientSignalingEvents(
+        that: this,
+      );
 }
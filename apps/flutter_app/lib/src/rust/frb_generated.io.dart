@@ -11,7 +11,22 @@ import 'discovery/mdns.dart';
 import 'frb_generated.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
+import 'signaling/client.dart';
+import 'transport/address.dart';
+import 'transport/broadcast.dart';
+import 'transport/datagram.dart';
+import 'transport/delivery.dart';
+import 'transport/events.dart';
+import 'transport/identity.dart';
+import 'transport/options.dart';
+import 'transport/protocol.dart';
 import 'transport/quic.dart';
+import 'transport/receiver.dart';
+import 'transport/stats.dart';
+import 'transport/stun.dart';
+import 'transport/supervisor.dart';
+import 'transport/transfer.dart';
+import 'transport/trust.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -21,12 +36,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DeviceIdentityPtr => wire
+      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr;
+
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MdnsDiscoveryPtr => wire
       ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscoveryPtr;
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_QuicTransportPtr => wire
       ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportPtr;
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SignalingClientPtr => wire
+      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClientPtr;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  DeviceIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      dynamic raw);
+
   @protected
   MdnsDiscovery dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
       dynamic raw);
@@ -35,10 +63,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   QuicTransport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       dynamic raw);
 
+  @protected
+  SignalingClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      dynamic raw);
+
   @protected
   QuicTransport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       dynamic raw);
 
+  @protected
+  DeviceIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      dynamic raw);
+
   @protected
   MdnsDiscovery dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
       dynamic raw);
@@ -47,45 +83,199 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   QuicTransport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       dynamic raw);
 
+  @protected
+  SignalingClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      dynamic raw);
+
+  @protected
+  Map<String, BroadcastOutcome> dco_decode_Map_String_broadcast_outcome_None(dynamic raw);
+
+  @protected
+  DeviceIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(dynamic raw);
+
   @protected
   MdnsDiscovery dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(dynamic raw);
 
   @protected
   QuicTransport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(dynamic raw);
 
+  @protected
+  SignalingClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(dynamic raw);
+
+  @protected
+  RustStreamSink<ConnectionEvent> dco_decode_StreamSink_connection_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<LinkEvent> dco_decode_StreamSink_link_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<List<PeerStats>> dco_decode_StreamSink_list_peer_stats_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<ReceivedItem> dco_decode_StreamSink_received_item_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<SignalingEvent> dco_decode_StreamSink_signaling_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<TransferEvent> dco_decode_StreamSink_transfer_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  Activity dco_decode_activity(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  BroadcastFilter dco_decode_box_autoadd_broadcast_filter(dynamic raw);
+
+  @protected
+  DisconnectReason dco_decode_box_autoadd_disconnect_reason(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  LinkQuality dco_decode_box_autoadd_link_quality(dynamic raw);
+
+  @protected
+  PeerInfo dco_decode_box_autoadd_peer_info(dynamic raw);
+
+  @protected
+  SyncMessage dco_decode_box_autoadd_sync_message(dynamic raw);
+
+  @protected
+  TransportOptions dco_decode_box_autoadd_transport_options(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  BroadcastFilter dco_decode_broadcast_filter(dynamic raw);
+
+  @protected
+  BroadcastOutcome dco_decode_broadcast_outcome(dynamic raw);
+
+  @protected
+  CongestionController dco_decode_congestion_controller(dynamic raw);
+
+  @protected
+  ConnectionEvent dco_decode_connection_event(dynamic raw);
+
+  @protected
+  DeliveryResult dco_decode_delivery_result(dynamic raw);
+
+  @protected
+  DisconnectReason dco_decode_disconnect_reason(dynamic raw);
+
   @protected
   DiscoveryError dco_decode_discovery_error(dynamic raw);
 
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  LinkEvent dco_decode_link_event(dynamic raw);
+
+  @protected
+  LinkQuality dco_decode_link_quality(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
   @protected
   List<PeerInfo> dco_decode_list_peer_info(dynamic raw);
 
+  @protected
+  List<PeerStats> dco_decode_list_peer_stats(dynamic raw);
+
+  @protected
+  List<PendingTransfer> dco_decode_list_pending_transfer(dynamic raw);
+
+  @protected
+  List<PinnedFingerprint> dco_decode_list_pinned_fingerprint(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<(String, BroadcastOutcome)> dco_decode_list_record_string_broadcast_outcome(dynamic raw);
+
+  @protected
+  NatReport dco_decode_nat_report(dynamic raw);
+
+  @protected
+  NatType dco_decode_nat_type(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  LinkQuality? dco_decode_opt_box_autoadd_link_quality(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
   @protected
   PeerInfo dco_decode_peer_info(dynamic raw);
 
+  @protected
+  PeerState dco_decode_peer_state(dynamic raw);
+
+  @protected
+  PeerStats dco_decode_peer_stats(dynamic raw);
+
+  @protected
+  PendingTransfer dco_decode_pending_transfer(dynamic raw);
+
+  @protected
+  PinnedFingerprint dco_decode_pinned_fingerprint(dynamic raw);
+
+  @protected
+  ReceivedItem dco_decode_received_item(dynamic raw);
+
   @protected
   (Uint8List, Uint8List) dco_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  (String, BroadcastOutcome) dco_decode_record_string_broadcast_outcome(dynamic raw);
+
+  @protected
+  SignalingError dco_decode_signaling_error(dynamic raw);
+
+  @protected
+  SignalingEvent dco_decode_signaling_event(dynamic raw);
+
+  @protected
+  SyncMessage dco_decode_sync_message(dynamic raw);
+
+  @protected
+  TransferEvent dco_decode_transfer_event(dynamic raw);
+
   @protected
   TransportError dco_decode_transport_error(dynamic raw);
 
+  @protected
+  TransportOptions dco_decode_transport_options(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_u_64(dynamic raw);
 
@@ -98,6 +288,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  DeviceIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      SseDeserializer deserializer);
+
   @protected
   MdnsDiscovery sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
       SseDeserializer deserializer);
@@ -106,10 +303,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   QuicTransport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       SseDeserializer deserializer);
 
+  @protected
+  SignalingClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SseDeserializer deserializer);
+
   @protected
   QuicTransport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       SseDeserializer deserializer);
 
+  @protected
+  DeviceIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      SseDeserializer deserializer);
+
   @protected
   MdnsDiscovery sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
       SseDeserializer deserializer);
@@ -118,6 +323,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   QuicTransport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       SseDeserializer deserializer);
 
+  @protected
+  SignalingClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SseDeserializer deserializer);
+
+  @protected
+  Map<String, BroadcastOutcome> sse_decode_Map_String_broadcast_outcome_None(SseDeserializer deserializer);
+
+  @protected
+  DeviceIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      SseDeserializer deserializer);
+
   @protected
   MdnsDiscovery sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
       SseDeserializer deserializer);
@@ -126,39 +342,184 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   QuicTransport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       SseDeserializer deserializer);
 
+  @protected
+  SignalingClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ConnectionEvent> sse_decode_StreamSink_connection_event_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<LinkEvent> sse_decode_StreamSink_link_event_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<List<PeerStats>> sse_decode_StreamSink_list_peer_stats_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ReceivedItem> sse_decode_StreamSink_received_item_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<SignalingEvent> sse_decode_StreamSink_signaling_event_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<TransferEvent> sse_decode_StreamSink_transfer_event_Sse(SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  Activity sse_decode_activity(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  BroadcastFilter sse_decode_box_autoadd_broadcast_filter(SseDeserializer deserializer);
+
+  @protected
+  DisconnectReason sse_decode_box_autoadd_disconnect_reason(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  LinkQuality sse_decode_box_autoadd_link_quality(SseDeserializer deserializer);
+
+  @protected
+  PeerInfo sse_decode_box_autoadd_peer_info(SseDeserializer deserializer);
+
+  @protected
+  SyncMessage sse_decode_box_autoadd_sync_message(SseDeserializer deserializer);
+
+  @protected
+  TransportOptions sse_decode_box_autoadd_transport_options(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  BroadcastFilter sse_decode_broadcast_filter(SseDeserializer deserializer);
+
+  @protected
+  BroadcastOutcome sse_decode_broadcast_outcome(SseDeserializer deserializer);
+
+  @protected
+  CongestionController sse_decode_congestion_controller(SseDeserializer deserializer);
+
+  @protected
+  ConnectionEvent sse_decode_connection_event(SseDeserializer deserializer);
+
+  @protected
+  DeliveryResult sse_decode_delivery_result(SseDeserializer deserializer);
+
+  @protected
+  DisconnectReason sse_decode_disconnect_reason(SseDeserializer deserializer);
+
   @protected
   DiscoveryError sse_decode_discovery_error(SseDeserializer deserializer);
 
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  LinkEvent sse_decode_link_event(SseDeserializer deserializer);
+
+  @protected
+  LinkQuality sse_decode_link_quality(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
   @protected
   List<PeerInfo> sse_decode_list_peer_info(SseDeserializer deserializer);
 
+  @protected
+  List<PeerStats> sse_decode_list_peer_stats(SseDeserializer deserializer);
+
+  @protected
+  List<PendingTransfer> sse_decode_list_pending_transfer(SseDeserializer deserializer);
+
+  @protected
+  List<PinnedFingerprint> sse_decode_list_pinned_fingerprint(SseDeserializer deserializer);
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<(String, BroadcastOutcome)> sse_decode_list_record_string_broadcast_outcome(SseDeserializer deserializer);
+
+  @protected
+  NatReport sse_decode_nat_report(SseDeserializer deserializer);
+
+  @protected
+  NatType sse_decode_nat_type(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  LinkQuality? sse_decode_opt_box_autoadd_link_quality(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   PeerInfo sse_decode_peer_info(SseDeserializer deserializer);
 
+  @protected
+  PeerState sse_decode_peer_state(SseDeserializer deserializer);
+
+  @protected
+  PeerStats sse_decode_peer_stats(SseDeserializer deserializer);
+
+  @protected
+  PendingTransfer sse_decode_pending_transfer(SseDeserializer deserializer);
+
+  @protected
+  PinnedFingerprint sse_decode_pinned_fingerprint(SseDeserializer deserializer);
+
+  @protected
+  ReceivedItem sse_decode_received_item(SseDeserializer deserializer);
+
   @protected
   (Uint8List, Uint8List) sse_decode_record_list_prim_u_8_strict_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  (String, BroadcastOutcome) sse_decode_record_string_broadcast_outcome(SseDeserializer deserializer);
+
+  @protected
+  SignalingError sse_decode_signaling_error(SseDeserializer deserializer);
+
+  @protected
+  SignalingEvent sse_decode_signaling_event(SseDeserializer deserializer);
+
+  @protected
+  SyncMessage sse_decode_sync_message(SseDeserializer deserializer);
+
+  @protected
+  TransferEvent sse_decode_transfer_event(SseDeserializer deserializer);
+
   @protected
   TransportError sse_decode_transport_error(SseDeserializer deserializer);
 
+  @protected
+  TransportOptions sse_decode_transport_options(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_u_64(SseDeserializer deserializer);
 
@@ -172,7 +533,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      DeviceIdentity self, SseSerializer serializer);
 
   @protected
   void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
@@ -182,10 +547,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       QuicTransport self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SignalingClient self, SseSerializer serializer);
+
   @protected
   void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       QuicTransport self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      DeviceIdentity self, SseSerializer serializer);
+
   @protected
   void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
       MdnsDiscovery self, SseSerializer serializer);
@@ -194,6 +567,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       QuicTransport self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SignalingClient self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Map_String_broadcast_outcome_None(Map < String, BroadcastOutcome > self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      DeviceIdentity self, SseSerializer serializer);
+
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
       MdnsDiscovery self, SseSerializer serializer);
@@ -202,40 +586,185 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport(
       QuicTransport self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      SignalingClient self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_connection_event_Sse(RustStreamSink<ConnectionEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_link_event_Sse(RustStreamSink<LinkEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_list_peer_stats_Sse(RustStreamSink<List<PeerStats>> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_received_item_Sse(RustStreamSink<ReceivedItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_signaling_event_Sse(RustStreamSink<SignalingEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_transfer_event_Sse(RustStreamSink<TransferEvent> self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_activity(Activity self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_broadcast_filter(BroadcastFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_disconnect_reason(DisconnectReason self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_link_quality(LinkQuality self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_peer_info(PeerInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sync_message(SyncMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_transport_options(TransportOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_broadcast_filter(BroadcastFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_broadcast_outcome(BroadcastOutcome self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_congestion_controller(CongestionController self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_connection_event(ConnectionEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_delivery_result(DeliveryResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_disconnect_reason(DisconnectReason self, SseSerializer serializer);
+
   @protected
   void sse_encode_discovery_error(DiscoveryError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_link_event(LinkEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_link_quality(LinkQuality self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_peer_info(List<PeerInfo> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_peer_stats(List<PeerStats> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pending_transfer(List<PendingTransfer> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pinned_fingerprint(List<PinnedFingerprint> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_record_string_broadcast_outcome(List<(String, BroadcastOutcome)> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_nat_report(NatReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_nat_type(NatType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_link_quality(LinkQuality? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_peer_info(PeerInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_peer_state(PeerState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_peer_stats(PeerStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pending_transfer(PendingTransfer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pinned_fingerprint(PinnedFingerprint self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_received_item(ReceivedItem self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_list_prim_u_8_strict_list_prim_u_8_strict(
       (Uint8List, Uint8List) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_record_string_broadcast_outcome((String, BroadcastOutcome) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_signaling_error(SignalingError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_signaling_event(SignalingEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_message(SyncMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_transfer_event(TransferEvent self, SseSerializer serializer);
+
   @protected
   void sse_encode_transport_error(TransportError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_transport_options(TransportOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
@@ -247,9 +776,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -263,6 +789,36 @@ class RustLibWire implements BaseWire {
   /// The symbols are looked up in [dynamicLibrary].
   RustLibWire(ffi.DynamicLibrary dynamicLibrary) : _lookup = dynamicLibrary.lookup;
 
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_syncmist_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity');
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_syncmist_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity');
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentity =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceIdentityPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
   void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMdnsDiscovery(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -322,4 +878,34 @@ class RustLibWire implements BaseWire {
   late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransport =
       _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClientPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_syncmist_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient');
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClientPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClientPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_syncmist_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient');
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClient =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSignalingClientPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 }
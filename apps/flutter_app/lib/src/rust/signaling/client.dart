@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'client.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `channel`, `decode`, `device_id`, `encode`, `is_online`, `online`, `open`, `receive`, `run`, `send`, `sender`, `subscribe`, `target`, `token_device_id`, `url_with_token`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Claims`, `HubMessage`, `Outgoing`, `Signal`, `SignalingChannel`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `subscribe_events`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SignalingClient>>
+abstract class SignalingClient implements RustOpaqueInterface {
+  /// Tell other devices we are leaving and close the connection to the hub
+  Future<void> close();
+
+  /// Connect to a hub and announce this device
+  ///
+  /// # Arguments
+  /// * `url` - WebSocket endpoint of the hub, e.g. `wss://hub.example.com/ws`
+  /// * `token` - JWT from the hub's `/register` endpoint; it names this device
+  static Future<SignalingClient> connect({required String url, required String token}) =>
+      RustLib.instance.api.crateSignalingClientSignalingClientConnect(url: url, token: token);
+
+  /// Device id the token was issued for
+  String deviceId();
+
+  /// Devices at the hub that announced themselves since we connected, ordered by id
+  List<String> onlinePeers();
+
+  /// Forward presence changes to a Dart stream
+  Stream<SignalingEvent> signalingEvents();
+}
+
+@freezed
+sealed class SignalingError with _$SignalingError implements FrbException {
+  const SignalingError._();
+
+  /// The hub could not be reached or the connection to it failed
+  const factory SignalingError.connection(
+    String field0,
+  ) = SignalingError_Connection;
+
+  /// The token is malformed or expired, or the hub rejected it
+  const factory SignalingError.unauthorized(
+    String field0,
+  ) = SignalingError_Unauthorized;
+
+  /// The hub sent something that isn't valid WebSocket, or a message couldn't be encoded
+  const factory SignalingError.protocol(
+    String field0,
+  ) = SignalingError_Protocol;
+
+  /// The client was closed
+  const factory SignalingError.closed() = SignalingError_Closed;
+}
+
+@freezed
+sealed class SignalingEvent with _$SignalingEvent {
+  const SignalingEvent._();
+
+  /// A device announced itself, or answered our announcement
+  const factory SignalingEvent.peerOnline({
+    required String deviceId,
+  }) = SignalingEvent_PeerOnline;
+
+  /// A device said it is leaving
+  const factory SignalingEvent.peerOffline({
+    required String deviceId,
+  }) = SignalingEvent_PeerOffline;
+
+  /// The connection to the hub ended; connect again to resume
+  const factory SignalingEvent.disconnected({
+    required String error,
+  }) = SignalingEvent_Disconnected;
+}
@@ -0,0 +1,1172 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'client.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$SignalingError {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) unauthorized,
+    required TResult Function(String field0) protocol,
+    required TResult Function() closed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? unauthorized,
+    TResult? Function(String field0)? protocol,
+    TResult? Function()? closed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? unauthorized,
+    TResult Function(String field0)? protocol,
+    TResult Function()? closed,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingError_Connection value) connection,
+    required TResult Function(SignalingError_Unauthorized value) unauthorized,
+    required TResult Function(SignalingError_Protocol value) protocol,
+    required TResult Function(SignalingError_Closed value) closed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingError_Connection value)? connection,
+    TResult? Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult? Function(SignalingError_Protocol value)? protocol,
+    TResult? Function(SignalingError_Closed value)? closed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingError_Connection value)? connection,
+    TResult Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult Function(SignalingError_Protocol value)? protocol,
+    TResult Function(SignalingError_Closed value)? closed,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $SignalingErrorCopyWith<$Res> {
+  factory $SignalingErrorCopyWith(SignalingError value, $Res Function(SignalingError) then) =
+      _$SignalingErrorCopyWithImpl<$Res, SignalingError>;
+}
+
+/// @nodoc
+class _$SignalingErrorCopyWithImpl<$Res, $Val extends SignalingError> implements $SignalingErrorCopyWith<$Res> {
+  _$SignalingErrorCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SignalingError_ConnectionImplCopyWith<$Res> {
+  factory _$$SignalingError_ConnectionImplCopyWith(
+          _$SignalingError_ConnectionImpl value, $Res Function(_$SignalingError_ConnectionImpl) then) =
+      __$$SignalingError_ConnectionImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$SignalingError_ConnectionImplCopyWithImpl<$Res>
+    extends _$SignalingErrorCopyWithImpl<$Res, _$SignalingError_ConnectionImpl>
+    implements _$$SignalingError_ConnectionImplCopyWith<$Res> {
+  __$$SignalingError_ConnectionImplCopyWithImpl(
+      _$SignalingError_ConnectionImpl _value, $Res Function(_$SignalingError_ConnectionImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$SignalingError_ConnectionImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignalingError_ConnectionImpl extends SignalingError_Connection {
+  const _$SignalingError_ConnectionImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'SignalingError.connection(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignalingError_ConnectionImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignalingError_ConnectionImplCopyWith<_$SignalingError_ConnectionImpl> get copyWith =>
+      __$$SignalingError_ConnectionImplCopyWithImpl<_$SignalingError_ConnectionImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) unauthorized,
+    required TResult Function(String field0) protocol,
+    required TResult Function() closed,
+  }) {
+    return connection(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? unauthorized,
+    TResult? Function(String field0)? protocol,
+    TResult? Function()? closed,
+  }) {
+    return connection?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? unauthorized,
+    TResult Function(String field0)? protocol,
+    TResult Function()? closed,
+    required TResult orElse(),
+  }) {
+    if (connection != null) {
+      return connection(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingError_Connection value) connection,
+    required TResult Function(SignalingError_Unauthorized value) unauthorized,
+    required TResult Function(SignalingError_Protocol value) protocol,
+    required TResult Function(SignalingError_Closed value) closed,
+  }) {
+    return connection(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingError_Connection value)? connection,
+    TResult? Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult? Function(SignalingError_Protocol value)? protocol,
+    TResult? Function(SignalingError_Closed value)? closed,
+  }) {
+    return connection?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingError_Connection value)? connection,
+    TResult Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult Function(SignalingError_Protocol value)? protocol,
+    TResult Function(SignalingError_Closed value)? closed,
+    required TResult orElse(),
+  }) {
+    if (connection != null) {
+      return connection(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SignalingError_Connection extends SignalingError {
+  const factory SignalingError_Connection(final String field0) = _$SignalingError_ConnectionImpl;
+  const SignalingError_Connection._() : super._();
+
+  String get field0;
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignalingError_ConnectionImplCopyWith<_$SignalingError_ConnectionImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignalingError_UnauthorizedImplCopyWith<$Res> {
+  factory _$$SignalingError_UnauthorizedImplCopyWith(
+          _$SignalingError_UnauthorizedImpl value, $Res Function(_$SignalingError_UnauthorizedImpl) then) =
+      __$$SignalingError_UnauthorizedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$SignalingError_UnauthorizedImplCopyWithImpl<$Res>
+    extends _$SignalingErrorCopyWithImpl<$Res, _$SignalingError_UnauthorizedImpl>
+    implements _$$SignalingError_UnauthorizedImplCopyWith<$Res> {
+  __$$SignalingError_UnauthorizedImplCopyWithImpl(
+      _$SignalingError_UnauthorizedImpl _value, $Res Function(_$SignalingError_UnauthorizedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$SignalingError_UnauthorizedImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignalingError_UnauthorizedImpl extends SignalingError_Unauthorized {
+  const _$SignalingError_UnauthorizedImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'SignalingError.unauthorized(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignalingError_UnauthorizedImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignalingError_UnauthorizedImplCopyWith<_$SignalingError_UnauthorizedImpl> get copyWith =>
+      __$$SignalingError_UnauthorizedImplCopyWithImpl<_$SignalingError_UnauthorizedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) unauthorized,
+    required TResult Function(String field0) protocol,
+    required TResult Function() closed,
+  }) {
+    return unauthorized(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? unauthorized,
+    TResult? Function(String field0)? protocol,
+    TResult? Function()? closed,
+  }) {
+    return unauthorized?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? unauthorized,
+    TResult Function(String field0)? protocol,
+    TResult Function()? closed,
+    required TResult orElse(),
+  }) {
+    if (unauthorized != null) {
+      return unauthorized(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingError_Connection value) connection,
+    required TResult Function(SignalingError_Unauthorized value) unauthorized,
+    required TResult Function(SignalingError_Protocol value) protocol,
+    required TResult Function(SignalingError_Closed value) closed,
+  }) {
+    return unauthorized(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingError_Connection value)? connection,
+    TResult? Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult? Function(SignalingError_Protocol value)? protocol,
+    TResult? Function(SignalingError_Closed value)? closed,
+  }) {
+    return unauthorized?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingError_Connection value)? connection,
+    TResult Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult Function(SignalingError_Protocol value)? protocol,
+    TResult Function(SignalingError_Closed value)? closed,
+    required TResult orElse(),
+  }) {
+    if (unauthorized != null) {
+      return unauthorized(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SignalingError_Unauthorized extends SignalingError {
+  const factory SignalingError_Unauthorized(final String field0) = _$SignalingError_UnauthorizedImpl;
+  const SignalingError_Unauthorized._() : super._();
+
+  String get field0;
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignalingError_UnauthorizedImplCopyWith<_$SignalingError_UnauthorizedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignalingError_ProtocolImplCopyWith<$Res> {
+  factory _$$SignalingError_ProtocolImplCopyWith(
+          _$SignalingError_ProtocolImpl value, $Res Function(_$SignalingError_ProtocolImpl) then) =
+      __$$SignalingError_ProtocolImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$SignalingError_ProtocolImplCopyWithImpl<$Res>
+    extends _$SignalingErrorCopyWithImpl<$Res, _$SignalingError_ProtocolImpl>
+    implements _$$SignalingError_ProtocolImplCopyWith<$Res> {
+  __$$SignalingError_ProtocolImplCopyWithImpl(
+      _$SignalingError_ProtocolImpl _value, $Res Function(_$SignalingError_ProtocolImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$SignalingError_ProtocolImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignalingError_ProtocolImpl extends SignalingError_Protocol {
+  const _$SignalingError_ProtocolImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'SignalingError.protocol(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignalingError_ProtocolImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignalingError_ProtocolImplCopyWith<_$SignalingError_ProtocolImpl> get copyWith =>
+      __$$SignalingError_ProtocolImplCopyWithImpl<_$SignalingError_ProtocolImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) unauthorized,
+    required TResult Function(String field0) protocol,
+    required TResult Function() closed,
+  }) {
+    return protocol(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? unauthorized,
+    TResult? Function(String field0)? protocol,
+    TResult? Function()? closed,
+  }) {
+    return protocol?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? unauthorized,
+    TResult Function(String field0)? protocol,
+    TResult Function()? closed,
+    required TResult orElse(),
+  }) {
+    if (protocol != null) {
+      return protocol(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingError_Connection value) connection,
+    required TResult Function(SignalingError_Unauthorized value) unauthorized,
+    required TResult Function(SignalingError_Protocol value) protocol,
+    required TResult Function(SignalingError_Closed value) closed,
+  }) {
+    return protocol(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingError_Connection value)? connection,
+    TResult? Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult? Function(SignalingError_Protocol value)? protocol,
+    TResult? Function(SignalingError_Closed value)? closed,
+  }) {
+    return protocol?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingError_Connection value)? connection,
+    TResult Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult Function(SignalingError_Protocol value)? protocol,
+    TResult Function(SignalingError_Closed value)? closed,
+    required TResult orElse(),
+  }) {
+    if (protocol != null) {
+      return protocol(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SignalingError_Protocol extends SignalingError {
+  const factory SignalingError_Protocol(final String field0) = _$SignalingError_ProtocolImpl;
+  const SignalingError_Protocol._() : super._();
+
+  String get field0;
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignalingError_ProtocolImplCopyWith<_$SignalingError_ProtocolImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignalingError_ClosedImplCopyWith<$Res> {
+  factory _$$SignalingError_ClosedImplCopyWith(
+          _$SignalingError_ClosedImpl value, $Res Function(_$SignalingError_ClosedImpl) then) =
+      __$$SignalingError_ClosedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$SignalingError_ClosedImplCopyWithImpl<$Res>
+    extends _$SignalingErrorCopyWithImpl<$Res, _$SignalingError_ClosedImpl>
+    implements _$$SignalingError_ClosedImplCopyWith<$Res> {
+  __$$SignalingError_ClosedImplCopyWithImpl(
+      _$SignalingError_ClosedImpl _value, $Res Function(_$SignalingError_ClosedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignalingError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$SignalingError_ClosedImpl extends SignalingError_Closed {
+  const _$SignalingError_ClosedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'SignalingError.closed()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$SignalingError_ClosedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) unauthorized,
+    required TResult Function(String field0) protocol,
+    required TResult Function() closed,
+  }) {
+    return closed();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? unauthorized,
+    TResult? Function(String field0)? protocol,
+    TResult? Function()? closed,
+  }) {
+    return closed?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? unauthorized,
+    TResult Function(String field0)? protocol,
+    TResult Function()? closed,
+    required TResult orElse(),
+  }) {
+    if (closed != null) {
+      return closed();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingError_Connection value) connection,
+    required TResult Function(SignalingError_Unauthorized value) unauthorized,
+    required TResult Function(SignalingError_Protocol value) protocol,
+    required TResult Function(SignalingError_Closed value) closed,
+  }) {
+    return closed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingError_Connection value)? connection,
+    TResult? Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult? Function(SignalingError_Protocol value)? protocol,
+    TResult? Function(SignalingError_Closed value)? closed,
+  }) {
+    return closed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingError_Connection value)? connection,
+    TResult Function(SignalingError_Unauthorized value)? unauthorized,
+    TResult Function(SignalingError_Protocol value)? protocol,
+    TResult Function(SignalingError_Closed value)? closed,
+    required TResult orElse(),
+  }) {
+    if (closed != null) {
+      return closed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SignalingError_Closed extends SignalingError {
+  const factory SignalingError_Closed() = _$SignalingError_ClosedImpl;
+  const SignalingError_Closed._() : super._();
+}
+
+/// @nodoc
+mixin _$SignalingEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId) peerOnline,
+    required TResult Function(String deviceId) peerOffline,
+    required TResult Function(String error) disconnected,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId)? peerOnline,
+    TResult? Function(String deviceId)? peerOffline,
+    TResult? Function(String error)? disconnected,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId)? peerOnline,
+    TResult Function(String deviceId)? peerOffline,
+    TResult Function(String error)? disconnected,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingEvent_PeerOnline value) peerOnline,
+    required TResult Function(SignalingEvent_PeerOffline value) peerOffline,
+    required TResult Function(SignalingEvent_Disconnected value) disconnected,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult? Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult? Function(SignalingEvent_Disconnected value)? disconnected,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult Function(SignalingEvent_Disconnected value)? disconnected,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $SignalingEventCopyWith<$Res> {
+  factory $SignalingEventCopyWith(SignalingEvent value, $Res Function(SignalingEvent) then) =
+      _$SignalingEventCopyWithImpl<$Res, SignalingEvent>;
+}
+
+/// @nodoc
+class _$SignalingEventCopyWithImpl<$Res, $Val extends SignalingEvent> implements $SignalingEventCopyWith<$Res> {
+  _$SignalingEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SignalingEvent_PeerOnlineImplCopyWith<$Res> {
+  factory _$$SignalingEvent_PeerOnlineImplCopyWith(
+          _$SignalingEvent_PeerOnlineImpl value, $Res Function(_$SignalingEvent_PeerOnlineImpl) then) =
+      __$$SignalingEvent_PeerOnlineImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String deviceId});
+}
+
+/// @nodoc
+class __$$SignalingEvent_PeerOnlineImplCopyWithImpl<$Res>
+    extends _$SignalingEventCopyWithImpl<$Res, _$SignalingEvent_PeerOnlineImpl>
+    implements _$$SignalingEvent_PeerOnlineImplCopyWith<$Res> {
+  __$$SignalingEvent_PeerOnlineImplCopyWithImpl(
+      _$SignalingEvent_PeerOnlineImpl _value, $Res Function(_$SignalingEvent_PeerOnlineImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? deviceId = null,
+  }) {
+    return _then(_$SignalingEvent_PeerOnlineImpl(
+      deviceId: null == deviceId
+          ? _value.deviceId
+          : deviceId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignalingEvent_PeerOnlineImpl extends SignalingEvent_PeerOnline {
+  const _$SignalingEvent_PeerOnlineImpl({required this.deviceId}) : super._();
+
+  @override
+  final String deviceId;
+
+  @override
+  String toString() {
+    return 'SignalingEvent.peerOnline(deviceId: $deviceId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignalingEvent_PeerOnlineImpl &&
+            (identical(other.deviceId, deviceId) || other.deviceId == deviceId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, deviceId);
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignalingEvent_PeerOnlineImplCopyWith<_$SignalingEvent_PeerOnlineImpl> get copyWith =>
+      __$$SignalingEvent_PeerOnlineImplCopyWithImpl<_$SignalingEvent_PeerOnlineImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId) peerOnline,
+    required TResult Function(String deviceId) peerOffline,
+    required TResult Function(String error) disconnected,
+  }) {
+    return peerOnline(deviceId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId)? peerOnline,
+    TResult? Function(String deviceId)? peerOffline,
+    TResult? Function(String error)? disconnected,
+  }) {
+    return peerOnline?.call(deviceId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId)? peerOnline,
+    TResult Function(String deviceId)? peerOffline,
+    TResult Function(String error)? disconnected,
+    required TResult orElse(),
+  }) {
+    if (peerOnline != null) {
+      return peerOnline(deviceId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingEvent_PeerOnline value) peerOnline,
+    required TResult Function(SignalingEvent_PeerOffline value) peerOffline,
+    required TResult Function(SignalingEvent_Disconnected value) disconnected,
+  }) {
+    return peerOnline(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult? Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult? Function(SignalingEvent_Disconnected value)? disconnected,
+  }) {
+    return peerOnline?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult Function(SignalingEvent_Disconnected value)? disconnected,
+    required TResult orElse(),
+  }) {
+    if (peerOnline != null) {
+      return peerOnline(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SignalingEvent_PeerOnline extends SignalingEvent {
+  const factory SignalingEvent_PeerOnline({required final String deviceId}) = _$SignalingEvent_PeerOnlineImpl;
+  const SignalingEvent_PeerOnline._() : super._();
+
+  String get deviceId;
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignalingEvent_PeerOnlineImplCopyWith<_$SignalingEvent_PeerOnlineImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignalingEvent_PeerOfflineImplCopyWith<$Res> {
+  factory _$$SignalingEvent_PeerOfflineImplCopyWith(
+          _$SignalingEvent_PeerOfflineImpl value, $Res Function(_$SignalingEvent_PeerOfflineImpl) then) =
+      __$$SignalingEvent_PeerOfflineImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String deviceId});
+}
+
+/// @nodoc
+class __$$SignalingEvent_PeerOfflineImplCopyWithImpl<$Res>
+    extends _$SignalingEventCopyWithImpl<$Res, _$SignalingEvent_PeerOfflineImpl>
+    implements _$$SignalingEvent_PeerOfflineImplCopyWith<$Res> {
+  __$$SignalingEvent_PeerOfflineImplCopyWithImpl(
+      _$SignalingEvent_PeerOfflineImpl _value, $Res Function(_$SignalingEvent_PeerOfflineImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? deviceId = null,
+  }) {
+    return _then(_$SignalingEvent_PeerOfflineImpl(
+      deviceId: null == deviceId
+          ? _value.deviceId
+          : deviceId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignalingEvent_PeerOfflineImpl extends SignalingEvent_PeerOffline {
+  const _$SignalingEvent_PeerOfflineImpl({required this.deviceId}) : super._();
+
+  @override
+  final String deviceId;
+
+  @override
+  String toString() {
+    return 'SignalingEvent.peerOffline(deviceId: $deviceId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignalingEvent_PeerOfflineImpl &&
+            (identical(other.deviceId, deviceId) || other.deviceId == deviceId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, deviceId);
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignalingEvent_PeerOfflineImplCopyWith<_$SignalingEvent_PeerOfflineImpl> get copyWith =>
+      __$$SignalingEvent_PeerOfflineImplCopyWithImpl<_$SignalingEvent_PeerOfflineImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId) peerOnline,
+    required TResult Function(String deviceId) peerOffline,
+    required TResult Function(String error) disconnected,
+  }) {
+    return peerOffline(deviceId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId)? peerOnline,
+    TResult? Function(String deviceId)? peerOffline,
+    TResult? Function(String error)? disconnected,
+  }) {
+    return peerOffline?.call(deviceId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId)? peerOnline,
+    TResult Function(String deviceId)? peerOffline,
+    TResult Function(String error)? disconnected,
+    required TResult orElse(),
+  }) {
+    if (peerOffline != null) {
+      return peerOffline(deviceId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingEvent_PeerOnline value) peerOnline,
+    required TResult Function(SignalingEvent_PeerOffline value) peerOffline,
+    required TResult Function(SignalingEvent_Disconnected value) disconnected,
+  }) {
+    return peerOffline(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult? Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult? Function(SignalingEvent_Disconnected value)? disconnected,
+  }) {
+    return peerOffline?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult Function(SignalingEvent_Disconnected value)? disconnected,
+    required TResult orElse(),
+  }) {
+    if (peerOffline != null) {
+      return peerOffline(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SignalingEvent_PeerOffline extends SignalingEvent {
+  const factory SignalingEvent_PeerOffline({required final String deviceId}) = _$SignalingEvent_PeerOfflineImpl;
+  const SignalingEvent_PeerOffline._() : super._();
+
+  String get deviceId;
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignalingEvent_PeerOfflineImplCopyWith<_$SignalingEvent_PeerOfflineImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignalingEvent_DisconnectedImplCopyWith<$Res> {
+  factory _$$SignalingEvent_DisconnectedImplCopyWith(
+          _$SignalingEvent_DisconnectedImpl value, $Res Function(_$SignalingEvent_DisconnectedImpl) then) =
+      __$$SignalingEvent_DisconnectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String error});
+}
+
+/// @nodoc
+class __$$SignalingEvent_DisconnectedImplCopyWithImpl<$Res>
+    extends _$SignalingEventCopyWithImpl<$Res, _$SignalingEvent_DisconnectedImpl>
+    implements _$$SignalingEvent_DisconnectedImplCopyWith<$Res> {
+  __$$SignalingEvent_DisconnectedImplCopyWithImpl(
+      _$SignalingEvent_DisconnectedImpl _value, $Res Function(_$SignalingEvent_DisconnectedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? error = null,
+  }) {
+    return _then(_$SignalingEvent_DisconnectedImpl(
+      error: null == error
+          ? _value.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignalingEvent_DisconnectedImpl extends SignalingEvent_Disconnected {
+  const _$SignalingEvent_DisconnectedImpl({required this.error}) : super._();
+
+  @override
+  final String error;
+
+  @override
+  String toString() {
+    return 'SignalingEvent.disconnected(error: $error)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignalingEvent_DisconnectedImpl &&
+            (identical(other.error, error) || other.error == error));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, error);
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignalingEvent_DisconnectedImplCopyWith<_$SignalingEvent_DisconnectedImpl> get copyWith =>
+      __$$SignalingEvent_DisconnectedImplCopyWithImpl<_$SignalingEvent_DisconnectedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId) peerOnline,
+    required TResult Function(String deviceId) peerOffline,
+    required TResult Function(String error) disconnected,
+  }) {
+    return disconnected(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId)? peerOnline,
+    TResult? Function(String deviceId)? peerOffline,
+    TResult? Function(String error)? disconnected,
+  }) {
+    return disconnected?.call(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId)? peerOnline,
+    TResult Function(String deviceId)? peerOffline,
+    TResult Function(String error)? disconnected,
+    required TResult orElse(),
+  }) {
+    if (disconnected != null) {
+      return disconnected(error);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SignalingEvent_PeerOnline value) peerOnline,
+    required TResult Function(SignalingEvent_PeerOffline value) peerOffline,
+    required TResult Function(SignalingEvent_Disconnected value) disconnected,
+  }) {
+    return disconnected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult? Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult? Function(SignalingEvent_Disconnected value)? disconnected,
+  }) {
+    return disconnected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SignalingEvent_PeerOnline value)? peerOnline,
+    TResult Function(SignalingEvent_PeerOffline value)? peerOffline,
+    TResult Function(SignalingEvent_Disconnected value)? disconnected,
+    required TResult orElse(),
+  }) {
+    if (disconnected != null) {
+      return disconnected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SignalingEvent_Disconnected extends SignalingEvent {
+  const factory SignalingEvent_Disconnected({required final String error}) = _$SignalingEvent_DisconnectedImpl;
+  const SignalingEvent_Disconnected._() : super._();
+
+  String get error;
+
+  /// Create a copy of SignalingEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignalingEvent_DisconnectedImplCopyWith<_$SignalingEvent_DisconnectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,14 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'quic.dart';
+
+// These functions are ignored because they are not marked as `pub`: `interface_index`, `link_local_scopes`, `parse_ip`, `resolve_addresses`
+
+/// Format an address and port as `ip:port` or `[ipv6%zone]:port`
+String formatPeerAddress({required String addr, required int port}) =>
+    RustLib.instance.api.crateTransportAddressFormatPeerAddress(addr: addr, port: port);
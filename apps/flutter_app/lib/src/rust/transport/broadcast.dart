@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'broadcast.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `matches`, `message`, `new`, `send_to_all`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BroadcastItem`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// Which connected devices a broadcast goes to
+class BroadcastFilter {
+  /// Devices to send to; empty means every connected device
+  final List<String> include;
+
+  /// Devices to leave out, such as the one the item came from
+  final List<String> exclude;
+
+  const BroadcastFilter({
+    required this.include,
+    required this.exclude,
+  });
+
+  static BroadcastFilter default_() => RustLib.instance.api.crateTransportBroadcastBroadcastFilterDefault();
+
+  @override
+  int get hashCode => include.hashCode ^ exclude.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BroadcastFilter &&
+          runtimeType == other.runtimeType &&
+          include == other.include &&
+          exclude == other.exclude;
+}
+
+@freezed
+sealed class BroadcastOutcome with _$BroadcastOutcome {
+  const BroadcastOutcome._();
+
+  /// The item was sent on its own stream
+  const factory BroadcastOutcome.sent() = BroadcastOutcome_Sent;
+
+  /// The device was asked for by the filter but isn't connected
+  const factory BroadcastOutcome.notConnected() = BroadcastOutcome_NotConnected;
+
+  /// No encryption key is set for the device, so nothing was sent
+  const factory BroadcastOutcome.noKey() = BroadcastOutcome_NoKey;
+
+  /// The device is offline; the item was sealed for it and left at the signaling hub
+  const factory BroadcastOutcome.deposited() = BroadcastOutcome_Deposited;
+
+  /// Encrypting or sending failed
+  const factory BroadcastOutcome.failed({
+    required String error,
+  }) = BroadcastOutcome_Failed;
+}
@@ -0,0 +1,745 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'broadcast.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$BroadcastOutcome {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() sent,
+    required TResult Function() notConnected,
+    required TResult Function() noKey,
+    required TResult Function() deposited,
+    required TResult Function(String error) failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? sent,
+    TResult? Function()? notConnected,
+    TResult? Function()? noKey,
+    TResult? Function()? deposited,
+    TResult? Function(String error)? failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? sent,
+    TResult Function()? notConnected,
+    TResult Function()? noKey,
+    TResult Function()? deposited,
+    TResult Function(String error)? failed,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BroadcastOutcome_Sent value) sent,
+    required TResult Function(BroadcastOutcome_NotConnected value) notConnected,
+    required TResult Function(BroadcastOutcome_NoKey value) noKey,
+    required TResult Function(BroadcastOutcome_Deposited value) deposited,
+    required TResult Function(BroadcastOutcome_Failed value) failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BroadcastOutcome_Sent value)? sent,
+    TResult? Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult? Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult? Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult? Function(BroadcastOutcome_Failed value)? failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BroadcastOutcome_Sent value)? sent,
+    TResult Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult Function(BroadcastOutcome_Failed value)? failed,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BroadcastOutcomeCopyWith<$Res> {
+  factory $BroadcastOutcomeCopyWith(BroadcastOutcome value, $Res Function(BroadcastOutcome) then) =
+      _$BroadcastOutcomeCopyWithImpl<$Res, BroadcastOutcome>;
+}
+
+/// @nodoc
+class _$BroadcastOutcomeCopyWithImpl<$Res, $Val extends BroadcastOutcome> implements $BroadcastOutcomeCopyWith<$Res> {
+  _$BroadcastOutcomeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$BroadcastOutcome_SentImplCopyWith<$Res> {
+  factory _$$BroadcastOutcome_SentImplCopyWith(
+          _$BroadcastOutcome_SentImpl value, $Res Function(_$BroadcastOutcome_SentImpl) then) =
+      __$$BroadcastOutcome_SentImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BroadcastOutcome_SentImplCopyWithImpl<$Res>
+    extends _$BroadcastOutcomeCopyWithImpl<$Res, _$BroadcastOutcome_SentImpl>
+    implements _$$BroadcastOutcome_SentImplCopyWith<$Res> {
+  __$$BroadcastOutcome_SentImplCopyWithImpl(
+      _$BroadcastOutcome_SentImpl _value, $Res Function(_$BroadcastOutcome_SentImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$BroadcastOutcome_SentImpl extends BroadcastOutcome_Sent {
+  const _$BroadcastOutcome_SentImpl() : super._();
+
+  @override
+  String toString() {
+    return 'BroadcastOutcome.sent()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BroadcastOutcome_SentImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() sent,
+    required TResult Function() notConnected,
+    required TResult Function() noKey,
+    required TResult Function() deposited,
+    required TResult Function(String error) failed,
+  }) {
+    return sent();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? sent,
+    TResult? Function()? notConnected,
+    TResult? Function()? noKey,
+    TResult? Function()? deposited,
+    TResult? Function(String error)? failed,
+  }) {
+    return sent?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? sent,
+    TResult Function()? notConnected,
+    TResult Function()? noKey,
+    TResult Function()? deposited,
+    TResult Function(String error)? failed,
+    required TResult orElse(),
+  }) {
+    if (sent != null) {
+      return sent();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BroadcastOutcome_Sent value) sent,
+    required TResult Function(BroadcastOutcome_NotConnected value) notConnected,
+    required TResult Function(BroadcastOutcome_NoKey value) noKey,
+    required TResult Function(BroadcastOutcome_Deposited value) deposited,
+    required TResult Function(BroadcastOutcome_Failed value) failed,
+  }) {
+    return sent(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BroadcastOutcome_Sent value)? sent,
+    TResult? Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult? Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult? Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult? Function(BroadcastOutcome_Failed value)? failed,
+  }) {
+    return sent?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BroadcastOutcome_Sent value)? sent,
+    TResult Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult Function(BroadcastOutcome_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    if (sent != null) {
+      return sent(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BroadcastOutcome_Sent extends BroadcastOutcome {
+  const factory BroadcastOutcome_Sent() = _$BroadcastOutcome_SentImpl;
+  const BroadcastOutcome_Sent._() : super._();
+}
+
+/// @nodoc
+abstract class _$$BroadcastOutcome_NotConnectedImplCopyWith<$Res> {
+  factory _$$BroadcastOutcome_NotConnectedImplCopyWith(
+          _$BroadcastOutcome_NotConnectedImpl value, $Res Function(_$BroadcastOutcome_NotConnectedImpl) then) =
+      __$$BroadcastOutcome_NotConnectedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BroadcastOutcome_NotConnectedImplCopyWithImpl<$Res>
+    extends _$BroadcastOutcomeCopyWithImpl<$Res, _$BroadcastOutcome_NotConnectedImpl>
+    implements _$$BroadcastOutcome_NotConnectedImplCopyWith<$Res> {
+  __$$BroadcastOutcome_NotConnectedImplCopyWithImpl(
+      _$BroadcastOutcome_NotConnectedImpl _value, $Res Function(_$BroadcastOutcome_NotConnectedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$BroadcastOutcome_NotConnectedImpl extends BroadcastOutcome_NotConnected {
+  const _$BroadcastOutcome_NotConnectedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'BroadcastOutcome.notConnected()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BroadcastOutcome_NotConnectedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() sent,
+    required TResult Function() notConnected,
+    required TResult Function() noKey,
+    required TResult Function() deposited,
+    required TResult Function(String error) failed,
+  }) {
+    return notConnected();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? sent,
+    TResult? Function()? notConnected,
+    TResult? Function()? noKey,
+    TResult? Function()? deposited,
+    TResult? Function(String error)? failed,
+  }) {
+    return notConnected?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? sent,
+    TResult Function()? notConnected,
+    TResult Function()? noKey,
+    TResult Function()? deposited,
+    TResult Function(String error)? failed,
+    required TResult orElse(),
+  }) {
+    if (notConnected != null) {
+      return notConnected();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BroadcastOutcome_Sent value) sent,
+    required TResult Function(BroadcastOutcome_NotConnected value) notConnected,
+    required TResult Function(BroadcastOutcome_NoKey value) noKey,
+    required TResult Function(BroadcastOutcome_Deposited value) deposited,
+    required TResult Function(BroadcastOutcome_Failed value) failed,
+  }) {
+    return notConnected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BroadcastOutcome_Sent value)? sent,
+    TResult? Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult? Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult? Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult? Function(BroadcastOutcome_Failed value)? failed,
+  }) {
+    return notConnected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BroadcastOutcome_Sent value)? sent,
+    TResult Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult Function(BroadcastOutcome_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    if (notConnected != null) {
+      return notConnected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BroadcastOutcome_NotConnected extends BroadcastOutcome {
+  const factory BroadcastOutcome_NotConnected() = _$BroadcastOutcome_NotConnectedImpl;
+  const BroadcastOutcome_NotConnected._() : super._();
+}
+
+/// @nodoc
+abstract class _$$BroadcastOutcome_NoKeyImplCopyWith<$Res> {
+  factory _$$BroadcastOutcome_NoKeyImplCopyWith(
+          _$BroadcastOutcome_NoKeyImpl value, $Res Function(_$BroadcastOutcome_NoKeyImpl) then) =
+      __$$BroadcastOutcome_NoKeyImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BroadcastOutcome_NoKeyImplCopyWithImpl<$Res>
+    extends _$BroadcastOutcomeCopyWithImpl<$Res, _$BroadcastOutcome_NoKeyImpl>
+    implements _$$BroadcastOutcome_NoKeyImplCopyWith<$Res> {
+  __$$BroadcastOutcome_NoKeyImplCopyWithImpl(
+      _$BroadcastOutcome_NoKeyImpl _value, $Res Function(_$BroadcastOutcome_NoKeyImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$BroadcastOutcome_NoKeyImpl extends BroadcastOutcome_NoKey {
+  const _$BroadcastOutcome_NoKeyImpl() : super._();
+
+  @override
+  String toString() {
+    return 'BroadcastOutcome.noKey()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BroadcastOutcome_NoKeyImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() sent,
+    required TResult Function() notConnected,
+    required TResult Function() noKey,
+    required TResult Function() deposited,
+    required TResult Function(String error) failed,
+  }) {
+    return noKey();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? sent,
+    TResult? Function()? notConnected,
+    TResult? Function()? noKey,
+    TResult? Function()? deposited,
+    TResult? Function(String error)? failed,
+  }) {
+    return noKey?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? sent,
+    TResult Function()? notConnected,
+    TResult Function()? noKey,
+    TResult Function()? deposited,
+    TResult Function(String error)? failed,
+    required TResult orElse(),
+  }) {
+    if (noKey != null) {
+      return noKey();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BroadcastOutcome_Sent value) sent,
+    required TResult Function(BroadcastOutcome_NotConnected value) notConnected,
+    required TResult Function(BroadcastOutcome_NoKey value) noKey,
+    required TResult Function(BroadcastOutcome_Deposited value) deposited,
+    required TResult Function(BroadcastOutcome_Failed value) failed,
+  }) {
+    return noKey(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BroadcastOutcome_Sent value)? sent,
+    TResult? Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult? Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult? Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult? Function(BroadcastOutcome_Failed value)? failed,
+  }) {
+    return noKey?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BroadcastOutcome_Sent value)? sent,
+    TResult Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult Function(BroadcastOutcome_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    if (noKey != null) {
+      return noKey(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BroadcastOutcome_NoKey extends BroadcastOutcome {
+  const factory BroadcastOutcome_NoKey() = _$BroadcastOutcome_NoKeyImpl;
+  const BroadcastOutcome_NoKey._() : super._();
+}
+
+/// @nodoc
+abstract class _$$BroadcastOutcome_DepositedImplCopyWith<$Res> {
+  factory _$$BroadcastOutcome_DepositedImplCopyWith(
+          _$BroadcastOutcome_DepositedImpl value, $Res Function(_$BroadcastOutcome_DepositedImpl) then) =
+      __$$BroadcastOutcome_DepositedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BroadcastOutcome_DepositedImplCopyWithImpl<$Res>
+    extends _$BroadcastOutcomeCopyWithImpl<$Res, _$BroadcastOutcome_DepositedImpl>
+    implements _$$BroadcastOutcome_DepositedImplCopyWith<$Res> {
+  __$$BroadcastOutcome_DepositedImplCopyWithImpl(
+      _$BroadcastOutcome_DepositedImpl _value, $Res Function(_$BroadcastOutcome_DepositedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$BroadcastOutcome_DepositedImpl extends BroadcastOutcome_Deposited {
+  const _$BroadcastOutcome_DepositedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'BroadcastOutcome.deposited()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BroadcastOutcome_DepositedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() sent,
+    required TResult Function() notConnected,
+    required TResult Function() noKey,
+    required TResult Function() deposited,
+    required TResult Function(String error) failed,
+  }) {
+    return deposited();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? sent,
+    TResult? Function()? notConnected,
+    TResult? Function()? noKey,
+    TResult? Function()? deposited,
+    TResult? Function(String error)? failed,
+  }) {
+    return deposited?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? sent,
+    TResult Function()? notConnected,
+    TResult Function()? noKey,
+    TResult Function()? deposited,
+    TResult Function(String error)? failed,
+    required TResult orElse(),
+  }) {
+    if (deposited != null) {
+      return deposited();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BroadcastOutcome_Sent value) sent,
+    required TResult Function(BroadcastOutcome_NotConnected value) notConnected,
+    required TResult Function(BroadcastOutcome_NoKey value) noKey,
+    required TResult Function(BroadcastOutcome_Deposited value) deposited,
+    required TResult Function(BroadcastOutcome_Failed value) failed,
+  }) {
+    return deposited(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BroadcastOutcome_Sent value)? sent,
+    TResult? Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult? Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult? Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult? Function(BroadcastOutcome_Failed value)? failed,
+  }) {
+    return deposited?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BroadcastOutcome_Sent value)? sent,
+    TResult Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult Function(BroadcastOutcome_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    if (deposited != null) {
+      return deposited(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BroadcastOutcome_Deposited extends BroadcastOutcome {
+  const factory BroadcastOutcome_Deposited() = _$BroadcastOutcome_DepositedImpl;
+  const BroadcastOutcome_Deposited._() : super._();
+}
+
+/// @nodoc
+abstract class _$$BroadcastOutcome_FailedImplCopyWith<$Res> {
+  factory _$$BroadcastOutcome_FailedImplCopyWith(
+          _$BroadcastOutcome_FailedImpl value, $Res Function(_$BroadcastOutcome_FailedImpl) then) =
+      __$$BroadcastOutcome_FailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String error});
+}
+
+/// @nodoc
+class __$$BroadcastOutcome_FailedImplCopyWithImpl<$Res>
+    extends _$BroadcastOutcomeCopyWithImpl<$Res, _$BroadcastOutcome_FailedImpl>
+    implements _$$BroadcastOutcome_FailedImplCopyWith<$Res> {
+  __$$BroadcastOutcome_FailedImplCopyWithImpl(
+      _$BroadcastOutcome_FailedImpl _value, $Res Function(_$BroadcastOutcome_FailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? error = null,
+  }) {
+    return _then(_$BroadcastOutcome_FailedImpl(
+      error: null == error
+          ? _value.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BroadcastOutcome_FailedImpl extends BroadcastOutcome_Failed {
+  const _$BroadcastOutcome_FailedImpl({required this.error}) : super._();
+
+  @override
+  final String error;
+
+  @override
+  String toString() {
+    return 'BroadcastOutcome.failed(error: $error)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BroadcastOutcome_FailedImpl &&
+            (identical(other.error, error) || other.error == error));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, error);
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BroadcastOutcome_FailedImplCopyWith<_$BroadcastOutcome_FailedImpl> get copyWith =>
+      __$$BroadcastOutcome_FailedImplCopyWithImpl<_$BroadcastOutcome_FailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() sent,
+    required TResult Function() notConnected,
+    required TResult Function() noKey,
+    required TResult Function() deposited,
+    required TResult Function(String error) failed,
+  }) {
+    return failed(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? sent,
+    TResult? Function()? notConnected,
+    TResult? Function()? noKey,
+    TResult? Function()? deposited,
+    TResult? Function(String error)? failed,
+  }) {
+    return failed?.call(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? sent,
+    TResult Function()? notConnected,
+    TResult Function()? noKey,
+    TResult Function()? deposited,
+    TResult Function(String error)? failed,
+    required TResult orElse(),
+  }) {
+    if (failed != null) {
+      return failed(error);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BroadcastOutcome_Sent value) sent,
+    required TResult Function(BroadcastOutcome_NotConnected value) notConnected,
+    required TResult Function(BroadcastOutcome_NoKey value) noKey,
+    required TResult Function(BroadcastOutcome_Deposited value) deposited,
+    required TResult Function(BroadcastOutcome_Failed value) failed,
+  }) {
+    return failed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BroadcastOutcome_Sent value)? sent,
+    TResult? Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult? Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult? Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult? Function(BroadcastOutcome_Failed value)? failed,
+  }) {
+    return failed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BroadcastOutcome_Sent value)? sent,
+    TResult Function(BroadcastOutcome_NotConnected value)? notConnected,
+    TResult Function(BroadcastOutcome_NoKey value)? noKey,
+    TResult Function(BroadcastOutcome_Deposited value)? deposited,
+    TResult Function(BroadcastOutcome_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    if (failed != null) {
+      return failed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BroadcastOutcome_Failed extends BroadcastOutcome {
+  const factory BroadcastOutcome_Failed({required final String error}) = _$BroadcastOutcome_FailedImpl;
+  const BroadcastOutcome_Failed._() : super._();
+
+  String get error;
+
+  /// Create a copy of BroadcastOutcome
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$BroadcastOutcome_FailedImplCopyWith<_$BroadcastOutcome_FailedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
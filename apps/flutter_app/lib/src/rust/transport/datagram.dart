@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'datagram.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `answer`, `code`, `from_code`, `handle`, `heartbeat`, `link_state`, `new`, `quality`, `quality`, `send`, `set_activity`, `spawn`, `subscribe`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LinkState`, `Links`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// What a user is doing on a device, shown to its peers
+enum Activity {
+  idle,
+  typing,
+  copying,
+  ;
+  static Activity default_() => RustLib.instance.api.crateTransportDatagramActivityDefault();
+}
+
+@freezed
+sealed class LinkEvent with _$LinkEvent {
+  const LinkEvent._();
+
+  /// Published after every heartbeat sent to the peer
+  const factory LinkEvent.qualityChanged({
+    required LinkQuality quality,
+  }) = LinkEvent_QualityChanged;
+
+  /// The peer reported a different activity
+  const factory LinkEvent.activityChanged({
+    required String peerId,
+    required Activity activity,
+  }) = LinkEvent_ActivityChanged;
+}
+
+/// Live quality of the link to a peer
+class LinkQuality {
+  final String peerId;
+
+  /// Smoothed heartbeat round-trip time in milliseconds, `None` until the first reply
+  final double? rttMs;
+
+  /// Share of recent heartbeats left unanswered, from 0.0 to 1.0
+  final double loss;
+
+  /// Milliseconds since the last datagram from the peer
+  final BigInt lastHeardMs;
+
+  /// What the peer last said it is doing
+  final Activity activity;
+
+  const LinkQuality({
+    required this.peerId,
+    this.rttMs,
+    required this.loss,
+    required this.lastHeardMs,
+    required this.activity,
+  });
+
+  @override
+  int get hashCode => peerId.hashCode ^ rttMs.hashCode ^ loss.hashCode ^ lastHeardMs.hashCode ^ activity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LinkQuality &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          rttMs == other.rttMs &&
+          loss == other.loss &&
+          lastHeardMs == other.lastHeardMs &&
+          activity == other.activity;
+}
@@ -0,0 +1,367 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'datagram.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$LinkEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LinkQuality quality) qualityChanged,
+    required TResult Function(String peerId, Activity activity) activityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LinkQuality quality)? qualityChanged,
+    TResult? Function(String peerId, Activity activity)? activityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LinkQuality quality)? qualityChanged,
+    TResult Function(String peerId, Activity activity)? activityChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LinkEvent_QualityChanged value) qualityChanged,
+    required TResult Function(LinkEvent_ActivityChanged value) activityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LinkEvent_QualityChanged value)? qualityChanged,
+    TResult? Function(LinkEvent_ActivityChanged value)? activityChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LinkEvent_QualityChanged value)? qualityChanged,
+    TResult Function(LinkEvent_ActivityChanged value)? activityChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $LinkEventCopyWith<$Res> {
+  factory $LinkEventCopyWith(LinkEvent value, $Res Function(LinkEvent) then) = _$LinkEventCopyWithImpl<$Res, LinkEvent>;
+}
+
+/// @nodoc
+class _$LinkEventCopyWithImpl<$Res, $Val extends LinkEvent> implements $LinkEventCopyWith<$Res> {
+  _$LinkEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of LinkEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$LinkEvent_QualityChangedImplCopyWith<$Res> {
+  factory _$$LinkEvent_QualityChangedImplCopyWith(
+          _$LinkEvent_QualityChangedImpl value, $Res Function(_$LinkEvent_QualityChangedImpl) then) =
+      __$$LinkEvent_QualityChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LinkQuality quality});
+}
+
+/// @nodoc
+class __$$LinkEvent_QualityChangedImplCopyWithImpl<$Res>
+    extends _$LinkEventCopyWithImpl<$Res, _$LinkEvent_QualityChangedImpl>
+    implements _$$LinkEvent_QualityChangedImplCopyWith<$Res> {
+  __$$LinkEvent_QualityChangedImplCopyWithImpl(
+      _$LinkEvent_QualityChangedImpl _value, $Res Function(_$LinkEvent_QualityChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LinkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? quality = null,
+  }) {
+    return _then(_$LinkEvent_QualityChangedImpl(
+      quality: null == quality
+          ? _value.quality
+          : quality // ignore: cast_nullable_to_non_nullable
+              as LinkQuality,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LinkEvent_QualityChangedImpl extends LinkEvent_QualityChanged {
+  const _$LinkEvent_QualityChangedImpl({required this.quality}) : super._();
+
+  @override
+  final LinkQuality quality;
+
+  @override
+  String toString() {
+    return 'LinkEvent.qualityChanged(quality: $quality)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LinkEvent_QualityChangedImpl &&
+            (identical(other.quality, quality) || other.quality == quality));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, quality);
+
+  /// Create a copy of LinkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LinkEvent_QualityChangedImplCopyWith<_$LinkEvent_QualityChangedImpl> get copyWith =>
+      __$$LinkEvent_QualityChangedImplCopyWithImpl<_$LinkEvent_QualityChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LinkQuality quality) qualityChanged,
+    required TResult Function(String peerId, Activity activity) activityChanged,
+  }) {
+    return qualityChanged(quality);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LinkQuality quality)? qualityChanged,
+    TResult? Function(String peerId, Activity activity)? activityChanged,
+  }) {
+    return qualityChanged?.call(quality);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LinkQuality quality)? qualityChanged,
+    TResult Function(String peerId, Activity activity)? activityChanged,
+    required TResult orElse(),
+  }) {
+    if (qualityChanged != null) {
+      return qualityChanged(quality);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LinkEvent_QualityChanged value) qualityChanged,
+    required TResult Function(LinkEvent_ActivityChanged value) activityChanged,
+  }) {
+    return qualityChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LinkEvent_QualityChanged value)? qualityChanged,
+    TResult? Function(LinkEvent_ActivityChanged value)? activityChanged,
+  }) {
+    return qualityChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LinkEvent_QualityChanged value)? qualityChanged,
+    TResult Function(LinkEvent_ActivityChanged value)? activityChanged,
+    required TResult orElse(),
+  }) {
+    if (qualityChanged != null) {
+      return qualityChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LinkEvent_QualityChanged extends LinkEvent {
+  const factory LinkEvent_QualityChanged({required final LinkQuality quality}) = _$LinkEvent_QualityChangedImpl;
+  const LinkEvent_QualityChanged._() : super._();
+
+  LinkQuality get quality;
+
+  /// Create a copy of LinkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LinkEvent_QualityChangedImplCopyWith<_$LinkEvent_QualityChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LinkEvent_ActivityChangedImplCopyWith<$Res> {
+  factory _$$LinkEvent_ActivityChangedImplCopyWith(
+          _$LinkEvent_ActivityChangedImpl value, $Res Function(_$LinkEvent_ActivityChangedImpl) then) =
+      __$$LinkEvent_ActivityChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String peerId, Activity activity});
+}
+
+/// @nodoc
+class __$$LinkEvent_ActivityChangedImplCopyWithImpl<$Res>
+    extends _$LinkEventCopyWithImpl<$Res, _$LinkEvent_ActivityChangedImpl>
+    implements _$$LinkEvent_ActivityChangedImplCopyWith<$Res> {
+  __$$LinkEvent_ActivityChangedImplCopyWithImpl(
+      _$LinkEvent_ActivityChangedImpl _value, $Res Function(_$LinkEvent_ActivityChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LinkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? peerId = null,
+    Object? activity = null,
+  }) {
+    return _then(_$LinkEvent_ActivityChangedImpl(
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      activity: null == activity
+          ? _value.activity
+          : activity // ignore: cast_nullable_to_non_nullable
+              as Activity,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LinkEvent_ActivityChangedImpl extends LinkEvent_ActivityChanged {
+  const _$LinkEvent_ActivityChangedImpl({required this.peerId, required this.activity}) : super._();
+
+  @override
+  final String peerId;
+  @override
+  final Activity activity;
+
+  @override
+  String toString() {
+    return 'LinkEvent.activityChanged(peerId: $peerId, activity: $activity)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LinkEvent_ActivityChangedImpl &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.activity, activity) || other.activity == activity));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, peerId, activity);
+
+  /// Create a copy of LinkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LinkEvent_ActivityChangedImplCopyWith<_$LinkEvent_ActivityChangedImpl> get copyWith =>
+      __$$LinkEvent_ActivityChangedImplCopyWithImpl<_$LinkEvent_ActivityChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LinkQuality quality) qualityChanged,
+    required TResult Function(String peerId, Activity activity) activityChanged,
+  }) {
+    return activityChanged(peerId, activity);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LinkQuality quality)? qualityChanged,
+    TResult? Function(String peerId, Activity activity)? activityChanged,
+  }) {
+    return activityChanged?.call(peerId, activity);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LinkQuality quality)? qualityChanged,
+    TResult Function(String peerId, Activity activity)? activityChanged,
+    required TResult orElse(),
+  }) {
+    if (activityChanged != null) {
+      return activityChanged(peerId, activity);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LinkEvent_QualityChanged value) qualityChanged,
+    required TResult Function(LinkEvent_ActivityChanged value) activityChanged,
+  }) {
+    return activityChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LinkEvent_QualityChanged value)? qualityChanged,
+    TResult? Function(LinkEvent_ActivityChanged value)? activityChanged,
+  }) {
+    return activityChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LinkEvent_QualityChanged value)? qualityChanged,
+    TResult Function(LinkEvent_ActivityChanged value)? activityChanged,
+    required TResult orElse(),
+  }) {
+    if (activityChanged != null) {
+      return activityChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LinkEvent_ActivityChanged extends LinkEvent {
+  const factory LinkEvent_ActivityChanged({required final String peerId, required final Activity activity}) =
+      _$LinkEvent_ActivityChangedImpl;
+  const LinkEvent_ActivityChanged._() : super._();
+
+  String get peerId;
+  Activity get activity;
+
+  /// Create a copy of LinkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LinkEvent_ActivityChangedImplCopyWith<_$LinkEvent_ActivityChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
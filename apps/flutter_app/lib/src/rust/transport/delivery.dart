@@ -0,0 +1,35 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'delivery.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `forget`, `receive_with_ack`, `resolve`, `send_with_ack`, `wait`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PendingAcks`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+@freezed
+sealed class DeliveryResult with _$DeliveryResult {
+  const DeliveryResult._();
+
+  /// The peer applied the item
+  const factory DeliveryResult.delivered({
+    required BigInt itemId,
+  }) = DeliveryResult_Delivered;
+
+  /// The peer received the item but could not or would not apply it
+  const factory DeliveryResult.rejected({
+    required BigInt itemId,
+    required String reason,
+  }) = DeliveryResult_Rejected;
+
+  /// No answer arrived before the timeout
+  const factory DeliveryResult.timedOut({
+    required BigInt itemId,
+  }) = DeliveryResult_TimedOut;
+}
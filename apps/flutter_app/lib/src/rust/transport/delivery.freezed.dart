@@ -0,0 +1,562 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'delivery.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$DeliveryResult {
+  BigInt get itemId => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt itemId) delivered,
+    required TResult Function(BigInt itemId, String reason) rejected,
+    required TResult Function(BigInt itemId) timedOut,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt itemId)? delivered,
+    TResult? Function(BigInt itemId, String reason)? rejected,
+    TResult? Function(BigInt itemId)? timedOut,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt itemId)? delivered,
+    TResult Function(BigInt itemId, String reason)? rejected,
+    TResult Function(BigInt itemId)? timedOut,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DeliveryResult_Delivered value) delivered,
+    required TResult Function(DeliveryResult_Rejected value) rejected,
+    required TResult Function(DeliveryResult_TimedOut value) timedOut,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DeliveryResult_Delivered value)? delivered,
+    TResult? Function(DeliveryResult_Rejected value)? rejected,
+    TResult? Function(DeliveryResult_TimedOut value)? timedOut,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DeliveryResult_Delivered value)? delivered,
+    TResult Function(DeliveryResult_Rejected value)? rejected,
+    TResult Function(DeliveryResult_TimedOut value)? timedOut,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $DeliveryResultCopyWith<DeliveryResult> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $DeliveryResultCopyWith<$Res> {
+  factory $DeliveryResultCopyWith(DeliveryResult value, $Res Function(DeliveryResult) then) =
+      _$DeliveryResultCopyWithImpl<$Res, DeliveryResult>;
+  @useResult
+  $Res call({BigInt itemId});
+}
+
+/// @nodoc
+class _$DeliveryResultCopyWithImpl<$Res, $Val extends DeliveryResult> implements $DeliveryResultCopyWith<$Res> {
+  _$DeliveryResultCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? itemId = null,
+  }) {
+    return _then(_value.copyWith(
+      itemId: null == itemId
+          ? _value.itemId
+          : itemId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$DeliveryResult_DeliveredImplCopyWith<$Res> implements $DeliveryResultCopyWith<$Res> {
+  factory _$$DeliveryResult_DeliveredImplCopyWith(
+          _$DeliveryResult_DeliveredImpl value, $Res Function(_$DeliveryResult_DeliveredImpl) then) =
+      __$$DeliveryResult_DeliveredImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt itemId});
+}
+
+/// @nodoc
+class __$$DeliveryResult_DeliveredImplCopyWithImpl<$Res>
+    extends _$DeliveryResultCopyWithImpl<$Res, _$DeliveryResult_DeliveredImpl>
+    implements _$$DeliveryResult_DeliveredImplCopyWith<$Res> {
+  __$$DeliveryResult_DeliveredImplCopyWithImpl(
+      _$DeliveryResult_DeliveredImpl _value, $Res Function(_$DeliveryResult_DeliveredImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? itemId = null,
+  }) {
+    return _then(_$DeliveryResult_DeliveredImpl(
+      itemId: null == itemId
+          ? _value.itemId
+          : itemId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$DeliveryResult_DeliveredImpl extends DeliveryResult_Delivered {
+  const _$DeliveryResult_DeliveredImpl({required this.itemId}) : super._();
+
+  @override
+  final BigInt itemId;
+
+  @override
+  String toString() {
+    return 'DeliveryResult.delivered(itemId: $itemId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DeliveryResult_DeliveredImpl &&
+            (identical(other.itemId, itemId) || other.itemId == itemId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, itemId);
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DeliveryResult_DeliveredImplCopyWith<_$DeliveryResult_DeliveredImpl> get copyWith =>
+      __$$DeliveryResult_DeliveredImplCopyWithImpl<_$DeliveryResult_DeliveredImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt itemId) delivered,
+    required TResult Function(BigInt itemId, String reason) rejected,
+    required TResult Function(BigInt itemId) timedOut,
+  }) {
+    return delivered(itemId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt itemId)? delivered,
+    TResult? Function(BigInt itemId, String reason)? rejected,
+    TResult? Function(BigInt itemId)? timedOut,
+  }) {
+    return delivered?.call(itemId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt itemId)? delivered,
+    TResult Function(BigInt itemId, String reason)? rejected,
+    TResult Function(BigInt itemId)? timedOut,
+    required TResult orElse(),
+  }) {
+    if (delivered != null) {
+      return delivered(itemId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DeliveryResult_Delivered value) delivered,
+    required TResult Function(DeliveryResult_Rejected value) rejected,
+    required TResult Function(DeliveryResult_TimedOut value) timedOut,
+  }) {
+    return delivered(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DeliveryResult_Delivered value)? delivered,
+    TResult? Function(DeliveryResult_Rejected value)? rejected,
+    TResult? Function(DeliveryResult_TimedOut value)? timedOut,
+  }) {
+    return delivered?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DeliveryResult_Delivered value)? delivered,
+    TResult Function(DeliveryResult_Rejected value)? rejected,
+    TResult Function(DeliveryResult_TimedOut value)? timedOut,
+    required TResult orElse(),
+  }) {
+    if (delivered != null) {
+      return delivered(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DeliveryResult_Delivered extends DeliveryResult {
+  const factory DeliveryResult_Delivered({required final BigInt itemId}) = _$DeliveryResult_DeliveredImpl;
+  const DeliveryResult_Delivered._() : super._();
+
+  @override
+  BigInt get itemId;
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DeliveryResult_DeliveredImplCopyWith<_$DeliveryResult_DeliveredImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$DeliveryResult_RejectedImplCopyWith<$Res> implements $DeliveryResultCopyWith<$Res> {
+  factory _$$DeliveryResult_RejectedImplCopyWith(
+          _$DeliveryResult_RejectedImpl value, $Res Function(_$DeliveryResult_RejectedImpl) then) =
+      __$$DeliveryResult_RejectedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt itemId, String reason});
+}
+
+/// @nodoc
+class __$$DeliveryResult_RejectedImplCopyWithImpl<$Res>
+    extends _$DeliveryResultCopyWithImpl<$Res, _$DeliveryResult_RejectedImpl>
+    implements _$$DeliveryResult_RejectedImplCopyWith<$Res> {
+  __$$DeliveryResult_RejectedImplCopyWithImpl(
+      _$DeliveryResult_RejectedImpl _value, $Res Function(_$DeliveryResult_RejectedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? itemId = null,
+    Object? reason = null,
+  }) {
+    return _then(_$DeliveryResult_RejectedImpl(
+      itemId: null == itemId
+          ? _value.itemId
+          : itemId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$DeliveryResult_RejectedImpl extends DeliveryResult_Rejected {
+  const _$DeliveryResult_RejectedImpl({required this.itemId, required this.reason}) : super._();
+
+  @override
+  final BigInt itemId;
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'DeliveryResult.rejected(itemId: $itemId, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DeliveryResult_RejectedImpl &&
+            (identical(other.itemId, itemId) || other.itemId == itemId) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, itemId, reason);
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DeliveryResult_RejectedImplCopyWith<_$DeliveryResult_RejectedImpl> get copyWith =>
+      __$$DeliveryResult_RejectedImplCopyWithImpl<_$DeliveryResult_RejectedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt itemId) delivered,
+    required TResult Function(BigInt itemId, String reason) rejected,
+    required TResult Function(BigInt itemId) timedOut,
+  }) {
+    return rejected(itemId, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt itemId)? delivered,
+    TResult? Function(BigInt itemId, String reason)? rejected,
+    TResult? Function(BigInt itemId)? timedOut,
+  }) {
+    return rejected?.call(itemId, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt itemId)? delivered,
+    TResult Function(BigInt itemId, String reason)? rejected,
+    TResult Function(BigInt itemId)? timedOut,
+    required TResult orElse(),
+  }) {
+    if (rejected != null) {
+      return rejected(itemId, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DeliveryResult_Delivered value) delivered,
+    required TResult Function(DeliveryResult_Rejected value) rejected,
+    required TResult Function(DeliveryResult_TimedOut value) timedOut,
+  }) {
+    return rejected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DeliveryResult_Delivered value)? delivered,
+    TResult? Function(DeliveryResult_Rejected value)? rejected,
+    TResult? Function(DeliveryResult_TimedOut value)? timedOut,
+  }) {
+    return rejected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DeliveryResult_Delivered value)? delivered,
+    TResult Function(DeliveryResult_Rejected value)? rejected,
+    TResult Function(DeliveryResult_TimedOut value)? timedOut,
+    required TResult orElse(),
+  }) {
+    if (rejected != null) {
+      return rejected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DeliveryResult_Rejected extends DeliveryResult {
+  const factory DeliveryResult_Rejected({required final BigInt itemId, required final String reason}) =
+      _$DeliveryResult_RejectedImpl;
+  const DeliveryResult_Rejected._() : super._();
+
+  @override
+  BigInt get itemId;
+  String get reason;
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DeliveryResult_RejectedImplCopyWith<_$DeliveryResult_RejectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$DeliveryResult_TimedOutImplCopyWith<$Res> implements $DeliveryResultCopyWith<$Res> {
+  factory _$$DeliveryResult_TimedOutImplCopyWith(
+          _$DeliveryResult_TimedOutImpl value, $Res Function(_$DeliveryResult_TimedOutImpl) then) =
+      __$$DeliveryResult_TimedOutImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt itemId});
+}
+
+/// @nodoc
+class __$$DeliveryResult_TimedOutImplCopyWithImpl<$Res>
+    extends _$DeliveryResultCopyWithImpl<$Res, _$DeliveryResult_TimedOutImpl>
+    implements _$$DeliveryResult_TimedOutImplCopyWith<$Res> {
+  __$$DeliveryResult_TimedOutImplCopyWithImpl(
+      _$DeliveryResult_TimedOutImpl _value, $Res Function(_$DeliveryResult_TimedOutImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? itemId = null,
+  }) {
+    return _then(_$DeliveryResult_TimedOutImpl(
+      itemId: null == itemId
+          ? _value.itemId
+          : itemId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$DeliveryResult_TimedOutImpl extends DeliveryResult_TimedOut {
+  const _$DeliveryResult_TimedOutImpl({required this.itemId}) : super._();
+
+  @override
+  final BigInt itemId;
+
+  @override
+  String toString() {
+    return 'DeliveryResult.timedOut(itemId: $itemId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DeliveryResult_TimedOutImpl &&
+            (identical(other.itemId, itemId) || other.itemId == itemId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, itemId);
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DeliveryResult_TimedOutImplCopyWith<_$DeliveryResult_TimedOutImpl> get copyWith =>
+      __$$DeliveryResult_TimedOutImplCopyWithImpl<_$DeliveryResult_TimedOutImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt itemId) delivered,
+    required TResult Function(BigInt itemId, String reason) rejected,
+    required TResult Function(BigInt itemId) timedOut,
+  }) {
+    return timedOut(itemId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt itemId)? delivered,
+    TResult? Function(BigInt itemId, String reason)? rejected,
+    TResult? Function(BigInt itemId)? timedOut,
+  }) {
+    return timedOut?.call(itemId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt itemId)? delivered,
+    TResult Function(BigInt itemId, String reason)? rejected,
+    TResult Function(BigInt itemId)? timedOut,
+    required TResult orElse(),
+  }) {
+    if (timedOut != null) {
+      return timedOut(itemId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DeliveryResult_Delivered value) delivered,
+    required TResult Function(DeliveryResult_Rejected value) rejected,
+    required TResult Function(DeliveryResult_TimedOut value) timedOut,
+  }) {
+    return timedOut(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DeliveryResult_Delivered value)? delivered,
+    TResult? Function(DeliveryResult_Rejected value)? rejected,
+    TResult? Function(DeliveryResult_TimedOut value)? timedOut,
+  }) {
+    return timedOut?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DeliveryResult_Delivered value)? delivered,
+    TResult Function(DeliveryResult_Rejected value)? rejected,
+    TResult Function(DeliveryResult_TimedOut value)? timedOut,
+    required TResult orElse(),
+  }) {
+    if (timedOut != null) {
+      return timedOut(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DeliveryResult_TimedOut extends DeliveryResult {
+  const factory DeliveryResult_TimedOut({required final BigInt itemId}) = _$DeliveryResult_TimedOutImpl;
+  const DeliveryResult_TimedOut._() : super._();
+
+  @override
+  BigInt get itemId;
+
+  /// Create a copy of DeliveryResult
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DeliveryResult_TimedOutImplCopyWith<_$DeliveryResult_TimedOutImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
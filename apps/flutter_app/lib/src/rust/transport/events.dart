@@ -0,0 +1,101 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'supervisor.dart';
+part 'events.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `from_close`, `from_connection_error`, `publish`, `spawn_migration_watch`, `wait_for_peer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+@freezed
+sealed class ConnectionEvent with _$ConnectionEvent {
+  const ConnectionEvent._();
+
+  /// A connection completed its handshake and was registered
+  const factory ConnectionEvent.peerConnected({
+    required String peerId,
+    required String address,
+
+    /// Whether we dialed the connection
+    required bool outbound,
+  }) = ConnectionEvent_PeerConnected;
+
+  /// A peer's connection closed or was disconnected
+  const factory ConnectionEvent.peerDisconnected({
+    required String peerId,
+    required DisconnectReason reason,
+
+    /// Whether the peer closed the connection, as opposed to us or the network
+    required bool byPeer,
+  }) = ConnectionEvent_PeerDisconnected;
+
+  /// A TLS or Hello handshake did not complete
+  const factory ConnectionEvent.handshakeFailed({
+    required String address,
+    required String error,
+  }) = ConnectionEvent_HandshakeFailed;
+
+  /// A peer's connection moved to a new network path
+  const factory ConnectionEvent.connectionMigrated({
+    required String peerId,
+    required String oldAddress,
+    required String newAddress,
+  }) = ConnectionEvent_ConnectionMigrated;
+
+  /// A supervised peer's connection state changed
+  const factory ConnectionEvent.peerStateChanged({
+    required String peerId,
+    required PeerState state,
+
+    /// Failed reconnect rounds so far, zero once connected
+    required int attempt,
+
+    /// Delay before the next reconnect round, zero unless reconnecting
+    required BigInt retryInMs,
+  }) = ConnectionEvent_PeerStateChanged;
+}
+
+@freezed
+sealed class DisconnectReason with _$DisconnectReason {
+  const DisconnectReason._();
+
+  /// The user disconnected
+  const factory DisconnectReason.userDisconnect() = DisconnectReason_UserDisconnect;
+
+  /// The device was unpaired or its pairing revoked
+  const factory DisconnectReason.unpaired() = DisconnectReason_Unpaired;
+
+  /// The devices share no protocol version
+  const factory DisconnectReason.versionMismatch() = DisconnectReason_VersionMismatch;
+
+  /// The device shut down on purpose
+  const factory DisconnectReason.shuttingDown() = DisconnectReason_ShuttingDown;
+
+  /// The device refused us for connecting too often
+  const factory DisconnectReason.rateLimited() = DisconnectReason_RateLimited;
+
+  /// A newer connection to the same device took over
+  const factory DisconnectReason.replaced() = DisconnectReason_Replaced;
+
+  /// The wire protocol was broken
+  const factory DisconnectReason.protocolViolation() = DisconnectReason_ProtocolViolation;
+
+  /// Nothing was heard from the peer within the idle timeout
+  const factory DisconnectReason.timedOut() = DisconnectReason_TimedOut;
+
+  /// The connection failed without a close, e.g. the peer crashed or the network went away
+  const factory DisconnectReason.connectionLost({
+    required String error,
+  }) = DisconnectReason_ConnectionLost;
+
+  /// The peer closed with a code this build doesn't know
+  const factory DisconnectReason.unknown({
+    required BigInt code,
+    required String message,
+  }) = DisconnectReason_Unknown;
+}
@@ -0,0 +1,2761 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'events.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$ConnectionEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String peerId, String address, bool outbound) peerConnected,
+    required TResult Function(String peerId, DisconnectReason reason, bool byPeer) peerDisconnected,
+    required TResult Function(String address, String error) handshakeFailed,
+    required TResult Function(String peerId, String oldAddress, String newAddress) connectionMigrated,
+    required TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs) peerStateChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult? Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult? Function(String address, String error)? handshakeFailed,
+    TResult? Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult? Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult Function(String address, String error)? handshakeFailed,
+    TResult Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ConnectionEvent_PeerConnected value) peerConnected,
+    required TResult Function(ConnectionEvent_PeerDisconnected value) peerDisconnected,
+    required TResult Function(ConnectionEvent_HandshakeFailed value) handshakeFailed,
+    required TResult Function(ConnectionEvent_ConnectionMigrated value) connectionMigrated,
+    required TResult Function(ConnectionEvent_PeerStateChanged value) peerStateChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult? Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult? Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult? Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult? Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ConnectionEventCopyWith<$Res> {
+  factory $ConnectionEventCopyWith(ConnectionEvent value, $Res Function(ConnectionEvent) then) =
+      _$ConnectionEventCopyWithImpl<$Res, ConnectionEvent>;
+}
+
+/// @nodoc
+class _$ConnectionEventCopyWithImpl<$Res, $Val extends ConnectionEvent> implements $ConnectionEventCopyWith<$Res> {
+  _$ConnectionEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$ConnectionEvent_PeerConnectedImplCopyWith<$Res> {
+  factory _$$ConnectionEvent_PeerConnectedImplCopyWith(
+          _$ConnectionEvent_PeerConnectedImpl value, $Res Function(_$ConnectionEvent_PeerConnectedImpl) then) =
+      __$$ConnectionEvent_PeerConnectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String peerId, String address, bool outbound});
+}
+
+/// @nodoc
+class __$$ConnectionEvent_PeerConnectedImplCopyWithImpl<$Res>
+    extends _$ConnectionEventCopyWithImpl<$Res, _$ConnectionEvent_PeerConnectedImpl>
+    implements _$$ConnectionEvent_PeerConnectedImplCopyWith<$Res> {
+  __$$ConnectionEvent_PeerConnectedImplCopyWithImpl(
+      _$ConnectionEvent_PeerConnectedImpl _value, $Res Function(_$ConnectionEvent_PeerConnectedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? peerId = null,
+    Object? address = null,
+    Object? outbound = null,
+  }) {
+    return _then(_$ConnectionEvent_PeerConnectedImpl(
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as String,
+      outbound: null == outbound
+          ? _value.outbound
+          : outbound // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ConnectionEvent_PeerConnectedImpl extends ConnectionEvent_PeerConnected {
+  const _$ConnectionEvent_PeerConnectedImpl({required this.peerId, required this.address, required this.outbound})
+      : super._();
+
+  @override
+  final String peerId;
+  @override
+  final String address;
+
+  /// Whether we dialed the connection
+  @override
+  final bool outbound;
+
+  @override
+  String toString() {
+    return 'ConnectionEvent.peerConnected(peerId: $peerId, address: $address, outbound: $outbound)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ConnectionEvent_PeerConnectedImpl &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.address, address) || other.address == address) &&
+            (identical(other.outbound, outbound) || other.outbound == outbound));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, peerId, address, outbound);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ConnectionEvent_PeerConnectedImplCopyWith<_$ConnectionEvent_PeerConnectedImpl> get copyWith =>
+      __$$ConnectionEvent_PeerConnectedImplCopyWithImpl<_$ConnectionEvent_PeerConnectedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String peerId, String address, bool outbound) peerConnected,
+    required TResult Function(String peerId, DisconnectReason reason, bool byPeer) peerDisconnected,
+    required TResult Function(String address, String error) handshakeFailed,
+    required TResult Function(String peerId, String oldAddress, String newAddress) connectionMigrated,
+    required TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs) peerStateChanged,
+  }) {
+    return peerConnected(peerId, address, outbound);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult? Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult? Function(String address, String error)? handshakeFailed,
+    TResult? Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult? Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+  }) {
+    return peerConnected?.call(peerId, address, outbound);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult Function(String address, String error)? handshakeFailed,
+    TResult Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (peerConnected != null) {
+      return peerConnected(peerId, address, outbound);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ConnectionEvent_PeerConnected value) peerConnected,
+    required TResult Function(ConnectionEvent_PeerDisconnected value) peerDisconnected,
+    required TResult Function(ConnectionEvent_HandshakeFailed value) handshakeFailed,
+    required TResult Function(ConnectionEvent_ConnectionMigrated value) connectionMigrated,
+    required TResult Function(ConnectionEvent_PeerStateChanged value) peerStateChanged,
+  }) {
+    return peerConnected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult? Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult? Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult? Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult? Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+  }) {
+    return peerConnected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (peerConnected != null) {
+      return peerConnected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ConnectionEvent_PeerConnected extends ConnectionEvent {
+  const factory ConnectionEvent_PeerConnected(
+      {required final String peerId,
+      required final String address,
+
+      /// Whether we dialed the connection
+      required final bool outbound}) = _$ConnectionEvent_PeerConnectedImpl;
+  const ConnectionEvent_PeerConnected._() : super._();
+
+  String get peerId;
+  String get address;
+
+  /// Whether we dialed the connection
+  bool get outbound;
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ConnectionEvent_PeerConnectedImplCopyWith<_$ConnectionEvent_PeerConnectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ConnectionEvent_PeerDisconnectedImplCopyWith<$Res> {
+  factory _$$ConnectionEvent_PeerDisconnectedImplCopyWith(
+          _$ConnectionEvent_PeerDisconnectedImpl value, $Res Function(_$ConnectionEvent_PeerDisconnectedImpl) then) =
+      __$$ConnectionEvent_PeerDisconnectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String peerId, DisconnectReason reason, bool byPeer});
+
+  $DisconnectReasonCopyWith<$Res> get reason;
+}
+
+/// @nodoc
+class __$$ConnectionEvent_PeerDisconnectedImplCopyWithImpl<$Res>
+    extends _$ConnectionEventCopyWithImpl<$Res, _$ConnectionEvent_PeerDisconnectedImpl>
+    implements _$$ConnectionEvent_PeerDisconnectedImplCopyWith<$Res> {
+  __$$ConnectionEvent_PeerDisconnectedImplCopyWithImpl(
+      _$ConnectionEvent_PeerDisconnectedImpl _value, $Res Function(_$ConnectionEvent_PeerDisconnectedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? peerId = null,
+    Object? reason = null,
+    Object? byPeer = null,
+  }) {
+    return _then(_$ConnectionEvent_PeerDisconnectedImpl(
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as DisconnectReason,
+      byPeer: null == byPeer
+          ? _value.byPeer
+          : byPeer // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $DisconnectReasonCopyWith<$Res> get reason {
+    return $DisconnectReasonCopyWith<$Res>(_value.reason, (value) {
+      return _then(_value.copyWith(reason: value));
+    });
+  }
+}
+
+/// @nodoc
+
+class _$ConnectionEvent_PeerDisconnectedImpl extends ConnectionEvent_PeerDisconnected {
+  const _$ConnectionEvent_PeerDisconnectedImpl({required this.peerId, required this.reason, required this.byPeer})
+      : super._();
+
+  @override
+  final String peerId;
+  @override
+  final DisconnectReason reason;
+
+  /// Whether the peer closed the connection, as opposed to us or the network
+  @override
+  final bool byPeer;
+
+  @override
+  String toString() {
+    return 'ConnectionEvent.peerDisconnected(peerId: $peerId, reason: $reason, byPeer: $byPeer)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ConnectionEvent_PeerDisconnectedImpl &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.reason, reason) || other.reason == reason) &&
+            (identical(other.byPeer, byPeer) || other.byPeer == byPeer));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, peerId, reason, byPeer);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ConnectionEvent_PeerDisconnectedImplCopyWith<_$ConnectionEvent_PeerDisconnectedImpl> get copyWith =>
+      __$$ConnectionEvent_PeerDisconnectedImplCopyWithImpl<_$ConnectionEvent_PeerDisconnectedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String peerId, String address, bool outbound) peerConnected,
+    required TResult Function(String peerId, DisconnectReason reason, bool byPeer) peerDisconnected,
+    required TResult Function(String address, String error) handshakeFailed,
+    required TResult Function(String peerId, String oldAddress, String newAddress) connectionMigrated,
+    required TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs) peerStateChanged,
+  }) {
+    return peerDisconnected(peerId, reason, byPeer);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult? Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult? Function(String address, String error)? handshakeFailed,
+    TResult? Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult? Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+  }) {
+    return peerDisconnected?.call(peerId, reason, byPeer);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult Function(String address, String error)? handshakeFailed,
+    TResult Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (peerDisconnected != null) {
+      return peerDisconnected(peerId, reason, byPeer);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ConnectionEvent_PeerConnected value) peerConnected,
+    required TResult Function(ConnectionEvent_PeerDisconnected value) peerDisconnected,
+    required TResult Function(ConnectionEvent_HandshakeFailed value) handshakeFailed,
+    required TResult Function(ConnectionEvent_ConnectionMigrated value) connectionMigrated,
+    required TResult Function(ConnectionEvent_PeerStateChanged value) peerStateChanged,
+  }) {
+    return peerDisconnected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult? Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult? Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult? Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult? Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+  }) {
+    return peerDisconnected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (peerDisconnected != null) {
+      return peerDisconnected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ConnectionEvent_PeerDisconnected extends ConnectionEvent {
+  const factory ConnectionEvent_PeerDisconnected(
+      {required final String peerId,
+      required final DisconnectReason reason,
+
+      /// Whether the peer closed the connection, as opposed to us or the network
+      required final bool byPeer}) = _$ConnectionEvent_PeerDisconnectedImpl;
+  const ConnectionEvent_PeerDisconnected._() : super._();
+
+  String get peerId;
+  DisconnectReason get reason;
+
+  /// Whether the peer closed the connection, as opposed to us or the network
+  bool get byPeer;
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ConnectionEvent_PeerDisconnectedImplCopyWith<_$ConnectionEvent_PeerDisconnectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ConnectionEvent_HandshakeFailedImplCopyWith<$Res> {
+  factory _$$ConnectionEvent_HandshakeFailedImplCopyWith(
+          _$ConnectionEvent_HandshakeFailedImpl value, $Res Function(_$ConnectionEvent_HandshakeFailedImpl) then) =
+      __$$ConnectionEvent_HandshakeFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String address, String error});
+}
+
+/// @nodoc
+class __$$ConnectionEvent_HandshakeFailedImplCopyWithImpl<$Res>
+    extends _$ConnectionEventCopyWithImpl<$Res, _$ConnectionEvent_HandshakeFailedImpl>
+    implements _$$ConnectionEvent_HandshakeFailedImplCopyWith<$Res> {
+  __$$ConnectionEvent_HandshakeFailedImplCopyWithImpl(
+      _$ConnectionEvent_HandshakeFailedImpl _value, $Res Function(_$ConnectionEvent_HandshakeFailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? address = null,
+    Object? error = null,
+  }) {
+    return _then(_$ConnectionEvent_HandshakeFailedImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as String,
+      error: null == error
+          ? _value.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ConnectionEvent_HandshakeFailedImpl extends ConnectionEvent_HandshakeFailed {
+  const _$ConnectionEvent_HandshakeFailedImpl({required this.address, required this.error}) : super._();
+
+  @override
+  final String address;
+  @override
+  final String error;
+
+  @override
+  String toString() {
+    return 'ConnectionEvent.handshakeFailed(address: $address, error: $error)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ConnectionEvent_HandshakeFailedImpl &&
+            (identical(other.address, address) || other.address == address) &&
+            (identical(other.error, error) || other.error == error));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, address, error);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ConnectionEvent_HandshakeFailedImplCopyWith<_$ConnectionEvent_HandshakeFailedImpl> get copyWith =>
+      __$$ConnectionEvent_HandshakeFailedImplCopyWithImpl<_$ConnectionEvent_HandshakeFailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String peerId, String address, bool outbound) peerConnected,
+    required TResult Function(String peerId, DisconnectReason reason, bool byPeer) peerDisconnected,
+    required TResult Function(String address, String error) handshakeFailed,
+    required TResult Function(String peerId, String oldAddress, String newAddress) connectionMigrated,
+    required TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs) peerStateChanged,
+  }) {
+    return handshakeFailed(address, error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult? Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult? Function(String address, String error)? handshakeFailed,
+    TResult? Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult? Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+  }) {
+    return handshakeFailed?.call(address, error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult Function(String address, String error)? handshakeFailed,
+    TResult Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (handshakeFailed != null) {
+      return handshakeFailed(address, error);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ConnectionEvent_PeerConnected value) peerConnected,
+    required TResult Function(ConnectionEvent_PeerDisconnected value) peerDisconnected,
+    required TResult Function(ConnectionEvent_HandshakeFailed value) handshakeFailed,
+    required TResult Function(ConnectionEvent_ConnectionMigrated value) connectionMigrated,
+    required TResult Function(ConnectionEvent_PeerStateChanged value) peerStateChanged,
+  }) {
+    return handshakeFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult? Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult? Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult? Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult? Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+  }) {
+    return handshakeFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (handshakeFailed != null) {
+      return handshakeFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ConnectionEvent_HandshakeFailed extends ConnectionEvent {
+  const factory ConnectionEvent_HandshakeFailed({required final String address, required final String error}) =
+      _$ConnectionEvent_HandshakeFailedImpl;
+  const ConnectionEvent_HandshakeFailed._() : super._();
+
+  String get address;
+  String get error;
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ConnectionEvent_HandshakeFailedImplCopyWith<_$ConnectionEvent_HandshakeFailedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ConnectionEvent_ConnectionMigratedImplCopyWith<$Res> {
+  factory _$$ConnectionEvent_ConnectionMigratedImplCopyWith(
+          _$ConnectionEvent_ConnectionMigratedImpl value,
+          $Res Function(_$ConnectionEvent_ConnectionMigratedImpl) then) =
+      __$$ConnectionEvent_ConnectionMigratedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String peerId, String oldAddress, String newAddress});
+}
+
+/// @nodoc
+class __$$ConnectionEvent_ConnectionMigratedImplCopyWithImpl<$Res>
+    extends _$ConnectionEventCopyWithImpl<$Res, _$ConnectionEvent_ConnectionMigratedImpl>
+    implements _$$ConnectionEvent_ConnectionMigratedImplCopyWith<$Res> {
+  __$$ConnectionEvent_ConnectionMigratedImplCopyWithImpl(
+      _$ConnectionEvent_ConnectionMigratedImpl _value, $Res Function(_$ConnectionEvent_ConnectionMigratedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? peerId = null,
+    Object? oldAddress = null,
+    Object? newAddress = null,
+  }) {
+    return _then(_$ConnectionEvent_ConnectionMigratedImpl(
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      oldAddress: null == oldAddress
+          ? _value.oldAddress
+          : oldAddress // ignore: cast_nullable_to_non_nullable
+              as String,
+      newAddress: null == newAddress
+          ? _value.newAddress
+          : newAddress // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ConnectionEvent_ConnectionMigratedImpl extends ConnectionEvent_ConnectionMigrated {
+  const _$ConnectionEvent_ConnectionMigratedImpl(
+      {required this.peerId, required this.oldAddress, required this.newAddress})
+      : super._();
+
+  @override
+  final String peerId;
+  @override
+  final String oldAddress;
+  @override
+  final String newAddress;
+
+  @override
+  String toString() {
+    return 'ConnectionEvent.connectionMigrated(peerId: $peerId, oldAddress: $oldAddress, newAddress: $newAddress)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ConnectionEvent_ConnectionMigratedImpl &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.oldAddress, oldAddress) || other.oldAddress == oldAddress) &&
+            (identical(other.newAddress, newAddress) || other.newAddress == newAddress));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, peerId, oldAddress, newAddress);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ConnectionEvent_ConnectionMigratedImplCopyWith<_$ConnectionEvent_ConnectionMigratedImpl> get copyWith =>
+      __$$ConnectionEvent_ConnectionMigratedImplCopyWithImpl<_$ConnectionEvent_ConnectionMigratedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String peerId, String address, bool outbound) peerConnected,
+    required TResult Function(String peerId, DisconnectReason reason, bool byPeer) peerDisconnected,
+    required TResult Function(String address, String error) handshakeFailed,
+    required TResult Function(String peerId, String oldAddress, String newAddress) connectionMigrated,
+    required TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs) peerStateChanged,
+  }) {
+    return connectionMigrated(peerId, oldAddress, newAddress);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult? Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult? Function(String address, String error)? handshakeFailed,
+    TResult? Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult? Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+  }) {
+    return connectionMigrated?.call(peerId, oldAddress, newAddress);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult Function(String address, String error)? handshakeFailed,
+    TResult Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (connectionMigrated != null) {
+      return connectionMigrated(peerId, oldAddress, newAddress);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ConnectionEvent_PeerConnected value) peerConnected,
+    required TResult Function(ConnectionEvent_PeerDisconnected value) peerDisconnected,
+    required TResult Function(ConnectionEvent_HandshakeFailed value) handshakeFailed,
+    required TResult Function(ConnectionEvent_ConnectionMigrated value) connectionMigrated,
+    required TResult Function(ConnectionEvent_PeerStateChanged value) peerStateChanged,
+  }) {
+    return connectionMigrated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult? Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult? Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult? Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult? Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+  }) {
+    return connectionMigrated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (connectionMigrated != null) {
+      return connectionMigrated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ConnectionEvent_ConnectionMigrated extends ConnectionEvent {
+  const factory ConnectionEvent_ConnectionMigrated(
+          {required final String peerId, required final String oldAddress, required final String newAddress}) =
+      _$ConnectionEvent_ConnectionMigratedImpl;
+  const ConnectionEvent_ConnectionMigrated._() : super._();
+
+  String get peerId;
+  String get oldAddress;
+  String get newAddress;
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ConnectionEvent_ConnectionMigratedImplCopyWith<_$ConnectionEvent_ConnectionMigratedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ConnectionEvent_PeerStateChangedImplCopyWith<$Res> {
+  factory _$$ConnectionEvent_PeerStateChangedImplCopyWith(
+          _$ConnectionEvent_PeerStateChangedImpl value, $Res Function(_$ConnectionEvent_PeerStateChangedImpl) then) =
+      __$$ConnectionEvent_PeerStateChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String peerId, PeerState state, int attempt, BigInt retryInMs});
+}
+
+/// @nodoc
+class __$$ConnectionEvent_PeerStateChangedImplCopyWithImpl<$Res>
+    extends _$ConnectionEventCopyWithImpl<$Res, _$ConnectionEvent_PeerStateChangedImpl>
+    implements _$$ConnectionEvent_PeerStateChangedImplCopyWith<$Res> {
+  __$$ConnectionEvent_PeerStateChangedImplCopyWithImpl(
+      _$ConnectionEvent_PeerStateChangedImpl _value, $Res Function(_$ConnectionEvent_PeerStateChangedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? peerId = null,
+    Object? state = null,
+    Object? attempt = null,
+    Object? retryInMs = null,
+  }) {
+    return _then(_$ConnectionEvent_PeerStateChangedImpl(
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      state: null == state
+          ? _value.state
+          : state // ignore: cast_nullable_to_non_nullable
+              as PeerState,
+      attempt: null == attempt
+          ? _value.attempt
+          : attempt // ignore: cast_nullable_to_non_nullable
+              as int,
+      retryInMs: null == retryInMs
+          ? _value.retryInMs
+          : retryInMs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ConnectionEvent_PeerStateChangedImpl extends ConnectionEvent_PeerStateChanged {
+  const _$ConnectionEvent_PeerStateChangedImpl(
+      {required this.peerId, required this.state, required this.attempt, required this.retryInMs})
+      : super._();
+
+  @override
+  final String peerId;
+  @override
+  final PeerState state;
+
+  /// Failed reconnect rounds so far, zero once connected
+  @override
+  final int attempt;
+
+  /// Delay before the next reconnect round, zero unless reconnecting
+  @override
+  final BigInt retryInMs;
+
+  @override
+  String toString() {
+    return 'ConnectionEvent.peerStateChanged(peerId: $peerId, state: $state, attempt: $attempt, retryInMs: $retryInMs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ConnectionEvent_PeerStateChangedImpl &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.state, state) || other.state == state) &&
+            (identical(other.attempt, attempt) || other.attempt == attempt) &&
+            (identical(other.retryInMs, retryInMs) || other.retryInMs == retryInMs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, peerId, state, attempt, retryInMs);
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ConnectionEvent_PeerStateChangedImplCopyWith<_$ConnectionEvent_PeerStateChangedImpl> get copyWith =>
+      __$$ConnectionEvent_PeerStateChangedImplCopyWithImpl<_$ConnectionEvent_PeerStateChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String peerId, String address, bool outbound) peerConnected,
+    required TResult Function(String peerId, DisconnectReason reason, bool byPeer) peerDisconnected,
+    required TResult Function(String address, String error) handshakeFailed,
+    required TResult Function(String peerId, String oldAddress, String newAddress) connectionMigrated,
+    required TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs) peerStateChanged,
+  }) {
+    return peerStateChanged(peerId, state, attempt, retryInMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult? Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult? Function(String address, String error)? handshakeFailed,
+    TResult? Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult? Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+  }) {
+    return peerStateChanged?.call(peerId, state, attempt, retryInMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String peerId, String address, bool outbound)? peerConnected,
+    TResult Function(String peerId, DisconnectReason reason, bool byPeer)? peerDisconnected,
+    TResult Function(String address, String error)? handshakeFailed,
+    TResult Function(String peerId, String oldAddress, String newAddress)? connectionMigrated,
+    TResult Function(String peerId, PeerState state, int attempt, BigInt retryInMs)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (peerStateChanged != null) {
+      return peerStateChanged(peerId, state, attempt, retryInMs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ConnectionEvent_PeerConnected value) peerConnected,
+    required TResult Function(ConnectionEvent_PeerDisconnected value) peerDisconnected,
+    required TResult Function(ConnectionEvent_HandshakeFailed value) handshakeFailed,
+    required TResult Function(ConnectionEvent_ConnectionMigrated value) connectionMigrated,
+    required TResult Function(ConnectionEvent_PeerStateChanged value) peerStateChanged,
+  }) {
+    return peerStateChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult? Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult? Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult? Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult? Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+  }) {
+    return peerStateChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ConnectionEvent_PeerConnected value)? peerConnected,
+    TResult Function(ConnectionEvent_PeerDisconnected value)? peerDisconnected,
+    TResult Function(ConnectionEvent_HandshakeFailed value)? handshakeFailed,
+    TResult Function(ConnectionEvent_ConnectionMigrated value)? connectionMigrated,
+    TResult Function(ConnectionEvent_PeerStateChanged value)? peerStateChanged,
+    required TResult orElse(),
+  }) {
+    if (peerStateChanged != null) {
+      return peerStateChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ConnectionEvent_PeerStateChanged extends ConnectionEvent {
+  const factory ConnectionEvent_PeerStateChanged(
+      {required final String peerId,
+      required final PeerState state,
+
+      /// Failed reconnect rounds so far, zero once connected
+      required final int attempt,
+
+      /// Delay before the next reconnect round, zero unless reconnecting
+      required final BigInt retryInMs}) = _$ConnectionEvent_PeerStateChangedImpl;
+  const ConnectionEvent_PeerStateChanged._() : super._();
+
+  String get peerId;
+  PeerState get state;
+
+  /// Failed reconnect rounds so far, zero once connected
+  int get attempt;
+
+  /// Delay before the next reconnect round, zero unless reconnecting
+  BigInt get retryInMs;
+
+  /// Create a copy of ConnectionEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$ConnectionEvent_PeerStateChangedImplCopyWith<_$ConnectionEvent_PeerStateChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$DisconnectReason {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $DisconnectReasonCopyWith<$Res> {
+  factory $DisconnectReasonCopyWith(DisconnectReason value, $Res Function(DisconnectReason) then) =
+      _$DisconnectReasonCopyWithImpl<$Res, DisconnectReason>;
+}
+
+/// @nodoc
+class _$DisconnectReasonCopyWithImpl<$Res, $Val extends DisconnectReason> implements $DisconnectReasonCopyWith<$Res> {
+  _$DisconnectReasonCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_UserDisconnectImplCopyWith<$Res> {
+  factory _$$DisconnectReason_UserDisconnectImplCopyWith(
+          _$DisconnectReason_UserDisconnectImpl value, $Res Function(_$DisconnectReason_UserDisconnectImpl) then) =
+      __$$DisconnectReason_UserDisconnectImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_UserDisconnectImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_UserDisconnectImpl>
+    implements _$$DisconnectReason_UserDisconnectImplCopyWith<$Res> {
+  __$$DisconnectReason_UserDisconnectImplCopyWithImpl(
+      _$DisconnectReason_UserDisconnectImpl _value, $Res Function(_$DisconnectReason_UserDisconnectImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_UserDisconnectImpl extends DisconnectReason_UserDisconnect {
+  const _$DisconnectReason_UserDisconnectImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.userDisconnect()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$DisconnectReason_UserDisconnectImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return userDisconnect();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return userDisconnect?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (userDisconnect != null) {
+      return userDisconnect();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return userDisconnect(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return userDisconnect?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (userDisconnect != null) {
+      return userDisconnect(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_UserDisconnect extends DisconnectReason {
+  const factory DisconnectReason_UserDisconnect() = _$DisconnectReason_UserDisconnectImpl;
+  const DisconnectReason_UserDisconnect._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_UnpairedImplCopyWith<$Res> {
+  factory _$$DisconnectReason_UnpairedImplCopyWith(
+          _$DisconnectReason_UnpairedImpl value, $Res Function(_$DisconnectReason_UnpairedImpl) then) =
+      __$$DisconnectReason_UnpairedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_UnpairedImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_UnpairedImpl>
+    implements _$$DisconnectReason_UnpairedImplCopyWith<$Res> {
+  __$$DisconnectReason_UnpairedImplCopyWithImpl(
+      _$DisconnectReason_UnpairedImpl _value, $Res Function(_$DisconnectReason_UnpairedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_UnpairedImpl extends DisconnectReason_Unpaired {
+  const _$DisconnectReason_UnpairedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.unpaired()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$DisconnectReason_UnpairedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return unpaired();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return unpaired?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (unpaired != null) {
+      return unpaired();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return unpaired(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return unpaired?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (unpaired != null) {
+      return unpaired(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_Unpaired extends DisconnectReason {
+  const factory DisconnectReason_Unpaired() = _$DisconnectReason_UnpairedImpl;
+  const DisconnectReason_Unpaired._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_VersionMismatchImplCopyWith<$Res> {
+  factory _$$DisconnectReason_VersionMismatchImplCopyWith(
+          _$DisconnectReason_VersionMismatchImpl value, $Res Function(_$DisconnectReason_VersionMismatchImpl) then) =
+      __$$DisconnectReason_VersionMismatchImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_VersionMismatchImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_VersionMismatchImpl>
+    implements _$$DisconnectReason_VersionMismatchImplCopyWith<$Res> {
+  __$$DisconnectReason_VersionMismatchImplCopyWithImpl(
+      _$DisconnectReason_VersionMismatchImpl _value, $Res Function(_$DisconnectReason_VersionMismatchImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_VersionMismatchImpl extends DisconnectReason_VersionMismatch {
+  const _$DisconnectReason_VersionMismatchImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.versionMismatch()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$DisconnectReason_VersionMismatchImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return versionMismatch();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return versionMismatch?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (versionMismatch != null) {
+      return versionMismatch();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return versionMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return versionMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (versionMismatch != null) {
+      return versionMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_VersionMismatch extends DisconnectReason {
+  const factory DisconnectReason_VersionMismatch() = _$DisconnectReason_VersionMismatchImpl;
+  const DisconnectReason_VersionMismatch._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_ShuttingDownImplCopyWith<$Res> {
+  factory _$$DisconnectReason_ShuttingDownImplCopyWith(
+          _$DisconnectReason_ShuttingDownImpl value, $Res Function(_$DisconnectReason_ShuttingDownImpl) then) =
+      __$$DisconnectReason_ShuttingDownImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_ShuttingDownImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_ShuttingDownImpl>
+    implements _$$DisconnectReason_ShuttingDownImplCopyWith<$Res> {
+  __$$DisconnectReason_ShuttingDownImplCopyWithImpl(
+      _$DisconnectReason_ShuttingDownImpl _value, $Res Function(_$DisconnectReason_ShuttingDownImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_ShuttingDownImpl extends DisconnectReason_ShuttingDown {
+  const _$DisconnectReason_ShuttingDownImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.shuttingDown()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$DisconnectReason_ShuttingDownImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return shuttingDown();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return shuttingDown?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (shuttingDown != null) {
+      return shuttingDown();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return shuttingDown(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return shuttingDown?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (shuttingDown != null) {
+      return shuttingDown(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_ShuttingDown extends DisconnectReason {
+  const factory DisconnectReason_ShuttingDown() = _$DisconnectReason_ShuttingDownImpl;
+  const DisconnectReason_ShuttingDown._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_RateLimitedImplCopyWith<$Res> {
+  factory _$$DisconnectReason_RateLimitedImplCopyWith(
+          _$DisconnectReason_RateLimitedImpl value, $Res Function(_$DisconnectReason_RateLimitedImpl) then) =
+      __$$DisconnectReason_RateLimitedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_RateLimitedImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_RateLimitedImpl>
+    implements _$$DisconnectReason_RateLimitedImplCopyWith<$Res> {
+  __$$DisconnectReason_RateLimitedImplCopyWithImpl(
+      _$DisconnectReason_RateLimitedImpl _value, $Res Function(_$DisconnectReason_RateLimitedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_RateLimitedImpl extends DisconnectReason_RateLimited {
+  const _$DisconnectReason_RateLimitedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.rateLimited()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$DisconnectReason_RateLimitedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return rateLimited();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return rateLimited?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (rateLimited != null) {
+      return rateLimited();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return rateLimited(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return rateLimited?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (rateLimited != null) {
+      return rateLimited(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_RateLimited extends DisconnectReason {
+  const factory DisconnectReason_RateLimited() = _$DisconnectReason_RateLimitedImpl;
+  const DisconnectReason_RateLimited._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_ReplacedImplCopyWith<$Res> {
+  factory _$$DisconnectReason_ReplacedImplCopyWith(
+          _$DisconnectReason_ReplacedImpl value, $Res Function(_$DisconnectReason_ReplacedImpl) then) =
+      __$$DisconnectReason_ReplacedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_ReplacedImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_ReplacedImpl>
+    implements _$$DisconnectReason_ReplacedImplCopyWith<$Res> {
+  __$$DisconnectReason_ReplacedImplCopyWithImpl(
+      _$DisconnectReason_ReplacedImpl _value, $Res Function(_$DisconnectReason_ReplacedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_ReplacedImpl extends DisconnectReason_Replaced {
+  const _$DisconnectReason_ReplacedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.replaced()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$DisconnectReason_ReplacedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return replaced();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return replaced?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (replaced != null) {
+      return replaced();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return replaced(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return replaced?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (replaced != null) {
+      return replaced(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_Replaced extends DisconnectReason {
+  const factory DisconnectReason_Replaced() = _$DisconnectReason_ReplacedImpl;
+  const DisconnectReason_Replaced._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_ProtocolViolationImplCopyWith<$Res> {
+  factory _$$DisconnectReason_ProtocolViolationImplCopyWith(
+          _$DisconnectReason_ProtocolViolationImpl value,
+          $Res Function(_$DisconnectReason_ProtocolViolationImpl) then) =
+      __$$DisconnectReason_ProtocolViolationImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_ProtocolViolationImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_ProtocolViolationImpl>
+    implements _$$DisconnectReason_ProtocolViolationImplCopyWith<$Res> {
+  __$$DisconnectReason_ProtocolViolationImplCopyWithImpl(
+      _$DisconnectReason_ProtocolViolationImpl _value, $Res Function(_$DisconnectReason_ProtocolViolationImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_ProtocolViolationImpl extends DisconnectReason_ProtocolViolation {
+  const _$DisconnectReason_ProtocolViolationImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.protocolViolation()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$DisconnectReason_ProtocolViolationImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return protocolViolation();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return protocolViolation?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (protocolViolation != null) {
+      return protocolViolation();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return protocolViolation(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return protocolViolation?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (protocolViolation != null) {
+      return protocolViolation(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_ProtocolViolation extends DisconnectReason {
+  const factory DisconnectReason_ProtocolViolation() = _$DisconnectReason_ProtocolViolationImpl;
+  const DisconnectReason_ProtocolViolation._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_TimedOutImplCopyWith<$Res> {
+  factory _$$DisconnectReason_TimedOutImplCopyWith(
+          _$DisconnectReason_TimedOutImpl value, $Res Function(_$DisconnectReason_TimedOutImpl) then) =
+      __$$DisconnectReason_TimedOutImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$DisconnectReason_TimedOutImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_TimedOutImpl>
+    implements _$$DisconnectReason_TimedOutImplCopyWith<$Res> {
+  __$$DisconnectReason_TimedOutImplCopyWithImpl(
+      _$DisconnectReason_TimedOutImpl _value, $Res Function(_$DisconnectReason_TimedOutImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$DisconnectReason_TimedOutImpl extends DisconnectReason_TimedOut {
+  const _$DisconnectReason_TimedOutImpl() : super._();
+
+  @override
+  String toString() {
+    return 'DisconnectReason.timedOut()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$DisconnectReason_TimedOutImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return timedOut();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return timedOut?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (timedOut != null) {
+      return timedOut();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return timedOut(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return timedOut?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (timedOut != null) {
+      return timedOut(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_TimedOut extends DisconnectReason {
+  const factory DisconnectReason_TimedOut() = _$DisconnectReason_TimedOutImpl;
+  const DisconnectReason_TimedOut._() : super._();
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_ConnectionLostImplCopyWith<$Res> {
+  factory _$$DisconnectReason_ConnectionLostImplCopyWith(
+          _$DisconnectReason_ConnectionLostImpl value, $Res Function(_$DisconnectReason_ConnectionLostImpl) then) =
+      __$$DisconnectReason_ConnectionLostImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String error});
+}
+
+/// @nodoc
+class __$$DisconnectReason_ConnectionLostImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_ConnectionLostImpl>
+    implements _$$DisconnectReason_ConnectionLostImplCopyWith<$Res> {
+  __$$DisconnectReason_ConnectionLostImplCopyWithImpl(
+      _$DisconnectReason_ConnectionLostImpl _value, $Res Function(_$DisconnectReason_ConnectionLostImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? error = null,
+  }) {
+    return _then(_$DisconnectReason_ConnectionLostImpl(
+      error: null == error
+          ? _value.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$DisconnectReason_ConnectionLostImpl extends DisconnectReason_ConnectionLost {
+  const _$DisconnectReason_ConnectionLostImpl({required this.error}) : super._();
+
+  @override
+  final String error;
+
+  @override
+  String toString() {
+    return 'DisconnectReason.connectionLost(error: $error)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DisconnectReason_ConnectionLostImpl &&
+            (identical(other.error, error) || other.error == error));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, error);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DisconnectReason_ConnectionLostImplCopyWith<_$DisconnectReason_ConnectionLostImpl> get copyWith =>
+      __$$DisconnectReason_ConnectionLostImplCopyWithImpl<_$DisconnectReason_ConnectionLostImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return connectionLost(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return connectionLost?.call(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (connectionLost != null) {
+      return connectionLost(error);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return connectionLost(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return connectionLost?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (connectionLost != null) {
+      return connectionLost(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_ConnectionLost extends DisconnectReason {
+  const factory DisconnectReason_ConnectionLost({required final String error}) = _$DisconnectReason_ConnectionLostImpl;
+  const DisconnectReason_ConnectionLost._() : super._();
+
+  String get error;
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DisconnectReason_ConnectionLostImplCopyWith<_$DisconnectReason_ConnectionLostImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$DisconnectReason_UnknownImplCopyWith<$Res> {
+  factory _$$DisconnectReason_UnknownImplCopyWith(
+          _$DisconnectReason_UnknownImpl value, $Res Function(_$DisconnectReason_UnknownImpl) then) =
+      __$$DisconnectReason_UnknownImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt code, String message});
+}
+
+/// @nodoc
+class __$$DisconnectReason_UnknownImplCopyWithImpl<$Res>
+    extends _$DisconnectReasonCopyWithImpl<$Res, _$DisconnectReason_UnknownImpl>
+    implements _$$DisconnectReason_UnknownImplCopyWith<$Res> {
+  __$$DisconnectReason_UnknownImplCopyWithImpl(
+      _$DisconnectReason_UnknownImpl _value, $Res Function(_$DisconnectReason_UnknownImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? code = null,
+    Object? message = null,
+  }) {
+    return _then(_$DisconnectReason_UnknownImpl(
+      code: null == code
+          ? _value.code
+          : code // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$DisconnectReason_UnknownImpl extends DisconnectReason_Unknown {
+  const _$DisconnectReason_UnknownImpl({required this.code, required this.message}) : super._();
+
+  @override
+  final BigInt code;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'DisconnectReason.unknown(code: $code, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$DisconnectReason_UnknownImpl &&
+            (identical(other.code, code) || other.code == code) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, code, message);
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$DisconnectReason_UnknownImplCopyWith<_$DisconnectReason_UnknownImpl> get copyWith =>
+      __$$DisconnectReason_UnknownImplCopyWithImpl<_$DisconnectReason_UnknownImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() userDisconnect,
+    required TResult Function() unpaired,
+    required TResult Function() versionMismatch,
+    required TResult Function() shuttingDown,
+    required TResult Function() rateLimited,
+    required TResult Function() replaced,
+    required TResult Function() protocolViolation,
+    required TResult Function() timedOut,
+    required TResult Function(String error) connectionLost,
+    required TResult Function(BigInt code, String message) unknown,
+  }) {
+    return unknown(code, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? userDisconnect,
+    TResult? Function()? unpaired,
+    TResult? Function()? versionMismatch,
+    TResult? Function()? shuttingDown,
+    TResult? Function()? rateLimited,
+    TResult? Function()? replaced,
+    TResult? Function()? protocolViolation,
+    TResult? Function()? timedOut,
+    TResult? Function(String error)? connectionLost,
+    TResult? Function(BigInt code, String message)? unknown,
+  }) {
+    return unknown?.call(code, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? userDisconnect,
+    TResult Function()? unpaired,
+    TResult Function()? versionMismatch,
+    TResult Function()? shuttingDown,
+    TResult Function()? rateLimited,
+    TResult Function()? replaced,
+    TResult Function()? protocolViolation,
+    TResult Function()? timedOut,
+    TResult Function(String error)? connectionLost,
+    TResult Function(BigInt code, String message)? unknown,
+    required TResult orElse(),
+  }) {
+    if (unknown != null) {
+      return unknown(code, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DisconnectReason_UserDisconnect value) userDisconnect,
+    required TResult Function(DisconnectReason_Unpaired value) unpaired,
+    required TResult Function(DisconnectReason_VersionMismatch value) versionMismatch,
+    required TResult Function(DisconnectReason_ShuttingDown value) shuttingDown,
+    required TResult Function(DisconnectReason_RateLimited value) rateLimited,
+    required TResult Function(DisconnectReason_Replaced value) replaced,
+    required TResult Function(DisconnectReason_ProtocolViolation value) protocolViolation,
+    required TResult Function(DisconnectReason_TimedOut value) timedOut,
+    required TResult Function(DisconnectReason_ConnectionLost value) connectionLost,
+    required TResult Function(DisconnectReason_Unknown value) unknown,
+  }) {
+    return unknown(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult? Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult? Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult? Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult? Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult? Function(DisconnectReason_Replaced value)? replaced,
+    TResult? Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult? Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult? Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult? Function(DisconnectReason_Unknown value)? unknown,
+  }) {
+    return unknown?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DisconnectReason_UserDisconnect value)? userDisconnect,
+    TResult Function(DisconnectReason_Unpaired value)? unpaired,
+    TResult Function(DisconnectReason_VersionMismatch value)? versionMismatch,
+    TResult Function(DisconnectReason_ShuttingDown value)? shuttingDown,
+    TResult Function(DisconnectReason_RateLimited value)? rateLimited,
+    TResult Function(DisconnectReason_Replaced value)? replaced,
+    TResult Function(DisconnectReason_ProtocolViolation value)? protocolViolation,
+    TResult Function(DisconnectReason_TimedOut value)? timedOut,
+    TResult Function(DisconnectReason_ConnectionLost value)? connectionLost,
+    TResult Function(DisconnectReason_Unknown value)? unknown,
+    required TResult orElse(),
+  }) {
+    if (unknown != null) {
+      return unknown(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class DisconnectReason_Unknown extends DisconnectReason {
+  const factory DisconnectReason_Unknown({required final BigInt code, required final String message}) =
+      _$DisconnectReason_UnknownImpl;
+  const DisconnectReason_Unknown._() : super._();
+
+  BigInt get code;
+  String get message;
+
+  /// Create a copy of DisconnectReason
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$DisconnectReason_UnknownImplCopyWith<_$DisconnectReason_UnknownImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'quic.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cert_and_key`, `device_id_from_cert`, `sign`, `take_field`, `validate`, `verify_signature`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceIdentity>>
+abstract class DeviceIdentity implements RustOpaqueInterface {
+  /// DER encoded certificate
+  Uint8List get certificate;
+
+  /// Device id this identity was issued for
+  String get deviceId;
+
+  /// SHA-256 fingerprint of the certificate, as shared in pairing payloads
+  String get fingerprint;
+
+  /// Deserialize an identity produced by [`DeviceIdentity::to_bytes`]
+  static DeviceIdentity fromBytes({required List<int> bytes}) =>
+      RustLib.instance.api.crateTransportIdentityDeviceIdentityFromBytes(bytes: bytes);
+
+  /// Generate a new identity for a device
+  static DeviceIdentity generate({required String deviceId}) =>
+      RustLib.instance.api.crateTransportIdentityDeviceIdentityGenerate(deviceId: deviceId);
+
+  /// Load an identity previously written with [`DeviceIdentity::save`]
+  static DeviceIdentity load({required String path}) =>
+      RustLib.instance.api.crateTransportIdentityDeviceIdentityLoad(path: path);
+
+  /// Load an identity from `path`, generating and saving a new one if the file does not exist
+  static DeviceIdentity loadOrGenerate({required String path, required String deviceId}) =>
+      RustLib.instance.api.crateTransportIdentityDeviceIdentityLoadOrGenerate(path: path, deviceId: deviceId);
+
+  /// Write the identity to `path`
+  ///
+  /// The file contains the private key and is created readable by the owner only.
+  void save({required String path});
+
+  /// Serialize the identity (including the private key) to a byte blob
+  Uint8List toBytes();
+}
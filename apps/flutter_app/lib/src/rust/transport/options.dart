@@ -0,0 +1,123 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bind_socket`, `bind_udp`, `max_message_size`, `non_zero_millis`, `spool_directory`, `transfer_directory`, `transport_config`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+/// Congestion control algorithm used for outgoing data
+enum CongestionController {
+  cubic,
+  newReno,
+  bbr,
+  ;
+}
+
+/// Tunable settings for a [`super::QuicTransport`]
+class TransportOptions {
+  /// Local IP address to bind, e.g. `::` (the default) or `0.0.0.0`
+  final String bindAddress;
+
+  /// When bound to an IPv6 address, also send and receive IPv4
+  final bool dualStack;
+
+  /// Interval between keep-alive packets in milliseconds, 0 to disable
+  final BigInt keepAliveIntervalMs;
+
+  /// Close connections idle for this many milliseconds, 0 to never time out
+  final BigInt idleTimeoutMs;
+
+  /// Streams of each direction a peer may have open at once
+  final int maxConcurrentStreams;
+
+  /// Largest message accepted from or sent to a peer, in bytes
+  final BigInt maxMessageSize;
+
+  /// Bytes a peer may send ahead of what we have read, per connection and per stream
+  final BigInt receiveWindow;
+  final CongestionController congestionController;
+
+  /// Largest chunked transfer accepted from a peer, in bytes
+  final BigInt maxTransferSize;
+
+  /// Directory received transfers are saved to, empty for a `syncmist` folder in the temp directory
+  final String transferDirectory;
+
+  /// Directory interrupted transfers are kept in, empty for `syncmist/spool` in the temp directory
+  final String spoolDirectory;
+
+  /// Interval between datagram heartbeats that measure link quality, 0 to disable
+  final BigInt heartbeatIntervalMs;
+
+  /// Interval between attempts to replace a relayed connection with a direct one, 0 to disable
+  final BigInt directUpgradeIntervalMs;
+
+  /// How long the signaling hub keeps items sealed for offline devices, 0 to not leave any
+  final BigInt mailboxTtlMs;
+
+  const TransportOptions({
+    required this.bindAddress,
+    required this.dualStack,
+    required this.keepAliveIntervalMs,
+    required this.idleTimeoutMs,
+    required this.maxConcurrentStreams,
+    required this.maxMessageSize,
+    required this.receiveWindow,
+    required this.congestionController,
+    required this.maxTransferSize,
+    required this.transferDirectory,
+    required this.spoolDirectory,
+    required this.heartbeatIntervalMs,
+    required this.directUpgradeIntervalMs,
+    required this.mailboxTtlMs,
+  });
+
+  static TransportOptions default_() => RustLib.instance.api.crateTransportOptionsTransportOptionsDefault();
+
+  /// Defaults suited to desktops on a LAN
+  static TransportOptions desktop() => RustLib.instance.api.crateTransportOptionsTransportOptionsDesktop();
+
+  /// Defaults suited to phones: fewer keep-alives and smaller buffers
+  static TransportOptions mobile() => RustLib.instance.api.crateTransportOptionsTransportOptionsMobile();
+
+  @override
+  int get hashCode =>
+      bindAddress.hashCode ^
+      dualStack.hashCode ^
+      keepAliveIntervalMs.hashCode ^
+      idleTimeoutMs.hashCode ^
+      maxConcurrentStreams.hashCode ^
+      maxMessageSize.hashCode ^
+      receiveWindow.hashCode ^
+      congestionController.hashCode ^
+      maxTransferSize.hashCode ^
+      transferDirectory.hashCode ^
+      spoolDirectory.hashCode ^
+      heartbeatIntervalMs.hashCode ^
+      directUpgradeIntervalMs.hashCode ^
+      mailboxTtlMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TransportOptions &&
+          runtimeType == other.runtimeType &&
+          bindAddress == other.bindAddress &&
+          dualStack == other.dualStack &&
+          keepAliveIntervalMs == other.keepAliveIntervalMs &&
+          idleTimeoutMs == other.idleTimeoutMs &&
+          maxConcurrentStreams == other.maxConcurrentStreams &&
+          maxMessageSize == other.maxMessageSize &&
+          receiveWindow == other.receiveWindow &&
+          congestionController == other.congestionController &&
+          maxTransferSize == other.maxTransferSize &&
+          transferDirectory == other.transferDirectory &&
+          spoolDirectory == other.spoolDirectory &&
+          heartbeatIntervalMs == other.heartbeatIntervalMs &&
+          directUpgradeIntervalMs == other.directUpgradeIntervalMs &&
+          mailboxTtlMs == other.mailboxTtlMs;
+}
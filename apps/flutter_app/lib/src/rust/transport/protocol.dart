@@ -0,0 +1,98 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'datagram.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'protocol.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `bytes`, `check_hello_device`, `close_connection`, `decode_header`, `decode_payload`, `hello_initiator`, `hello_responder`, `is_empty`, `new`, `put_bytes`, `put_u16`, `put_u32`, `put_u64`, `read_error`, `read_message`, `send_on_new_stream`, `string`, `take`, `u16`, `u32`, `u64`, `u8`, `with_hello_timeout`, `write_message`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ProtocolError`, `Reader`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `from`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `clipboard_item`, `decode`, `encode`, `hello`, `kind`, `negotiate_version`
+
+@freezed
+sealed class SyncMessage with _$SyncMessage {
+  const SyncMessage._();
+
+  /// Version negotiation, sent by both sides when a connection opens
+  const factory SyncMessage.hello({
+    required String deviceId,
+    required int minVersion,
+    required int maxVersion,
+  }) = SyncMessage_Hello;
+
+  /// A clipboard entry (payload is already end-to-end encrypted)
+  const factory SyncMessage.clipboardItem({
+    required BigInt itemId,
+    required String contentType,
+    required BigInt timestamp,
+    required Uint8List payload,
+  }) = SyncMessage_ClipboardItem;
+
+  /// Acknowledges receipt of a clipboard item
+  const factory SyncMessage.ack({
+    required BigInt itemId,
+  }) = SyncMessage_Ack;
+
+  /// Liveness probe
+  const factory SyncMessage.ping({
+    required BigInt nonce,
+  }) = SyncMessage_Ping;
+
+  /// Reply to a [`SyncMessage::Ping`]
+  const factory SyncMessage.pong({
+    required BigInt nonce,
+  }) = SyncMessage_Pong;
+
+  /// Ask a peer for clipboard items newer than `since` (unix millis)
+  const factory SyncMessage.historyRequest({
+    required BigInt since,
+    required int limit,
+  }) = SyncMessage_HistoryRequest;
+
+  /// Report a failure to the peer
+  const factory SyncMessage.error({
+    required int code,
+    required String message,
+  }) = SyncMessage_Error;
+
+  /// Opens a chunked transfer of `size` bytes
+  const factory SyncMessage.transferOffer({
+    required BigInt transferId,
+    required String name,
+    required String contentType,
+    required BigInt size,
+  }) = SyncMessage_TransferOffer;
+
+  /// Receiver accepts a transfer and asks for content from `offset`
+  const factory SyncMessage.transferAccept({
+    required BigInt transferId,
+    required BigInt offset,
+  }) = SyncMessage_TransferAccept;
+
+  /// Transfer content starting at `offset`
+  const factory SyncMessage.transferChunk({
+    required BigInt offset,
+    required Uint8List data,
+  }) = SyncMessage_TransferChunk;
+
+  /// Ends a transfer with the SHA-256 hash of the whole content
+  const factory SyncMessage.transferEnd({
+    required Uint8List sha256,
+  }) = SyncMessage_TransferEnd;
+
+  /// Receiver has stored transfer content up to `offset` durably
+  const factory SyncMessage.transferAck({
+    required BigInt transferId,
+    required BigInt offset,
+  }) = SyncMessage_TransferAck;
+
+  /// What the user is doing on the sending device
+  const factory SyncMessage.presence({
+    required Activity activity,
+  }) = SyncMessage_Presence;
+}
@@ -0,0 +1,2947 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'protocol.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$SyncMessage {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $SyncMessageCopyWith<$Res> {
+  factory $SyncMessageCopyWith(SyncMessage value, $Res Function(SyncMessage) then) =
+      _$SyncMessageCopyWithImpl<$Res, SyncMessage>;
+}
+
+/// @nodoc
+class _$SyncMessageCopyWithImpl<$Res, $Val extends SyncMessage> implements $SyncMessageCopyWith<$Res> {
+  _$SyncMessageCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_HelloImplCopyWith<$Res> {
+  factory _$$SyncMessage_HelloImplCopyWith(_$SyncMessage_HelloImpl value, $Res Function(_$SyncMessage_HelloImpl) then) =
+      __$$SyncMessage_HelloImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String deviceId, int minVersion, int maxVersion});
+}
+
+/// @nodoc
+class __$$SyncMessage_HelloImplCopyWithImpl<$Res> extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_HelloImpl>
+    implements _$$SyncMessage_HelloImplCopyWith<$Res> {
+  __$$SyncMessage_HelloImplCopyWithImpl(_$SyncMessage_HelloImpl _value, $Res Function(_$SyncMessage_HelloImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? deviceId = null,
+    Object? minVersion = null,
+    Object? maxVersion = null,
+  }) {
+    return _then(_$SyncMessage_HelloImpl(
+      deviceId: null == deviceId
+          ? _value.deviceId
+          : deviceId // ignore: cast_nullable_to_non_nullable
+              as String,
+      minVersion: null == minVersion
+          ? _value.minVersion
+          : minVersion // ignore: cast_nullable_to_non_nullable
+              as int,
+      maxVersion: null == maxVersion
+          ? _value.maxVersion
+          : maxVersion // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_HelloImpl extends SyncMessage_Hello {
+  const _$SyncMessage_HelloImpl({required this.deviceId, required this.minVersion, required this.maxVersion})
+      : super._();
+
+  @override
+  final String deviceId;
+  @override
+  final int minVersion;
+  @override
+  final int maxVersion;
+
+  @override
+  String toString() {
+    return 'SyncMessage.hello(deviceId: $deviceId, minVersion: $minVersion, maxVersion: $maxVersion)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_HelloImpl &&
+            (identical(other.deviceId, deviceId) || other.deviceId == deviceId) &&
+            (identical(other.minVersion, minVersion) || other.minVersion == minVersion) &&
+            (identical(other.maxVersion, maxVersion) || other.maxVersion == maxVersion));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, deviceId, minVersion, maxVersion);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_HelloImplCopyWith<_$SyncMessage_HelloImpl> get copyWith =>
+      __$$SyncMessage_HelloImplCopyWithImpl<_$SyncMessage_HelloImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return hello(deviceId, minVersion, maxVersion);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return hello?.call(deviceId, minVersion, maxVersion);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (hello != null) {
+      return hello(deviceId, minVersion, maxVersion);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return hello(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return hello?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (hello != null) {
+      return hello(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_Hello extends SyncMessage {
+  const factory SyncMessage_Hello(
+          {required final String deviceId, required final int minVersion, required final int maxVersion}) =
+      _$SyncMessage_HelloImpl;
+  const SyncMessage_Hello._() : super._();
+
+  String get deviceId;
+  int get minVersion;
+  int get maxVersion;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_HelloImplCopyWith<_$SyncMessage_HelloImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_ClipboardItemImplCopyWith<$Res> {
+  factory _$$SyncMessage_ClipboardItemImplCopyWith(
+          _$SyncMessage_ClipboardItemImpl value, $Res Function(_$SyncMessage_ClipboardItemImpl) then) =
+      __$$SyncMessage_ClipboardItemImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt itemId, String contentType, BigInt timestamp, Uint8List payload});
+}
+
+/// @nodoc
+class __$$SyncMessage_ClipboardItemImplCopyWithImpl<$Res>
+    extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_ClipboardItemImpl>
+    implements _$$SyncMessage_ClipboardItemImplCopyWith<$Res> {
+  __$$SyncMessage_ClipboardItemImplCopyWithImpl(
+      _$SyncMessage_ClipboardItemImpl _value, $Res Function(_$SyncMessage_ClipboardItemImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? itemId = null,
+    Object? contentType = null,
+    Object? timestamp = null,
+    Object? payload = null,
+  }) {
+    return _then(_$SyncMessage_ClipboardItemImpl(
+      itemId: null == itemId
+          ? _value.itemId
+          : itemId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      contentType: null == contentType
+          ? _value.contentType
+          : contentType // ignore: cast_nullable_to_non_nullable
+              as String,
+      timestamp: null == timestamp
+          ? _value.timestamp
+          : timestamp // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      payload: null == payload
+          ? _value.payload
+          : payload // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_ClipboardItemImpl extends SyncMessage_ClipboardItem {
+  const _$SyncMessage_ClipboardItemImpl(
+      {required this.itemId, required this.contentType, required this.timestamp, required this.payload})
+      : super._();
+
+  @override
+  final BigInt itemId;
+  @override
+  final String contentType;
+  @override
+  final BigInt timestamp;
+  @override
+  final Uint8List payload;
+
+  @override
+  String toString() {
+    return 'SyncMessage.clipboardItem(itemId: $itemId, contentType: $contentType, timestamp: $timestamp, payload: $payload)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_ClipboardItemImpl &&
+            (identical(other.itemId, itemId) || other.itemId == itemId) &&
+            (identical(other.contentType, contentType) || other.contentType == contentType) &&
+            (identical(other.timestamp, timestamp) || other.timestamp == timestamp) &&
+            (identical(other.payload, payload) || other.payload == payload));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, itemId, contentType, timestamp, payload);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_ClipboardItemImplCopyWith<_$SyncMessage_ClipboardItemImpl> get copyWith =>
+      __$$SyncMessage_ClipboardItemImplCopyWithImpl<_$SyncMessage_ClipboardItemImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return clipboardItem(itemId, contentType, timestamp, payload);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return clipboardItem?.call(itemId, contentType, timestamp, payload);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (clipboardItem != null) {
+      return clipboardItem(itemId, contentType, timestamp, payload);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return clipboardItem(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return clipboardItem?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (clipboardItem != null) {
+      return clipboardItem(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_ClipboardItem extends SyncMessage {
+  const factory SyncMessage_ClipboardItem(
+      {required final BigInt itemId,
+      required final String contentType,
+      required final BigInt timestamp,
+      required final Uint8List payload}) = _$SyncMessage_ClipboardItemImpl;
+  const SyncMessage_ClipboardItem._() : super._();
+
+  BigInt get itemId;
+  String get contentType;
+  BigInt get timestamp;
+  Uint8List get payload;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_ClipboardItemImplCopyWith<_$SyncMessage_ClipboardItemImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_AckImplCopyWith<$Res> {
+  factory _$$SyncMessage_AckImplCopyWith(_$SyncMessage_AckImpl value, $Res Function(_$SyncMessage_AckImpl) then) =
+      __$$SyncMessage_AckImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt itemId});
+}
+
+/// @nodoc
+class __$$SyncMessage_AckImplCopyWithImpl<$Res> extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_AckImpl>
+    implements _$$SyncMessage_AckImplCopyWith<$Res> {
+  __$$SyncMessage_AckImplCopyWithImpl(_$SyncMessage_AckImpl _value, $Res Function(_$SyncMessage_AckImpl) _then) : super(
+      _value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? itemId = null,
+  }) {
+    return _then(_$SyncMessage_AckImpl(
+      itemId: null == itemId
+          ? _value.itemId
+          : itemId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_AckImpl extends SyncMessage_Ack {
+  const _$SyncMessage_AckImpl({required this.itemId}) : super._();
+
+  @override
+  final BigInt itemId;
+
+  @override
+  String toString() {
+    return 'SyncMessage.ack(itemId: $itemId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_AckImpl &&
+            (identical(other.itemId, itemId) || other.itemId == itemId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, itemId);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_AckImplCopyWith<_$SyncMessage_AckImpl> get copyWith =>
+      __$$SyncMessage_AckImplCopyWithImpl<_$SyncMessage_AckImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return ack(itemId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return ack?.call(itemId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (ack != null) {
+      return ack(itemId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return ack(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return ack?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (ack != null) {
+      return ack(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_Ack extends SyncMessage {
+  const factory SyncMessage_Ack({required final BigInt itemId}) = _$SyncMessage_AckImpl;
+  const SyncMessage_Ack._() : super._();
+
+  BigInt get itemId;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_AckImplCopyWith<_$SyncMessage_AckImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_PingImplCopyWith<$Res> {
+  factory _$$SyncMessage_PingImplCopyWith(_$SyncMessage_PingImpl value, $Res Function(_$SyncMessage_PingImpl) then) =
+      __$$SyncMessage_PingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt nonce});
+}
+
+/// @nodoc
+class __$$SyncMessage_PingImplCopyWithImpl<$Res> extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_PingImpl>
+    implements _$$SyncMessage_PingImplCopyWith<$Res> {
+  __$$SyncMessage_PingImplCopyWithImpl(_$SyncMessage_PingImpl _value, $Res Function(_$SyncMessage_PingImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? nonce = null,
+  }) {
+    return _then(_$SyncMessage_PingImpl(
+      nonce: null == nonce
+          ? _value.nonce
+          : nonce // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_PingImpl extends SyncMessage_Ping {
+  const _$SyncMessage_PingImpl({required this.nonce}) : super._();
+
+  @override
+  final BigInt nonce;
+
+  @override
+  String toString() {
+    return 'SyncMessage.ping(nonce: $nonce)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_PingImpl &&
+            (identical(other.nonce, nonce) || other.nonce == nonce));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, nonce);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_PingImplCopyWith<_$SyncMessage_PingImpl> get copyWith =>
+      __$$SyncMessage_PingImplCopyWithImpl<_$SyncMessage_PingImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return ping(nonce);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return ping?.call(nonce);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (ping != null) {
+      return ping(nonce);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return ping(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return ping?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (ping != null) {
+      return ping(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_Ping extends SyncMessage {
+  const factory SyncMessage_Ping({required final BigInt nonce}) = _$SyncMessage_PingImpl;
+  const SyncMessage_Ping._() : super._();
+
+  BigInt get nonce;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_PingImplCopyWith<_$SyncMessage_PingImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_PongImplCopyWith<$Res> {
+  factory _$$SyncMessage_PongImplCopyWith(_$SyncMessage_PongImpl value, $Res Function(_$SyncMessage_PongImpl) then) =
+      __$$SyncMessage_PongImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt nonce});
+}
+
+/// @nodoc
+class __$$SyncMessage_PongImplCopyWithImpl<$Res> extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_PongImpl>
+    implements _$$SyncMessage_PongImplCopyWith<$Res> {
+  __$$SyncMessage_PongImplCopyWithImpl(_$SyncMessage_PongImpl _value, $Res Function(_$SyncMessage_PongImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? nonce = null,
+  }) {
+    return _then(_$SyncMessage_PongImpl(
+      nonce: null == nonce
+          ? _value.nonce
+          : nonce // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_PongImpl extends SyncMessage_Pong {
+  const _$SyncMessage_PongImpl({required this.nonce}) : super._();
+
+  @override
+  final BigInt nonce;
+
+  @override
+  String toString() {
+    return 'SyncMessage.pong(nonce: $nonce)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_PongImpl &&
+            (identical(other.nonce, nonce) || other.nonce == nonce));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, nonce);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_PongImplCopyWith<_$SyncMessage_PongImpl> get copyWith =>
+      __$$SyncMessage_PongImplCopyWithImpl<_$SyncMessage_PongImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return pong(nonce);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return pong?.call(nonce);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (pong != null) {
+      return pong(nonce);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return pong(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return pong?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (pong != null) {
+      return pong(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_Pong extends SyncMessage {
+  const factory SyncMessage_Pong({required final BigInt nonce}) = _$SyncMessage_PongImpl;
+  const SyncMessage_Pong._() : super._();
+
+  BigInt get nonce;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_PongImplCopyWith<_$SyncMessage_PongImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_HistoryRequestImplCopyWith<$Res> {
+  factory _$$SyncMessage_HistoryRequestImplCopyWith(
+          _$SyncMessage_HistoryRequestImpl value, $Res Function(_$SyncMessage_HistoryRequestImpl) then) =
+      __$$SyncMessage_HistoryRequestImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt since, int limit});
+}
+
+/// @nodoc
+class __$$SyncMessage_HistoryRequestImplCopyWithImpl<$Res>
+    extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_HistoryRequestImpl>
+    implements _$$SyncMessage_HistoryRequestImplCopyWith<$Res> {
+  __$$SyncMessage_HistoryRequestImplCopyWithImpl(
+      _$SyncMessage_HistoryRequestImpl _value, $Res Function(_$SyncMessage_HistoryRequestImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? since = null,
+    Object? limit = null,
+  }) {
+    return _then(_$SyncMessage_HistoryRequestImpl(
+      since: null == since
+          ? _value.since
+          : since // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      limit: null == limit
+          ? _value.limit
+          : limit // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_HistoryRequestImpl extends SyncMessage_HistoryRequest {
+  const _$SyncMessage_HistoryRequestImpl({required this.since, required this.limit}) : super._();
+
+  @override
+  final BigInt since;
+  @override
+  final int limit;
+
+  @override
+  String toString() {
+    return 'SyncMessage.historyRequest(since: $since, limit: $limit)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_HistoryRequestImpl &&
+            (identical(other.since, since) || other.since == since) &&
+            (identical(other.limit, limit) || other.limit == limit));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, since, limit);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_HistoryRequestImplCopyWith<_$SyncMessage_HistoryRequestImpl> get copyWith =>
+      __$$SyncMessage_HistoryRequestImplCopyWithImpl<_$SyncMessage_HistoryRequestImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return historyRequest(since, limit);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return historyRequest?.call(since, limit);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (historyRequest != null) {
+      return historyRequest(since, limit);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return historyRequest(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return historyRequest?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (historyRequest != null) {
+      return historyRequest(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_HistoryRequest extends SyncMessage {
+  const factory SyncMessage_HistoryRequest({required final BigInt since, required final int limit}) =
+      _$SyncMessage_HistoryRequestImpl;
+  const SyncMessage_HistoryRequest._() : super._();
+
+  BigInt get since;
+  int get limit;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_HistoryRequestImplCopyWith<_$SyncMessage_HistoryRequestImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_ErrorImplCopyWith<$Res> {
+  factory _$$SyncMessage_ErrorImplCopyWith(_$SyncMessage_ErrorImpl value, $Res Function(_$SyncMessage_ErrorImpl) then) =
+      __$$SyncMessage_ErrorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int code, String message});
+}
+
+/// @nodoc
+class __$$SyncMessage_ErrorImplCopyWithImpl<$Res> extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_ErrorImpl>
+    implements _$$SyncMessage_ErrorImplCopyWith<$Res> {
+  __$$SyncMessage_ErrorImplCopyWithImpl(_$SyncMessage_ErrorImpl _value, $Res Function(_$SyncMessage_ErrorImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? code = null,
+    Object? message = null,
+  }) {
+    return _then(_$SyncMessage_ErrorImpl(
+      code: null == code
+          ? _value.code
+          : code // ignore: cast_nullable_to_non_nullable
+              as int,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_ErrorImpl extends SyncMessage_Error {
+  const _$SyncMessage_ErrorImpl({required this.code, required this.message}) : super._();
+
+  @override
+  final int code;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'SyncMessage.error(code: $code, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_ErrorImpl &&
+            (identical(other.code, code) || other.code == code) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, code, message);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_ErrorImplCopyWith<_$SyncMessage_ErrorImpl> get copyWith =>
+      __$$SyncMessage_ErrorImplCopyWithImpl<_$SyncMessage_ErrorImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return error(code, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return error?.call(code, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (error != null) {
+      return error(code, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return error(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return error?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (error != null) {
+      return error(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_Error extends SyncMessage {
+  const factory SyncMessage_Error({required final int code, required final String message}) = _$SyncMessage_ErrorImpl;
+  const SyncMessage_Error._() : super._();
+
+  int get code;
+  String get message;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_ErrorImplCopyWith<_$SyncMessage_ErrorImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_TransferOfferImplCopyWith<$Res> {
+  factory _$$SyncMessage_TransferOfferImplCopyWith(
+          _$SyncMessage_TransferOfferImpl value, $Res Function(_$SyncMessage_TransferOfferImpl) then) =
+      __$$SyncMessage_TransferOfferImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt transferId, String name, String contentType, BigInt size});
+}
+
+/// @nodoc
+class __$$SyncMessage_TransferOfferImplCopyWithImpl<$Res>
+    extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_TransferOfferImpl>
+    implements _$$SyncMessage_TransferOfferImplCopyWith<$Res> {
+  __$$SyncMessage_TransferOfferImplCopyWithImpl(
+      _$SyncMessage_TransferOfferImpl _value, $Res Function(_$SyncMessage_TransferOfferImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? name = null,
+    Object? contentType = null,
+    Object? size = null,
+  }) {
+    return _then(_$SyncMessage_TransferOfferImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      contentType: null == contentType
+          ? _value.contentType
+          : contentType // ignore: cast_nullable_to_non_nullable
+              as String,
+      size: null == size
+          ? _value.size
+          : size // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_TransferOfferImpl extends SyncMessage_TransferOffer {
+  const _$SyncMessage_TransferOfferImpl(
+      {required this.transferId, required this.name, required this.contentType, required this.size})
+      : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final String name;
+  @override
+  final String contentType;
+  @override
+  final BigInt size;
+
+  @override
+  String toString() {
+    return 'SyncMessage.transferOffer(transferId: $transferId, name: $name, contentType: $contentType, size: $size)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_TransferOfferImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.contentType, contentType) || other.contentType == contentType) &&
+            (identical(other.size, size) || other.size == size));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, name, contentType, size);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_TransferOfferImplCopyWith<_$SyncMessage_TransferOfferImpl> get copyWith =>
+      __$$SyncMessage_TransferOfferImplCopyWithImpl<_$SyncMessage_TransferOfferImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return transferOffer(transferId, name, contentType, size);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return transferOffer?.call(transferId, name, contentType, size);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferOffer != null) {
+      return transferOffer(transferId, name, contentType, size);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return transferOffer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return transferOffer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferOffer != null) {
+      return transferOffer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_TransferOffer extends SyncMessage {
+  const factory SyncMessage_TransferOffer(
+      {required final BigInt transferId,
+      required final String name,
+      required final String contentType,
+      required final BigInt size}) = _$SyncMessage_TransferOfferImpl;
+  const SyncMessage_TransferOffer._() : super._();
+
+  BigInt get transferId;
+  String get name;
+  String get contentType;
+  BigInt get size;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_TransferOfferImplCopyWith<_$SyncMessage_TransferOfferImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_TransferAcceptImplCopyWith<$Res> {
+  factory _$$SyncMessage_TransferAcceptImplCopyWith(
+          _$SyncMessage_TransferAcceptImpl value, $Res Function(_$SyncMessage_TransferAcceptImpl) then) =
+      __$$SyncMessage_TransferAcceptImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt transferId, BigInt offset});
+}
+
+/// @nodoc
+class __$$SyncMessage_TransferAcceptImplCopyWithImpl<$Res>
+    extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_TransferAcceptImpl>
+    implements _$$SyncMessage_TransferAcceptImplCopyWith<$Res> {
+  __$$SyncMessage_TransferAcceptImplCopyWithImpl(
+      _$SyncMessage_TransferAcceptImpl _value, $Res Function(_$SyncMessage_TransferAcceptImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? offset = null,
+  }) {
+    return _then(_$SyncMessage_TransferAcceptImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      offset: null == offset
+          ? _value.offset
+          : offset // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_TransferAcceptImpl extends SyncMessage_TransferAccept {
+  const _$SyncMessage_TransferAcceptImpl({required this.transferId, required this.offset}) : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final BigInt offset;
+
+  @override
+  String toString() {
+    return 'SyncMessage.transferAccept(transferId: $transferId, offset: $offset)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_TransferAcceptImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.offset, offset) || other.offset == offset));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, offset);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_TransferAcceptImplCopyWith<_$SyncMessage_TransferAcceptImpl> get copyWith =>
+      __$$SyncMessage_TransferAcceptImplCopyWithImpl<_$SyncMessage_TransferAcceptImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return transferAccept(transferId, offset);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return transferAccept?.call(transferId, offset);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferAccept != null) {
+      return transferAccept(transferId, offset);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return transferAccept(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return transferAccept?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferAccept != null) {
+      return transferAccept(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_TransferAccept extends SyncMessage {
+  const factory SyncMessage_TransferAccept({required final BigInt transferId, required final BigInt offset}) =
+      _$SyncMessage_TransferAcceptImpl;
+  const SyncMessage_TransferAccept._() : super._();
+
+  BigInt get transferId;
+  BigInt get offset;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_TransferAcceptImplCopyWith<_$SyncMessage_TransferAcceptImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_TransferChunkImplCopyWith<$Res> {
+  factory _$$SyncMessage_TransferChunkImplCopyWith(
+          _$SyncMessage_TransferChunkImpl value, $Res Function(_$SyncMessage_TransferChunkImpl) then) =
+      __$$SyncMessage_TransferChunkImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt offset, Uint8List data});
+}
+
+/// @nodoc
+class __$$SyncMessage_TransferChunkImplCopyWithImpl<$Res>
+    extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_TransferChunkImpl>
+    implements _$$SyncMessage_TransferChunkImplCopyWith<$Res> {
+  __$$SyncMessage_TransferChunkImplCopyWithImpl(
+      _$SyncMessage_TransferChunkImpl _value, $Res Function(_$SyncMessage_TransferChunkImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? offset = null,
+    Object? data = null,
+  }) {
+    return _then(_$SyncMessage_TransferChunkImpl(
+      offset: null == offset
+          ? _value.offset
+          : offset // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_TransferChunkImpl extends SyncMessage_TransferChunk {
+  const _$SyncMessage_TransferChunkImpl({required this.offset, required this.data}) : super._();
+
+  @override
+  final BigInt offset;
+  @override
+  final Uint8List data;
+
+  @override
+  String toString() {
+    return 'SyncMessage.transferChunk(offset: $offset, data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_TransferChunkImpl &&
+            (identical(other.offset, offset) || other.offset == offset) &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, offset, data);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_TransferChunkImplCopyWith<_$SyncMessage_TransferChunkImpl> get copyWith =>
+      __$$SyncMessage_TransferChunkImplCopyWithImpl<_$SyncMessage_TransferChunkImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return transferChunk(offset, data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return transferChunk?.call(offset, data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferChunk != null) {
+      return transferChunk(offset, data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return transferChunk(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return transferChunk?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferChunk != null) {
+      return transferChunk(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_TransferChunk extends SyncMessage {
+  const factory SyncMessage_TransferChunk({required final BigInt offset, required final Uint8List data}) =
+      _$SyncMessage_TransferChunkImpl;
+  const SyncMessage_TransferChunk._() : super._();
+
+  BigInt get offset;
+  Uint8List get data;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_TransferChunkImplCopyWith<_$SyncMessage_TransferChunkImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_TransferEndImplCopyWith<$Res> {
+  factory _$$SyncMessage_TransferEndImplCopyWith(
+          _$SyncMessage_TransferEndImpl value, $Res Function(_$SyncMessage_TransferEndImpl) then) =
+      __$$SyncMessage_TransferEndImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List sha256});
+}
+
+/// @nodoc
+class __$$SyncMessage_TransferEndImplCopyWithImpl<$Res>
+    extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_TransferEndImpl>
+    implements _$$SyncMessage_TransferEndImplCopyWith<$Res> {
+  __$$SyncMessage_TransferEndImplCopyWithImpl(
+      _$SyncMessage_TransferEndImpl _value, $Res Function(_$SyncMessage_TransferEndImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? sha256 = null,
+  }) {
+    return _then(_$SyncMessage_TransferEndImpl(
+      sha256: null == sha256
+          ? _value.sha256
+          : sha256 // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_TransferEndImpl extends SyncMessage_TransferEnd {
+  const _$SyncMessage_TransferEndImpl({required this.sha256}) : super._();
+
+  @override
+  final Uint8List sha256;
+
+  @override
+  String toString() {
+    return 'SyncMessage.transferEnd(sha256: $sha256)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_TransferEndImpl &&
+            (identical(other.sha256, sha256) || other.sha256 == sha256));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, sha256);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_TransferEndImplCopyWith<_$SyncMessage_TransferEndImpl> get copyWith =>
+      __$$SyncMessage_TransferEndImplCopyWithImpl<_$SyncMessage_TransferEndImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return transferEnd(sha256);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return transferEnd?.call(sha256);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferEnd != null) {
+      return transferEnd(sha256);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return transferEnd(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return transferEnd?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferEnd != null) {
+      return transferEnd(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_TransferEnd extends SyncMessage {
+  const factory SyncMessage_TransferEnd({required final Uint8List sha256}) = _$SyncMessage_TransferEndImpl;
+  const SyncMessage_TransferEnd._() : super._();
+
+  Uint8List get sha256;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_TransferEndImplCopyWith<_$SyncMessage_TransferEndImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_TransferAckImplCopyWith<$Res> {
+  factory _$$SyncMessage_TransferAckImplCopyWith(
+          _$SyncMessage_TransferAckImpl value, $Res Function(_$SyncMessage_TransferAckImpl) then) =
+      __$$SyncMessage_TransferAckImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt transferId, BigInt offset});
+}
+
+/// @nodoc
+class __$$SyncMessage_TransferAckImplCopyWithImpl<$Res>
+    extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_TransferAckImpl>
+    implements _$$SyncMessage_TransferAckImplCopyWith<$Res> {
+  __$$SyncMessage_TransferAckImplCopyWithImpl(
+      _$SyncMessage_TransferAckImpl _value, $Res Function(_$SyncMessage_TransferAckImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? offset = null,
+  }) {
+    return _then(_$SyncMessage_TransferAckImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      offset: null == offset
+          ? _value.offset
+          : offset // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_TransferAckImpl extends SyncMessage_TransferAck {
+  const _$SyncMessage_TransferAckImpl({required this.transferId, required this.offset}) : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final BigInt offset;
+
+  @override
+  String toString() {
+    return 'SyncMessage.transferAck(transferId: $transferId, offset: $offset)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_TransferAckImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.offset, offset) || other.offset == offset));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, offset);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_TransferAckImplCopyWith<_$SyncMessage_TransferAckImpl> get copyWith =>
+      __$$SyncMessage_TransferAckImplCopyWithImpl<_$SyncMessage_TransferAckImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return transferAck(transferId, offset);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return transferAck?.call(transferId, offset);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferAck != null) {
+      return transferAck(transferId, offset);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return transferAck(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return transferAck?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (transferAck != null) {
+      return transferAck(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_TransferAck extends SyncMessage {
+  const factory SyncMessage_TransferAck({required final BigInt transferId, required final BigInt offset}) =
+      _$SyncMessage_TransferAckImpl;
+  const SyncMessage_TransferAck._() : super._();
+
+  BigInt get transferId;
+  BigInt get offset;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_TransferAckImplCopyWith<_$SyncMessage_TransferAckImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SyncMessage_PresenceImplCopyWith<$Res> {
+  factory _$$SyncMessage_PresenceImplCopyWith(
+          _$SyncMessage_PresenceImpl value, $Res Function(_$SyncMessage_PresenceImpl) then) =
+      __$$SyncMessage_PresenceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Activity activity});
+}
+
+/// @nodoc
+class __$$SyncMessage_PresenceImplCopyWithImpl<$Res> extends _$SyncMessageCopyWithImpl<$Res, _$SyncMessage_PresenceImpl>
+    implements _$$SyncMessage_PresenceImplCopyWith<$Res> {
+  __$$SyncMessage_PresenceImplCopyWithImpl(
+      _$SyncMessage_PresenceImpl _value, $Res Function(_$SyncMessage_PresenceImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? activity = null,
+  }) {
+    return _then(_$SyncMessage_PresenceImpl(
+      activity: null == activity
+          ? _value.activity
+          : activity // ignore: cast_nullable_to_non_nullable
+              as Activity,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SyncMessage_PresenceImpl extends SyncMessage_Presence {
+  const _$SyncMessage_PresenceImpl({required this.activity}) : super._();
+
+  @override
+  final Activity activity;
+
+  @override
+  String toString() {
+    return 'SyncMessage.presence(activity: $activity)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SyncMessage_PresenceImpl &&
+            (identical(other.activity, activity) || other.activity == activity));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, activity);
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SyncMessage_PresenceImplCopyWith<_$SyncMessage_PresenceImpl> get copyWith =>
+      __$$SyncMessage_PresenceImplCopyWithImpl<_$SyncMessage_PresenceImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String deviceId, int minVersion, int maxVersion) hello,
+    required TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload) clipboardItem,
+    required TResult Function(BigInt itemId) ack,
+    required TResult Function(BigInt nonce) ping,
+    required TResult Function(BigInt nonce) pong,
+    required TResult Function(BigInt since, int limit) historyRequest,
+    required TResult Function(int code, String message) error,
+    required TResult Function(BigInt transferId, String name, String contentType, BigInt size) transferOffer,
+    required TResult Function(BigInt transferId, BigInt offset) transferAccept,
+    required TResult Function(BigInt offset, Uint8List data) transferChunk,
+    required TResult Function(Uint8List sha256) transferEnd,
+    required TResult Function(BigInt transferId, BigInt offset) transferAck,
+    required TResult Function(Activity activity) presence,
+  }) {
+    return presence(activity);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult? Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult? Function(BigInt itemId)? ack,
+    TResult? Function(BigInt nonce)? ping,
+    TResult? Function(BigInt nonce)? pong,
+    TResult? Function(BigInt since, int limit)? historyRequest,
+    TResult? Function(int code, String message)? error,
+    TResult? Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult? Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult? Function(Uint8List sha256)? transferEnd,
+    TResult? Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult? Function(Activity activity)? presence,
+  }) {
+    return presence?.call(activity);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String deviceId, int minVersion, int maxVersion)? hello,
+    TResult Function(BigInt itemId, String contentType, BigInt timestamp, Uint8List payload)? clipboardItem,
+    TResult Function(BigInt itemId)? ack,
+    TResult Function(BigInt nonce)? ping,
+    TResult Function(BigInt nonce)? pong,
+    TResult Function(BigInt since, int limit)? historyRequest,
+    TResult Function(int code, String message)? error,
+    TResult Function(BigInt transferId, String name, String contentType, BigInt size)? transferOffer,
+    TResult Function(BigInt transferId, BigInt offset)? transferAccept,
+    TResult Function(BigInt offset, Uint8List data)? transferChunk,
+    TResult Function(Uint8List sha256)? transferEnd,
+    TResult Function(BigInt transferId, BigInt offset)? transferAck,
+    TResult Function(Activity activity)? presence,
+    required TResult orElse(),
+  }) {
+    if (presence != null) {
+      return presence(activity);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(SyncMessage_Hello value) hello,
+    required TResult Function(SyncMessage_ClipboardItem value) clipboardItem,
+    required TResult Function(SyncMessage_Ack value) ack,
+    required TResult Function(SyncMessage_Ping value) ping,
+    required TResult Function(SyncMessage_Pong value) pong,
+    required TResult Function(SyncMessage_HistoryRequest value) historyRequest,
+    required TResult Function(SyncMessage_Error value) error,
+    required TResult Function(SyncMessage_TransferOffer value) transferOffer,
+    required TResult Function(SyncMessage_TransferAccept value) transferAccept,
+    required TResult Function(SyncMessage_TransferChunk value) transferChunk,
+    required TResult Function(SyncMessage_TransferEnd value) transferEnd,
+    required TResult Function(SyncMessage_TransferAck value) transferAck,
+    required TResult Function(SyncMessage_Presence value) presence,
+  }) {
+    return presence(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(SyncMessage_Hello value)? hello,
+    TResult? Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult? Function(SyncMessage_Ack value)? ack,
+    TResult? Function(SyncMessage_Ping value)? ping,
+    TResult? Function(SyncMessage_Pong value)? pong,
+    TResult? Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult? Function(SyncMessage_Error value)? error,
+    TResult? Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult? Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult? Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult? Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult? Function(SyncMessage_TransferAck value)? transferAck,
+    TResult? Function(SyncMessage_Presence value)? presence,
+  }) {
+    return presence?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(SyncMessage_Hello value)? hello,
+    TResult Function(SyncMessage_ClipboardItem value)? clipboardItem,
+    TResult Function(SyncMessage_Ack value)? ack,
+    TResult Function(SyncMessage_Ping value)? ping,
+    TResult Function(SyncMessage_Pong value)? pong,
+    TResult Function(SyncMessage_HistoryRequest value)? historyRequest,
+    TResult Function(SyncMessage_Error value)? error,
+    TResult Function(SyncMessage_TransferOffer value)? transferOffer,
+    TResult Function(SyncMessage_TransferAccept value)? transferAccept,
+    TResult Function(SyncMessage_TransferChunk value)? transferChunk,
+    TResult Function(SyncMessage_TransferEnd value)? transferEnd,
+    TResult Function(SyncMessage_TransferAck value)? transferAck,
+    TResult Function(SyncMessage_Presence value)? presence,
+    required TResult orElse(),
+  }) {
+    if (presence != null) {
+      return presence(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class SyncMessage_Presence extends SyncMessage {
+  const factory SyncMessage_Presence({required final Activity activity}) = _$SyncMessage_PresenceImpl;
+  const SyncMessage_Presence._() : super._();
+
+  Activity get activity;
+
+  /// Create a copy of SyncMessage
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SyncMessage_PresenceImplCopyWith<_$SyncMessage_PresenceImpl> get copyWith => throw _privateConstructorUsedError;
+}
@@ -3,15 +3,30 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../discovery/mdns.dart';
 import '../frb_generated.dart';
+import '../signaling/client.dart';
+import 'broadcast.dart';
+import 'datagram.dart';
+import 'delivery.dart';
+import 'events.dart';
+import 'identity.dart';
+import 'options.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'protocol.dart';
+import 'receiver.dart';
+import 'stats.dart';
+import 'stun.dart';
+import 'supervisor.dart';
+import 'transfer.dart';
+import 'trust.dart';
 part 'quic.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_cert_and_key`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TofuCertVerifier`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `fmt`, `supported_verify_schemes`, `verify_server_cert`, `verify_tls12_signature`, `verify_tls13_signature`
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `receive_data`
+// These functions are ignored because they are not marked as `pub`: `accept_incoming`, `accept_loop`, `bind`, `cert_and_key`, `close_connections`, `connect`, `connect`, `device_id`, `dial_in_turn`, `dial`, `dialer`, `handshake`, `hello`, `local_device_id`, `open_endpoint`, `peer_connection`, `register`, `registry`, `reset`, `resolve_server`, `stop_supervising`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Dialer`, `Handshake`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `receive_data`, `send_reader`, `subscribe_events`, `subscribe_links`, `subscribe_transfers`
 
 /// Generate a self-signed certificate for QUIC transport
 ///
@@ -20,45 +35,397 @@ part 'quic.freezed.dart';
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicTransport>>
 abstract class QuicTransport implements RustOpaqueInterface {
-  /// Accept an incoming connection (server only)
+  /// Wait for the next incoming connection
+  ///
+  /// Connections are accepted by the background accept loop; this resolves
+  /// with the peer id of the next one to complete its handshake, or the error
+  /// of the next failed handshake. Clients must present a certificate for a
+  /// paired device; pin their fingerprints with [`QuicTransport::pin_fingerprint`]
+  /// beforehand.
   Future<String> acceptConnection();
 
+  /// Confirm that a received item with `requires_ack` was applied
+  ///
+  /// Returns `false` if the sender is no longer waiting for it.
+  bool acknowledgeItem({required String peerId, required BigInt itemId});
+
+  /// Send data to every connected device, or those matching `filter`, at once
+  ///
+  /// The data is encrypted with each device's key from
+  /// [`QuicTransport::set_peer_key`], once per distinct key, and sent as the
+  /// same [`SyncMessage::ClipboardItem`] to all of them. Returns what happened
+  /// for each device; one failing doesn't affect the others.
+  ///
+  /// With [`QuicTransport::use_signaling`], paired devices that aren't
+  /// connected but have a key from [`QuicTransport::set_peer_public_key`] get
+  /// the item sealed and left at the hub, see [`BroadcastOutcome::Deposited`].
+  ///
+  /// # Arguments
+  /// * `data` - Plaintext to send
+  /// * `filter` - Devices to include or leave out
+  Future<Map<String, BroadcastOutcome>> broadcast({required List<int> data, required BroadcastFilter filter});
+
+  /// Cancel a transfer in either direction
+  ///
+  /// A running transfer is cancelled for the peer too. An interrupted one is
+  /// removed from the spool; for incoming transfers the sender may still
+  /// offer it again. Returns `false` if there is no such transfer.
+  bool cancelTransfer({required BigInt transferId});
+
   /// Close the transport and all connections
+  ///
+  /// Anything not yet delivered is dropped; use [`QuicTransport::shutdown`]
+  /// to deliver it first.
   Future<void> close();
 
-  /// Connect to a peer (client mode)
+  /// Connect to a known device, pinning its certificate fingerprint
+  ///
+  /// The first successful connection records the fingerprint of the device's
+  /// certificate; later connections presenting a different certificate fail
+  /// with [`TransportError::FingerprintMismatch`].
   ///
   /// # Arguments
-  /// * `addr` - IP address or hostname of the peer
+  /// * `device_id` - Device id of the peer (from mDNS or pairing)
+  /// * `addr` - IPv4 or IPv6 address of the peer, optionally with a zone (`fe80::1%eth0`)
+  /// * `port` - Port number of the peer
+  Future<String> connectToDevice({required String deviceId, required String addr, required int port});
+
+  /// Connect to a peer
+  ///
+  /// The peer's certificate is not pinned because its device id is unknown;
+  /// prefer [`QuicTransport::connect_to_device`] when it is.
+  ///
+  /// # Arguments
+  /// * `addr` - IPv4 or IPv6 address of the peer, optionally with a zone (`fe80::1%eth0`)
   /// * `port` - Port number of the peer
   Future<String> connectToPeer({required String addr, required int port});
 
+  /// Connect to a peer found by mDNS, racing its addresses
+  ///
+  /// Attempts start 250ms apart, local-network and IPv6 addresses first, and
+  /// the first handshake to complete wins; the others are cancelled. The
+  /// winning address is tried first next time. The peer's certificate
+  /// fingerprint is pinned as in [`QuicTransport::connect_to_device`].
+  Future<String> connectToPeerInfo({required PeerInfo peer});
+
+  /// Connect to a device through the relay set with [`QuicTransport::use_relay`]
+  ///
+  /// The device must connect to us through the same relay at about the same
+  /// time. When both are registered with a rendezvous service, the relayed
+  /// connection is replaced by a direct one once hole punching succeeds,
+  /// tried every [`TransportOptions::direct_upgrade_interval_ms`].
+  Future<String> connectViaRelay({required String deviceId});
+
+  /// Connect to a device through the rendezvous service, punching through NATs
+  ///
+  /// Both devices must be registered with the same service with
+  /// [`QuicTransport::register_rendezvous`] and have each other's
+  /// fingerprints pinned.
+  Future<String> connectViaRendezvous({required String deviceId});
+
+  /// Connect to a device at the signaling hub, punching through NATs
+  ///
+  /// The device must be online at the hub set with
+  /// [`QuicTransport::use_signaling`] and have our fingerprint pinned.
+  Future<String> connectViaSignaling({required String deviceId});
+
+  /// Forward connection events to a Dart stream
+  ///
+  /// Events published before the stream is opened are not replayed; call
+  /// this before starting the server or dialing peers. A listener that falls
+  /// far behind skips the oldest events.
+  Stream<ConnectionEvent> connectionEvents();
+
   static QuicTransport default_() => RustLib.instance.api.crateTransportQuicQuicTransportDefault();
 
   /// Disconnect from a peer
+  ///
+  /// Also stops reconnecting to it if it was kept connected.
   Future<void> disconnect({required String peerId});
 
+  /// Learn our public address and NAT behaviour from STUN servers
+  ///
+  /// Binding requests are sent from the QUIC socket to every server at once,
+  /// so the mapped address is the one peers can reach QUIC at. At least two
+  /// servers on different addresses are needed to tell an
+  /// endpoint-independent NAT from a symmetric one.
+  ///
+  /// # Arguments
+  /// * `stun_servers` - Server addresses such as `stun.example.com:3478`
+  Future<NatReport> discoverNat({required List<String> stunServers});
+
+  /// Forget a device's pinned fingerprint
+  ///
+  /// Returns `true` if a fingerprint was pinned for the device.
+  bool forgetFingerprint({required String deviceId});
+
+  /// Forget the key shared with a device, e.g. after unpairing
+  bool forgetPeerKey({required String deviceId});
+
   /// Get list of connected peers
   Future<List<String>> getConnectedPeers();
 
+  /// Round-trip time, loss and activity of a connected peer, measured with datagram heartbeats
+  LinkQuality? getLinkQuality({required String peerId});
+
+  /// Get the current network address of a connected peer
+  ///
+  /// Reflects QUIC connection migration and reconnects from new addresses.
+  Future<String> getPeerAddress({required String peerId});
+
+  /// Get the device id proven by a peer's certificate during the handshake
+  Future<String> getPeerDeviceId({required String peerId});
+
+  /// Get the wire protocol version negotiated with a connected peer
+  Future<int> getPeerProtocolVersion({required String peerId});
+
+  /// RTT, congestion window, traffic counters, path address and age of a peer's connection
+  Future<PeerStats> getPeerStats({required String peerId});
+
+  /// Transfers that are running or waiting in the spool for their peer to reconnect
+  List<PendingTransfer> getPendingTransfers();
+
+  /// Address that won the last [`QuicTransport::connect_to_peer_info`] race for a device
+  String? getPreferredAddress({required String deviceId});
+
+  /// Devices kept connected with [`QuicTransport::keep_connected`]
+  ///
+  /// Devices whose supervision ended on its own, because they unpaired us or
+  /// share no protocol version, are not included.
+  List<String> getSupervisedPeers();
+
+  /// Whether a peer is reached through a relay rather than directly
+  Future<bool> isPeerRelayed({required String peerId});
+
   /// Check if transport is running
   bool isRunning();
 
+  /// Keep a connection to a device open, reconnecting whenever it drops
+  ///
+  /// Dials the device now if it isn't connected. After a disconnect the last
+  /// address that worked is tried first, then addresses from mDNS (see
+  /// [`QuicTransport::use_discovery`]), then `addresses`, with jittered
+  /// exponential backoff between rounds. Progress is reported as
+  /// [`ConnectionEvent::PeerStateChanged`] events. Supervision stops by itself
+  /// when the device unpairs us or shares no protocol version with us.
+  ///
+  /// # Arguments
+  /// * `device_id` - Device id of the peer
+  /// * `addresses` - Known IP addresses of the peer, IPv6 optionally with a zone
+  /// * `port` - Port number of the peer
+  Future<void> keepConnected({required String deviceId, required List<String> addresses, required int port});
+
+  /// Forward link quality and activity updates to a Dart stream
+  ///
+  /// A quality update arrives for each peer every heartbeat interval, see
+  /// [`TransportOptions::heartbeat_interval_ms`].
+  Stream<LinkEvent> linkEvents();
+
+  /// List all pinned certificate fingerprints
+  List<PinnedFingerprint> listPinnedFingerprints();
+
+  /// Port the endpoint accepts and dials from, `None` before it is created
+  int? localPort();
+
   /// Create a new QUIC transport instance
-  factory QuicTransport() => RustLib.instance.api.crateTransportQuicQuicTransportNew();
+  ///
+  /// # Arguments
+  /// * `options` - Bind address and QUIC tuning, see [`TransportOptions::desktop`] and [`TransportOptions::mobile`]
+  factory QuicTransport({required TransportOptions options}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportNew(options: options);
+
+  /// Send statistics of all connected peers to a Dart stream every `interval_ms`
+  ///
+  /// Each update lists every peer connected at the time, ordered by peer id.
+  /// Intervals below 100ms are raised to 100ms. Stops when the Dart stream is
+  /// cancelled.
+  Stream<List<PeerStats>> peerStats({required BigInt intervalMs});
+
+  /// Pin a device's certificate fingerprint ahead of the first connection
+  ///
+  /// Used with the fingerprint carried in a QR pairing payload. Accepts hex
+  /// with or without `:` separators and replaces any existing pin. This pairs
+  /// the device, which lets it connect to us; fingerprints pinned on first
+  /// use when dialing don't.
+  void pinFingerprint({required String deviceId, required String fingerprint});
+
+  /// Forward clipboard items received from all peers to a Dart stream
+  ///
+  /// Each item carries the id of the peer that sent it. Forwarding stops when
+  /// the Dart stream is cancelled.
+  Stream<ReceivedItem> receiveItems();
+
+  /// Register with a rendezvous service so devices behind NATs can reach us
+  ///
+  /// Registration is sent from the QUIC socket, so the service sees the
+  /// public address our NAT maps it to, and is refreshed in the background.
+  /// Devices the service introduces are connected to by hole punching.
+  /// Returns our public address as the service saw it.
+  ///
+  /// # Arguments
+  /// * `server` - Address of the service, e.g. `rendezvous.example.com:3478`
+  Future<String> registerRendezvous({required String server});
+
+  /// Tell the sender of a received item with `requires_ack` why it wasn't applied
+  ///
+  /// Returns `false` if the sender is no longer waiting for it.
+  bool rejectItem({required String peerId, required BigInt itemId, required String reason});
 
   /// Send data to a specific peer
   ///
+  /// The data is wrapped in a [`SyncMessage::ClipboardItem`].
+  ///
   /// # Arguments
-  /// * `peer_id` - The peer identifier (address:port)
+  /// * `peer_id` - The peer identifier (device id)
   /// * `data` - Data to send
   Future<void> sendData({required String peerId, required List<int> data});
 
-  /// Start the QUIC server on the specified port
+  /// Send data to a peer and wait until it confirms applying it
+  ///
+  /// Like [`QuicTransport::send_data`], but the item goes on a bidirectional
+  /// stream and the peer's app answers it with
+  /// [`QuicTransport::acknowledge_item`] or [`QuicTransport::reject_item`].
+  ///
+  /// # Arguments
+  /// * `peer_id` - The peer identifier (device id)
+  /// * `data` - Data to send
+  /// * `timeout_ms` - How long to wait for the answer, including sending the data
+  Future<DeliveryResult> sendDataAcked({required String peerId, required List<int> data, required BigInt timeoutMs});
+
+  /// Send a file to a peer in chunks
+  ///
+  /// Completes once the peer has stored the file and verified its SHA-256
+  /// hash, returning the transfer id. Progress is reported as
+  /// [`TransferEvent`]s, see [`QuicTransport::transfer_events`]. If the
+  /// connection drops, this fails but the transfer is kept in the spool and
+  /// continues from the last acknowledged offset when the peer reconnects.
+  ///
+  /// # Arguments
+  /// * `peer_id` - The peer identifier (device id)
+  /// * `path` - File to send; the peer saves it under the same file name
+  /// * `content_type` - MIME type of the file
+  Future<BigInt> sendFile({required String peerId, required String path, required String contentType});
+
+  /// Send a payload too large for [`QuicTransport::send_data`], such as a clipboard image, in chunks
+  ///
+  /// Behaves like [`QuicTransport::send_file`]; the peer saves the payload as
+  /// `name`. A copy is kept in the spool until the peer has all of it.
+  Future<BigInt> sendLargeData(
+      {required String peerId, required String name, required String contentType, required List<int> data});
+
+  /// Send a protocol message to a specific peer on its own stream
+  Future<void> sendMessage({required String peerId, required SyncMessage message});
+
+  /// Send a [`SyncMessage::ClipboardItem`] and wait until the peer confirms applying it
+  ///
+  /// The item id identifies the answer, so reuse it when resending an item.
+  /// Fails only if the item could not be sent; a peer that doesn't answer in
+  /// time gives [`DeliveryResult::TimedOut`].
+  Future<DeliveryResult> sendMessageAcked(
+      {required String peerId, required SyncMessage message, required BigInt timeoutMs});
+
+  /// Tell all connected peers what the user is doing
+  ///
+  /// Sent as a datagram, so it costs no stream; peers connecting later are
+  /// told when they connect.
+  Future<void> setActivity({required Activity activity});
+
+  /// Set the device identity used for subsequent servers and connections
+  void setIdentity({required DeviceIdentity identity});
+
+  /// Set the key shared with a device, see [`crate::crypto::derive_shared_secret`]
+  ///
+  /// [`QuicTransport::broadcast`] encrypts items for the device with this key.
+  void setPeerKey({required String deviceId, required List<int> key});
+
+  /// Set a paired device's X25519 public key, used to seal items left for it while offline
+  void setPeerPublicKey({required String deviceId, required List<int> publicKey});
+
+  /// Set our X25519 secret key, the one whose public key paired devices seal items with
+  ///
+  /// Use the key pair from [`crate::crypto::generate_keypair`] exchanged
+  /// during pairing. Set it before [`QuicTransport::use_signaling`], which
+  /// only fetches our mailbox when it can open what it gets.
+  void setSealingKey({required List<int> secretKey});
+
+  /// Shut down gracefully, delivering what was already sent
+  ///
+  /// New sends and incoming connections are refused while sends in flight,
+  /// including chunked transfers, get up to `timeout_ms` to be acknowledged
+  /// by their peers. Transfers still running then are interrupted and resume
+  /// from the spool later. Connections are closed with a code peers report
+  /// as a shutdown rather than a lost connection.
+  ///
+  /// Takes `&self` so sends already waiting on the transport are not blocked
+  /// behind it. The transport can be started again afterwards.
+  Future<void> shutdown({required BigInt timeoutMs});
+
+  /// Start accepting connections on the specified port
+  ///
+  /// The transport has a single endpoint that both accepts and dials with
+  /// the same identity. If it already exists because a peer was dialed, it
+  /// moves to `port` unless `port` is 0; connections it dialed follow it, but
+  /// ones it accepted can't change their server address and are lost. Incoming
+  /// connections are accepted in the background, with handshakes running
+  /// concurrently; subscribe with [`QuicTransport::connection_events`] to
+  /// learn about new peers.
   ///
   /// # Arguments
-  /// * `port` - Port number to listen on
+  /// * `port` - Port number to listen on, 0 for any
   Future<void> startServer({required int port});
+
+  /// Stop reconnecting to a device; an open connection is left as is
+  ///
+  /// Returns `true` if the device was supervised.
+  bool stopKeepingConnected({required String deviceId});
+
+  /// Forward transfer progress in both directions to a Dart stream
+  ///
+  /// Like [`QuicTransport::connection_events`], only events published after
+  /// the stream is opened are delivered.
+  Stream<TransferEvent> transferEvents();
+
+  /// Unpair a device, dropping its pinned fingerprint and key and closing any connection to it
+  ///
+  /// The device sees the close as [`DisconnectReason::Unpaired`]. Returns
+  /// `true` if the device was pinned, keyed, supervised or connected.
+  Future<bool> unpair({required String deviceId});
+
+  /// Use peers found by an mDNS browser as fresh addresses when reconnecting
+  ///
+  /// Applies to peers supervised after this call.
+  void useDiscovery({required MdnsDiscovery discovery});
+
+  /// Use a relay server when no direct path to a device works
+  ///
+  /// Devices registered with a rendezvous service fall back to the relay
+  /// when hole punching fails. QUIC runs end to end through the relay, so it
+  /// only sees ciphertext.
+  ///
+  /// # Arguments
+  /// * `server` - Address of a `syncmist-relay` server, e.g. `relay.example.com:3479`
+  Future<void> useRelay({required String server});
+
+  /// Exchange connection candidates with paired devices through a signaling hub
+  ///
+  /// Paired devices that are, or come, online at the hub are connected to by
+  /// hole punching. Candidates include the public address learned with
+  /// [`QuicTransport::discover_nat`] or [`QuicTransport::register_rendezvous`],
+  /// so call one of those first when behind a NAT.
+  ///
+  /// The hub also becomes our mailbox: items left for us while we were
+  /// offline are fetched, verified and opened with the key from
+  /// [`QuicTransport::set_sealing_key`], and arrive like items from connected
+  /// peers. From then on [`QuicTransport::broadcast`] leaves items for offline
+  /// paired devices there.
+  ///
+  /// # Arguments
+  /// * `signaling` - Client connected to the hub as this device
+  Future<void> useSignaling({required SignalingClient signaling});
+
+  /// Create a new QUIC transport with default options that uses a persistent device identity
+  static QuicTransport withIdentity({required DeviceIdentity identity}) =>
+      RustLib.instance.api.crateTransportQuicQuicTransportWithIdentity(identity: identity);
 }
 
 @freezed
@@ -87,4 +454,26 @@ sealed class TransportError with _$TransportError implements FrbException {
   const factory TransportError.peerNotFound(
     String field0,
   ) = TransportError_PeerNotFound;
+
+  /// Peer presented a certificate that differs from its pinned fingerprint
+  const factory TransportError.fingerprintMismatch({
+    required String deviceId,
+    required String expected,
+    required String actual,
+  }) = TransportError_FingerprintMismatch;
+
+  /// Peer violated the wire protocol or shares no protocol version with us
+  const factory TransportError.protocol(
+    String field0,
+  ) = TransportError_Protocol;
+
+  /// Transfer was cancelled by us or the peer
+  const factory TransportError.cancelled(
+    String field0,
+  ) = TransportError_Cancelled;
+
+  /// Peer refused a transfer
+  const factory TransportError.rejected(
+    String field0,
+  ) = TransportError_Rejected;
 }
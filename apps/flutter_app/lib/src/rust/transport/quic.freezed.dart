@@ -23,6 +23,10 @@ mixin _$TransportError {
     required TResult Function(String field0) tls,
     required TResult Function() notConnected,
     required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -32,6 +36,10 @@ mixin _$TransportError {
     TResult? Function(String field0)? tls,
     TResult? Function()? notConnected,
     TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -41,6 +49,10 @@ mixin _$TransportError {
     TResult Function(String field0)? tls,
     TResult Function()? notConnected,
     TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -51,6 +63,10 @@ mixin _$TransportError {
     required TResult Function(TransportError_Tls value) tls,
     required TResult Function(TransportError_NotConnected value) notConnected,
     required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -60,6 +76,10 @@ mixin _$TransportError {
     TResult? Function(TransportError_Tls value)? tls,
     TResult? Function(TransportError_NotConnected value)? notConnected,
     TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -69,6 +89,10 @@ mixin _$TransportError {
     TResult Function(TransportError_Tls value)? tls,
     TResult Function(TransportError_NotConnected value)? notConnected,
     TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -166,6 +190,10 @@ class _$TransportError_ConnectionImpl extends TransportError_Connection {
     required TResult Function(String field0) tls,
     required TResult Function() notConnected,
     required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
   }) {
     return connection(field0);
   }
@@ -178,6 +206,10 @@ class _$TransportError_ConnectionImpl extends TransportError_Connection {
     TResult? Function(String field0)? tls,
     TResult? Function()? notConnected,
     TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
   }) {
     return connection?.call(field0);
   }
@@ -190,6 +222,10 @@ class _$TransportError_ConnectionImpl extends TransportError_Connection {
     TResult Function(String field0)? tls,
     TResult Function()? notConnected,
     TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
     required TResult orElse(),
   }) {
     if (connection != null) {
@@ -206,6 +242,10 @@ class _$TransportError_ConnectionImpl extends TransportError_Connection {
     required TResult Function(TransportError_Tls value) tls,
     required TResult Function(TransportError_NotConnected value) notConnected,
     required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
   }) {
     return connection(this);
   }
@@ -218,6 +258,10 @@ class _$TransportError_ConnectionImpl extends TransportError_Connection {
     TResult? Function(TransportError_Tls value)? tls,
     TResult? Function(TransportError_NotConnected value)? notConnected,
     TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
   }) {
     return connection?.call(this);
   }
@@ -230,6 +274,10 @@ class _$TransportError_ConnectionImpl extends TransportError_Connection {
     TResult Function(TransportError_Tls value)? tls,
     TResult Function(TransportError_NotConnected value)? notConnected,
     TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
     required TResult orElse(),
   }) {
     if (connection != null) {
@@ -322,6 +370,10 @@ class _$TransportError_IoImpl extends TransportError_Io {
     required TResult Function(String field0) tls,
     required TResult Function() notConnected,
     required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
   }) {
     return io(field0);
   }
@@ -334,6 +386,10 @@ class _$TransportError_IoImpl extends TransportError_Io {
     TResult? Function(String field0)? tls,
     TResult? Function()? notConnected,
     TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
   }) {
     return io?.call(field0);
   }
@@ -346,6 +402,10 @@ class _$TransportError_IoImpl extends TransportError_Io {
     TResult Function(String field0)? tls,
     TResult Function()? notConnected,
     TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
     required TResult orElse(),
   }) {
     if (io != null) {
@@ -362,6 +422,10 @@ class _$TransportError_IoImpl extends TransportError_Io {
     required TResult Function(TransportError_Tls value) tls,
     required TResult Function(TransportError_NotConnected value) notConnected,
     required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
   }) {
     return io(this);
   }
@@ -374,6 +438,10 @@ class _$TransportError_IoImpl extends TransportError_Io {
     TResult? Function(TransportError_Tls value)? tls,
     TResult? Function(TransportError_NotConnected value)? notConnected,
     TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
   }) {
     return io?.call(this);
   }
@@ -386,6 +454,10 @@ class _$TransportError_IoImpl extends TransportError_Io {
     TResult Function(TransportError_Tls value)? tls,
     TResult Function(TransportError_NotConnected value)? notConnected,
     TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
     required TResult orElse(),
   }) {
     if (io != null) {
@@ -478,6 +550,10 @@ class _$TransportError_TlsImpl extends TransportError_Tls {
     required TResult Function(String field0) tls,
     required TResult Function() notConnected,
     required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
   }) {
     return tls(field0);
   }
@@ -490,6 +566,10 @@ class _$TransportError_TlsImpl extends TransportError_Tls {
     TResult? Function(String field0)? tls,
     TResult? Function()? notConnected,
     TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
   }) {
     return tls?.call(field0);
   }
@@ -502,6 +582,10 @@ class _$TransportError_TlsImpl extends TransportError_Tls {
     TResult Function(String field0)? tls,
     TResult Function()? notConnected,
     TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
     required TResult orElse(),
   }) {
     if (tls != null) {
@@ -518,6 +602,10 @@ class _$TransportError_TlsImpl extends TransportError_Tls {
     required TResult Function(TransportError_Tls value) tls,
     required TResult Function(TransportError_NotConnected value) notConnected,
     required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
   }) {
     return tls(this);
   }
@@ -530,6 +618,10 @@ class _$TransportError_TlsImpl extends TransportError_Tls {
     TResult? Function(TransportError_Tls value)? tls,
     TResult? Function(TransportError_NotConnected value)? notConnected,
     TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
   }) {
     return tls?.call(this);
   }
@@ -542,6 +634,10 @@ class _$TransportError_TlsImpl extends TransportError_Tls {
     TResult Function(TransportError_Tls value)? tls,
     TResult Function(TransportError_NotConnected value)? notConnected,
     TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
     required TResult orElse(),
   }) {
     if (tls != null) {
@@ -608,6 +704,10 @@ class _$TransportError_NotConnectedImpl extends TransportError_NotConnected {
     required TResult Function(String field0) tls,
     required TResult Function() notConnected,
     required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
   }) {
     return notConnected();
   }
@@ -620,6 +720,10 @@ class _$TransportError_NotConnectedImpl extends TransportError_NotConnected {
     TResult? Function(String field0)? tls,
     TResult? Function()? notConnected,
     TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
   }) {
     return notConnected?.call();
   }
@@ -632,6 +736,10 @@ class _$TransportError_NotConnectedImpl extends TransportError_NotConnected {
     TResult Function(String field0)? tls,
     TResult Function()? notConnected,
     TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
     required TResult orElse(),
   }) {
     if (notConnected != null) {
@@ -648,6 +756,10 @@ class _$TransportError_NotConnectedImpl extends TransportError_NotConnected {
     required TResult Function(TransportError_Tls value) tls,
     required TResult Function(TransportError_NotConnected value) notConnected,
     required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
   }) {
     return notConnected(this);
   }
@@ -660,6 +772,10 @@ class _$TransportError_NotConnectedImpl extends TransportError_NotConnected {
     TResult? Function(TransportError_Tls value)? tls,
     TResult? Function(TransportError_NotConnected value)? notConnected,
     TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
   }) {
     return notConnected?.call(this);
   }
@@ -672,6 +788,10 @@ class _$TransportError_NotConnectedImpl extends TransportError_NotConnected {
     TResult Function(TransportError_Tls value)? tls,
     TResult Function(TransportError_NotConnected value)? notConnected,
     TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
     required TResult orElse(),
   }) {
     if (notConnected != null) {
@@ -759,6 +879,10 @@ class _$TransportError_PeerNotFoundImpl extends TransportError_PeerNotFound {
     required TResult Function(String field0) tls,
     required TResult Function() notConnected,
     required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
   }) {
     return peerNotFound(field0);
   }
@@ -771,6 +895,10 @@ class _$TransportError_PeerNotFoundImpl extends TransportError_PeerNotFound {
     TResult? Function(String field0)? tls,
     TResult? Function()? notConnected,
     TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
   }) {
     return peerNotFound?.call(field0);
   }
@@ -783,6 +911,10 @@ class _$TransportError_PeerNotFoundImpl extends TransportError_PeerNotFound {
     TResult Function(String field0)? tls,
     TResult Function()? notConnected,
     TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
     required TResult orElse(),
   }) {
     if (peerNotFound != null) {
@@ -799,6 +931,10 @@ class _$TransportError_PeerNotFoundImpl extends TransportError_PeerNotFound {
     required TResult Function(TransportError_Tls value) tls,
     required TResult Function(TransportError_NotConnected value) notConnected,
     required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
   }) {
     return peerNotFound(this);
   }
@@ -811,6 +947,10 @@ class _$TransportError_PeerNotFoundImpl extends TransportError_PeerNotFound {
     TResult? Function(TransportError_Tls value)? tls,
     TResult? Function(TransportError_NotConnected value)? notConnected,
     TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
   }) {
     return peerNotFound?.call(this);
   }
@@ -823,6 +963,10 @@ class _$TransportError_PeerNotFoundImpl extends TransportError_PeerNotFound {
     TResult Function(TransportError_Tls value)? tls,
     TResult Function(TransportError_NotConnected value)? notConnected,
     TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
     required TResult orElse(),
   }) {
     if (peerNotFound != null) {
@@ -844,3 +988,758 @@ abstract class TransportError_PeerNotFound extends TransportError {
   _$$TransportError_PeerNotFoundImplCopyWith<_$TransportError_PeerNotFoundImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
+
+/// @nodoc
+abstract class _$$TransportError_FingerprintMismatchImplCopyWith<$Res> {
+  factory _$$TransportError_FingerprintMismatchImplCopyWith(
+          _$TransportError_FingerprintMismatchImpl value,
+          $Res Function(_$TransportError_FingerprintMismatchImpl) then) =
+      __$$TransportError_FingerprintMismatchImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String deviceId, String expected, String actual});
+}
+
+/// @nodoc
+class __$$TransportError_FingerprintMismatchImplCopyWithImpl<$Res>
+    extends _$TransportErrorCopyWithImpl<$Res, _$TransportError_FingerprintMismatchImpl>
+    implements _$$TransportError_FingerprintMismatchImplCopyWith<$Res> {
+  __$$TransportError_FingerprintMismatchImplCopyWithImpl(
+      _$TransportError_FingerprintMismatchImpl _value, $Res Function(_$TransportError_FingerprintMismatchImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? deviceId = null,
+    Object? expected = null,
+    Object? actual = null,
+  }) {
+    return _then(_$TransportError_FingerprintMismatchImpl(
+      deviceId: null == deviceId
+          ? _value.deviceId
+          : deviceId // ignore: cast_nullable_to_non_nullable
+              as String,
+      expected: null == expected
+          ? _value.expected
+          : expected // ignore: cast_nullable_to_non_nullable
+              as String,
+      actual: null == actual
+          ? _value.actual
+          : actual // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransportError_FingerprintMismatchImpl extends TransportError_FingerprintMismatch {
+  const _$TransportError_FingerprintMismatchImpl({required this.deviceId, required this.expected, required this.actual})
+      : super._();
+
+  @override
+  final String deviceId;
+  @override
+  final String expected;
+  @override
+  final String actual;
+
+  @override
+  String toString() {
+    return 'TransportError.fingerprintMismatch(deviceId: $deviceId, expected: $expected, actual: $actual)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransportError_FingerprintMismatchImpl &&
+            (identical(other.deviceId, deviceId) || other.deviceId == deviceId) &&
+            (identical(other.expected, expected) || other.expected == expected) &&
+            (identical(other.actual, actual) || other.actual == actual));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, deviceId, expected, actual);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransportError_FingerprintMismatchImplCopyWith<_$TransportError_FingerprintMismatchImpl> get copyWith =>
+      __$$TransportError_FingerprintMismatchImplCopyWithImpl<_$TransportError_FingerprintMismatchImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) tls,
+    required TResult Function() notConnected,
+    required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
+  }) {
+    return fingerprintMismatch(deviceId, expected, actual);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? tls,
+    TResult? Function()? notConnected,
+    TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
+  }) {
+    return fingerprintMismatch?.call(deviceId, expected, actual);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? tls,
+    TResult Function()? notConnected,
+    TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
+    required TResult orElse(),
+  }) {
+    if (fingerprintMismatch != null) {
+      return fingerprintMismatch(deviceId, expected, actual);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransportError_Connection value) connection,
+    required TResult Function(TransportError_Io value) io,
+    required TResult Function(TransportError_Tls value) tls,
+    required TResult Function(TransportError_NotConnected value) notConnected,
+    required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
+  }) {
+    return fingerprintMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransportError_Connection value)? connection,
+    TResult? Function(TransportError_Io value)? io,
+    TResult? Function(TransportError_Tls value)? tls,
+    TResult? Function(TransportError_NotConnected value)? notConnected,
+    TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
+  }) {
+    return fingerprintMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransportError_Connection value)? connection,
+    TResult Function(TransportError_Io value)? io,
+    TResult Function(TransportError_Tls value)? tls,
+    TResult Function(TransportError_NotConnected value)? notConnected,
+    TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
+    required TResult orElse(),
+  }) {
+    if (fingerprintMismatch != null) {
+      return fingerprintMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransportError_FingerprintMismatch extends TransportError {
+  const factory TransportError_FingerprintMismatch(
+          {required final String deviceId, required final String expected, required final String actual}) =
+      _$TransportError_FingerprintMismatchImpl;
+  const TransportError_FingerprintMismatch._() : super._();
+
+  String get deviceId;
+  String get expected;
+  String get actual;
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransportError_FingerprintMismatchImplCopyWith<_$TransportError_FingerprintMismatchImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransportError_ProtocolImplCopyWith<$Res> {
+  factory _$$TransportError_ProtocolImplCopyWith(
+          _$TransportError_ProtocolImpl value, $Res Function(_$TransportError_ProtocolImpl) then) =
+      __$$TransportError_ProtocolImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$TransportError_ProtocolImplCopyWithImpl<$Res>
+    extends _$TransportErrorCopyWithImpl<$Res, _$TransportError_ProtocolImpl>
+    implements _$$TransportError_ProtocolImplCopyWith<$Res> {
+  __$$TransportError_ProtocolImplCopyWithImpl(
+      _$TransportError_ProtocolImpl _value, $Res Function(_$TransportError_ProtocolImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TransportError_ProtocolImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransportError_ProtocolImpl extends TransportError_Protocol {
+  const _$TransportError_ProtocolImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'TransportError.protocol(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransportError_ProtocolImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransportError_ProtocolImplCopyWith<_$TransportError_ProtocolImpl> get copyWith =>
+      __$$TransportError_ProtocolImplCopyWithImpl<_$TransportError_ProtocolImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) tls,
+    required TResult Function() notConnected,
+    required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
+  }) {
+    return protocol(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? tls,
+    TResult? Function()? notConnected,
+    TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
+  }) {
+    return protocol?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? tls,
+    TResult Function()? notConnected,
+    TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
+    required TResult orElse(),
+  }) {
+    if (protocol != null) {
+      return protocol(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransportError_Connection value) connection,
+    required TResult Function(TransportError_Io value) io,
+    required TResult Function(TransportError_Tls value) tls,
+    required TResult Function(TransportError_NotConnected value) notConnected,
+    required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
+  }) {
+    return protocol(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransportError_Connection value)? connection,
+    TResult? Function(TransportError_Io value)? io,
+    TResult? Function(TransportError_Tls value)? tls,
+    TResult? Function(TransportError_NotConnected value)? notConnected,
+    TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
+  }) {
+    return protocol?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransportError_Connection value)? connection,
+    TResult Function(TransportError_Io value)? io,
+    TResult Function(TransportError_Tls value)? tls,
+    TResult Function(TransportError_NotConnected value)? notConnected,
+    TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
+    required TResult orElse(),
+  }) {
+    if (protocol != null) {
+      return protocol(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransportError_Protocol extends TransportError {
+  const factory TransportError_Protocol(final String field0) = _$TransportError_ProtocolImpl;
+  const TransportError_Protocol._() : super._();
+
+  String get field0;
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransportError_ProtocolImplCopyWith<_$TransportError_ProtocolImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransportError_CancelledImplCopyWith<$Res> {
+  factory _$$TransportError_CancelledImplCopyWith(
+          _$TransportError_CancelledImpl value, $Res Function(_$TransportError_CancelledImpl) then) =
+      __$$TransportError_CancelledImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$TransportError_CancelledImplCopyWithImpl<$Res>
+    extends _$TransportErrorCopyWithImpl<$Res, _$TransportError_CancelledImpl>
+    implements _$$TransportError_CancelledImplCopyWith<$Res> {
+  __$$TransportError_CancelledImplCopyWithImpl(
+      _$TransportError_CancelledImpl _value, $Res Function(_$TransportError_CancelledImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TransportError_CancelledImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransportError_CancelledImpl extends TransportError_Cancelled {
+  const _$TransportError_CancelledImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'TransportError.cancelled(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransportError_CancelledImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransportError_CancelledImplCopyWith<_$TransportError_CancelledImpl> get copyWith =>
+      __$$TransportError_CancelledImplCopyWithImpl<_$TransportError_CancelledImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) tls,
+    required TResult Function() notConnected,
+    required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
+  }) {
+    return cancelled(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? tls,
+    TResult? Function()? notConnected,
+    TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
+  }) {
+    return cancelled?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? tls,
+    TResult Function()? notConnected,
+    TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransportError_Connection value) connection,
+    required TResult Function(TransportError_Io value) io,
+    required TResult Function(TransportError_Tls value) tls,
+    required TResult Function(TransportError_NotConnected value) notConnected,
+    required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
+  }) {
+    return cancelled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransportError_Connection value)? connection,
+    TResult? Function(TransportError_Io value)? io,
+    TResult? Function(TransportError_Tls value)? tls,
+    TResult? Function(TransportError_NotConnected value)? notConnected,
+    TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
+  }) {
+    return cancelled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransportError_Connection value)? connection,
+    TResult Function(TransportError_Io value)? io,
+    TResult Function(TransportError_Tls value)? tls,
+    TResult Function(TransportError_NotConnected value)? notConnected,
+    TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransportError_Cancelled extends TransportError {
+  const factory TransportError_Cancelled(final String field0) = _$TransportError_CancelledImpl;
+  const TransportError_Cancelled._() : super._();
+
+  String get field0;
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransportError_CancelledImplCopyWith<_$TransportError_CancelledImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransportError_RejectedImplCopyWith<$Res> {
+  factory _$$TransportError_RejectedImplCopyWith(
+          _$TransportError_RejectedImpl value, $Res Function(_$TransportError_RejectedImpl) then) =
+      __$$TransportError_RejectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$TransportError_RejectedImplCopyWithImpl<$Res>
+    extends _$TransportErrorCopyWithImpl<$Res, _$TransportError_RejectedImpl>
+    implements _$$TransportError_RejectedImplCopyWith<$Res> {
+  __$$TransportError_RejectedImplCopyWithImpl(
+      _$TransportError_RejectedImpl _value, $Res Function(_$TransportError_RejectedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$TransportError_RejectedImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransportError_RejectedImpl extends TransportError_Rejected {
+  const _$TransportError_RejectedImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'TransportError.rejected(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransportError_RejectedImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransportError_RejectedImplCopyWith<_$TransportError_RejectedImpl> get copyWith =>
+      __$$TransportError_RejectedImplCopyWithImpl<_$TransportError_RejectedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) connection,
+    required TResult Function(String field0) io,
+    required TResult Function(String field0) tls,
+    required TResult Function() notConnected,
+    required TResult Function(String field0) peerNotFound,
+    required TResult Function(String deviceId, String expected, String actual) fingerprintMismatch,
+    required TResult Function(String field0) protocol,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) rejected,
+  }) {
+    return rejected(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? connection,
+    TResult? Function(String field0)? io,
+    TResult? Function(String field0)? tls,
+    TResult? Function()? notConnected,
+    TResult? Function(String field0)? peerNotFound,
+    TResult? Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult? Function(String field0)? protocol,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? rejected,
+  }) {
+    return rejected?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? connection,
+    TResult Function(String field0)? io,
+    TResult Function(String field0)? tls,
+    TResult Function()? notConnected,
+    TResult Function(String field0)? peerNotFound,
+    TResult Function(String deviceId, String expected, String actual)? fingerprintMismatch,
+    TResult Function(String field0)? protocol,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? rejected,
+    required TResult orElse(),
+  }) {
+    if (rejected != null) {
+      return rejected(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransportError_Connection value) connection,
+    required TResult Function(TransportError_Io value) io,
+    required TResult Function(TransportError_Tls value) tls,
+    required TResult Function(TransportError_NotConnected value) notConnected,
+    required TResult Function(TransportError_PeerNotFound value) peerNotFound,
+    required TResult Function(TransportError_FingerprintMismatch value) fingerprintMismatch,
+    required TResult Function(TransportError_Protocol value) protocol,
+    required TResult Function(TransportError_Cancelled value) cancelled,
+    required TResult Function(TransportError_Rejected value) rejected,
+  }) {
+    return rejected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransportError_Connection value)? connection,
+    TResult? Function(TransportError_Io value)? io,
+    TResult? Function(TransportError_Tls value)? tls,
+    TResult? Function(TransportError_NotConnected value)? notConnected,
+    TResult? Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult? Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult? Function(TransportError_Protocol value)? protocol,
+    TResult? Function(TransportError_Cancelled value)? cancelled,
+    TResult? Function(TransportError_Rejected value)? rejected,
+  }) {
+    return rejected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransportError_Connection value)? connection,
+    TResult Function(TransportError_Io value)? io,
+    TResult Function(TransportError_Tls value)? tls,
+    TResult Function(TransportError_NotConnected value)? notConnected,
+    TResult Function(TransportError_PeerNotFound value)? peerNotFound,
+    TResult Function(TransportError_FingerprintMismatch value)? fingerprintMismatch,
+    TResult Function(TransportError_Protocol value)? protocol,
+    TResult Function(TransportError_Cancelled value)? cancelled,
+    TResult Function(TransportError_Rejected value)? rejected,
+    required TResult orElse(),
+  }) {
+    if (rejected != null) {
+      return rejected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransportError_Rejected extends TransportError {
+  const factory TransportError_Rejected(final String field0) = _$TransportError_RejectedImpl;
+  const TransportError_Rejected._() : super._();
+
+  String get field0;
+
+  /// Create a copy of TransportError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransportError_RejectedImplCopyWith<_$TransportError_RejectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
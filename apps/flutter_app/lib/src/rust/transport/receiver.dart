@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `handle_bi_stream`, `handle_stream`, `spawn_receiver`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// A clipboard item received from a peer
+class ReceivedItem {
+  /// Peer the item came from (device id)
+  final String peerId;
+  final BigInt itemId;
+  final String contentType;
+
+  /// Milliseconds since the Unix epoch, as set by the sender
+  final BigInt timestamp;
+  final Uint8List payload;
+
+  /// Whether the sender waits for [`super::quic::QuicTransport::acknowledge_item`]
+  /// or [`super::quic::QuicTransport::reject_item`]
+  final bool requiresAck;
+
+  const ReceivedItem({
+    required this.peerId,
+    required this.itemId,
+    required this.contentType,
+    required this.timestamp,
+    required this.payload,
+    required this.requiresAck,
+  });
+
+  @override
+  int get hashCode =>
+      peerId.hashCode ^
+      itemId.hashCode ^
+      contentType.hashCode ^
+      timestamp.hashCode ^
+      payload.hashCode ^
+      requiresAck.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceivedItem &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          itemId == other.itemId &&
+          contentType == other.contentType &&
+          timestamp == other.timestamp &&
+          payload == other.payload &&
+          requiresAck == other.requiresAck;
+}
@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+/// Counters and path state of the connection to a peer
+class PeerStats {
+  final String peerId;
+
+  /// Current path address of the peer
+  final String address;
+
+  /// Smoothed round-trip time estimated by QUIC, in milliseconds
+  final double rttMs;
+
+  /// Congestion window in bytes
+  final BigInt congestionWindow;
+
+  /// UDP payload bytes sent and received, including QUIC overhead
+  final BigInt bytesSent;
+  final BigInt bytesReceived;
+
+  /// UDP datagrams sent and received
+  final BigInt packetsSent;
+  final BigInt packetsReceived;
+
+  /// Packets QUIC declared lost and retransmitted
+  final BigInt lostPackets;
+
+  /// Times the congestion controller backed off
+  final BigInt congestionEvents;
+
+  /// Path MTU in bytes
+  final int mtu;
+
+  /// Milliseconds since the connection was registered
+  final BigInt connectedMs;
+
+  const PeerStats({
+    required this.peerId,
+    required this.address,
+    required this.rttMs,
+    required this.congestionWindow,
+    required this.bytesSent,
+    required this.bytesReceived,
+    required this.packetsSent,
+    required this.packetsReceived,
+    required this.lostPackets,
+    required this.congestionEvents,
+    required this.mtu,
+    required this.connectedMs,
+  });
+
+  @override
+  int get hashCode =>
+      peerId.hashCode ^
+      address.hashCode ^
+      rttMs.hashCode ^
+      congestionWindow.hashCode ^
+      bytesSent.hashCode ^
+      bytesReceived.hashCode ^
+      packetsSent.hashCode ^
+      packetsReceived.hashCode ^
+      lostPackets.hashCode ^
+      congestionEvents.hashCode ^
+      mtu.hashCode ^
+      connectedMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PeerStats &&
+          runtimeType == other.runtimeType &&
+          peerId == other.peerId &&
+          address == other.address &&
+          rttMs == other.rttMs &&
+          congestionWindow == other.congestionWindow &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived &&
+          packetsSent == other.packetsSent &&
+          packetsReceived == other.packetsReceived &&
+          lostPackets == other.lostPackets &&
+          congestionEvents == other.congestionEvents &&
+          mtu == other.mtu &&
+          connectedMs == other.connectedMs;
+}
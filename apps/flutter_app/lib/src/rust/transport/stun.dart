@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `binding`, `classify`, `decode`, `encode`, `plain_address`, `unxor_address`, `xor_address`, `xor_key`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Message`, `StunServer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `drop`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `bind`, `local_addr`
+
+/// What STUN servers saw of our QUIC socket
+class NatReport {
+  /// Public address and port of the QUIC socket, as seen by the first server that answered
+  final String mappedAddress;
+  final NatType natType;
+
+  const NatReport({
+    required this.mappedAddress,
+    required this.natType,
+  });
+
+  @override
+  int get hashCode => mappedAddress.hashCode ^ natType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NatReport &&
+          runtimeType == other.runtimeType &&
+          mappedAddress == other.mappedAddress &&
+          natType == other.natType;
+}
+
+/// How a NAT maps our socket to public addresses
+enum NatType {
+  /// The mapped address is our own: there is no NAT
+  open,
+
+  /// Every destination sees the same mapped address, so hole punching works
+  endpointIndependent,
+
+  /// Each destination sees a different mapped address; only a relay gets through
+  symmetric,
+
+  /// Too few servers answered to tell
+  unknown,
+  ;
+}
@@ -0,0 +1,24 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `backoff_delay`, `candidate_addresses`, `live_connection`, `mdns_addresses`, `publish_state`, `spawn_supervisor`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SupervisedPeer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+/// Connection state of a supervised peer
+enum PeerState {
+  /// A connection to the peer is open
+  connected,
+
+  /// The peer is unreachable and will be dialed again after a delay
+  reconnecting,
+
+  /// The peer is no longer supervised
+  stopped,
+  ;
+}
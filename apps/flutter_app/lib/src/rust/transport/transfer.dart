@@ -0,0 +1,116 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'transfer.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cancel`, `file_name`, `finish`, `is_interruption`, `new_entry`, `new`, `new`, `pending`, `publish`, `read_chunk`, `read_replies`, `receive_content`, `receive_transfer`, `report`, `resume`, `run_outgoing`, `save_part`, `send_content`, `send_data`, `send_file`, `send_reader`, `start_new`, `start_when_free`, `start`, `subscribe`, `to_hex`, `unique_path`, `write_content`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActiveTransfer`, `Progress`, `TransferInfo`, `Transfers`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `fmt`, `fmt`
+
+/// An interrupted or running transfer recorded in the spool
+class PendingTransfer {
+  final BigInt transferId;
+  final String peerId;
+  final String name;
+  final BigInt size;
+
+  /// Bytes the receiver has acknowledged
+  final BigInt transferred;
+
+  /// Whether we are sending
+  final bool outbound;
+
+  const PendingTransfer({
+    required this.transferId,
+    required this.peerId,
+    required this.name,
+    required this.size,
+    required this.transferred,
+    required this.outbound,
+  });
+
+  @override
+  int get hashCode =>
+      transferId.hashCode ^ peerId.hashCode ^ name.hashCode ^ size.hashCode ^ transferred.hashCode ^ outbound.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PendingTransfer &&
+          runtimeType == other.runtimeType &&
+          transferId == other.transferId &&
+          peerId == other.peerId &&
+          name == other.name &&
+          size == other.size &&
+          transferred == other.transferred &&
+          outbound == other.outbound;
+}
+
+@freezed
+sealed class TransferEvent with _$TransferEvent {
+  const TransferEvent._();
+
+  /// A transfer was accepted by the receiver, possibly resuming an earlier attempt
+  const factory TransferEvent.started({
+    required BigInt transferId,
+    required String peerId,
+    required String name,
+    required BigInt size,
+
+    /// Whether we are sending
+    required bool outbound,
+
+    /// Bytes carried over from an interrupted attempt
+    required BigInt offset,
+  }) = TransferEvent_Started;
+
+  /// Bytes sent or received so far
+  const factory TransferEvent.progress({
+    required BigInt transferId,
+    required String peerId,
+    required BigInt transferred,
+    required BigInt size,
+  }) = TransferEvent_Progress;
+
+  /// The receiver verified the content hash
+  const factory TransferEvent.completed({
+    required BigInt transferId,
+    required String peerId,
+
+    /// Where the content was saved, empty for outgoing transfers
+    required String path,
+
+    /// SHA-256 of the content as lowercase hex
+    required String sha256,
+  }) = TransferEvent_Completed;
+
+  /// The transfer failed or was refused
+  const factory TransferEvent.failed({
+    required BigInt transferId,
+    required String peerId,
+    required String error,
+  }) = TransferEvent_Failed;
+
+  /// The transfer was cancelled
+  const factory TransferEvent.cancelled({
+    required BigInt transferId,
+    required String peerId,
+
+    /// Whether the peer cancelled rather than us
+    required bool byPeer,
+  }) = TransferEvent_Cancelled;
+
+  /// The connection was lost; the transfer resumes when the peer reconnects
+  const factory TransferEvent.interrupted({
+    required BigInt transferId,
+    required String peerId,
+
+    /// Bytes the receiver has acknowledged
+    required BigInt transferred,
+  }) = TransferEvent_Interrupted;
+}
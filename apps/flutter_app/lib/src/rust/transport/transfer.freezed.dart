@@ -0,0 +1,1376 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'transfer.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$TransferEvent {
+  BigInt get transferId => throw _privateConstructorUsedError;
+  String get peerId => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)
+        started,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size) progress,
+    required TResult Function(BigInt transferId, String peerId, String path, String sha256) completed,
+    required TResult Function(BigInt transferId, String peerId, String error) failed,
+    required TResult Function(BigInt transferId, String peerId, bool byPeer) cancelled,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred) interrupted,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)?
+        started,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult? Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult? Function(BigInt transferId, String peerId, String error)? failed,
+    TResult? Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)? started,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult Function(BigInt transferId, String peerId, String error)? failed,
+    TResult Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransferEvent_Started value) started,
+    required TResult Function(TransferEvent_Progress value) progress,
+    required TResult Function(TransferEvent_Completed value) completed,
+    required TResult Function(TransferEvent_Failed value) failed,
+    required TResult Function(TransferEvent_Cancelled value) cancelled,
+    required TResult Function(TransferEvent_Interrupted value) interrupted,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransferEvent_Started value)? started,
+    TResult? Function(TransferEvent_Progress value)? progress,
+    TResult? Function(TransferEvent_Completed value)? completed,
+    TResult? Function(TransferEvent_Failed value)? failed,
+    TResult? Function(TransferEvent_Cancelled value)? cancelled,
+    TResult? Function(TransferEvent_Interrupted value)? interrupted,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransferEvent_Started value)? started,
+    TResult Function(TransferEvent_Progress value)? progress,
+    TResult Function(TransferEvent_Completed value)? completed,
+    TResult Function(TransferEvent_Failed value)? failed,
+    TResult Function(TransferEvent_Cancelled value)? cancelled,
+    TResult Function(TransferEvent_Interrupted value)? interrupted,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $TransferEventCopyWith<TransferEvent> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $TransferEventCopyWith<$Res> {
+  factory $TransferEventCopyWith(TransferEvent value, $Res Function(TransferEvent) then) =
+      _$TransferEventCopyWithImpl<$Res, TransferEvent>;
+  @useResult
+  $Res call({BigInt transferId, String peerId});
+}
+
+/// @nodoc
+class _$TransferEventCopyWithImpl<$Res, $Val extends TransferEvent> implements $TransferEventCopyWith<$Res> {
+  _$TransferEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? peerId = null,
+  }) {
+    return _then(_value.copyWith(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$TransferEvent_StartedImplCopyWith<$Res> implements $TransferEventCopyWith<$Res> {
+  factory _$$TransferEvent_StartedImplCopyWith(
+          _$TransferEvent_StartedImpl value, $Res Function(_$TransferEvent_StartedImpl) then) =
+      __$$TransferEvent_StartedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset});
+}
+
+/// @nodoc
+class __$$TransferEvent_StartedImplCopyWithImpl<$Res>
+    extends _$TransferEventCopyWithImpl<$Res, _$TransferEvent_StartedImpl>
+    implements _$$TransferEvent_StartedImplCopyWith<$Res> {
+  __$$TransferEvent_StartedImplCopyWithImpl(
+      _$TransferEvent_StartedImpl _value, $Res Function(_$TransferEvent_StartedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? peerId = null,
+    Object? name = null,
+    Object? size = null,
+    Object? outbound = null,
+    Object? offset = null,
+  }) {
+    return _then(_$TransferEvent_StartedImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      name: null == name
+          ? _value.name
+          : name // ignore: cast_nullable_to_non_nullable
+              as String,
+      size: null == size
+          ? _value.size
+          : size // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      outbound: null == outbound
+          ? _value.outbound
+          : outbound // ignore: cast_nullable_to_non_nullable
+              as bool,
+      offset: null == offset
+          ? _value.offset
+          : offset // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransferEvent_StartedImpl extends TransferEvent_Started {
+  const _$TransferEvent_StartedImpl(
+      {required this.transferId,
+      required this.peerId,
+      required this.name,
+      required this.size,
+      required this.outbound,
+      required this.offset})
+      : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final String peerId;
+  @override
+  final String name;
+  @override
+  final BigInt size;
+
+  /// Whether we are sending
+  @override
+  final bool outbound;
+
+  /// Bytes carried over from an interrupted attempt
+  @override
+  final BigInt offset;
+
+  @override
+  String toString() {
+    return 'TransferEvent.started(transferId: $transferId, peerId: $peerId, name: $name, size: $size, outbound: $outbound, offset: $offset)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransferEvent_StartedImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.name, name) || other.name == name) &&
+            (identical(other.size, size) || other.size == size) &&
+            (identical(other.outbound, outbound) || other.outbound == outbound) &&
+            (identical(other.offset, offset) || other.offset == offset));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, peerId, name, size, outbound, offset);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransferEvent_StartedImplCopyWith<_$TransferEvent_StartedImpl> get copyWith =>
+      __$$TransferEvent_StartedImplCopyWithImpl<_$TransferEvent_StartedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)
+        started,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size) progress,
+    required TResult Function(BigInt transferId, String peerId, String path, String sha256) completed,
+    required TResult Function(BigInt transferId, String peerId, String error) failed,
+    required TResult Function(BigInt transferId, String peerId, bool byPeer) cancelled,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred) interrupted,
+  }) {
+    return started(transferId, peerId, name, size, outbound, offset);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)?
+        started,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult? Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult? Function(BigInt transferId, String peerId, String error)? failed,
+    TResult? Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+  }) {
+    return started?.call(transferId, peerId, name, size, outbound, offset);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)? started,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult Function(BigInt transferId, String peerId, String error)? failed,
+    TResult Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (started != null) {
+      return started(transferId, peerId, name, size, outbound, offset);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransferEvent_Started value) started,
+    required TResult Function(TransferEvent_Progress value) progress,
+    required TResult Function(TransferEvent_Completed value) completed,
+    required TResult Function(TransferEvent_Failed value) failed,
+    required TResult Function(TransferEvent_Cancelled value) cancelled,
+    required TResult Function(TransferEvent_Interrupted value) interrupted,
+  }) {
+    return started(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransferEvent_Started value)? started,
+    TResult? Function(TransferEvent_Progress value)? progress,
+    TResult? Function(TransferEvent_Completed value)? completed,
+    TResult? Function(TransferEvent_Failed value)? failed,
+    TResult? Function(TransferEvent_Cancelled value)? cancelled,
+    TResult? Function(TransferEvent_Interrupted value)? interrupted,
+  }) {
+    return started?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransferEvent_Started value)? started,
+    TResult Function(TransferEvent_Progress value)? progress,
+    TResult Function(TransferEvent_Completed value)? completed,
+    TResult Function(TransferEvent_Failed value)? failed,
+    TResult Function(TransferEvent_Cancelled value)? cancelled,
+    TResult Function(TransferEvent_Interrupted value)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (started != null) {
+      return started(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransferEvent_Started extends TransferEvent {
+  const factory TransferEvent_Started(
+      {required final BigInt transferId,
+      required final String peerId,
+      required final String name,
+      required final BigInt size,
+
+      /// Whether we are sending
+      required final bool outbound,
+
+      /// Bytes carried over from an interrupted attempt
+      required final BigInt offset}) = _$TransferEvent_StartedImpl;
+  const TransferEvent_Started._() : super._();
+
+  @override
+  BigInt get transferId;
+  @override
+  String get peerId;
+  String get name;
+  BigInt get size;
+
+  /// Whether we are sending
+  bool get outbound;
+
+  /// Bytes carried over from an interrupted attempt
+  BigInt get offset;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransferEvent_StartedImplCopyWith<_$TransferEvent_StartedImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransferEvent_ProgressImplCopyWith<$Res> implements $TransferEventCopyWith<$Res> {
+  factory _$$TransferEvent_ProgressImplCopyWith(
+          _$TransferEvent_ProgressImpl value, $Res Function(_$TransferEvent_ProgressImpl) then) =
+      __$$TransferEvent_ProgressImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt transferId, String peerId, BigInt transferred, BigInt size});
+}
+
+/// @nodoc
+class __$$TransferEvent_ProgressImplCopyWithImpl<$Res>
+    extends _$TransferEventCopyWithImpl<$Res, _$TransferEvent_ProgressImpl>
+    implements _$$TransferEvent_ProgressImplCopyWith<$Res> {
+  __$$TransferEvent_ProgressImplCopyWithImpl(
+      _$TransferEvent_ProgressImpl _value, $Res Function(_$TransferEvent_ProgressImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? peerId = null,
+    Object? transferred = null,
+    Object? size = null,
+  }) {
+    return _then(_$TransferEvent_ProgressImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      transferred: null == transferred
+          ? _value.transferred
+          : transferred // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      size: null == size
+          ? _value.size
+          : size // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransferEvent_ProgressImpl extends TransferEvent_Progress {
+  const _$TransferEvent_ProgressImpl(
+      {required this.transferId, required this.peerId, required this.transferred, required this.size})
+      : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final String peerId;
+  @override
+  final BigInt transferred;
+  @override
+  final BigInt size;
+
+  @override
+  String toString() {
+    return 'TransferEvent.progress(transferId: $transferId, peerId: $peerId, transferred: $transferred, size: $size)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransferEvent_ProgressImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.transferred, transferred) || other.transferred == transferred) &&
+            (identical(other.size, size) || other.size == size));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, peerId, transferred, size);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransferEvent_ProgressImplCopyWith<_$TransferEvent_ProgressImpl> get copyWith =>
+      __$$TransferEvent_ProgressImplCopyWithImpl<_$TransferEvent_ProgressImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)
+        started,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size) progress,
+    required TResult Function(BigInt transferId, String peerId, String path, String sha256) completed,
+    required TResult Function(BigInt transferId, String peerId, String error) failed,
+    required TResult Function(BigInt transferId, String peerId, bool byPeer) cancelled,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred) interrupted,
+  }) {
+    return progress(transferId, peerId, transferred, size);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)?
+        started,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult? Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult? Function(BigInt transferId, String peerId, String error)? failed,
+    TResult? Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+  }) {
+    return progress?.call(transferId, peerId, transferred, size);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)? started,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult Function(BigInt transferId, String peerId, String error)? failed,
+    TResult Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(transferId, peerId, transferred, size);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransferEvent_Started value) started,
+    required TResult Function(TransferEvent_Progress value) progress,
+    required TResult Function(TransferEvent_Completed value) completed,
+    required TResult Function(TransferEvent_Failed value) failed,
+    required TResult Function(TransferEvent_Cancelled value) cancelled,
+    required TResult Function(TransferEvent_Interrupted value) interrupted,
+  }) {
+    return progress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransferEvent_Started value)? started,
+    TResult? Function(TransferEvent_Progress value)? progress,
+    TResult? Function(TransferEvent_Completed value)? completed,
+    TResult? Function(TransferEvent_Failed value)? failed,
+    TResult? Function(TransferEvent_Cancelled value)? cancelled,
+    TResult? Function(TransferEvent_Interrupted value)? interrupted,
+  }) {
+    return progress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransferEvent_Started value)? started,
+    TResult Function(TransferEvent_Progress value)? progress,
+    TResult Function(TransferEvent_Completed value)? completed,
+    TResult Function(TransferEvent_Failed value)? failed,
+    TResult Function(TransferEvent_Cancelled value)? cancelled,
+    TResult Function(TransferEvent_Interrupted value)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (progress != null) {
+      return progress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransferEvent_Progress extends TransferEvent {
+  const factory TransferEvent_Progress(
+      {required final BigInt transferId,
+      required final String peerId,
+      required final BigInt transferred,
+      required final BigInt size}) = _$TransferEvent_ProgressImpl;
+  const TransferEvent_Progress._() : super._();
+
+  @override
+  BigInt get transferId;
+  @override
+  String get peerId;
+  BigInt get transferred;
+  BigInt get size;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransferEvent_ProgressImplCopyWith<_$TransferEvent_ProgressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransferEvent_CompletedImplCopyWith<$Res> implements $TransferEventCopyWith<$Res> {
+  factory _$$TransferEvent_CompletedImplCopyWith(
+          _$TransferEvent_CompletedImpl value, $Res Function(_$TransferEvent_CompletedImpl) then) =
+      __$$TransferEvent_CompletedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt transferId, String peerId, String path, String sha256});
+}
+
+/// @nodoc
+class __$$TransferEvent_CompletedImplCopyWithImpl<$Res>
+    extends _$TransferEventCopyWithImpl<$Res, _$TransferEvent_CompletedImpl>
+    implements _$$TransferEvent_CompletedImplCopyWith<$Res> {
+  __$$TransferEvent_CompletedImplCopyWithImpl(
+      _$TransferEvent_CompletedImpl _value, $Res Function(_$TransferEvent_CompletedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? peerId = null,
+    Object? path = null,
+    Object? sha256 = null,
+  }) {
+    return _then(_$TransferEvent_CompletedImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+      sha256: null == sha256
+          ? _value.sha256
+          : sha256 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransferEvent_CompletedImpl extends TransferEvent_Completed {
+  const _$TransferEvent_CompletedImpl(
+      {required this.transferId, required this.peerId, required this.path, required this.sha256})
+      : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final String peerId;
+
+  /// Where the content was saved, empty for outgoing transfers
+  @override
+  final String path;
+
+  /// SHA-256 of the content as lowercase hex
+  @override
+  final String sha256;
+
+  @override
+  String toString() {
+    return 'TransferEvent.completed(transferId: $transferId, peerId: $peerId, path: $path, sha256: $sha256)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransferEvent_CompletedImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.path, path) || other.path == path) &&
+            (identical(other.sha256, sha256) || other.sha256 == sha256));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, peerId, path, sha256);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransferEvent_CompletedImplCopyWith<_$TransferEvent_CompletedImpl> get copyWith =>
+      __$$TransferEvent_CompletedImplCopyWithImpl<_$TransferEvent_CompletedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)
+        started,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size) progress,
+    required TResult Function(BigInt transferId, String peerId, String path, String sha256) completed,
+    required TResult Function(BigInt transferId, String peerId, String error) failed,
+    required TResult Function(BigInt transferId, String peerId, bool byPeer) cancelled,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred) interrupted,
+  }) {
+    return completed(transferId, peerId, path, sha256);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)?
+        started,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult? Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult? Function(BigInt transferId, String peerId, String error)? failed,
+    TResult? Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+  }) {
+    return completed?.call(transferId, peerId, path, sha256);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)? started,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult Function(BigInt transferId, String peerId, String error)? failed,
+    TResult Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (completed != null) {
+      return completed(transferId, peerId, path, sha256);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransferEvent_Started value) started,
+    required TResult Function(TransferEvent_Progress value) progress,
+    required TResult Function(TransferEvent_Completed value) completed,
+    required TResult Function(TransferEvent_Failed value) failed,
+    required TResult Function(TransferEvent_Cancelled value) cancelled,
+    required TResult Function(TransferEvent_Interrupted value) interrupted,
+  }) {
+    return completed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransferEvent_Started value)? started,
+    TResult? Function(TransferEvent_Progress value)? progress,
+    TResult? Function(TransferEvent_Completed value)? completed,
+    TResult? Function(TransferEvent_Failed value)? failed,
+    TResult? Function(TransferEvent_Cancelled value)? cancelled,
+    TResult? Function(TransferEvent_Interrupted value)? interrupted,
+  }) {
+    return completed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransferEvent_Started value)? started,
+    TResult Function(TransferEvent_Progress value)? progress,
+    TResult Function(TransferEvent_Completed value)? completed,
+    TResult Function(TransferEvent_Failed value)? failed,
+    TResult Function(TransferEvent_Cancelled value)? cancelled,
+    TResult Function(TransferEvent_Interrupted value)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (completed != null) {
+      return completed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransferEvent_Completed extends TransferEvent {
+  const factory TransferEvent_Completed(
+      {required final BigInt transferId,
+      required final String peerId,
+
+      /// Where the content was saved, empty for outgoing transfers
+      required final String path,
+
+      /// SHA-256 of the content as lowercase hex
+      required final String sha256}) = _$TransferEvent_CompletedImpl;
+  const TransferEvent_Completed._() : super._();
+
+  @override
+  BigInt get transferId;
+  @override
+  String get peerId;
+
+  /// Where the content was saved, empty for outgoing transfers
+  String get path;
+
+  /// SHA-256 of the content as lowercase hex
+  String get sha256;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransferEvent_CompletedImplCopyWith<_$TransferEvent_CompletedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransferEvent_FailedImplCopyWith<$Res> implements $TransferEventCopyWith<$Res> {
+  factory _$$TransferEvent_FailedImplCopyWith(
+          _$TransferEvent_FailedImpl value, $Res Function(_$TransferEvent_FailedImpl) then) =
+      __$$TransferEvent_FailedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt transferId, String peerId, String error});
+}
+
+/// @nodoc
+class __$$TransferEvent_FailedImplCopyWithImpl<$Res>
+    extends _$TransferEventCopyWithImpl<$Res, _$TransferEvent_FailedImpl>
+    implements _$$TransferEvent_FailedImplCopyWith<$Res> {
+  __$$TransferEvent_FailedImplCopyWithImpl(
+      _$TransferEvent_FailedImpl _value, $Res Function(_$TransferEvent_FailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? peerId = null,
+    Object? error = null,
+  }) {
+    return _then(_$TransferEvent_FailedImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      error: null == error
+          ? _value.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransferEvent_FailedImpl extends TransferEvent_Failed {
+  const _$TransferEvent_FailedImpl({required this.transferId, required this.peerId, required this.error}) : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final String peerId;
+  @override
+  final String error;
+
+  @override
+  String toString() {
+    return 'TransferEvent.failed(transferId: $transferId, peerId: $peerId, error: $error)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransferEvent_FailedImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.error, error) || other.error == error));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, peerId, error);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransferEvent_FailedImplCopyWith<_$TransferEvent_FailedImpl> get copyWith =>
+      __$$TransferEvent_FailedImplCopyWithImpl<_$TransferEvent_FailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)
+        started,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size) progress,
+    required TResult Function(BigInt transferId, String peerId, String path, String sha256) completed,
+    required TResult Function(BigInt transferId, String peerId, String error) failed,
+    required TResult Function(BigInt transferId, String peerId, bool byPeer) cancelled,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred) interrupted,
+  }) {
+    return failed(transferId, peerId, error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)?
+        started,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult? Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult? Function(BigInt transferId, String peerId, String error)? failed,
+    TResult? Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+  }) {
+    return failed?.call(transferId, peerId, error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)? started,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult Function(BigInt transferId, String peerId, String error)? failed,
+    TResult Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (failed != null) {
+      return failed(transferId, peerId, error);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransferEvent_Started value) started,
+    required TResult Function(TransferEvent_Progress value) progress,
+    required TResult Function(TransferEvent_Completed value) completed,
+    required TResult Function(TransferEvent_Failed value) failed,
+    required TResult Function(TransferEvent_Cancelled value) cancelled,
+    required TResult Function(TransferEvent_Interrupted value) interrupted,
+  }) {
+    return failed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransferEvent_Started value)? started,
+    TResult? Function(TransferEvent_Progress value)? progress,
+    TResult? Function(TransferEvent_Completed value)? completed,
+    TResult? Function(TransferEvent_Failed value)? failed,
+    TResult? Function(TransferEvent_Cancelled value)? cancelled,
+    TResult? Function(TransferEvent_Interrupted value)? interrupted,
+  }) {
+    return failed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransferEvent_Started value)? started,
+    TResult Function(TransferEvent_Progress value)? progress,
+    TResult Function(TransferEvent_Completed value)? completed,
+    TResult Function(TransferEvent_Failed value)? failed,
+    TResult Function(TransferEvent_Cancelled value)? cancelled,
+    TResult Function(TransferEvent_Interrupted value)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (failed != null) {
+      return failed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransferEvent_Failed extends TransferEvent {
+  const factory TransferEvent_Failed(
+          {required final BigInt transferId, required final String peerId, required final String error}) =
+      _$TransferEvent_FailedImpl;
+  const TransferEvent_Failed._() : super._();
+
+  @override
+  BigInt get transferId;
+  @override
+  String get peerId;
+  String get error;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransferEvent_FailedImplCopyWith<_$TransferEvent_FailedImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransferEvent_CancelledImplCopyWith<$Res> implements $TransferEventCopyWith<$Res> {
+  factory _$$TransferEvent_CancelledImplCopyWith(
+          _$TransferEvent_CancelledImpl value, $Res Function(_$TransferEvent_CancelledImpl) then) =
+      __$$TransferEvent_CancelledImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt transferId, String peerId, bool byPeer});
+}
+
+/// @nodoc
+class __$$TransferEvent_CancelledImplCopyWithImpl<$Res>
+    extends _$TransferEventCopyWithImpl<$Res, _$TransferEvent_CancelledImpl>
+    implements _$$TransferEvent_CancelledImplCopyWith<$Res> {
+  __$$TransferEvent_CancelledImplCopyWithImpl(
+      _$TransferEvent_CancelledImpl _value, $Res Function(_$TransferEvent_CancelledImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? peerId = null,
+    Object? byPeer = null,
+  }) {
+    return _then(_$TransferEvent_CancelledImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      byPeer: null == byPeer
+          ? _value.byPeer
+          : byPeer // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransferEvent_CancelledImpl extends TransferEvent_Cancelled {
+  const _$TransferEvent_CancelledImpl({required this.transferId, required this.peerId, required this.byPeer})
+      : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final String peerId;
+
+  /// Whether the peer cancelled rather than us
+  @override
+  final bool byPeer;
+
+  @override
+  String toString() {
+    return 'TransferEvent.cancelled(transferId: $transferId, peerId: $peerId, byPeer: $byPeer)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransferEvent_CancelledImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.byPeer, byPeer) || other.byPeer == byPeer));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, peerId, byPeer);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransferEvent_CancelledImplCopyWith<_$TransferEvent_CancelledImpl> get copyWith =>
+      __$$TransferEvent_CancelledImplCopyWithImpl<_$TransferEvent_CancelledImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)
+        started,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size) progress,
+    required TResult Function(BigInt transferId, String peerId, String path, String sha256) completed,
+    required TResult Function(BigInt transferId, String peerId, String error) failed,
+    required TResult Function(BigInt transferId, String peerId, bool byPeer) cancelled,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred) interrupted,
+  }) {
+    return cancelled(transferId, peerId, byPeer);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)?
+        started,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult? Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult? Function(BigInt transferId, String peerId, String error)? failed,
+    TResult? Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+  }) {
+    return cancelled?.call(transferId, peerId, byPeer);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)? started,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult Function(BigInt transferId, String peerId, String error)? failed,
+    TResult Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(transferId, peerId, byPeer);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransferEvent_Started value) started,
+    required TResult Function(TransferEvent_Progress value) progress,
+    required TResult Function(TransferEvent_Completed value) completed,
+    required TResult Function(TransferEvent_Failed value) failed,
+    required TResult Function(TransferEvent_Cancelled value) cancelled,
+    required TResult Function(TransferEvent_Interrupted value) interrupted,
+  }) {
+    return cancelled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransferEvent_Started value)? started,
+    TResult? Function(TransferEvent_Progress value)? progress,
+    TResult? Function(TransferEvent_Completed value)? completed,
+    TResult? Function(TransferEvent_Failed value)? failed,
+    TResult? Function(TransferEvent_Cancelled value)? cancelled,
+    TResult? Function(TransferEvent_Interrupted value)? interrupted,
+  }) {
+    return cancelled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransferEvent_Started value)? started,
+    TResult Function(TransferEvent_Progress value)? progress,
+    TResult Function(TransferEvent_Completed value)? completed,
+    TResult Function(TransferEvent_Failed value)? failed,
+    TResult Function(TransferEvent_Cancelled value)? cancelled,
+    TResult Function(TransferEvent_Interrupted value)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransferEvent_Cancelled extends TransferEvent {
+  const factory TransferEvent_Cancelled(
+      {required final BigInt transferId,
+      required final String peerId,
+
+      /// Whether the peer cancelled rather than us
+      required final bool byPeer}) = _$TransferEvent_CancelledImpl;
+  const TransferEvent_Cancelled._() : super._();
+
+  @override
+  BigInt get transferId;
+  @override
+  String get peerId;
+
+  /// Whether the peer cancelled rather than us
+  bool get byPeer;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransferEvent_CancelledImplCopyWith<_$TransferEvent_CancelledImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$TransferEvent_InterruptedImplCopyWith<$Res> implements $TransferEventCopyWith<$Res> {
+  factory _$$TransferEvent_InterruptedImplCopyWith(
+          _$TransferEvent_InterruptedImpl value, $Res Function(_$TransferEvent_InterruptedImpl) then) =
+      __$$TransferEvent_InterruptedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({BigInt transferId, String peerId, BigInt transferred});
+}
+
+/// @nodoc
+class __$$TransferEvent_InterruptedImplCopyWithImpl<$Res>
+    extends _$TransferEventCopyWithImpl<$Res, _$TransferEvent_InterruptedImpl>
+    implements _$$TransferEvent_InterruptedImplCopyWith<$Res> {
+  __$$TransferEvent_InterruptedImplCopyWithImpl(
+      _$TransferEvent_InterruptedImpl _value, $Res Function(_$TransferEvent_InterruptedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? transferId = null,
+    Object? peerId = null,
+    Object? transferred = null,
+  }) {
+    return _then(_$TransferEvent_InterruptedImpl(
+      transferId: null == transferId
+          ? _value.transferId
+          : transferId // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      peerId: null == peerId
+          ? _value.peerId
+          : peerId // ignore: cast_nullable_to_non_nullable
+              as String,
+      transferred: null == transferred
+          ? _value.transferred
+          : transferred // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$TransferEvent_InterruptedImpl extends TransferEvent_Interrupted {
+  const _$TransferEvent_InterruptedImpl({required this.transferId, required this.peerId, required this.transferred})
+      : super._();
+
+  @override
+  final BigInt transferId;
+  @override
+  final String peerId;
+
+  /// Bytes the receiver has acknowledged
+  @override
+  final BigInt transferred;
+
+  @override
+  String toString() {
+    return 'TransferEvent.interrupted(transferId: $transferId, peerId: $peerId, transferred: $transferred)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$TransferEvent_InterruptedImpl &&
+            (identical(other.transferId, transferId) || other.transferId == transferId) &&
+            (identical(other.peerId, peerId) || other.peerId == peerId) &&
+            (identical(other.transferred, transferred) || other.transferred == transferred));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, transferId, peerId, transferred);
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$TransferEvent_InterruptedImplCopyWith<_$TransferEvent_InterruptedImpl> get copyWith =>
+      __$$TransferEvent_InterruptedImplCopyWithImpl<_$TransferEvent_InterruptedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)
+        started,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size) progress,
+    required TResult Function(BigInt transferId, String peerId, String path, String sha256) completed,
+    required TResult Function(BigInt transferId, String peerId, String error) failed,
+    required TResult Function(BigInt transferId, String peerId, bool byPeer) cancelled,
+    required TResult Function(BigInt transferId, String peerId, BigInt transferred) interrupted,
+  }) {
+    return interrupted(transferId, peerId, transferred);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)?
+        started,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult? Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult? Function(BigInt transferId, String peerId, String error)? failed,
+    TResult? Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult? Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+  }) {
+    return interrupted?.call(transferId, peerId, transferred);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BigInt transferId, String peerId, String name, BigInt size, bool outbound, BigInt offset)? started,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred, BigInt size)? progress,
+    TResult Function(BigInt transferId, String peerId, String path, String sha256)? completed,
+    TResult Function(BigInt transferId, String peerId, String error)? failed,
+    TResult Function(BigInt transferId, String peerId, bool byPeer)? cancelled,
+    TResult Function(BigInt transferId, String peerId, BigInt transferred)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (interrupted != null) {
+      return interrupted(transferId, peerId, transferred);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(TransferEvent_Started value) started,
+    required TResult Function(TransferEvent_Progress value) progress,
+    required TResult Function(TransferEvent_Completed value) completed,
+    required TResult Function(TransferEvent_Failed value) failed,
+    required TResult Function(TransferEvent_Cancelled value) cancelled,
+    required TResult Function(TransferEvent_Interrupted value) interrupted,
+  }) {
+    return interrupted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(TransferEvent_Started value)? started,
+    TResult? Function(TransferEvent_Progress value)? progress,
+    TResult? Function(TransferEvent_Completed value)? completed,
+    TResult? Function(TransferEvent_Failed value)? failed,
+    TResult? Function(TransferEvent_Cancelled value)? cancelled,
+    TResult? Function(TransferEvent_Interrupted value)? interrupted,
+  }) {
+    return interrupted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(TransferEvent_Started value)? started,
+    TResult Function(TransferEvent_Progress value)? progress,
+    TResult Function(TransferEvent_Completed value)? completed,
+    TResult Function(TransferEvent_Failed value)? failed,
+    TResult Function(TransferEvent_Cancelled value)? cancelled,
+    TResult Function(TransferEvent_Interrupted value)? interrupted,
+    required TResult orElse(),
+  }) {
+    if (interrupted != null) {
+      return interrupted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class TransferEvent_Interrupted extends TransferEvent {
+  const factory TransferEvent_Interrupted(
+      {required final BigInt transferId,
+      required final String peerId,
+
+      /// Bytes the receiver has acknowledged
+      required final BigInt transferred}) = _$TransferEvent_InterruptedImpl;
+  const TransferEvent_Interrupted._() : super._();
+
+  @override
+  BigInt get transferId;
+  @override
+  String get peerId;
+
+  /// Bytes the receiver has acknowledged
+  BigInt get transferred;
+
+  /// Create a copy of TransferEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$TransferEvent_InterruptedImplCopyWith<_$TransferEvent_InterruptedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
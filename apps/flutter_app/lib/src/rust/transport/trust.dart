@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check`, `fingerprint_of`, `forget`, `get`, `is_paired`, `list`, `new`, `new`, `new`, `normalize_fingerprint`, `paired`, `pin`, `record_if_pinning`, `record`, `reject`, `take_rejection`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PairedClientVerifier`, `TofuCertVerifier`, `TrustStore`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `root_hint_subjects`, `supported_verify_schemes`, `supported_verify_schemes`, `verify_client_cert`, `verify_server_cert`, `verify_tls12_signature`, `verify_tls12_signature`, `verify_tls13_signature`, `verify_tls13_signature`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+/// Compute the SHA-256 fingerprint of a DER encoded certificate
+///
+/// Returns the digest as 64 lowercase hex characters.
+String certificateFingerprint({required List<int> certDer}) =>
+    RustLib.instance.api.crateTransportTrustCertificateFingerprint(certDer: certDer);
+
+/// A certificate fingerprint pinned for a device
+class PinnedFingerprint {
+  final String deviceId;
+
+  /// Lowercase hex SHA-256 of the device's DER certificate
+  final String fingerprint;
+
+  const PinnedFingerprint({
+    required this.deviceId,
+    required this.fingerprint,
+  });
+
+  @override
+  int get hashCode => deviceId.hashCode ^ fingerprint.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PinnedFingerprint &&
+          runtimeType == other.runtimeType &&
+          deviceId == other.deviceId &&
+          fingerprint == other.fingerprint;
+}
@@ -40,6 +40,12 @@ type Message struct {
 	Type    string `json:"type"`
 	Content string `json:"content"`
 	Sender  string `json:"sender"`
+
+	// Device a deposit, candidates or presence answer is for
+	Target string `json:"target,omitempty"`
+
+	// Seconds to keep a deposit for an offline device
+	TTL int64 `json:"ttl,omitempty"`
 }
 
 // broadcastMessage wraps the raw message with the sender
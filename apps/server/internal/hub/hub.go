@@ -3,8 +3,12 @@ package hub
 import (
 	"encoding/json"
 	"log/slog"
+	"time"
 )
 
+// How often expired mailbox items are dropped
+const mailboxPruneInterval = time.Minute
+
 // Hub maintains the set of active clients and broadcasts messages to the clients.
 type Hub struct {
 	// Registered clients
@@ -21,6 +25,9 @@ type Hub struct {
 
 	// Unregister requests from clients
 	unregister chan *Client
+
+	// Items deposited for offline devices
+	mailbox *Mailbox
 }
 
 // NewHub creates a new Hub instance
@@ -31,13 +38,20 @@ func NewHub() *Hub {
 		unregister: make(chan *Client),
 		clients:    make(map[*Client]bool),
 		deviceMap:  make(map[string]*Client),
+		mailbox:    NewMailbox(),
 	}
 }
 
 // Run starts the hub's main loop to handle client registration, unregistration, and broadcasting
 func (h *Hub) Run() {
+	prune := time.NewTicker(mailboxPruneInterval)
+	defer prune.Stop()
+
 	for {
 		select {
+		case now := <-prune.C:
+			h.mailbox.Prune(now)
+
 		case client := <-h.register:
 			h.clients[client] = true
 			h.deviceMap[client.deviceID] = client
@@ -58,6 +72,24 @@ func (h *Hub) Run() {
 				continue
 			}
 
+			switch msg.Type {
+			case "deposit":
+				h.deposit(bm, msg)
+				continue
+			case "fetch":
+				h.fetch(bm.sender)
+				continue
+			case "candidates":
+				h.forward(bm, msg)
+				continue
+			case "presence":
+				// Announcements go to everyone, answers only to the announcer
+				if msg.Target != "" {
+					h.forward(bm, msg)
+					continue
+				}
+			}
+
 			// Truncate content for logging
 			displayContent := msg.Content
 			if len(displayContent) > 50 {
@@ -70,22 +102,71 @@ func (h *Hub) Run() {
 				if client == bm.sender {
 					continue
 				}
-
-				select {
-				case client.send <- bm.payload:
-					// Message sent successfully
-				default:
-					// Client's send buffer is full, assume client is dead or stuck
-					close(client.send)
-					delete(h.clients, client)
-					delete(h.deviceMap, client.deviceID)
-					slog.Warn("Removed stuck client", "device_id", client.deviceID, "address", client.conn.RemoteAddr())
-				}
+				h.send(client, bm.payload)
 			}
 		}
 	}
 }
 
+// send queues a message for a client, removing the client if it can't keep up
+func (h *Hub) send(client *Client, payload []byte) {
+	select {
+	case client.send <- payload:
+		// Message sent successfully
+	default:
+		// Client's send buffer is full, assume client is dead or stuck
+		close(client.send)
+		delete(h.clients, client)
+		delete(h.deviceMap, client.deviceID)
+		slog.Warn("Removed stuck client", "device_id", client.deviceID, "address", client.conn.RemoteAddr())
+	}
+}
+
+// forward hands a message to its target if connected, and drops it otherwise
+func (h *Hub) forward(bm broadcastMessage, msg Message) {
+	client, ok := h.deviceMap[msg.Target]
+	if !ok || client == bm.sender {
+		slog.Debug("Dropped message for unknown target", "type", msg.Type, "target_device_id", msg.Target, "sender_device_id", bm.sender.deviceID)
+		return
+	}
+	h.send(client, bm.payload)
+}
+
+// deposit hands a sealed item to its target if connected, and keeps it in the mailbox otherwise
+func (h *Hub) deposit(bm broadcastMessage, msg Message) {
+	if msg.Target == "" {
+		slog.Warn("Dropped deposit without a target", "sender_device_id", bm.sender.deviceID)
+		return
+	}
+
+	if client, ok := h.deviceMap[msg.Target]; ok {
+		h.send(client, bm.payload)
+		return
+	}
+
+	if err := h.mailbox.Deposit(msg.Target, bm.sender.deviceID, bm.payload, time.Duration(msg.TTL)*time.Second, time.Now()); err != nil {
+		slog.Warn("Refused deposit", "error", err, "target_device_id", msg.Target, "sender_device_id", bm.sender.deviceID)
+		return
+	}
+	slog.Info("Deposited item", "target_device_id", msg.Target, "size", len(bm.payload), "sender_device_id", bm.sender.deviceID)
+}
+
+// fetch hands a client the items deposited for its device
+func (h *Hub) fetch(client *Client) {
+	if _, ok := h.clients[client]; !ok {
+		return
+	}
+
+	items := h.mailbox.Collect(client.deviceID, time.Now())
+	slog.Info("Delivering mailbox", "device_id", client.deviceID, "items", len(items))
+	for _, payload := range items {
+		if _, ok := h.clients[client]; !ok {
+			return
+		}
+		h.send(client, payload)
+	}
+}
+
 // Register adds a client to the hub
 func (h *Hub) Register(client *Client) {
 	h.register <- client
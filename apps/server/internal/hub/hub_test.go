@@ -2,6 +2,7 @@ package hub
 
 import (
 	"encoding/json"
+	"fmt"
 	"net/http"
 	"net/http/httptest"
 	"strings"
@@ -92,3 +93,124 @@ func TestHubIntegration(t *testing.T) {
 		t.Error("conn1 (sender) should NOT have received its own message")
 	}
 }
+
+// startHub serves a hub whose clients take their device ID from the device_id query parameter
+func startHub(t *testing.T) string {
+	h := NewHub()
+	go h.Run()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		conn, err := upgrader.Upgrade(w, r, nil)
+		if err != nil {
+			return
+		}
+		client := NewClient(h, conn, r.URL.Query().Get("device_id"))
+		h.register <- client
+
+		go client.WritePump()
+		client.ReadPump()
+	}))
+	t.Cleanup(server.Close)
+
+	return "ws" + strings.TrimPrefix(server.URL, "http")
+}
+
+func dialDevice(t *testing.T, wsURL, deviceID string) *websocket.Conn {
+	t.Helper()
+	conn, _, err := websocket.DefaultDialer.Dial(wsURL+"?device_id="+deviceID, nil)
+	if err != nil {
+		t.Fatalf("Failed to dial %s: %v", deviceID, err)
+	}
+	t.Cleanup(func() { conn.Close() })
+	return conn
+}
+
+func sendMessage(t *testing.T, conn *websocket.Conn, msg Message) []byte {
+	t.Helper()
+	payload, _ := json.Marshal(msg)
+	if err := conn.WriteMessage(websocket.TextMessage, payload); err != nil {
+		t.Fatalf("Failed to write: %v", err)
+	}
+	return payload
+}
+
+func expectMessage(t *testing.T, conn *websocket.Conn, want []byte) {
+	t.Helper()
+	conn.SetReadDeadline(time.Now().Add(500 * time.Millisecond))
+	_, received, err := conn.ReadMessage()
+	if err != nil {
+		t.Fatalf("Expected %s, got error: %v", want, err)
+	}
+	if string(received) != string(want) {
+		t.Fatalf("Expected %s, got %s", want, received)
+	}
+}
+
+// expectNothing must be the last read on conn, which gorilla won't read from after a timeout
+func expectNothing(t *testing.T, conn *websocket.Conn) {
+	t.Helper()
+	conn.SetReadDeadline(time.Now().Add(100 * time.Millisecond))
+	if _, received, err := conn.ReadMessage(); err == nil {
+		t.Fatalf("Expected no message, got %s", received)
+	}
+}
+
+func TestHubRoutesTargetedMessages(t *testing.T) {
+	wsURL := startHub(t)
+	laptop := dialDevice(t, wsURL, "laptop")
+	phone := dialDevice(t, wsURL, "phone")
+	tablet := dialDevice(t, wsURL, "tablet")
+
+	// Wait for registration
+	time.Sleep(100 * time.Millisecond)
+
+	announcement := sendMessage(t, laptop, Message{Type: "presence", Content: "online", Sender: "laptop"})
+	expectMessage(t, phone, announcement)
+	expectMessage(t, tablet, announcement)
+
+	answer := sendMessage(t, phone, Message{Type: "presence", Content: "online", Sender: "phone", Target: "laptop"})
+	expectMessage(t, laptop, answer)
+
+	candidates := sendMessage(t, laptop, Message{Type: "candidates", Content: "203.0.113.7:4433", Sender: "laptop", Target: "phone"})
+	expectMessage(t, phone, candidates)
+
+	// Unknown targets are dropped rather than broadcast
+	sendMessage(t, laptop, Message{Type: "candidates", Content: "203.0.113.7:4433", Sender: "laptop", Target: "watch"})
+	expectNothing(t, phone)
+	expectNothing(t, tablet)
+}
+
+func TestHubDepositAndFetch(t *testing.T) {
+	wsURL := startHub(t)
+	laptop := dialDevice(t, wsURL, "laptop")
+	tablet := dialDevice(t, wsURL, "tablet")
+
+	// Wait for registration
+	time.Sleep(100 * time.Millisecond)
+
+	stored := sendMessage(t, laptop, Message{Type: "deposit", Content: "sealed", Sender: "laptop", Target: "phone", TTL: 60})
+	for i := 0; i < maxSenderTargets; i++ {
+		sendMessage(t, laptop, Message{Type: "deposit", Content: "sealed", Sender: "laptop", Target: fmt.Sprint("watch-", i)})
+	}
+
+	// Wait for the deposits to reach the mailbox
+	time.Sleep(100 * time.Millisecond)
+
+	phone := dialDevice(t, wsURL, "phone")
+	sendMessage(t, phone, Message{Type: "fetch", Sender: "phone"})
+	expectMessage(t, phone, stored)
+
+	// Connected devices get deposits directly
+	direct := sendMessage(t, laptop, Message{Type: "deposit", Content: "sealed", Sender: "laptop", Target: "phone"})
+	expectMessage(t, phone, direct)
+
+	// The laptop had items waiting for maxSenderTargets devices when it deposited for the last watch
+	watch := dialDevice(t, wsURL, fmt.Sprint("watch-", maxSenderTargets-1))
+	sendMessage(t, watch, Message{Type: "fetch", Sender: "watch"})
+	expectNothing(t, watch)
+
+	// Collected items are gone
+	sendMessage(t, phone, Message{Type: "fetch", Sender: "phone"})
+	expectNothing(t, phone)
+	expectNothing(t, tablet)
+}
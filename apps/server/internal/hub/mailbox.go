@@ -0,0 +1,139 @@
+package hub
+
+import (
+	"errors"
+	"time"
+)
+
+const (
+	// Longest a deposited item is kept for an offline device
+	maxMailboxTTL = 7 * 24 * time.Hour
+
+	// Items kept per device; the oldest are dropped first
+	maxMailboxItems = 100
+
+	// Devices one sender may have items waiting for at once
+	maxSenderTargets = 16
+
+	// Bytes kept for all devices together
+	maxMailboxBytes = 256 * 1024 * 1024 // 256MB
+)
+
+var (
+	// errTooManyTargets refuses a deposit for a device beyond maxSenderTargets
+	errTooManyTargets = errors.New("sender has items waiting for too many devices")
+
+	// errMailboxFull refuses a deposit that would exceed maxMailboxBytes
+	errMailboxFull = errors.New("mailbox is full")
+)
+
+// storedItem is a deposited message waiting for its device
+type storedItem struct {
+	sender  string
+	payload []byte
+	expires time.Time
+}
+
+// Mailbox keeps items deposited for offline devices until they fetch them.
+// Items are sealed to the recipient's key before they reach the server, so
+// they are stored and handed over as opaque payloads.
+type Mailbox struct {
+	items map[string][]storedItem
+
+	// Number of items each sender has waiting for each device
+	targets map[string]map[string]int
+
+	// Size of all payloads kept
+	bytes int
+}
+
+// NewMailbox creates an empty Mailbox
+func NewMailbox() *Mailbox {
+	return &Mailbox{
+		items:   make(map[string][]storedItem),
+		targets: make(map[string]map[string]int),
+	}
+}
+
+// Deposit keeps payload from sender for deviceID for ttl, capped at maxMailboxTTL.
+// It refuses the item if sender already has items waiting for maxSenderTargets
+// other devices, or if the mailbox would grow beyond maxMailboxBytes.
+func (m *Mailbox) Deposit(deviceID, sender string, payload []byte, ttl time.Duration, now time.Time) error {
+	if ttl <= 0 || ttl > maxMailboxTTL {
+		ttl = maxMailboxTTL
+	}
+
+	targets := m.targets[sender]
+	if _, ok := targets[deviceID]; !ok && len(targets) >= maxSenderTargets {
+		return errTooManyTargets
+	}
+	if m.bytes+len(payload) > maxMailboxBytes {
+		return errMailboxFull
+	}
+
+	items := append(m.live(deviceID, now), storedItem{sender: sender, payload: payload, expires: now.Add(ttl)})
+	if len(items) > maxMailboxItems {
+		items = items[len(items)-maxMailboxItems:]
+	}
+	m.set(deviceID, items)
+	return nil
+}
+
+// Collect removes and returns the unexpired items for deviceID, oldest first
+func (m *Mailbox) Collect(deviceID string, now time.Time) [][]byte {
+	items := m.live(deviceID, now)
+	m.set(deviceID, nil)
+
+	payloads := make([][]byte, 0, len(items))
+	for _, item := range items {
+		payloads = append(payloads, item.payload)
+	}
+	return payloads
+}
+
+// Prune drops expired items for every device
+func (m *Mailbox) Prune(now time.Time) {
+	for deviceID := range m.items {
+		m.set(deviceID, m.live(deviceID, now))
+	}
+}
+
+// live returns the items for deviceID that haven't expired
+func (m *Mailbox) live(deviceID string, now time.Time) []storedItem {
+	var live []storedItem
+	for _, item := range m.items[deviceID] {
+		if now.Before(item.expires) {
+			live = append(live, item)
+		}
+	}
+	return live
+}
+
+// set replaces the items for deviceID, keeping the sender and size totals in step
+func (m *Mailbox) set(deviceID string, items []storedItem) {
+	for _, item := range m.items[deviceID] {
+		m.bytes -= len(item.payload)
+		targets := m.targets[item.sender]
+		targets[deviceID]--
+		if targets[deviceID] == 0 {
+			delete(targets, deviceID)
+		}
+		if len(targets) == 0 {
+			delete(m.targets, item.sender)
+		}
+	}
+
+	for _, item := range items {
+		m.bytes += len(item.payload)
+		if m.targets[item.sender] == nil {
+			m.targets[item.sender] = make(map[string]int)
+		}
+		m.targets[item.sender][deviceID]++
+	}
+
+	if len(items) > 0 {
+		m.items[deviceID] = items
+	} else {
+		delete(m.items, deviceID)
+	}
+}
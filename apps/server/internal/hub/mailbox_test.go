@@ -0,0 +1,104 @@
+package hub
+
+import (
+	"fmt"
+	"testing"
+	"time"
+)
+
+func TestMailboxDepositAndCollect(t *testing.T) {
+	m := NewMailbox()
+	now := time.Now()
+
+	m.Deposit("phone", "laptop", []byte("first"), time.Minute, now)
+	m.Deposit("phone", "laptop", []byte("second"), time.Hour, now)
+	m.Deposit("tablet", "laptop", []byte("other"), time.Minute, now)
+
+	items := m.Collect("phone", now.Add(30*time.Second))
+	if len(items) != 2 || string(items[0]) != "first" || string(items[1]) != "second" {
+		t.Fatalf("Expected both items oldest first, got %q", items)
+	}
+	if items := m.Collect("phone", now); len(items) != 0 {
+		t.Errorf("Collected items should be gone, got %q", items)
+	}
+
+	if items := m.Collect("tablet", now.Add(2*time.Minute)); len(items) != 0 {
+		t.Errorf("Expired items should not be handed over, got %q", items)
+	}
+}
+
+func TestMailboxLimits(t *testing.T) {
+	m := NewMailbox()
+	now := time.Now()
+
+	for i := 0; i < maxMailboxItems+5; i++ {
+		m.Deposit("phone", "laptop", []byte(fmt.Sprint(i)), 0, now)
+	}
+	m.Prune(now.Add(maxMailboxTTL - time.Second))
+
+	items := m.Collect("phone", now)
+	if len(items) != maxMailboxItems {
+		t.Fatalf("Expected %d items, got %d", maxMailboxItems, len(items))
+	}
+	if string(items[0]) != "5" {
+		t.Errorf("Oldest items should be dropped first, got %q first", items[0])
+	}
+
+	m.Deposit("phone", "laptop", []byte("late"), 365*24*time.Hour, now)
+	m.Prune(now.Add(maxMailboxTTL))
+	if len(m.items) != 0 {
+		t.Errorf("TTL should be capped at %v", maxMailboxTTL)
+	}
+}
+
+func TestMailboxSenderTargets(t *testing.T) {
+	m := NewMailbox()
+	now := time.Now()
+
+	for i := 0; i < maxSenderTargets; i++ {
+		if err := m.Deposit(fmt.Sprint("device-", i), "laptop", []byte("item"), 0, now); err != nil {
+			t.Fatalf("Deposit %d should be kept, got %v", i, err)
+		}
+	}
+	if err := m.Deposit("device-0", "laptop", []byte("again"), 0, now); err != nil {
+		t.Errorf("Devices already holding items should take more, got %v", err)
+	}
+	if err := m.Deposit("phone", "laptop", []byte("item"), 0, now); err != errTooManyTargets {
+		t.Errorf("Expected %v, got %v", errTooManyTargets, err)
+	}
+	if err := m.Deposit("phone", "tablet", []byte("item"), 0, now); err != nil {
+		t.Errorf("Other senders should not be limited, got %v", err)
+	}
+
+	m.Collect("device-0", now)
+	if err := m.Deposit("phone", "laptop", []byte("item"), 0, now); err != nil {
+		t.Errorf("Collected devices should no longer count, got %v", err)
+	}
+}
+
+func TestMailboxBytes(t *testing.T) {
+	m := NewMailbox()
+	now := time.Now()
+	payload := make([]byte, 1024*1024)
+
+	var err error
+	for i := 0; err == nil; i++ {
+		deviceID := fmt.Sprint("device-", i/maxMailboxItems)
+		err = m.Deposit(deviceID, deviceID+"-sender", payload, 0, now)
+	}
+	if err != errMailboxFull {
+		t.Fatalf("Expected %v, got %v", errMailboxFull, err)
+	}
+	if m.bytes > maxMailboxBytes {
+		t.Errorf("Mailbox should stay within %d bytes, holds %d", maxMailboxBytes, m.bytes)
+	}
+
+	m.Collect("device-0", now)
+	if err := m.Deposit("phone", "laptop", payload, 0, now); err != nil {
+		t.Errorf("Collected items should free space, got %v", err)
+	}
+	m.Prune(now.Add(maxMailboxTTL))
+	if m.bytes != 0 || len(m.targets) != 0 {
+		t.Errorf("Pruning everything should reset the totals, got %d bytes and %d senders", m.bytes, len(m.targets))
+	}
+}
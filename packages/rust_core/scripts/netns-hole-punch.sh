@@ -0,0 +1,86 @@
+#!/usr/bin/env bash
+# Hole punching across two NATs, using network namespaces
+#
+#   peer-a 10.0.1.2 -- nat-a -+                      +- nat-b -- peer-b 10.0.2.2
+#                             +-- wan 203.0.113.0/24 -+
+#                                 rendezvous .10
+#
+# Each NAT masquerades its LAN behind its WAN address, so the peers can only
+# reach each other by punching. Runs the ignored test_hole_punch_netns test
+# once per role. Needs root, iproute2 and iptables.
+#
+# Usage: sudo scripts/netns-hole-punch.sh
+
+set -euo pipefail
+cd "$(dirname "$0")/.."
+
+PREFIX=sm
+NAMESPACES=(wan nat-a nat-b peer-a peer-b)
+RENDEZVOUS=203.0.113.10:3478
+
+cleanup() {
+    for ns in "${NAMESPACES[@]}"; do
+        ip netns del "$PREFIX-$ns" 2>/dev/null || true
+    done
+    [[ -n "${SHARED:-}" ]] && rm -rf "$SHARED"
+}
+trap cleanup EXIT
+
+in_ns() {
+    local ns=$1
+    shift
+    ip netns exec "$PREFIX-$ns" "$@"
+}
+
+# Connect namespace $1 (interface $2, address $3) to namespace $4 (interface $5, address $6)
+link() {
+    ip link add "$2" netns "$PREFIX-$1" type veth peer name "$5" netns "$PREFIX-$4"
+    in_ns "$1" ip addr add "$3" dev "$2"
+    in_ns "$4" ip addr add "$6" dev "$5"
+    in_ns "$1" ip link set "$2" up
+    in_ns "$4" ip link set "$5" up
+}
+
+cargo test --no-run
+TEST_BIN=$(cargo test --no-run --message-format=json 2>/dev/null \
+    | jq -r 'select(.profile.test == true and .target.name == "rust_core") | .executable' | head -n 1)
+
+cleanup
+for ns in "${NAMESPACES[@]}"; do
+    ip netns add "$PREFIX-$ns"
+    in_ns "$ns" ip link set lo up
+done
+
+# The wan namespace bridges the rendezvous service and both NATs' outside interfaces
+in_ns wan ip link add br0 type bridge
+in_ns wan ip addr add 203.0.113.10/24 dev br0
+in_ns wan ip link set br0 up
+for side in a b; do
+    octet=$([[ $side == a ]] && echo 1 || echo 2)
+    ip link add "wan-$side" netns "$PREFIX-wan" type veth peer name wan0 netns "$PREFIX-nat-$side"
+    in_ns wan ip link set "wan-$side" master br0 up
+    in_ns "nat-$side" ip addr add "203.0.113.$octet/24" dev wan0
+    in_ns "nat-$side" ip link set wan0 up
+
+    link "nat-$side" lan0 "10.0.$octet.1/24" "peer-$side" eth0 "10.0.$octet.2/24"
+    in_ns "peer-$side" ip route add default via "10.0.$octet.1"
+    in_ns "nat-$side" sysctl -qw net.ipv4.ip_forward=1
+    in_ns "nat-$side" iptables -t nat -A POSTROUTING -o wan0 -j MASQUERADE
+done
+
+SHARED=$(mktemp -d)
+export SYNCMIST_RENDEZVOUS=$RENDEZVOUS SYNCMIST_NETNS_DIR=$SHARED
+run() {
+    in_ns "$1" env SYNCMIST_NETNS_ROLE="$2" "$TEST_BIN" --ignored --exact \
+        transport::quic::tests::test_hole_punch_netns --nocapture
+}
+
+run wan rendezvous &
+RENDEZVOUS_PID=$!
+sleep 1
+run peer-a a &
+A_PID=$!
+run peer-b b
+wait "$A_PID"
+kill "$RENDEZVOUS_PID" 2>/dev/null || true
+echo "Hole punching across NATs succeeded"
@@ -0,0 +1,23 @@
+//! SyncMist Relay Server
+//!
+//! Forwards QUIC packets between devices that can't reach each other directly.
+//! The relay never sees plaintext: QUIC runs end to end between the devices.
+//!
+//! Usage: `syncmist-relay [address]`, listening on `0.0.0.0:3479` by default
+
+use rust_core::transport::relay::RelayServer;
+
+const DEFAULT_ADDRESS: &str = "0.0.0.0:3479";
+
+#[tokio::main]
+async fn main() {
+    let address = std::env::args().nth(1).unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let _relay = match RelayServer::bind(&address).await {
+        Ok(relay) => relay,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let _ = tokio::signal::ctrl_c().await;
+}
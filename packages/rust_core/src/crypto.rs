@@ -25,6 +25,14 @@ pub fn generate_key() -> Vec<u8> {
 /// Returns: nonce (12 bytes) || ciphertext || tag (16 bytes)
 #[flutter_rust_bridge::frb(sync)]
 pub fn encrypt_text(plaintext: String, key: Vec<u8>) -> Result<Vec<u8>, String> {
+    encrypt_bytes(plaintext.into_bytes(), key)
+}
+
+/// Encrypt binary data, such as an image, using AES-256-GCM
+/// 
+/// Returns: nonce (12 bytes) || ciphertext || tag (16 bytes)
+#[flutter_rust_bridge::frb(sync)]
+pub fn encrypt_bytes(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, String> {
     if key.len() != KEY_SIZE {
         return Err(format!("Key must be {} bytes, got {}", KEY_SIZE, key.len()));
     }
@@ -39,7 +47,7 @@ pub fn encrypt_text(plaintext: String, key: Vec<u8>) -> Result<Vec<u8>, String>
 
     // Encrypt
     let ciphertext = cipher
-        .encrypt(nonce, plaintext.as_bytes())
+        .encrypt(nonce, plaintext.as_slice())
         .map_err(|e| format!("Encryption failed: {}", e))?;
 
     // Prepend nonce to ciphertext
@@ -91,6 +99,16 @@ pub fn derive_shared_secret(my_secret: Vec<u8>, their_public: Vec<u8>) -> Result
 /// Expects: nonce (12 bytes) || ciphertext || tag (16 bytes)
 #[flutter_rust_bridge::frb(sync)]
 pub fn decrypt_text(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<String, String> {
+    let plaintext = decrypt_bytes(ciphertext, key)?;
+    String::from_utf8(plaintext)
+        .map_err(|e| format!("Invalid UTF-8: {}", e))
+}
+
+/// Decrypt binary data encrypted with [`encrypt_bytes`]
+/// 
+/// Expects: nonce (12 bytes) || ciphertext || tag (16 bytes)
+#[flutter_rust_bridge::frb(sync)]
+pub fn decrypt_bytes(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, String> {
     if key.len() != KEY_SIZE {
         return Err(format!("Key must be {} bytes, got {}", KEY_SIZE, key.len()));
     }
@@ -106,12 +124,9 @@ pub fn decrypt_text(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<String, String>
     let encrypted = &ciphertext[NONCE_SIZE..];
 
     // Decrypt
-    let plaintext = cipher
+    cipher
         .decrypt(nonce, encrypted)
-        .map_err(|e| format!("Decryption failed: {}", e))?;
-
-    String::from_utf8(plaintext)
-        .map_err(|e| format!("Invalid UTF-8: {}", e))
+        .map_err(|e| format!("Decryption failed: {}", e))
 }
 
 #[cfg(test)]
@@ -129,6 +144,16 @@ mod tests {
         assert_eq!(original, decrypted);
     }
 
+    #[test]
+    fn test_encrypt_decrypt_bytes_roundtrip() {
+        let key = generate_key();
+        let original = vec![0u8, 159, 146, 150, 255];
+
+        let encrypted = encrypt_bytes(original.clone(), key.clone()).unwrap();
+        assert_eq!(decrypt_bytes(encrypted.clone(), key).unwrap(), original);
+        assert!(decrypt_bytes(encrypted, generate_key()).is_err());
+    }
+
     #[test]
     fn test_different_keys_fail() {
         let key1 = generate_key();
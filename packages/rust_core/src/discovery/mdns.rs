@@ -1,12 +1,13 @@
 use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
-use tokio::sync::mpsc::{channel, Receiver};
 use tokio::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 const SERVICE_TYPE: &str = "_syncmist._udp.local.";
-const DEFAULT_PORT: u16 = 9876;
+/// Default port advertised for the SyncMist QUIC endpoint
+pub const DEFAULT_PORT: u16 = 9876;
 
 /// mDNS discovery errors
 #[derive(Debug)]
@@ -83,10 +84,11 @@ impl MdnsDiscovery {
             SERVICE_TYPE,
             &instance_name,
             &format!("{}.local.", instance_name),
-            "", // host_ipv4 (empty for all)
+            "", // no fixed address, advertise every interface address below
             port,
             Some(properties),
-        ).map_err(|e| DiscoveryError::Registration(format!("Failed to create service: {}", e)))?;
+        ).map_err(|e| DiscoveryError::Registration(format!("Failed to create service: {}", e)))?
+        .enable_addr_auto();
 
         self.daemon.register(service_info)
             .map_err(|e| DiscoveryError::Registration(format!("Failed to register: {}", e)))?;
@@ -123,10 +125,7 @@ impl MdnsDiscovery {
                         continue;
                     }
 
-                    let addresses: Vec<String> = info.get_addresses()
-                        .iter()
-                        .map(|ip| ip.to_string())
-                        .collect();
+                    let addresses = format_addresses(info.get_addresses());
 
                     let now = SystemTime::now()
                         .duration_since(UNIX_EPOCH)
@@ -164,6 +163,11 @@ impl MdnsDiscovery {
         peers.clone()
     }
 
+    /// Shared list of discovered peers, for components that follow discovery
+    pub(crate) fn peers_handle(&self) -> Arc<Mutex<Vec<PeerInfo>>> {
+        self.discovered_peers.clone()
+    }
+
     /// Stop discovery and unregister service
     #[flutter_rust_bridge::frb]
     pub fn stop(&self) -> Result<(), DiscoveryError> {
@@ -174,6 +178,16 @@ impl MdnsDiscovery {
     }
 }
 
+/// Format advertised addresses for [`PeerInfo`], IPv4 first, in a stable order
+///
+/// Link-local IPv6 addresses arrive without a zone; the transport tries them on
+/// every interface when connecting.
+fn format_addresses<'a>(addresses: impl IntoIterator<Item = &'a IpAddr>) -> Vec<String> {
+    let mut addresses: Vec<&IpAddr> = addresses.into_iter().collect();
+    addresses.sort_by_key(|ip| (ip.is_ipv6(), **ip));
+    addresses.into_iter().map(|ip| ip.to_string()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,9 +335,9 @@ mod tests {
         let discovery2 = discovery2.unwrap();
         
         // Try to register first device
-        if let Ok(_) = discovery1.register(29876) {
+        if discovery1.register(29876).is_ok() {
             // Start browsing on second device
-            if let Ok(_) = discovery2.start_browsing() {
+            if discovery2.start_browsing().is_ok() {
                 // Wait a bit for discovery
                 tokio::time::sleep(std::time::Duration::from_millis(500)).await;
                 
@@ -342,4 +356,19 @@ mod tests {
             println!("Could not register service - skipping integration test");
         }
     }
+
+    #[test]
+    fn test_format_addresses_stable_order() {
+        let addresses: Vec<IpAddr> = vec![
+            "fe80::1".parse().unwrap(),
+            "192.168.1.20".parse().unwrap(),
+            "fd00::2".parse().unwrap(),
+            "10.0.0.5".parse().unwrap(),
+        ];
+        assert_eq!(
+            format_addresses(&addresses),
+            vec!["10.0.0.5", "192.168.1.20", "fd00::2", "fe80::1"],
+            "IPv4 first, then IPv6, each sorted"
+        );
+    }
 }
@@ -8,11 +8,13 @@ mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be
 pub mod crypto;
 pub mod transport;
 pub mod discovery;
+pub mod signaling;
 
 // Re-export main functions for flutter_rust_bridge
 pub use crypto::*;
 pub use transport::*;
 pub use discovery::*;
+pub use signaling::*;
 
 
 /// Library initialization (called by Flutter)
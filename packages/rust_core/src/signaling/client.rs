@@ -0,0 +1,563 @@
+//! Signaling Client for SyncMist
+//!
+//! Devices on different networks meet at the hub in `apps/server`: a WebSocket
+//! service that admits devices holding a JWT from its `/register` endpoint and
+//! passes JSON messages between connected devices. Over it, devices announce
+//! their presence and exchange the candidate addresses of their QUIC sockets,
+//! which [`QuicTransport::use_signaling`] turns into punched connections.
+//!
+//! Messages keep the hub's `type`, `content` and `sender` fields. The hub
+//! passes announcements to every device and hands candidates and presence
+//! answers only to the device named in `target`. Deposits carry items sealed
+//! for the [mailbox](crate::transport::QuicTransport::use_signaling), which
+//! the hub hands to their target or keeps until it fetches them. The hub
+//! doesn't check `sender`, so everything received is only a hint, and
+//! messages for other devices are still ignored: connections require each
+//! device's pinned certificate.
+//!
+//! [`QuicTransport::use_signaling`]: crate::transport::QuicTransport::use_signaling
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use futures::{SinkExt, StreamExt};
+use rustls::ClientConfig;
+use rustls_platform_verifier::BuilderVerifierExt;
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpStream;
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{connect_async_tls_with_config, Connector, MaybeTlsStream, WebSocketStream};
+
+use crate::frb_generated::StreamSink;
+use crate::transport::events::EVENT_CAPACITY;
+
+/// Time allowed for reaching the hub and completing the WebSocket handshake
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Messages queued for the hub before senders wait
+const OUTGOING_CAPACITY: usize = 64;
+/// Largest message accepted; the hub itself stops at 512 KiB
+const MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+
+type HubStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Signaling errors
+#[derive(Debug)]
+#[flutter_rust_bridge::frb]
+pub enum SignalingError {
+    /// The hub could not be reached or the connection to it failed
+    Connection(String),
+    /// The token is malformed or expired, or the hub rejected it
+    Unauthorized(String),
+    /// The hub sent something that isn't valid WebSocket, or a message couldn't be encoded
+    Protocol(String),
+    /// The client was closed
+    Closed,
+}
+
+impl std::fmt::Display for SignalingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignalingError::Connection(e) => write!(f, "Connection error: {}", e),
+            SignalingError::Unauthorized(e) => write!(f, "Unauthorized: {}", e),
+            SignalingError::Protocol(e) => write!(f, "Protocol error: {}", e),
+            SignalingError::Closed => write!(f, "Signaling closed"),
+        }
+    }
+}
+
+impl std::error::Error for SignalingError {}
+
+/// A change in which devices are at the hub
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SignalingEvent {
+    /// A device announced itself, or answered our announcement
+    PeerOnline { device_id: String },
+    /// A device said it is leaving
+    PeerOffline { device_id: String },
+    /// The connection to the hub ended; connect again to resume
+    Disconnected { error: String },
+}
+
+/// A signaling message exchanged through the hub
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Signal {
+    /// A device came online or is leaving; a targeted presence answers an announcement
+    Presence { sender: String, online: bool, target: Option<String> },
+    /// Addresses `target` may reach `sender`'s QUIC socket at; a non-reply asks for the target's own
+    Candidates { sender: String, target: String, candidates: Vec<SocketAddr>, reply: bool },
+    /// An item sealed for `target`, kept by the hub for `ttl_secs` until the target fetches it
+    Deposit { sender: String, target: String, blob: String, ttl_secs: u64 },
+    /// Ask the hub for the items deposited for us
+    Fetch { sender: String },
+}
+
+impl Signal {
+    pub(crate) fn sender(&self) -> &str {
+        match self {
+            Signal::Presence { sender, .. }
+            | Signal::Candidates { sender, .. }
+            | Signal::Deposit { sender, .. }
+            | Signal::Fetch { sender } => sender,
+        }
+    }
+
+    fn target(&self) -> Option<&str> {
+        match self {
+            Signal::Presence { target, .. } => target.as_deref(),
+            Signal::Candidates { target, .. } | Signal::Deposit { target, .. } => Some(target),
+            Signal::Fetch { .. } => None,
+        }
+    }
+
+    fn encode(&self) -> Result<String, SignalingError> {
+        let (kind, content) = match self {
+            Signal::Presence { online, .. } => ("presence", if *online { "online" } else { "offline" }.to_string()),
+            Signal::Candidates { candidates, .. } => {
+                let candidates: Vec<String> = candidates.iter().map(SocketAddr::to_string).collect();
+                ("candidates", candidates.join(","))
+            }
+            Signal::Deposit { blob, .. } => ("deposit", blob.clone()),
+            Signal::Fetch { .. } => ("fetch", String::new()),
+        };
+        let message = HubMessage {
+            kind: kind.to_string(),
+            content,
+            sender: self.sender().to_string(),
+            target: self.target().map(str::to_string),
+            reply: matches!(self, Signal::Candidates { reply: true, .. }),
+            ttl: match self {
+                Signal::Deposit { ttl_secs, .. } => Some(*ttl_secs),
+                _ => None,
+            },
+        };
+        serde_json::to_string(&message).map_err(|e| SignalingError::Protocol(e.to_string()))
+    }
+
+    /// Decode a hub message, `None` for malformed messages and other types such as clipboard content
+    fn decode(message: &str) -> Option<Self> {
+        let HubMessage { kind, content, sender, target, reply, ttl } = serde_json::from_str(message).ok()?;
+        match kind.as_str() {
+            "presence" => Some(Signal::Presence {
+                sender,
+                online: match content.as_str() {
+                    "online" => true,
+                    "offline" => false,
+                    _ => return None,
+                },
+                target,
+            }),
+            "candidates" => Some(Signal::Candidates {
+                sender,
+                target: target?,
+                candidates: content.split(',').filter_map(|candidate| candidate.trim().parse().ok()).collect(),
+                reply,
+            }),
+            "deposit" => Some(Signal::Deposit { sender, target: target?, blob: content, ttl_secs: ttl.unwrap_or(0) }),
+            "fetch" => Some(Signal::Fetch { sender }),
+            _ => None,
+        }
+    }
+}
+
+/// A message in the hub's JSON format, which the Dart client's clipboard messages share
+#[derive(Debug, Serialize, Deserialize)]
+struct HubMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    content: String,
+    sender: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    reply: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ttl: Option<u64>,
+}
+
+/// Claims of a hub token that the client reads
+#[derive(Deserialize)]
+struct Claims {
+    #[serde(default)]
+    device_id: String,
+    exp: Option<f64>,
+}
+
+/// Device id a hub token was issued for, checking that it hasn't expired
+///
+/// The signature is left to the hub, which holds the secret.
+fn token_device_id(token: &str) -> Result<String, SignalingError> {
+    let malformed = || SignalingError::Unauthorized("Malformed token".to_string());
+    let mut parts = token.split('.');
+    let (Some(_), Some(claims), Some(_), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+        return Err(malformed());
+    };
+    let claims = URL_SAFE_NO_PAD.decode(claims.trim_end_matches('=')).map_err(|_| malformed())?;
+    let claims: Claims = serde_json::from_slice(&claims).map_err(|_| malformed())?;
+
+    if let Some(expiry) = claims.exp {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs_f64();
+        if expiry <= now {
+            return Err(SignalingError::Unauthorized("Token expired".to_string()));
+        }
+    }
+    if claims.device_id.is_empty() {
+        return Err(malformed());
+    }
+    Ok(claims.device_id)
+}
+
+/// Open a WebSocket to the hub, verifying `wss://` hubs against the platform's trust store
+async fn open(url: &str) -> Result<HubStream, SignalingError> {
+    let connector = if url.starts_with("wss://") {
+        let tls = ClientConfig::builder()
+            .with_platform_verifier()
+            .map_err(|e| SignalingError::Connection(format!("TLS error: {}", e)))?
+            .with_no_client_auth();
+        Some(Connector::Rustls(Arc::new(tls)))
+    } else {
+        None
+    };
+    let config = WebSocketConfig::default().max_message_size(Some(MAX_MESSAGE_SIZE));
+    let (stream, _) = connect_async_tls_with_config(url, Some(config), true, connector).await.map_err(|e| match e {
+        tungstenite::Error::Http(response) if matches!(response.status().as_u16(), 401 | 403) => {
+            SignalingError::Unauthorized(format!("Hub refused the token: {}", response.status()))
+        }
+        tungstenite::Error::Http(response) => {
+            SignalingError::Connection(format!("Hub refused the upgrade: {}", response.status()))
+        }
+        e => SignalingError::Connection(format!("Failed to connect to hub: {}", e)),
+    })?;
+    Ok(stream)
+}
+
+/// `url` with the token added to its query, as the hub expects it
+fn url_with_token(url: &str, token: &str) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}token={}", url, separator, token)
+}
+
+enum Outgoing {
+    Text(String),
+    /// Close the WebSocket, then signal the sender
+    Close(oneshot::Sender<()>),
+}
+
+/// Sending and receiving signals, shared with the transport
+#[derive(Clone)]
+pub(crate) struct SignalingChannel {
+    device_id: String,
+    outgoing: mpsc::Sender<Outgoing>,
+    signals: broadcast::Sender<Signal>,
+    online: Arc<Mutex<HashSet<String>>>,
+}
+
+impl SignalingChannel {
+    pub(crate) fn device_id(&self) -> &str {
+        &self.device_id
+    }
+
+    pub(crate) async fn send(&self, signal: Signal) -> Result<(), SignalingError> {
+        self.outgoing.send(Outgoing::Text(signal.encode()?)).await.map_err(|_| SignalingError::Closed)
+    }
+
+    /// Signals from other devices addressed to us or to everyone
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<Signal> {
+        self.signals.subscribe()
+    }
+
+    pub(crate) fn is_online(&self, device_id: &str) -> bool {
+        self.online.lock().unwrap().contains(device_id)
+    }
+
+    /// Devices currently at the hub, ordered by id
+    pub(crate) fn online(&self) -> Vec<String> {
+        let mut online: Vec<String> = self.online.lock().unwrap().iter().cloned().collect();
+        online.sort();
+        online
+    }
+}
+
+/// Connection to a signaling hub
+#[flutter_rust_bridge::frb]
+pub struct SignalingClient {
+    channel: SignalingChannel,
+    events: broadcast::Sender<SignalingEvent>,
+    task: JoinHandle<()>,
+}
+
+impl SignalingClient {
+    /// Connect to a hub and announce this device
+    ///
+    /// # Arguments
+    /// * `url` - WebSocket endpoint of the hub, e.g. `wss://hub.example.com/ws`
+    /// * `token` - JWT from the hub's `/register` endpoint; it names this device
+    #[flutter_rust_bridge::frb]
+    pub async fn connect(url: String, token: String) -> Result<Self, SignalingError> {
+        let device_id = token_device_id(&token)?;
+        let stream = tokio::time::timeout(CONNECT_TIMEOUT, open(&url_with_token(&url, &token)))
+            .await
+            .map_err(|_| SignalingError::Connection(format!("Connecting to {} timed out", url)))??;
+        println!("[Signaling] Connected to {} as {}", url, device_id);
+
+        let (outgoing, outgoing_rx) = mpsc::channel(OUTGOING_CAPACITY);
+        let (signals, _) = broadcast::channel(EVENT_CAPACITY);
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        let channel = SignalingChannel { device_id, outgoing, signals, online: Arc::new(Mutex::new(HashSet::new())) };
+        channel.send(Signal::Presence { sender: channel.device_id.clone(), online: true, target: None }).await?;
+        let task = tokio::spawn(run(stream, outgoing_rx, channel.clone(), events.clone()));
+        Ok(Self { channel, events, task })
+    }
+
+    /// Device id the token was issued for
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn device_id(&self) -> String {
+        self.channel.device_id.clone()
+    }
+
+    /// Devices at the hub that announced themselves since we connected, ordered by id
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn online_peers(&self) -> Vec<String> {
+        self.channel.online()
+    }
+
+    /// Forward presence changes to a Dart stream
+    #[flutter_rust_bridge::frb]
+    pub async fn signaling_events(&self, sink: StreamSink<SignalingEvent>) {
+        let mut events = self.events.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => {
+                        if sink.add(event).is_err() {
+                            println!("[Signaling] Event stream closed by listener");
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        println!("[Signaling] Event listener lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Subscribe to presence changes from Rust
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn subscribe_events(&self) -> broadcast::Receiver<SignalingEvent> {
+        self.events.subscribe()
+    }
+
+    /// Tell other devices we are leaving and close the connection to the hub
+    #[flutter_rust_bridge::frb]
+    pub async fn close(&self) {
+        let offline = Signal::Presence { sender: self.channel.device_id.clone(), online: false, target: None };
+        let _ = self.channel.send(offline).await;
+        let (done, closed) = oneshot::channel();
+        if self.channel.outgoing.send(Outgoing::Close(done)).await.is_ok() {
+            let _ = closed.await;
+        }
+    }
+
+    pub(crate) fn channel(&self) -> SignalingChannel {
+        self.channel.clone()
+    }
+}
+
+impl Drop for SignalingClient {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Pump messages between the hub and the channel until either side closes
+///
+/// Pings from the hub are answered by the WebSocket library as messages are read.
+async fn run(
+    stream: HubStream,
+    mut outgoing: mpsc::Receiver<Outgoing>,
+    channel: SignalingChannel,
+    events: broadcast::Sender<SignalingEvent>,
+) {
+    let (mut sink, mut stream) = stream.split();
+    let error = loop {
+        tokio::select! {
+            message = stream.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    // The hub joins messages that queued up with newlines
+                    let replies: Vec<Signal> = text.split('\n').filter_map(|message| receive(&channel, &events, message)).collect();
+                    let mut failed = None;
+                    for reply in replies {
+                        let sent = match reply.encode() {
+                            Ok(text) => sink.send(Message::text(text)).await.map_err(|e| e.to_string()),
+                            Err(e) => Err(e.to_string()),
+                        };
+                        if let Err(e) = sent {
+                            failed = Some(e);
+                            break;
+                        }
+                    }
+                    if let Some(error) = failed {
+                        break error;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | None => break "Hub closed the connection".to_string(),
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => break e.to_string(),
+            },
+            message = outgoing.recv() => match message {
+                Some(Outgoing::Text(text)) => {
+                    if let Err(e) = sink.send(Message::text(text)).await {
+                        break e.to_string();
+                    }
+                }
+                Some(Outgoing::Close(done)) => {
+                    let _ = sink.close().await;
+                    channel.online.lock().unwrap().clear();
+                    println!("[Signaling] Closed connection to hub");
+                    let _ = done.send(());
+                    return;
+                }
+                None => break "Client dropped".to_string(),
+            },
+        }
+    };
+
+    channel.online.lock().unwrap().clear();
+    println!("[Signaling] Disconnected from hub: {}", error);
+    let _ = events.send(SignalingEvent::Disconnected { error });
+}
+
+/// Track presence from a hub message and pass it to the transport
+///
+/// Returns the answer to send when another device announces itself.
+fn receive(channel: &SignalingChannel, events: &broadcast::Sender<SignalingEvent>, message: &str) -> Option<Signal> {
+    let signal = Signal::decode(message)?;
+    let sender = signal.sender().to_string();
+    if sender == channel.device_id || signal.target().is_some_and(|target| target != channel.device_id) {
+        return None;
+    }
+
+    // Deposits may have waited at the hub long after their sender left
+    let online = match signal {
+        Signal::Presence { online, .. } => Some(online),
+        Signal::Candidates { .. } => Some(true),
+        Signal::Deposit { .. } | Signal::Fetch { .. } => None,
+    };
+    if let Some(online) = online {
+        let changed = {
+            let mut devices = channel.online.lock().unwrap();
+            if online {
+                devices.insert(sender.clone())
+            } else {
+                devices.remove(&sender)
+            }
+        };
+        if changed {
+            println!("[Signaling] {} is {}", sender, if online { "online" } else { "offline" });
+            let event = if online {
+                SignalingEvent::PeerOnline { device_id: sender.clone() }
+            } else {
+                SignalingEvent::PeerOffline { device_id: sender.clone() }
+            };
+            let _ = events.send(event);
+        }
+    }
+
+    let announcement = matches!(signal, Signal::Presence { online: true, target: None, .. });
+    let _ = channel.signals.send(signal);
+    announcement.then(|| Signal::Presence { sender: channel.device_id.clone(), online: true, target: Some(sender) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signaling::test_hub::{issue_token, TestHub, SECRET};
+
+    #[test]
+    fn test_signal_roundtrip() {
+        let signals = [
+            Signal::Presence { sender: "laptop".to_string(), online: true, target: None },
+            Signal::Presence { sender: "laptop".to_string(), online: false, target: Some("phone".to_string()) },
+            Signal::Candidates {
+                sender: "laptop".to_string(),
+                target: "phone".to_string(),
+                candidates: vec!["203.0.113.7:61000".parse().unwrap(), "[2001:db8::5]:4000".parse().unwrap()],
+                reply: true,
+            },
+            Signal::Deposit {
+                sender: "laptop".to_string(),
+                target: "phone".to_string(),
+                blob: "U01TQgE=".to_string(),
+                ttl_secs: 86_400,
+            },
+            Signal::Fetch { sender: "phone".to_string() },
+        ];
+        for signal in signals {
+            assert_eq!(Signal::decode(&signal.encode().unwrap()), Some(signal));
+        }
+        // Clipboard messages from the Dart client share the hub
+        assert_eq!(Signal::decode(r#"{"type":"clipboard","content":"hi","sender":"phone","encrypted":false}"#), None);
+        assert_eq!(Signal::decode(r#"{"type":"presence","content":"away","sender":"phone"}"#), None);
+    }
+
+    #[test]
+    fn test_token_device_id() {
+        assert_eq!(token_device_id(&issue_token(SECRET, "laptop", 3600)).unwrap(), "laptop");
+        assert!(matches!(
+            token_device_id(&issue_token(SECRET, "laptop", -10)),
+            Err(SignalingError::Unauthorized(e)) if e.contains("expired")
+        ));
+        assert!(matches!(token_device_id("not-a-token"), Err(SignalingError::Unauthorized(_))));
+        assert_eq!(url_with_token("wss://hub/ws", "t"), "wss://hub/ws?token=t");
+        assert_eq!(url_with_token("ws://hub/ws?v=2", "t"), "ws://hub/ws?v=2&token=t");
+    }
+
+    async fn next_event(events: &mut broadcast::Receiver<SignalingEvent>) -> SignalingEvent {
+        tokio::time::timeout(Duration::from_secs(5), events.recv()).await.expect("Event should arrive").unwrap()
+    }
+
+    // Integration test: presence through a hub that behaves like the Go server
+    #[tokio::test]
+    async fn test_presence_through_hub() {
+        let hub = TestHub::bind().await;
+
+        let tampered = format!("{}x", hub.token("mallory", 3600));
+        assert!(matches!(
+            SignalingClient::connect(hub.url(), tampered).await,
+            Err(SignalingError::Unauthorized(_))
+        ));
+
+        let laptop = SignalingClient::connect(hub.url(), hub.token("laptop", 3600)).await.unwrap();
+        assert_eq!(laptop.device_id(), "laptop");
+        let mut laptop_events = laptop.subscribe_events();
+        let phone = SignalingClient::connect(hub.url(), hub.token("phone", 3600)).await.unwrap();
+        let mut phone_events = phone.subscribe_events();
+
+        // The laptop hears the announcement and answers it
+        assert_eq!(next_event(&mut laptop_events).await, SignalingEvent::PeerOnline { device_id: "phone".to_string() });
+        assert_eq!(next_event(&mut phone_events).await, SignalingEvent::PeerOnline { device_id: "laptop".to_string() });
+        assert_eq!(phone.online_peers(), vec!["laptop".to_string()]);
+
+        phone.close().await;
+        assert_eq!(next_event(&mut laptop_events).await, SignalingEvent::PeerOffline { device_id: "phone".to_string() });
+        assert!(laptop.online_peers().is_empty());
+        assert!(matches!(phone.channel().send(Signal::Presence {
+            sender: "phone".to_string(),
+            online: true,
+            target: None,
+        }).await, Err(SignalingError::Closed)));
+
+        drop(hub);
+        assert!(matches!(next_event(&mut laptop_events).await, SignalingEvent::Disconnected { .. }));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod client;
+#[cfg(test)]
+pub(crate) mod test_hub;
+pub use client::*;
@@ -0,0 +1,180 @@
+//! Test Hub for SyncMist Signaling
+//!
+//! Behaves like the Go hub in `apps/server` as far as clients can tell: it
+//! issues HS256 tokens, admits WebSockets whose `?token=` verifies, and passes
+//! each message to every other client, joining messages that queued up with
+//! newlines. Candidates and presence answers go only to their target, and
+//! deposits go to their target or wait in its mailbox until it fetches them.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use futures::{SinkExt, StreamExt};
+use ring::hmac;
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::Message;
+
+/// The hub's development secret
+pub(crate) const SECRET: &str = "dev-secret-change-in-production";
+
+/// Connection id, device id and queue of each client
+type Clients = Arc<Mutex<Vec<(u64, String, mpsc::UnboundedSender<String>)>>>;
+/// Deposits waiting for each device, with when they expire
+type Mailboxes = Arc<Mutex<HashMap<String, Vec<(String, Instant)>>>>;
+
+fn now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+}
+
+/// A token for `device_id` that expires `ttl` seconds from now
+pub(crate) fn issue_token(secret: &str, device_id: &str, ttl: i64) -> String {
+    let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#);
+    let claims = json!({ "device_id": device_id, "exp": now() + ttl, "iat": now() });
+    let signed = format!("{}.{}", header, URL_SAFE_NO_PAD.encode(claims.to_string()));
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let signature = hmac::sign(&key, signed.as_bytes());
+    format!("{}.{}", signed, URL_SAFE_NO_PAD.encode(signature.as_ref()))
+}
+
+/// Device id of a token with a valid signature that hasn't expired
+fn verify_token(secret: &str, token: &str) -> Option<String> {
+    let (signed, signature) = token.rsplit_once('.')?;
+    let (_, claims) = signed.split_once('.')?;
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    hmac::verify(&key, signed.as_bytes(), &URL_SAFE_NO_PAD.decode(signature).ok()?).ok()?;
+    let claims: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(claims).ok()?).ok()?;
+    if claims.get("exp")?.as_i64()? <= now() {
+        return None;
+    }
+    Some(claims.get("device_id")?.as_str()?.to_string())
+}
+
+pub(crate) struct TestHub {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl TestHub {
+    pub(crate) async fn bind() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        Self { address, task: tokio::spawn(serve(listener)) }
+    }
+
+    pub(crate) fn url(&self) -> String {
+        format!("ws://{}/ws", self.address)
+    }
+
+    pub(crate) fn token(&self, device_id: &str, ttl: i64) -> String {
+        issue_token(SECRET, device_id, ttl)
+    }
+}
+
+impl Drop for TestHub {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve(listener: TcpListener) {
+    let clients: Clients = Arc::new(Mutex::new(Vec::new()));
+    let mailboxes: Mailboxes = Arc::new(Mutex::new(HashMap::new()));
+    // Dropped with the hub, which disconnects every client
+    let mut connections = JoinSet::new();
+    let mut next_id = 0u64;
+    loop {
+        let Ok((tcp, _)) = listener.accept().await else { continue };
+        next_id += 1;
+        connections.spawn(handle(tcp, next_id, clients.clone(), mailboxes.clone()));
+    }
+}
+
+async fn handle(tcp: tokio::net::TcpStream, id: u64, clients: Clients, mailboxes: Mailboxes) {
+    let mut device_id = None;
+    // The response type is tungstenite's, not ours to shrink
+    #[allow(clippy::result_large_err)]
+    let authorize = |request: &Request, response: Response| -> Result<Response, ErrorResponse> {
+        let query = request.uri().query().unwrap_or_default();
+        let token = query.split('&').find_map(|pair| pair.strip_prefix("token="));
+        device_id = token.and_then(|token| verify_token(SECRET, token));
+        if device_id.is_none() {
+            let mut refused = ErrorResponse::new(None);
+            *refused.status_mut() = StatusCode::UNAUTHORIZED;
+            return Err(refused);
+        }
+        Ok(response)
+    };
+    let Ok(stream) = tokio_tungstenite::accept_hdr_async(tcp, authorize).await else { return };
+    let Some(device_id) = device_id else { return };
+    let (mut writer, mut reader) = stream.split();
+
+    let (queue, mut queued) = mpsc::unbounded_channel::<String>();
+    clients.lock().unwrap().push((id, device_id.clone(), queue));
+    let pump = tokio::spawn(async move {
+        while let Some(first) = queued.recv().await {
+            let mut batch = vec![first];
+            while let Ok(next) = queued.try_recv() {
+                batch.push(next);
+            }
+            if writer.send(Message::text(batch.join("\n"))).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    while let Some(Ok(message)) = reader.next().await {
+        match message {
+            Message::Text(text) => {
+                let text = text.to_string();
+                let Ok(message) = serde_json::from_str::<Value>(&text) else { continue };
+                let clients = clients.lock().unwrap();
+                let target = message.get("target").and_then(Value::as_str);
+                match message.get("type").and_then(Value::as_str) {
+                    Some("deposit") => {
+                        let Some(target) = target else { continue };
+                        let online: Vec<_> = clients.iter().filter(|(_, device, _)| device == target).collect();
+                        if online.is_empty() {
+                            let ttl = message.get("ttl").and_then(Value::as_f64).unwrap_or(0.0);
+                            let expiry = Instant::now() + Duration::from_secs_f64(ttl);
+                            mailboxes.lock().unwrap().entry(target.to_string()).or_default().push((text.clone(), expiry));
+                        }
+                        for (_, _, queue) in online {
+                            let _ = queue.send(text.clone());
+                        }
+                    }
+                    Some("fetch") => {
+                        let deposits = mailboxes.lock().unwrap().remove(&device_id).unwrap_or_default();
+                        let (_, _, queue) = clients.iter().find(|(other, _, _)| *other == id).unwrap();
+                        for (deposit, _) in deposits.into_iter().filter(|(_, expiry)| *expiry > Instant::now()) {
+                            let _ = queue.send(deposit);
+                        }
+                    }
+                    Some("candidates") | Some("presence") if target.is_some() => {
+                        let targets = clients.iter().filter(|(other, device, _)| *other != id && Some(device.as_str()) == target);
+                        for (_, _, queue) in targets {
+                            let _ = queue.send(text.clone());
+                        }
+                    }
+                    _ => {
+                        for (_, _, queue) in clients.iter().filter(|(other, _, _)| *other != id) {
+                            let _ = queue.send(text.clone());
+                        }
+                    }
+                }
+            }
+            Message::Close(_) => break,
+            _ => {}
+        }
+    }
+    clients.lock().unwrap().retain(|(other, _, _)| *other != id);
+    pump.abort();
+}
@@ -0,0 +1,140 @@
+//! Peer Address Handling for SyncMist
+//!
+//! Addresses arrive as strings from mDNS, pairing payloads and the app. IPv6
+//! link-local addresses are only reachable through a specific interface, so
+//! they may carry a zone (`fe80::1%eth0` or `fe80::1%2`); without one, every
+//! interface with a link-local address is a candidate.
+
+use std::net::{IpAddr, SocketAddr, SocketAddrV6};
+
+use super::quic::TransportError;
+
+/// Parse an IP address with an optional IPv6 zone, e.g. `10.0.0.2`, `fe80::1%eth0` or `[fe80::1%3]`
+///
+/// Returns the address and its scope id (0 when there is no zone).
+pub(crate) fn parse_ip(addr: &str) -> Result<(IpAddr, u32), TransportError> {
+    let invalid = |what: &str| TransportError::Connection(format!("Invalid address {}: {}", addr, what));
+
+    let trimmed = addr.trim();
+    let trimmed = trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(trimmed);
+    let (ip, zone) = match trimmed.split_once('%') {
+        Some((ip, zone)) => (ip, Some(zone)),
+        None => (trimmed, None),
+    };
+    let ip: IpAddr = ip.parse().map_err(|e| invalid(&format!("{}", e)))?;
+
+    let scope_id = match (ip, zone) {
+        (_, None) => 0,
+        (IpAddr::V4(_), Some(_)) => return Err(invalid("zones only apply to IPv6")),
+        (IpAddr::V6(_), Some(zone)) => match zone.parse::<u32>() {
+            Ok(index) => index,
+            Err(_) => interface_index(zone).ok_or_else(|| invalid(&format!("unknown interface {}", zone)))?,
+        },
+    };
+    Ok((ip, scope_id))
+}
+
+/// Socket addresses to try for an IP string and port
+///
+/// A link-local IPv6 address without a zone expands to one address per local
+/// interface that has an IPv6 link-local address.
+pub(crate) fn resolve_addresses(addr: &str, port: u16) -> Result<Vec<SocketAddr>, TransportError> {
+    let (ip, scope_id) = parse_ip(addr)?;
+    match ip {
+        IpAddr::V6(v6) if v6.is_unicast_link_local() && scope_id == 0 => {
+            let scopes = link_local_scopes();
+            if scopes.is_empty() {
+                return Ok(vec![SocketAddr::new(ip, port)]);
+            }
+            Ok(scopes
+                .into_iter()
+                .map(|scope| SocketAddr::V6(SocketAddrV6::new(v6, port, 0, scope)))
+                .collect())
+        }
+        IpAddr::V6(v6) => Ok(vec![SocketAddr::V6(SocketAddrV6::new(v6, port, 0, scope_id))]),
+        IpAddr::V4(_) => Ok(vec![SocketAddr::new(ip, port)]),
+    }
+}
+
+/// Format an address and port as `ip:port` or `[ipv6%zone]:port`
+#[flutter_rust_bridge::frb(sync)]
+pub fn format_peer_address(addr: String, port: u16) -> Result<String, TransportError> {
+    let (ip, scope_id) = parse_ip(&addr)?;
+    Ok(match ip {
+        IpAddr::V6(v6) => SocketAddrV6::new(v6, port, 0, scope_id).to_string(),
+        IpAddr::V4(_) => SocketAddr::new(ip, port).to_string(),
+    })
+}
+
+/// Index of a network interface by name
+fn interface_index(name: &str) -> Option<u32> {
+    if_addrs::get_if_addrs()
+        .ok()?
+        .into_iter()
+        .find(|interface| interface.name == name)
+        .and_then(|interface| interface.index)
+}
+
+/// Indexes of interfaces that have an IPv6 link-local address
+fn link_local_scopes() -> Vec<u32> {
+    let mut scopes: Vec<u32> = if_addrs::get_if_addrs()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|interface| matches!(interface.ip(), IpAddr::V6(v6) if v6.is_unicast_link_local()))
+        .filter_map(|interface| interface.index)
+        .collect();
+    scopes.sort_unstable();
+    scopes.dedup();
+    scopes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ip_forms() {
+        assert_eq!(parse_ip("192.168.1.5").unwrap(), ("192.168.1.5".parse().unwrap(), 0));
+        assert_eq!(parse_ip("fd00::2").unwrap(), ("fd00::2".parse().unwrap(), 0));
+        assert_eq!(parse_ip("[fd00::2]").unwrap(), ("fd00::2".parse().unwrap(), 0));
+        assert_eq!(parse_ip("fe80::1%3").unwrap(), ("fe80::1".parse().unwrap(), 3));
+        assert_eq!(parse_ip("[fe80::1%7]").unwrap(), ("fe80::1".parse().unwrap(), 7));
+
+        assert!(parse_ip("10.0.0.1%2").is_err(), "IPv4 has no zones");
+        assert!(parse_ip("fe80::1%no-such-interface0").is_err());
+        assert!(parse_ip("not an address").is_err());
+    }
+
+    #[test]
+    fn test_parse_ip_interface_name() {
+        let Some(interface) = if_addrs::get_if_addrs().unwrap().into_iter().find(|i| i.index.is_some()) else {
+            return;
+        };
+        let (_, scope_id) = parse_ip(&format!("fe80::1%{}", interface.name)).unwrap();
+        assert_eq!(Some(scope_id), interface.index);
+    }
+
+    #[test]
+    fn test_format_peer_address() {
+        assert_eq!(format_peer_address("fe80::1%4".to_string(), 9876).unwrap(), "[fe80::1%4]:9876");
+        assert_eq!(format_peer_address("fd00::2".to_string(), 9876).unwrap(), "[fd00::2]:9876");
+        assert_eq!(format_peer_address("10.1.2.3".to_string(), 9876).unwrap(), "10.1.2.3:9876");
+    }
+
+    #[test]
+    fn test_resolve_addresses() {
+        assert_eq!(resolve_addresses("10.1.2.3", 80).unwrap(), vec!["10.1.2.3:80".parse().unwrap()]);
+        assert_eq!(resolve_addresses("fe80::1%5", 80).unwrap(), vec!["[fe80::1%5]:80".parse().unwrap()]);
+
+        // Unscoped link-local addresses are tried on each candidate interface
+        let resolved = resolve_addresses("fe80::1", 80).unwrap();
+        assert!(!resolved.is_empty());
+        for addr in resolved {
+            assert_eq!(addr.ip(), "fe80::1".parse::<IpAddr>().unwrap());
+            assert_eq!(addr.port(), 80);
+        }
+    }
+}
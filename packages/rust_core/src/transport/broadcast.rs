@@ -0,0 +1,158 @@
+//! Broadcast for SyncMist
+//!
+//! Sends one clipboard item to many peers in a single call. The registry is
+//! locked once to take a snapshot of the connections, the item is encrypted
+//! once for each distinct key, and the sends run concurrently so a slow peer
+//! doesn't hold up the others.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures::future::join_all;
+use quinn::Connection;
+
+use super::protocol::{send_on_new_stream, SyncMessage};
+use super::shutdown::Drain;
+use crate::crypto::encrypt_bytes;
+
+/// Which connected devices a broadcast goes to
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BroadcastFilter {
+    /// Devices to send to; empty means every connected device
+    pub include: Vec<String>,
+    /// Devices to leave out, such as the one the item came from
+    pub exclude: Vec<String>,
+}
+
+impl BroadcastFilter {
+    pub(crate) fn matches(&self, peer_id: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|id| id == peer_id);
+        included && !self.exclude.iter().any(|id| id == peer_id)
+    }
+}
+
+/// A clipboard item to broadcast, whose payload is encrypted separately for each peer
+#[derive(Clone, Debug)]
+pub(crate) struct BroadcastItem {
+    pub(crate) item_id: u64,
+    pub(crate) content_type: String,
+    pub(crate) timestamp: u64,
+    pub(crate) payload: Vec<u8>,
+}
+
+impl BroadcastItem {
+    /// Item with a fresh random id, stamped with the current time
+    pub(crate) fn new(content_type: String, payload: Vec<u8>) -> Self {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        Self { item_id: rand::random(), content_type, timestamp, payload }
+    }
+
+    /// The clipboard item one peer receives, carrying the payload encrypted for it
+    pub(crate) fn message(&self, ciphertext: Vec<u8>) -> SyncMessage {
+        SyncMessage::ClipboardItem {
+            item_id: self.item_id,
+            content_type: self.content_type.clone(),
+            timestamp: self.timestamp,
+            payload: ciphertext,
+        }
+    }
+}
+
+/// What happened to a broadcast item for one peer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BroadcastOutcome {
+    /// The item was sent on its own stream
+    Sent,
+    /// The device was asked for by the filter but isn't connected
+    NotConnected,
+    /// No encryption key is set for the device, so nothing was sent
+    NoKey,
+    /// The device is offline; the item was sealed for it and left at the signaling hub
+    Deposited,
+    /// Encrypting or sending failed
+    Failed { error: String },
+}
+
+/// Encrypt the item's payload for each matching peer and send it to all of them at once
+///
+/// Every peer gets the same item id and timestamp. `keys` maps device ids to
+/// the AES-256 key shared with that device.
+pub(crate) async fn send_to_all(
+    connections: Vec<(String, Connection)>,
+    keys: &HashMap<String, Vec<u8>>,
+    filter: &BroadcastFilter,
+    item: &BroadcastItem,
+    max_size: usize,
+    drain: &Drain,
+) -> HashMap<String, BroadcastOutcome> {
+    let mut outcomes: HashMap<String, BroadcastOutcome> = filter
+        .include
+        .iter()
+        .filter(|id| filter.matches(id))
+        .map(|id| (id.clone(), BroadcastOutcome::NotConnected))
+        .collect();
+
+    let mut encrypted: HashMap<&[u8], Result<Vec<u8>, String>> = HashMap::new();
+    let mut sends = Vec::new();
+    for (peer_id, connection) in connections.into_iter().filter(|(peer_id, _)| filter.matches(peer_id)) {
+        let Some(key) = keys.get(&peer_id) else {
+            outcomes.insert(peer_id, BroadcastOutcome::NoKey);
+            continue;
+        };
+        let ciphertext = encrypted
+            .entry(key.as_slice())
+            .or_insert_with(|| encrypt_bytes(item.payload.clone(), key.clone()));
+        let message = match ciphertext {
+            Ok(ciphertext) => item.message(ciphertext.clone()),
+            Err(e) => {
+                outcomes.insert(peer_id, BroadcastOutcome::Failed { error: e.clone() });
+                continue;
+            }
+        };
+        let guard = match drain.begin() {
+            Ok(guard) => guard,
+            Err(e) => {
+                outcomes.insert(peer_id, BroadcastOutcome::Failed { error: e.to_string() });
+                continue;
+            }
+        };
+        sends.push(async move {
+            let result = send_on_new_stream(&connection, &message, max_size).await;
+            (peer_id, result.map(|send| guard.release_when_acknowledged(send)))
+        });
+    }
+
+    println!("[QUIC] Broadcasting item {} to {} peers", item.item_id, sends.len());
+    for (peer_id, result) in join_all(sends).await {
+        let outcome = match result {
+            Ok(()) => BroadcastOutcome::Sent,
+            Err(e) => {
+                println!("[QUIC] Broadcast to {} failed: {}", peer_id, e);
+                BroadcastOutcome::Failed { error: e.to_string() }
+            }
+        };
+        outcomes.insert(peer_id, outcome);
+    }
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let everyone = BroadcastFilter::default();
+        assert!(everyone.matches("phone"));
+
+        let filter = BroadcastFilter {
+            include: vec!["phone".to_string(), "tablet".to_string()],
+            exclude: vec!["tablet".to_string()],
+        };
+        assert!(filter.matches("phone"));
+        assert!(!filter.matches("tablet"), "Exclusion wins over inclusion");
+        assert!(!filter.matches("laptop"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Candidate Exchange for SyncMist
+//!
+//! Hole punching over a [signaling hub](crate::signaling) instead of a
+//! rendezvous service. When a paired device comes online, the one with the
+//! smaller id offers the addresses its QUIC socket can be reached at: the
+//! public address learned from STUN or a rendezvous service, then its interface
+//! addresses. The other answers with its own, and both start
+//! [hole punching](super::holepunch).
+//!
+//! Offers from unpaired devices are ignored, so the hub can't make us probe
+//! arbitrary addresses on behalf of strangers.
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::events::wait_for_peer;
+use super::holepunch::{punch, PUNCH_TIMEOUT};
+use super::quic::{Dialer, TransportError};
+use super::relay::is_relayed;
+use super::rendezvous::local_candidates;
+use super::socket::RawSocket;
+use super::trust::TrustStore;
+use crate::signaling::client::{Signal, SignalingChannel};
+
+/// Time allowed for the peer to answer an offer through the hub
+const ANSWER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Candidate exchange over a signaling hub, shared with its background task
+#[derive(Clone)]
+pub(crate) struct CandidateExchange {
+    channel: SignalingChannel,
+    dialer: Dialer,
+    raw: RawSocket,
+    trust_store: TrustStore,
+    /// Our address as seen from outside the NAT, shared with the transport
+    public_address: Arc<Mutex<Option<SocketAddr>>>,
+    /// Peers we are punching to, so repeated offers don't start another attempt
+    punching: Arc<Mutex<HashSet<String>>>,
+}
+
+impl CandidateExchange {
+    pub(crate) fn new(
+        channel: SignalingChannel,
+        dialer: Dialer,
+        raw: RawSocket,
+        trust_store: TrustStore,
+        public_address: Arc<Mutex<Option<SocketAddr>>>,
+    ) -> Self {
+        Self { channel, dialer, raw, trust_store, public_address, punching: Arc::new(Mutex::new(HashSet::new())) }
+    }
+
+    fn is_paired(&self, device_id: &str) -> bool {
+        self.trust_store.is_paired(device_id)
+    }
+
+    /// Whether `peer_id` is connected other than through a relay
+    async fn is_direct(&self, peer_id: &str) -> bool {
+        let connection = self.dialer.registry().connection(peer_id).await;
+        connection.is_some_and(|connection| !is_relayed(connection.remote_address()))
+    }
+
+    fn candidates(&self) -> Result<Vec<SocketAddr>, TransportError> {
+        let mut candidates: Vec<SocketAddr> = self.public_address.lock().unwrap().iter().copied().collect();
+        for candidate in local_candidates(self.raw.local_addr()?) {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+        Ok(candidates)
+    }
+
+    async fn offer(&self, peer_id: &str, reply: bool) -> Result<(), TransportError> {
+        let signal = Signal::Candidates {
+            sender: self.channel.device_id().to_string(),
+            target: peer_id.to_string(),
+            candidates: self.candidates()?,
+            reply,
+        };
+        self.channel.send(signal).await.map_err(|e| TransportError::Connection(e.to_string()))
+    }
+
+    /// Offer candidates to paired devices already online
+    pub(crate) async fn offer_to_online(&self) {
+        for peer_id in self.channel.online() {
+            if self.should_offer(&peer_id).await {
+                let _ = self.offer(&peer_id, false).await;
+            }
+        }
+    }
+
+    /// Only the device with the smaller id offers, so the two don't offer at once
+    async fn should_offer(&self, peer_id: &str) -> bool {
+        self.channel.device_id() < peer_id && self.is_paired(peer_id) && !self.is_direct(peer_id).await
+    }
+
+    /// Answer presence and offers from the hub
+    pub(crate) fn spawn(&self) -> JoinHandle<()> {
+        let exchange = self.clone();
+        let mut signals = self.channel.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match signals.recv().await {
+                    Ok(Signal::Presence { sender, online: true, .. }) => {
+                        if exchange.should_offer(&sender).await {
+                            let _ = exchange.offer(&sender, false).await;
+                        }
+                    }
+                    Ok(Signal::Candidates { sender, candidates, reply, .. }) => {
+                        if !exchange.is_paired(&sender) {
+                            println!("[QUIC] Ignoring candidates from unpaired device {}", sender);
+                            continue;
+                        }
+                        if !reply {
+                            let _ = exchange.offer(&sender, true).await;
+                        }
+                        exchange.punch(sender, candidates);
+                    }
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    fn punch(&self, peer_id: String, candidates: Vec<SocketAddr>) {
+        if candidates.is_empty() || !self.punching.lock().unwrap().insert(peer_id.clone()) {
+            return;
+        }
+        println!("[QUIC] Signaling exchanged candidates with {}: {:?}", peer_id, candidates);
+        let exchange = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = punch(&exchange.dialer, &exchange.raw, &peer_id, &candidates).await {
+                println!("[QUIC] Hole punching to {} failed: {}", peer_id, e);
+            }
+            exchange.punching.lock().unwrap().remove(&peer_id);
+        });
+    }
+
+    /// Offer candidates to a device at the hub and wait for the punched connection
+    pub(crate) async fn connect(&self, peer_id: &str) -> Result<String, TransportError> {
+        if !self.channel.is_online(peer_id) {
+            return Err(TransportError::PeerNotFound(peer_id.to_string()));
+        }
+        let mut events = self.dialer.registry().subscribe();
+        if self.is_direct(peer_id).await {
+            return Ok(peer_id.to_string());
+        }
+        self.offer(peer_id, false).await?;
+        tokio::time::timeout(ANSWER_TIMEOUT + PUNCH_TIMEOUT, wait_for_peer(&mut events, peer_id, true))
+            .await
+            .ok()
+            .filter(|connected| *connected)
+            .map(|_| peer_id.to_string())
+            .ok_or_else(|| TransportError::Connection(format!("No path to {} found through signaling", peer_id)))
+    }
+}
@@ -0,0 +1,293 @@
+//! Datagram Link Monitoring for SyncMist
+//!
+//! Heartbeats and presence updates are useless once stale, so instead of
+//! streams they travel as unreliable QUIC datagrams holding one message frame
+//! each. Every connection sends a `Ping` datagram per heartbeat interval; the
+//! `Pong` gives a round-trip sample, and the share of recent heartbeats left
+//! unanswered estimates packet loss. Presence is re-announced every few
+//! heartbeats in case an update was lost.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use quinn::Connection;
+use tokio::sync::broadcast;
+
+use super::events::EVENT_CAPACITY;
+use super::options::TransportOptions;
+use super::protocol::SyncMessage;
+
+/// Heartbeats considered when estimating loss
+const HEARTBEAT_WINDOW: usize = 20;
+
+/// Presence is repeated every this many heartbeats
+const PRESENCE_REFRESH_TICKS: u32 = 5;
+
+/// What a user is doing on a device, shown to its peers
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Activity {
+    #[default]
+    Idle,
+    Typing,
+    Copying,
+}
+
+impl Activity {
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            Activity::Idle => 0,
+            Activity::Typing => 1,
+            Activity::Copying => 2,
+        }
+    }
+
+    /// Activities added by newer peers are shown as idle
+    pub(crate) fn from_code(code: u8) -> Self {
+        match code {
+            1 => Activity::Typing,
+            2 => Activity::Copying,
+            _ => Activity::Idle,
+        }
+    }
+}
+
+/// Live quality of the link to a peer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkQuality {
+    pub peer_id: String,
+    /// Smoothed heartbeat round-trip time in milliseconds, `None` until the first reply
+    pub rtt_ms: Option<f64>,
+    /// Share of recent heartbeats left unanswered, from 0.0 to 1.0
+    pub loss: f64,
+    /// Milliseconds since the last datagram from the peer
+    pub last_heard_ms: u64,
+    /// What the peer last said it is doing
+    pub activity: Activity,
+}
+
+/// A datagram-based update about a peer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinkEvent {
+    /// Published after every heartbeat sent to the peer
+    QualityChanged { quality: LinkQuality },
+    /// The peer reported a different activity
+    ActivityChanged { peer_id: String, activity: Activity },
+}
+
+struct LinkState {
+    /// Connection the state belongs to, so a replaced connection can't remove it
+    connection_id: usize,
+    srtt: Option<Duration>,
+    /// Recent heartbeats as (nonce, sent at, answered), oldest first
+    heartbeats: VecDeque<(u64, Instant, bool)>,
+    last_heard: Instant,
+    activity: Activity,
+}
+
+impl LinkState {
+    fn quality(&self, peer_id: &str) -> LinkQuality {
+        // The newest heartbeat can't have been answered yet
+        let settled = self.heartbeats.len().saturating_sub(1);
+        let lost = self.heartbeats.iter().take(settled).filter(|(_, _, answered)| !answered).count();
+        LinkQuality {
+            peer_id: peer_id.to_string(),
+            rtt_ms: self.srtt.map(|rtt| rtt.as_secs_f64() * 1000.0),
+            loss: if settled == 0 { 0.0 } else { lost as f64 / settled as f64 },
+            last_heard_ms: self.last_heard.elapsed().as_millis() as u64,
+            activity: self.activity,
+        }
+    }
+
+    /// Record a reply, returning `false` for unknown or repeated nonces
+    fn answer(&mut self, nonce: u64) -> bool {
+        let Some(heartbeat) = self.heartbeats.iter_mut().find(|(sent, _, answered)| *sent == nonce && !answered) else {
+            return false;
+        };
+        heartbeat.2 = true;
+        let sample = heartbeat.1.elapsed();
+        // Same smoothing as TCP's SRTT (RFC 6298)
+        self.srtt = Some(self.srtt.map_or(sample, |srtt| srtt * 7 / 8 + sample / 8));
+        true
+    }
+}
+
+/// Heartbeat and presence state of every connected peer
+#[derive(Clone)]
+pub(crate) struct Links {
+    states: Arc<Mutex<HashMap<String, LinkState>>>,
+    local_activity: Arc<Mutex<Activity>>,
+    events: broadcast::Sender<LinkEvent>,
+    /// Time between heartbeats, `None` if disabled
+    interval: Option<Duration>,
+}
+
+impl Links {
+    pub(crate) fn new(options: &TransportOptions) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            states: Arc::new(Mutex::new(HashMap::new())),
+            local_activity: Arc::new(Mutex::new(Activity::Idle)),
+            events,
+            interval: (options.heartbeat_interval_ms > 0).then(|| Duration::from_millis(options.heartbeat_interval_ms)),
+        }
+    }
+
+    pub(crate) fn quality(&self, peer_id: &str) -> Option<LinkQuality> {
+        self.states.lock().unwrap().get(peer_id).map(|state| state.quality(peer_id))
+    }
+
+    /// Change our activity and announce it on the given connections
+    pub(crate) fn set_activity(&self, activity: Activity, connections: &[Connection]) {
+        *self.local_activity.lock().unwrap() = activity;
+        for connection in connections {
+            send(connection, &SyncMessage::Presence { activity });
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<LinkEvent> {
+        self.events.subscribe()
+    }
+
+    /// Start heartbeats on a newly registered connection and handle its datagrams until it closes
+    pub(crate) fn spawn(&self, peer_id: String, connection: Connection) {
+        let state = LinkState {
+            connection_id: connection.stable_id(),
+            srtt: None,
+            heartbeats: VecDeque::with_capacity(HEARTBEAT_WINDOW + 1),
+            last_heard: Instant::now(),
+            activity: Activity::Idle,
+        };
+        self.states.lock().unwrap().insert(peer_id.clone(), state);
+
+        let links = self.clone();
+        tokio::spawn(async move {
+            let activity = *links.local_activity.lock().unwrap();
+            send(&connection, &SyncMessage::Presence { activity });
+
+            let mut heartbeat = tokio::time::interval(links.interval.unwrap_or(Duration::from_secs(3600)));
+            heartbeat.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut ticks: u32 = 0;
+            loop {
+                tokio::select! {
+                    datagram = connection.read_datagram() => match datagram {
+                        Ok(datagram) => links.handle(&peer_id, &connection, &datagram),
+                        Err(_) => break,
+                    },
+                    _ = heartbeat.tick(), if links.interval.is_some() => {
+                        ticks = ticks.wrapping_add(1);
+                        links.heartbeat(&peer_id, &connection, ticks);
+                    }
+                }
+            }
+
+            let mut states = links.states.lock().unwrap();
+            if link_state(&mut states, &peer_id, &connection).is_some() {
+                states.remove(&peer_id);
+            }
+        });
+    }
+
+    fn heartbeat(&self, peer_id: &str, connection: &Connection, ticks: u32) {
+        let nonce = rand::random();
+        let quality = {
+            let mut states = self.states.lock().unwrap();
+            let Some(state) = link_state(&mut states, peer_id, connection) else { return };
+            state.heartbeats.push_back((nonce, Instant::now(), false));
+            if state.heartbeats.len() > HEARTBEAT_WINDOW {
+                state.heartbeats.pop_front();
+            }
+            state.quality(peer_id)
+        };
+        send(connection, &SyncMessage::Ping { nonce });
+        if ticks.is_multiple_of(PRESENCE_REFRESH_TICKS) {
+            let activity = *self.local_activity.lock().unwrap();
+            send(connection, &SyncMessage::Presence { activity });
+        }
+        let _ = self.events.send(LinkEvent::QualityChanged { quality });
+    }
+
+    fn handle(&self, peer_id: &str, connection: &Connection, datagram: &[u8]) {
+        let message = match SyncMessage::decode(datagram) {
+            Ok(message) => message,
+            Err(e) => {
+                println!("[QUIC] Bad datagram from {}: {}", peer_id, e);
+                return;
+            }
+        };
+
+        let mut states = self.states.lock().unwrap();
+        let Some(state) = link_state(&mut states, peer_id, connection) else { return };
+        state.last_heard = Instant::now();
+        match message {
+            SyncMessage::Ping { nonce } => send(connection, &SyncMessage::Pong { nonce }),
+            SyncMessage::Pong { nonce } => {
+                state.answer(nonce);
+            }
+            SyncMessage::Presence { activity } => {
+                if state.activity != activity {
+                    state.activity = activity;
+                    let _ = self.events.send(LinkEvent::ActivityChanged { peer_id: peer_id.to_string(), activity });
+                }
+            }
+            other => println!("[QUIC] Ignoring {} datagram from {}", other.kind(), peer_id),
+        }
+    }
+}
+
+/// State of a peer's link, unless `connection` has been replaced by a newer one
+fn link_state<'a>(
+    states: &'a mut HashMap<String, LinkState>,
+    peer_id: &str,
+    connection: &Connection,
+) -> Option<&'a mut LinkState> {
+    states.get_mut(peer_id).filter(|state| state.connection_id == connection.stable_id())
+}
+
+/// Send a message as a datagram, dropping it if the peer can't take datagrams
+fn send(connection: &Connection, message: &SyncMessage) {
+    let _ = connection.send_datagram(message.encode().into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rtt_and_loss_estimates() {
+        let start = Instant::now();
+        let mut state = LinkState {
+            connection_id: 0,
+            srtt: None,
+            heartbeats: VecDeque::new(),
+            last_heard: start,
+            activity: Activity::Idle,
+        };
+        assert_eq!(state.quality("phone").loss, 0.0);
+        assert_eq!(state.quality("phone").rtt_ms, None);
+
+        for nonce in 1..=5 {
+            state.heartbeats.push_back((nonce, start, false));
+        }
+        assert!(state.answer(1));
+        assert!(state.answer(2));
+        assert!(!state.answer(2), "Repeated replies are ignored");
+        assert!(!state.answer(99), "Unknown nonces are ignored");
+
+        // Of the four settled heartbeats, 3 and 4 went unanswered
+        let quality = state.quality("phone");
+        assert_eq!(quality.loss, 0.5);
+        assert!(quality.rtt_ms.is_some());
+    }
+
+    #[test]
+    fn test_activity_codes() {
+        for activity in [Activity::Idle, Activity::Typing, Activity::Copying] {
+            assert_eq!(Activity::from_code(activity.code()), activity);
+        }
+        assert_eq!(Activity::from_code(200), Activity::Idle);
+    }
+}
@@ -0,0 +1,184 @@
+//! Delivery Acknowledgements for SyncMist
+//!
+//! A clipboard item sent with an acknowledgement travels on its own
+//! bidirectional stream. The receiver hands it to the app like any other item
+//! and keeps the stream open until the app reports whether it applied the item,
+//! then answers with an `Ack` or an `Error` carrying the reason. The sender
+//! waits for that answer up to a timeout and gives up by cancelling the stream.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use quinn::{Connection, SendStream};
+use tokio::sync::{mpsc, oneshot};
+
+use super::protocol::{error_code, read_message, write_message, SyncMessage, STREAM_CANCELLED};
+use super::quic::TransportError;
+use super::receiver::ReceivedItem;
+
+/// Longest the receiver keeps a stream open waiting for the app's decision
+const DECISION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Outcome of sending a clipboard item that the peer has to acknowledge
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeliveryResult {
+    /// The peer applied the item
+    Delivered { item_id: u64 },
+    /// The peer received the item but could not or would not apply it
+    Rejected { item_id: u64, reason: String },
+    /// No answer arrived before the timeout
+    TimedOut { item_id: u64 },
+}
+
+/// Answers the receive task waiting on an item, `Err` carrying the rejection reason
+type Decision = oneshot::Sender<Result<(), String>>;
+
+/// App decisions awaited by receive tasks, keyed by peer id and item id
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Default)]
+pub(crate) struct PendingAcks {
+    pub(super) waiting: Arc<Mutex<HashMap<(String, u64), Decision>>>,
+}
+
+impl PendingAcks {
+    fn wait(&self, peer_id: &str, item_id: u64) -> oneshot::Receiver<Result<(), String>> {
+        let (decide, decision) = oneshot::channel();
+        self.waiting.lock().unwrap().insert((peer_id.to_string(), item_id), decide);
+        decision
+    }
+
+    /// Answer an item, `Err` carrying the reason it was rejected
+    ///
+    /// Returns `false` if no item from that peer is waiting for an answer.
+    pub(crate) fn resolve(&self, peer_id: &str, item_id: u64, verdict: Result<(), String>) -> bool {
+        let decide = self.waiting.lock().unwrap().remove(&(peer_id.to_string(), item_id));
+        decide.is_some_and(|decide| decide.send(verdict).is_ok())
+    }
+
+    /// Drop an item nobody is waiting for any more, leaving a resend of it alone
+    fn forget(&self, peer_id: &str, item_id: u64) {
+        let key = (peer_id.to_string(), item_id);
+        let mut waiting = self.waiting.lock().unwrap();
+        if waiting.get(&key).is_some_and(|decide| decide.is_closed()) {
+            waiting.remove(&key);
+        }
+    }
+}
+
+/// Send a clipboard item and wait for the peer to acknowledge it
+///
+/// `timeout` covers the whole exchange, including writing the item.
+pub(crate) async fn send_with_ack(
+    connection: &Connection,
+    message: &SyncMessage,
+    max_size: usize,
+    timeout: Duration,
+) -> Result<DeliveryResult, TransportError> {
+    let SyncMessage::ClipboardItem { item_id, .. } = message else {
+        return Err(TransportError::Protocol(format!("{} can't be acknowledged", message.kind())));
+    };
+    let item_id = *item_id;
+
+    // Opened inside the timed exchange, as opening waits while the peer's stream limit is reached
+    let mut streams = None;
+    let exchange = async {
+        let (send, recv) = streams.insert(
+            connection
+                .open_bi()
+                .await
+                .map_err(|e| TransportError::Connection(format!("Failed to open stream: {}", e)))?,
+        );
+        write_message(send, message, max_size).await?;
+        send.finish()
+            .map_err(|e| TransportError::Io(format!("Failed to finish stream: {}", e)))?;
+        read_message(recv, max_size).await
+    };
+    let result = tokio::time::timeout(timeout, exchange).await;
+
+    match result {
+        Ok(Ok(SyncMessage::Ack { item_id: acked })) if acked == item_id => Ok(DeliveryResult::Delivered { item_id }),
+        Ok(Ok(SyncMessage::Error { message, .. })) => Ok(DeliveryResult::Rejected { item_id, reason: message }),
+        Ok(Ok(other)) => Err(TransportError::Protocol(format!(
+            "Expected Ack for item {}, got {}",
+            item_id,
+            other.kind()
+        ))),
+        Ok(Err(e)) => Err(e),
+        Err(_) => {
+            // Let the receiver stop waiting for the app
+            if let Some((mut send, mut recv)) = streams {
+                let _ = send.reset(STREAM_CANCELLED);
+                let _ = recv.stop(STREAM_CANCELLED);
+            }
+            Ok(DeliveryResult::TimedOut { item_id })
+        }
+    }
+}
+
+/// Hand an item to the app and answer the sender once the app has decided
+///
+/// Gives up without answering if the sender cancels the stream first.
+pub(crate) async fn receive_with_ack(
+    peer_id: String,
+    mut send: SendStream,
+    item: ReceivedItem,
+    incoming: mpsc::Sender<ReceivedItem>,
+    acks: PendingAcks,
+    max_size: usize,
+) {
+    let item_id = item.item_id;
+    let decision = acks.wait(&peer_id, item_id);
+    let stopped = send.stopped();
+
+    let verdict = match incoming.send(item).await {
+        Err(_) => {
+            drop(decision);
+            Some(Err("Receiver is shutting down".to_string()))
+        }
+        Ok(()) => tokio::select! {
+            verdict = tokio::time::timeout(DECISION_TIMEOUT, decision) => Some(match verdict {
+                Ok(Ok(verdict)) => verdict,
+                Ok(Err(_)) => Err("Item was superseded by a resend".to_string()),
+                Err(_) => Err("Item was not confirmed in time".to_string()),
+            }),
+            _ = stopped => None,
+        },
+    };
+    acks.forget(&peer_id, item_id);
+    let Some(verdict) = verdict else {
+        println!("[QUIC] {} stopped waiting for item {}", peer_id, item_id);
+        return;
+    };
+
+    let answer = match verdict {
+        Ok(()) => SyncMessage::Ack { item_id },
+        Err(reason) => {
+            println!("[QUIC] Rejecting item {} from {}: {}", item_id, peer_id, reason);
+            SyncMessage::Error { code: error_code::REJECTED, message: reason }
+        }
+    };
+    if let Err(e) = write_message(&mut send, &answer, max_size).await {
+        println!("[QUIC] Failed to answer item {} from {}: {}", item_id, peer_id, e);
+        return;
+    }
+    let _ = send.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve_answers_waiting_item() {
+        let acks = PendingAcks::default();
+        assert!(!acks.resolve("phone", 1, Ok(())), "Nothing is waiting yet");
+
+        let decision = acks.wait("phone", 1);
+        assert!(!acks.resolve("laptop", 1, Ok(())), "Item ids are per peer");
+        assert!(acks.resolve("phone", 1, Err("clipboard locked".to_string())));
+        assert_eq!(decision.await.unwrap(), Err("clipboard locked".to_string()));
+        assert!(!acks.resolve("phone", 1, Ok(())), "An item is answered once");
+    }
+}
@@ -0,0 +1,196 @@
+//! Connection Events for SyncMist
+//!
+//! Connectivity changes are published on a broadcast channel so the app can
+//! react to peers coming and going instead of polling the peer list.
+
+use std::time::Duration;
+
+use quinn::{Connection, ConnectionError};
+use tokio::sync::broadcast;
+
+use super::protocol::close_code;
+use super::relay::is_relayed;
+use super::supervisor::PeerState;
+
+/// Number of events buffered per subscriber before the oldest are dropped
+pub(crate) const EVENT_CAPACITY: usize = 64;
+
+/// How often live connections are checked for a changed network path
+const MIGRATION_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A change in connectivity with a peer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionEvent {
+    /// A connection completed its handshake and was registered
+    PeerConnected {
+        peer_id: String,
+        address: String,
+        /// Whether we dialed the connection
+        outbound: bool,
+    },
+    /// A peer's connection closed or was disconnected
+    PeerDisconnected {
+        peer_id: String,
+        reason: DisconnectReason,
+        /// Whether the peer closed the connection, as opposed to us or the network
+        by_peer: bool,
+    },
+    /// A TLS or Hello handshake did not complete
+    HandshakeFailed { address: String, error: String },
+    /// A peer's connection moved to a new network path
+    ConnectionMigrated {
+        peer_id: String,
+        old_address: String,
+        new_address: String,
+    },
+    /// A supervised peer's connection state changed
+    PeerStateChanged {
+        peer_id: String,
+        state: PeerState,
+        /// Failed reconnect rounds so far, zero once connected
+        attempt: u32,
+        /// Delay before the next reconnect round, zero unless reconnecting
+        retry_in_ms: u64,
+    },
+}
+
+/// Why a connection to a peer ended
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisconnectReason {
+    /// The user disconnected
+    UserDisconnect,
+    /// The device was unpaired or its pairing revoked
+    Unpaired,
+    /// The devices share no protocol version
+    VersionMismatch,
+    /// The device shut down on purpose
+    ShuttingDown,
+    /// The device refused us for connecting too often
+    RateLimited,
+    /// A newer connection to the same device took over
+    Replaced,
+    /// The wire protocol was broken
+    ProtocolViolation,
+    /// Nothing was heard from the peer within the idle timeout
+    TimedOut,
+    /// The connection failed without a close, e.g. the peer crashed or the network went away
+    ConnectionLost { error: String },
+    /// The peer closed with a code this build doesn't know
+    Unknown { code: u64, message: String },
+}
+
+impl DisconnectReason {
+    /// Interpret an application close code and reason phrase received from a peer
+    pub(crate) fn from_close(code: u64, phrase: &[u8]) -> Self {
+        let Ok(code) = u32::try_from(code) else {
+            return DisconnectReason::Unknown { code, message: String::from_utf8_lossy(phrase).to_string() };
+        };
+        match (code, phrase) {
+            (close_code::SHUTTING_DOWN, _) => DisconnectReason::ShuttingDown,
+            (close_code::USER_DISCONNECT, _) => DisconnectReason::UserDisconnect,
+            (close_code::UNPAIRED, _) => DisconnectReason::Unpaired,
+            (close_code::VERSION_MISMATCH, _) => DisconnectReason::VersionMismatch,
+            (close_code::RATE_LIMITED, _) => DisconnectReason::RateLimited,
+            (close_code::REPLACED, _) => DisconnectReason::Replaced,
+            (close_code::PROTOCOL_VIOLATION, _) => DisconnectReason::ProtocolViolation,
+            // Older builds close with code 0 and say why in the phrase
+            (close_code::UNSPECIFIED, b"shutdown") => DisconnectReason::ShuttingDown,
+            (close_code::UNSPECIFIED, b"disconnect") => DisconnectReason::UserDisconnect,
+            (close_code::UNSPECIFIED, b"duplicate" | b"replaced") => DisconnectReason::Replaced,
+            (close_code::UNSPECIFIED, b"protocol") => DisconnectReason::ProtocolViolation,
+            (code, phrase) => DisconnectReason::Unknown {
+                code: code as u64,
+                message: String::from_utf8_lossy(phrase).to_string(),
+            },
+        }
+    }
+
+    /// Reason a connection ended with `error`, and whether the peer ended it
+    pub(crate) fn from_connection_error(error: &ConnectionError) -> (Self, bool) {
+        match error {
+            ConnectionError::ApplicationClosed(close) => {
+                (Self::from_close(close.error_code.into_inner(), &close.reason), true)
+            }
+            ConnectionError::TimedOut => (DisconnectReason::TimedOut, false),
+            ConnectionError::VersionMismatch => (DisconnectReason::VersionMismatch, false),
+            ConnectionError::ConnectionClosed(_) | ConnectionError::Reset => {
+                (DisconnectReason::ConnectionLost { error: error.to_string() }, true)
+            }
+            e => (DisconnectReason::ConnectionLost { error: e.to_string() }, false),
+        }
+    }
+}
+
+/// Publish an event, ignoring the case where nobody is subscribed
+pub(crate) fn publish(events: &broadcast::Sender<ConnectionEvent>, event: ConnectionEvent) {
+    let _ = events.send(event);
+}
+
+/// Wait until `peer_id` connects, `false` if the events stopped first
+///
+/// With `direct_only`, connections through a relay are not counted.
+pub(crate) async fn wait_for_peer(
+    events: &mut broadcast::Receiver<ConnectionEvent>,
+    peer_id: &str,
+    direct_only: bool,
+) -> bool {
+    loop {
+        match events.recv().await {
+            Ok(ConnectionEvent::PeerConnected { peer_id: id, address, .. }) if id == peer_id => {
+                let relayed = address.parse().is_ok_and(is_relayed);
+                if !(direct_only && relayed) {
+                    return true;
+                }
+            }
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => return false,
+        }
+    }
+}
+
+/// Watch a connection for path migration until it closes
+pub(crate) fn spawn_migration_watch(
+    peer_id: String,
+    connection: Connection,
+    events: broadcast::Sender<ConnectionEvent>,
+) {
+    tokio::spawn(async move {
+        let mut address = connection.remote_address();
+        loop {
+            tokio::select! {
+                _ = connection.closed() => break,
+                _ = tokio::time::sleep(MIGRATION_CHECK_INTERVAL) => {}
+            }
+            let current = connection.remote_address();
+            if current != address {
+                println!("[QUIC] Connection to {} migrated from {} to {}", peer_id, address, current);
+                publish(&events, ConnectionEvent::ConnectionMigrated {
+                    peer_id: peer_id.clone(),
+                    old_address: address.to_string(),
+                    new_address: current.to_string(),
+                });
+                address = current;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_close_codes_become_reasons() {
+        assert_eq!(DisconnectReason::from_close(close_code::UNPAIRED as u64, b"unpaired"), DisconnectReason::Unpaired);
+        assert_eq!(DisconnectReason::from_close(close_code::RATE_LIMITED as u64, b""), DisconnectReason::RateLimited);
+        assert_eq!(DisconnectReason::from_close(0, b"shutdown"), DisconnectReason::ShuttingDown, "Older builds");
+        assert_eq!(DisconnectReason::from_close(0, b"duplicate"), DisconnectReason::Replaced, "Older builds");
+        assert_eq!(
+            DisconnectReason::from_close(99, b"\xffnew"),
+            DisconnectReason::Unknown { code: 99, message: "\u{fffd}new".to_string() }
+        );
+        assert!(matches!(DisconnectReason::from_close(u64::MAX, b""), DisconnectReason::Unknown { .. }));
+    }
+}
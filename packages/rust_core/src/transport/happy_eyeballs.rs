@@ -0,0 +1,172 @@
+//! Happy Eyeballs Connection Racing for SyncMist
+//!
+//! A peer found by mDNS usually advertises several addresses, not all of them
+//! reachable from here. Attempts are started one after another with a short
+//! stagger (RFC 8305) and the first to complete its TLS handshake wins. Only
+//! the winner goes on to the Hello exchange, which is when the peer registers a
+//! connection, so the others are closed before the peer can mistake them for a
+//! newer connection and replace the winner.
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use futures::stream::{FuturesUnordered, StreamExt};
+use futures::Stream;
+
+use super::protocol::{close_code, close_connection};
+use super::quic::{Dialer, Handshake, TransportError};
+
+/// Delay before starting the next attempt while earlier ones are still pending
+const ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// Time allowed for a single attempt
+const ATTEMPT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Race handshakes to `addresses`, started in the given order
+///
+/// Returns the address that won together with its handshake. A certificate
+/// fingerprint mismatch ends the race immediately.
+pub(crate) async fn race(
+    dialer: &Dialer,
+    addresses: &[SocketAddr],
+    device_id: Option<String>,
+) -> Result<(SocketAddr, Handshake), TransportError> {
+    let attempt = |address: SocketAddr| {
+        let dialer = dialer.clone();
+        let device_id = device_id.clone();
+        async move {
+            let result = match tokio::time::timeout(ATTEMPT_TIMEOUT, dialer.connect(address, device_id)).await {
+                Ok(result) => result,
+                Err(_) => Err(TransportError::Connection(format!("Connecting to {} timed out", address))),
+            };
+            (address, result)
+        }
+    };
+
+    let mut pending = addresses.iter().copied().peekable();
+    let mut attempts = FuturesUnordered::new();
+    let mut last_error = TransportError::Connection("No address to connect to".to_string());
+
+    loop {
+        if attempts.is_empty() {
+            match pending.next() {
+                Some(address) => attempts.push(attempt(address)),
+                None => return Err(last_error),
+            }
+        }
+
+        let more_pending = pending.peek().is_some();
+        tokio::select! {
+            Some((address, result)) = attempts.next() => match result {
+                Ok(connection) => {
+                    println!("[QUIC] Connected via {}, cancelling {} other attempts", address, attempts.len());
+                    tokio::spawn(cancel(attempts));
+                    let handshake = dialer.hello(address, connection, device_id.as_deref()).await?;
+                    return Ok((address, handshake));
+                }
+                Err(e @ TransportError::FingerprintMismatch { .. }) => {
+                    tokio::spawn(cancel(attempts));
+                    return Err(e);
+                }
+                Err(e) => {
+                    println!("[QUIC] Attempt via {} failed: {}", address, e);
+                    last_error = e;
+                    // Don't wait out the stagger after a failure
+                    if let Some(address) = pending.next() {
+                        attempts.push(attempt(address));
+                    }
+                }
+            },
+            _ = tokio::time::sleep(ATTEMPT_DELAY), if more_pending => {
+                if let Some(address) = pending.next() {
+                    attempts.push(attempt(address));
+                }
+            }
+        }
+    }
+}
+
+/// Close the connections of attempts that lost the race as they complete
+async fn cancel<S>(mut attempts: S)
+where
+    S: Stream<Item = (SocketAddr, Result<quinn::Connection, TransportError>)> + Unpin,
+{
+    while let Some((_, result)) = attempts.next().await {
+        if let Ok(connection) = result {
+            close_connection(&connection, close_code::CANCELLED);
+        }
+    }
+}
+
+/// Order addresses for racing
+///
+/// The address that worked last time goes first. The rest prefer local-network
+/// addresses over public ones and IPv6 over IPv4, alternating address families
+/// so a broken IPv6 path only delays IPv4 by one stagger.
+pub(crate) fn order_addresses(addresses: &[SocketAddr], preferred: Option<SocketAddr>) -> Vec<SocketAddr> {
+    let mut sorted: Vec<SocketAddr> = Vec::new();
+    for address in addresses {
+        if !sorted.contains(address) && Some(*address) != preferred {
+            sorted.push(*address);
+        }
+    }
+    sorted.sort_by_key(|address| (!is_lan(address.ip()), address.is_ipv4()));
+
+    let (mut v6, mut v4): (Vec<_>, Vec<_>) = sorted.into_iter().partition(SocketAddr::is_ipv6);
+    v6.reverse();
+    v4.reverse();
+    let mut ordered: Vec<SocketAddr> = preferred.into_iter().collect();
+    let mut take_v6 = preferred.is_none_or(|address| address.is_ipv4());
+    while !v6.is_empty() || !v4.is_empty() {
+        let next = if take_v6 { v6.pop().or_else(|| v4.pop()) } else { v4.pop().or_else(|| v6.pop()) };
+        ordered.extend(next);
+        take_v6 = !take_v6;
+    }
+    ordered
+}
+
+/// Whether an address is on the local network (private, unique local or link-local)
+fn is_lan(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_private() || v4.is_link_local() || v4.is_loopback(),
+        IpAddr::V6(v6) => v6.is_unique_local() || v6.is_unicast_link_local() || v6.is_loopback(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addrs(list: &[&str]) -> Vec<SocketAddr> {
+        list.iter().map(|a| a.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_order_prefers_lan_and_ipv6() {
+        let addresses = addrs(&["203.0.113.5:9876", "192.168.1.20:9876", "[2001:db8::5]:9876", "[fd00::2]:9876"]);
+        assert_eq!(
+            order_addresses(&addresses, None),
+            addrs(&["[fd00::2]:9876", "192.168.1.20:9876", "[2001:db8::5]:9876", "203.0.113.5:9876"])
+        );
+    }
+
+    #[test]
+    fn test_order_interleaves_families() {
+        let addresses = addrs(&["10.0.0.1:1", "10.0.0.2:1", "10.0.0.3:1", "[fd00::1]:1"]);
+        assert_eq!(
+            order_addresses(&addresses, None),
+            addrs(&["[fd00::1]:1", "10.0.0.1:1", "10.0.0.2:1", "10.0.0.3:1"])
+        );
+    }
+
+    #[test]
+    fn test_order_remembered_address_first() {
+        let addresses = addrs(&["[fd00::2]:1", "192.168.1.20:1", "192.168.1.20:1"]);
+        let preferred = "192.168.1.20:1".parse().unwrap();
+        assert_eq!(
+            order_addresses(&addresses, Some(preferred)),
+            addrs(&["192.168.1.20:1", "[fd00::2]:1"]),
+            "Remembered address first, duplicates removed"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! UDP Hole Punching for SyncMist
+//!
+//! Two devices behind NATs can't dial each other until each NAT has a mapping
+//! for the other's address. Given the other device's candidate addresses, both
+//! sides send probes from the QUIC socket to every candidate at once, and
+//! answer the probes they receive. The first probe or reply heard from the peer
+//! shows a working path: the device with the smaller id then dials QUIC over
+//! it, while the other keeps probing so its NAT lets the handshake in.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tokio::time::Instant;
+
+use super::events::wait_for_peer;
+use super::protocol::{put_bytes, Reader};
+use super::quic::{Dialer, TransportError};
+use super::relay::is_relayed;
+use super::socket::RawSocket;
+
+/// First bytes of every probe, below `0x40` so it isn't taken for QUIC
+const PROBE_MAGIC: &[u8; 4] = b"\x00SMP";
+const PROBE_REQUEST: u8 = 0;
+const PROBE_REPLY: u8 = 1;
+
+/// Time between probe rounds
+const PROBE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Time allowed for finding a path and completing the handshake
+pub(crate) const PUNCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn encode_probe(kind: u8, device_id: &str) -> Vec<u8> {
+    let mut out = PROBE_MAGIC.to_vec();
+    out.push(kind);
+    put_bytes(&mut out, device_id.as_bytes());
+    out
+}
+
+/// Kind and sender device id of a probe, `None` for anything else
+fn decode_probe(datagram: &[u8]) -> Option<(u8, String)> {
+    let mut r = Reader::new(datagram.strip_prefix(PROBE_MAGIC)?);
+    let kind = r.u8().ok()?;
+    let device_id = r.string().ok()?;
+    r.is_empty().then_some((kind, device_id))
+}
+
+/// Open a direct connection to `peer_id` through the NATs in between
+///
+/// Returns the peer id once the connection is registered, whichever side dialed.
+pub(crate) async fn punch(
+    dialer: &Dialer,
+    raw: &RawSocket,
+    peer_id: &str,
+    candidates: &[SocketAddr],
+) -> Result<String, TransportError> {
+    let local_id = dialer.device_id();
+    let deadline = Instant::now() + PUNCH_TIMEOUT;
+    println!("[QUIC] Punching to {} via {} candidates", peer_id, candidates.len());
+
+    // Same tie-break as simultaneous opens: the smaller device id dials
+    if local_id.as_str() < peer_id {
+        let address = probe(raw, &local_id, peer_id, candidates, deadline, true)
+            .await
+            .ok_or_else(|| TransportError::Connection(format!("No path to {} found", peer_id)))?;
+        println!("[QUIC] Punched through to {} at {}", peer_id, address);
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        return tokio::time::timeout(remaining, dialer.dial(address, Some(peer_id.to_string())))
+            .await
+            .map_err(|_| TransportError::Connection(format!("Handshake with {} timed out", peer_id)))?;
+    }
+
+    // A relayed connection is what punching replaces, so only a direct one counts
+    let mut events = dialer.registry().subscribe();
+    let connection = dialer.registry().connection(peer_id).await;
+    if connection.is_some_and(|connection| !is_relayed(connection.remote_address())) {
+        return Ok(peer_id.to_string());
+    }
+    tokio::select! {
+        true = wait_for_peer(&mut events, peer_id, true) => Ok(peer_id.to_string()),
+        _ = probe(raw, &local_id, peer_id, candidates, deadline, false) => {
+            Err(TransportError::Connection(format!("{} did not connect through the NAT", peer_id)))
+        }
+    }
+}
+
+/// Probe the peer's candidates and answer its probes until `deadline`
+///
+/// With `until_heard` this returns the first address the peer was heard from
+/// instead of probing on.
+async fn probe(
+    raw: &RawSocket,
+    local_id: &str,
+    peer_id: &str,
+    candidates: &[SocketAddr],
+    deadline: Instant,
+    until_heard: bool,
+) -> Option<SocketAddr> {
+    let mut received = raw.subscribe();
+    let request = encode_probe(PROBE_REQUEST, local_id);
+    let reply = encode_probe(PROBE_REPLY, local_id);
+    let mut rounds = tokio::time::interval(PROBE_INTERVAL);
+    let mut heard = None;
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep_until(deadline) => return heard,
+            _ = rounds.tick() => {
+                for candidate in candidates {
+                    let _ = raw.send_to(&request, *candidate).await;
+                }
+            }
+            datagram = received.recv() => match datagram {
+                Ok((from, datagram)) => {
+                    let Some((kind, device_id)) = decode_probe(&datagram) else { continue };
+                    if device_id != peer_id {
+                        continue;
+                    }
+                    if kind == PROBE_REQUEST {
+                        let _ = raw.send_to(&reply, from).await;
+                    }
+                    if heard.is_none() {
+                        heard = Some(SocketAddr::new(from.ip().to_canonical(), from.port()));
+                    }
+                    if until_heard {
+                        return heard;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return heard,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_encoding() {
+        let probe = encode_probe(PROBE_REPLY, "laptop");
+        assert_eq!(decode_probe(&probe), Some((PROBE_REPLY, "laptop".to_string())));
+        assert_eq!(decode_probe(&probe[..probe.len() - 1]), None, "Truncated");
+        assert_eq!(decode_probe(b"\x00SMRxxxx"), None, "Other magic");
+    }
+}
@@ -0,0 +1,346 @@
+//! Persistent Device Identity for SyncMist
+//!
+//! A device identity is a keypair plus a self-signed certificate carrying the
+//! device id in its subject alternative name. It is generated once, stored by the
+//! app, and reused for every QUIC connection so peers can pin its fingerprint.
+
+use std::path::Path;
+
+use rcgen::{CertificateParams, KeyPair, SanType};
+use ring::signature::{EcdsaKeyPair, UnparsedPublicKey, ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_ASN1_SIGNING};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+
+use super::quic::TransportError;
+use super::trust::fingerprint_of;
+
+/// Server name presented in the TLS handshake and included in every certificate
+pub(crate) const SERVER_NAME: &str = "syncmist";
+
+/// Prefix of the URI SAN that carries the device id
+const DEVICE_URI_PREFIX: &str = "urn:syncmist:device:";
+
+/// Magic bytes and format version of a serialized identity
+const IDENTITY_MAGIC: &[u8; 4] = b"SMID";
+const IDENTITY_VERSION: u8 = 1;
+
+/// Longest device id, as serialized identities store its length in two bytes
+const MAX_DEVICE_ID_LEN: usize = u16::MAX as usize;
+
+/// Long-lived TLS identity of this device
+///
+/// Used for both the server and client side of [`super::QuicTransport`].
+#[flutter_rust_bridge::frb(opaque)]
+#[derive(Clone, Debug)]
+pub struct DeviceIdentity {
+    device_id: String,
+    cert_der: Vec<u8>,
+    key_der: Vec<u8>,
+}
+
+impl DeviceIdentity {
+    /// Generate a new identity for a device
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn generate(device_id: String) -> Result<Self, TransportError> {
+        if device_id.len() > MAX_DEVICE_ID_LEN {
+            return Err(TransportError::Tls(format!(
+                "Device id is {} bytes, longer than {}",
+                device_id.len(),
+                MAX_DEVICE_ID_LEN
+            )));
+        }
+        println!("[QUIC] Generating device identity for {}", device_id);
+
+        let uri = format!("{}{}", DEVICE_URI_PREFIX, device_id)
+            .try_into()
+            .map_err(|e| TransportError::Tls(format!("Invalid device id for certificate: {}", e)))?;
+
+        let mut params = CertificateParams::new(vec![SERVER_NAME.to_string()])
+            .map_err(|e| TransportError::Tls(format!("Certificate params error: {}", e)))?;
+        params.subject_alt_names.push(SanType::URI(uri));
+
+        let key_pair = KeyPair::generate()
+            .map_err(|e| TransportError::Tls(format!("Key generation failed: {}", e)))?;
+        let cert = params
+            .self_signed(&key_pair)
+            .map_err(|e| TransportError::Tls(format!("Certificate generation failed: {}", e)))?;
+
+        Ok(Self {
+            device_id,
+            cert_der: cert.der().to_vec(),
+            key_der: key_pair.serialize_der(),
+        })
+    }
+
+    /// Load an identity from `path`, generating and saving a new one if the file does not exist
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn load_or_generate(path: String, device_id: String) -> Result<Self, TransportError> {
+        if Path::new(&path).exists() {
+            let identity = Self::load(path)?;
+            if identity.device_id != device_id {
+                return Err(TransportError::Tls(format!(
+                    "Stored identity belongs to device {}, expected {}",
+                    identity.device_id, device_id
+                )));
+            }
+            return Ok(identity);
+        }
+
+        let identity = Self::generate(device_id)?;
+        identity.save(path)?;
+        Ok(identity)
+    }
+
+    /// Load an identity previously written with [`DeviceIdentity::save`]
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn load(path: String) -> Result<Self, TransportError> {
+        let bytes = std::fs::read(&path)
+            .map_err(|e| TransportError::Io(format!("Failed to read identity {}: {}", path, e)))?;
+        Self::from_bytes(bytes)
+    }
+
+    /// Write the identity to `path`
+    ///
+    /// The file contains the private key and is created readable by the owner only.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn save(&self, path: String) -> Result<(), TransportError> {
+        use std::io::Write;
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options
+            .open(&path)
+            .map_err(|e| TransportError::Io(format!("Failed to create identity {}: {}", path, e)))?;
+        file.write_all(&self.to_bytes())
+            .map_err(|e| TransportError::Io(format!("Failed to write identity {}: {}", path, e)))?;
+
+        println!("[QUIC] Saved device identity to {}", path);
+        Ok(())
+    }
+
+    /// Serialize the identity (including the private key) to a byte blob
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(
+            IDENTITY_MAGIC.len() + 1 + 2 + self.device_id.len() + 8 + self.cert_der.len() + self.key_der.len(),
+        );
+        out.extend_from_slice(IDENTITY_MAGIC);
+        out.push(IDENTITY_VERSION);
+        // Identities only come from `generate` and `from_bytes`, which both keep the id within a u16
+        out.extend_from_slice(&(self.device_id.len() as u16).to_be_bytes());
+        out.extend_from_slice(self.device_id.as_bytes());
+        out.extend_from_slice(&(self.cert_der.len() as u32).to_be_bytes());
+        out.extend_from_slice(&self.cert_der);
+        out.extend_from_slice(&(self.key_der.len() as u32).to_be_bytes());
+        out.extend_from_slice(&self.key_der);
+        out
+    }
+
+    /// Deserialize an identity produced by [`DeviceIdentity::to_bytes`]
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, TransportError> {
+        let invalid = |what: &str| TransportError::Tls(format!("Invalid identity data: {}", what));
+
+        let rest = bytes.strip_prefix(IDENTITY_MAGIC).ok_or_else(|| invalid("bad magic"))?;
+        let (&version, rest) = rest.split_first().ok_or_else(|| invalid("truncated"))?;
+        if version != IDENTITY_VERSION {
+            return Err(invalid(&format!("unsupported version {}", version)));
+        }
+
+        let (device_id, rest) = take_field(rest, 2).ok_or_else(|| invalid("truncated device id"))?;
+        let (cert_der, rest) = take_field(rest, 4).ok_or_else(|| invalid("truncated certificate"))?;
+        let (key_der, rest) = take_field(rest, 4).ok_or_else(|| invalid("truncated key"))?;
+        if !rest.is_empty() {
+            return Err(invalid("trailing bytes"));
+        }
+
+        let device_id = String::from_utf8(device_id.to_vec()).map_err(|_| invalid("device id is not UTF-8"))?;
+        let identity = Self {
+            device_id,
+            cert_der: cert_der.to_vec(),
+            key_der: key_der.to_vec(),
+        };
+        identity.validate()?;
+        Ok(identity)
+    }
+
+    /// Device id this identity was issued for
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn device_id(&self) -> String {
+        self.device_id.clone()
+    }
+
+    /// SHA-256 fingerprint of the certificate, as shared in pairing payloads
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn fingerprint(&self) -> String {
+        fingerprint_of(&self.cert_der)
+    }
+
+    /// DER encoded certificate
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn certificate(&self) -> Vec<u8> {
+        self.cert_der.clone()
+    }
+
+    /// Certificate and key in the form rustls expects
+    pub(crate) fn cert_and_key(&self) -> (CertificateDer<'static>, PrivateKeyDer<'static>) {
+        let cert = CertificateDer::from(self.cert_der.clone());
+        let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(self.key_der.clone()));
+        (cert, key)
+    }
+
+    /// ECDSA P-256 signature over `message` with the identity key
+    pub(crate) fn sign(&self, message: &[u8]) -> Result<Vec<u8>, TransportError> {
+        let rng = ring::rand::SystemRandom::new();
+        let key = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &self.key_der, &rng)
+            .map_err(|e| TransportError::Tls(format!("Identity key can't sign: {}", e)))?;
+        let signature = key
+            .sign(&rng, message)
+            .map_err(|e| TransportError::Tls(format!("Signing failed: {}", e)))?;
+        Ok(signature.as_ref().to_vec())
+    }
+
+    /// Check that the key parses, matches the certificate, and the certificate names this device
+    fn validate(&self) -> Result<(), TransportError> {
+        let (cert, key) = self.cert_and_key();
+        let signing_key = rustls::crypto::ring::sign::any_supported_type(&key)
+            .map_err(|e| TransportError::Tls(format!("Invalid identity key: {}", e)))?;
+        rustls::sign::CertifiedKey::new(vec![cert], signing_key)
+            .keys_match()
+            .map_err(|e| TransportError::Tls(format!("Identity key does not match certificate: {}", e)))?;
+
+        match device_id_from_cert(&self.cert_der) {
+            Some(id) if id == self.device_id => Ok(()),
+            other => Err(TransportError::Tls(format!(
+                "Identity certificate names device {:?}, expected {}",
+                other, self.device_id
+            ))),
+        }
+    }
+}
+
+/// Extract the device id from a SyncMist certificate's subject alternative name
+///
+/// Returns `None` for certificates that don't carry a device id, such as those
+/// from [`super::generate_self_signed_cert`].
+pub(crate) fn device_id_from_cert(cert_der: &[u8]) -> Option<String> {
+    use x509_parser::extensions::GeneralName;
+
+    let (_, cert) = x509_parser::parse_x509_certificate(cert_der).ok()?;
+    let san = cert.subject_alternative_name().ok()??;
+    san.value.general_names.iter().find_map(|name| match name {
+        GeneralName::URI(uri) => uri.strip_prefix(DEVICE_URI_PREFIX).map(str::to_string),
+        _ => None,
+    })
+}
+
+/// Whether `signature` is a signature over `message` by the key of a certificate, see [`DeviceIdentity::sign`]
+pub(crate) fn verify_signature(cert_der: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Ok((_, cert)) = x509_parser::parse_x509_certificate(cert_der) else { return false };
+    let public_key = cert.public_key().subject_public_key.data.as_ref();
+    UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, public_key).verify(message, signature).is_ok()
+}
+
+/// Split a big-endian length-prefixed field off the front of `data`
+fn take_field(data: &[u8], len_size: usize) -> Option<(&[u8], &[u8])> {
+    if data.len() < len_size {
+        return None;
+    }
+    let (len_bytes, rest) = data.split_at(len_size);
+    let len = len_bytes.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize);
+    if rest.len() < len {
+        return None;
+    }
+    Some(rest.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_identity() {
+        let identity = DeviceIdentity::generate("device-abc".to_string()).unwrap();
+        assert_eq!(identity.device_id(), "device-abc");
+        assert_eq!(identity.fingerprint().len(), 64);
+        assert_eq!(
+            device_id_from_cert(&identity.certificate()).as_deref(),
+            Some("device-abc"),
+            "Certificate SAN should carry the device id"
+        );
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let identity = DeviceIdentity::generate("device-signer".to_string()).unwrap();
+        let other = DeviceIdentity::generate("device-other".to_string()).unwrap();
+        let signature = identity.sign(b"sealed item").unwrap();
+
+        assert!(verify_signature(&identity.certificate(), b"sealed item", &signature));
+        assert!(!verify_signature(&identity.certificate(), b"tampered item", &signature));
+        assert!(!verify_signature(&other.certificate(), b"sealed item", &signature), "Signed by another key");
+    }
+
+    #[test]
+    fn test_identity_bytes_roundtrip() {
+        let identity = DeviceIdentity::generate("device-roundtrip".to_string()).unwrap();
+        let restored = DeviceIdentity::from_bytes(identity.to_bytes()).unwrap();
+
+        assert_eq!(restored.device_id(), identity.device_id());
+        assert_eq!(restored.fingerprint(), identity.fingerprint());
+    }
+
+    #[test]
+    fn test_identity_from_invalid_bytes() {
+        assert!(DeviceIdentity::from_bytes(Vec::new()).is_err());
+        assert!(DeviceIdentity::from_bytes(b"SMID".to_vec()).is_err());
+
+        let mut bytes = DeviceIdentity::generate("device-trunc".to_string()).unwrap().to_bytes();
+        bytes.pop();
+        assert!(DeviceIdentity::from_bytes(bytes).is_err(), "Truncated identity should be rejected");
+    }
+
+    #[test]
+    fn test_generate_rejects_long_device_id() {
+        assert!(DeviceIdentity::generate("d".repeat(MAX_DEVICE_ID_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_identity_key_must_match_certificate() {
+        let a = DeviceIdentity::generate("device-a".to_string()).unwrap();
+        let b = DeviceIdentity::generate("device-a".to_string()).unwrap();
+        let mismatched = DeviceIdentity {
+            device_id: a.device_id.clone(),
+            cert_der: a.cert_der.clone(),
+            key_der: b.key_der.clone(),
+        };
+        assert!(DeviceIdentity::from_bytes(mismatched.to_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_load_or_generate_persists() {
+        let path = std::env::temp_dir().join(format!("syncmist-identity-{}.bin", std::process::id()));
+        let path = path.to_string_lossy().to_string();
+        let _ = std::fs::remove_file(&path);
+
+        let first = DeviceIdentity::load_or_generate(path.clone(), "device-persist".to_string()).unwrap();
+        let second = DeviceIdentity::load_or_generate(path.clone(), "device-persist".to_string()).unwrap();
+        assert_eq!(first.fingerprint(), second.fingerprint(), "Identity should be reloaded, not regenerated");
+
+        let other = DeviceIdentity::load_or_generate(path.clone(), "another-device".to_string());
+        assert!(other.is_err(), "Identity for a different device should be rejected");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_legacy_certificate_has_no_device_id() {
+        let (cert, _) = crate::transport::quic::generate_self_signed_cert().unwrap();
+        assert_eq!(device_id_from_cert(&cert), None);
+    }
+}
@@ -0,0 +1,384 @@
+//! Mailbox for SyncMist
+//!
+//! Items broadcast while a paired device is offline are left for it at the
+//! [signaling hub](crate::signaling), which hands them over when the device
+//! next fetches its mailbox. Each item is sealed to the device's X25519
+//! sealing key from pairing: a fresh ephemeral key agrees an AES-256-GCM key
+//! with it, and the sender signs the result with its identity key. The hub
+//! only learns who the item is for, its size and how long to keep it.
+//!
+//! A recipient accepts an item only if it is addressed to it, hasn't expired,
+//! and is signed by the certificate pinned for its sender. The sealed payload
+//! is the same one a live broadcast would carry, so apps handle both alike.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use super::broadcast::{BroadcastFilter, BroadcastItem, BroadcastOutcome};
+use super::identity::{device_id_from_cert, verify_signature, DeviceIdentity};
+use super::protocol::{put_bytes, put_u64, Reader, SyncMessage};
+use super::quic::TransportError;
+use super::receiver::ReceivedItem;
+use super::trust::{fingerprint_of, TrustStore};
+use crate::crypto::{decrypt_bytes, encrypt_bytes};
+use crate::signaling::client::{Signal, SignalingChannel};
+
+/// First bytes of every sealed item
+const SEAL_MAGIC: &[u8; 4] = b"SMSB";
+const SEAL_VERSION: u8 = 1;
+
+/// Largest sealed item, leaving room for base64 within the hub's 512 KiB messages
+const MAX_SEALED_SIZE: usize = 256 * 1024;
+
+fn unix_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+fn key_from(bytes: &[u8], what: &str) -> Result<[u8; 32], TransportError> {
+    bytes
+        .try_into()
+        .map_err(|_| TransportError::Connection(format!("{} must be 32 bytes, got {}", what, bytes.len())))
+}
+
+/// X25519 keys items are sealed with: ours, and those of paired devices
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Default)]
+pub(crate) struct SealingKeys {
+    own: Arc<Mutex<Option<[u8; 32]>>>,
+    peers: Arc<Mutex<HashMap<String, [u8; 32]>>>,
+}
+
+impl SealingKeys {
+    pub(crate) fn set_own(&self, secret_key: &[u8]) -> Result<(), TransportError> {
+        *self.own.lock().unwrap() = Some(key_from(secret_key, "Sealing key")?);
+        Ok(())
+    }
+
+    pub(crate) fn set_peer(&self, device_id: &str, public_key: &[u8]) -> Result<(), TransportError> {
+        let public_key = key_from(public_key, "Public key")?;
+        self.peers.lock().unwrap().insert(device_id.to_string(), public_key);
+        Ok(())
+    }
+
+    pub(crate) fn forget_peer(&self, device_id: &str) -> bool {
+        self.peers.lock().unwrap().remove(device_id).is_some()
+    }
+
+    fn own(&self) -> Option<[u8; 32]> {
+        *self.own.lock().unwrap()
+    }
+
+    fn peer(&self, device_id: &str) -> Option<[u8; 32]> {
+        self.peers.lock().unwrap().get(device_id).copied()
+    }
+}
+
+/// AES-256 key for one sealed item, bound to both public keys involved
+fn seal_key(shared: &[u8], ephemeral: &[u8; 32], recipient: &[u8; 32]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(b"syncmist seal\0");
+    hasher.update(shared);
+    hasher.update(ephemeral);
+    hasher.update(recipient);
+    hasher.finalize().to_vec()
+}
+
+/// Seal a message for `recipient`, to be opened before `expires_at` (Unix seconds)
+pub(crate) fn seal(
+    identity: &DeviceIdentity,
+    recipient: &str,
+    recipient_key: &[u8; 32],
+    message: &SyncMessage,
+    expires_at: u64,
+) -> Result<Vec<u8>, TransportError> {
+    let ephemeral = StaticSecret::random_from_rng(OsRng);
+    let ephemeral_public = PublicKey::from(&ephemeral).to_bytes();
+    let shared = ephemeral.diffie_hellman(&PublicKey::from(*recipient_key));
+    let key = seal_key(shared.as_bytes(), &ephemeral_public, recipient_key);
+
+    // The sender is sealed in too, so another paired device can't sign the ciphertext as its own
+    let mut plaintext = Vec::new();
+    put_bytes(&mut plaintext, identity.device_id().as_bytes());
+    put_bytes(&mut plaintext, &message.encode());
+    let ciphertext = encrypt_bytes(plaintext, key).map_err(TransportError::Connection)?;
+
+    let mut sealed = SEAL_MAGIC.to_vec();
+    sealed.push(SEAL_VERSION);
+    put_bytes(&mut sealed, identity.device_id().as_bytes());
+    put_bytes(&mut sealed, recipient.as_bytes());
+    put_u64(&mut sealed, expires_at);
+    put_bytes(&mut sealed, &ephemeral_public);
+    put_bytes(&mut sealed, &identity.certificate());
+    put_bytes(&mut sealed, &ciphertext);
+    let signature = identity.sign(&sealed)?;
+    put_bytes(&mut sealed, &signature);
+    Ok(sealed)
+}
+
+/// Verify and open a sealed item, returning its sender, expiry and message
+pub(crate) fn open(
+    sealed: &[u8],
+    local_id: &str,
+    secret_key: &[u8; 32],
+    trust_store: &TrustStore,
+) -> Result<(String, u64, SyncMessage), TransportError> {
+    let invalid = |what: &str| TransportError::Protocol(format!("Sealed item {}", what));
+    let mut r = Reader::new(sealed.strip_prefix(SEAL_MAGIC).ok_or_else(|| invalid("has a bad magic"))?);
+    if r.u8()? != SEAL_VERSION {
+        return Err(invalid("has an unsupported version"));
+    }
+    let sender = r.string()?;
+    let recipient = r.string()?;
+    let expires_at = r.u64()?;
+    let ephemeral: [u8; 32] = r.bytes()?.try_into().map_err(|_| invalid("has a bad ephemeral key"))?;
+    let cert = r.bytes()?;
+    let ciphertext = r.bytes()?;
+    let signature = r.bytes()?;
+    if !r.is_empty() {
+        return Err(invalid("has trailing bytes"));
+    }
+    let signed = &sealed[..sealed.len() - 4 - signature.len()];
+
+    if recipient != local_id {
+        return Err(invalid(&format!("is for {}", recipient)));
+    }
+    if expires_at <= unix_secs() {
+        return Err(invalid("has expired"));
+    }
+    // Only a device we paired with, holding the key of its pinned certificate, may leave us items
+    if !trust_store.is_paired(&sender)
+        || trust_store.get(&sender) != Some(fingerprint_of(cert))
+        || device_id_from_cert(cert).as_deref() != Some(&sender)
+    {
+        return Err(invalid(&format!("is not from paired device {}", sender)));
+    }
+    if !verify_signature(cert, signed, signature) {
+        return Err(invalid(&format!("has a bad signature from {}", sender)));
+    }
+
+    let secret = StaticSecret::from(*secret_key);
+    let shared = secret.diffie_hellman(&PublicKey::from(ephemeral));
+    let key = seal_key(shared.as_bytes(), &ephemeral, &PublicKey::from(&secret).to_bytes());
+    let plaintext = decrypt_bytes(ciphertext.to_vec(), key).map_err(|e| invalid(&format!("can't be opened: {}", e)))?;
+    let mut r = Reader::new(&plaintext);
+    if r.string()? != sender {
+        return Err(invalid("names another sender inside"));
+    }
+    let message = SyncMessage::decode(r.bytes()?)?;
+    Ok((sender, expires_at, message))
+}
+
+/// Mailbox at a signaling hub, shared with its background task
+#[derive(Clone)]
+pub(crate) struct Mailbox {
+    channel: SignalingChannel,
+    identity: DeviceIdentity,
+    trust_store: TrustStore,
+    keys: SealingKeys,
+    incoming: mpsc::Sender<ReceivedItem>,
+    /// How long the hub keeps deposited items
+    ttl: Duration,
+    /// Digests of items already opened with their expiry, so a replayed item isn't delivered twice
+    opened: Arc<Mutex<HashMap<[u8; 32], u64>>>,
+}
+
+impl Mailbox {
+    pub(crate) fn new(
+        channel: SignalingChannel,
+        identity: DeviceIdentity,
+        trust_store: TrustStore,
+        keys: SealingKeys,
+        incoming: mpsc::Sender<ReceivedItem>,
+        ttl: Duration,
+    ) -> Self {
+        Self { channel, identity, trust_store, keys, incoming, ttl, opened: Arc::new(Mutex::new(HashMap::new())) }
+    }
+
+    /// Fetch items deposited while we were offline, then open those that arrive
+    ///
+    /// Nothing is fetched without a sealing key: the hub forgets items once handed over.
+    pub(crate) fn spawn(&self) -> JoinHandle<()> {
+        let mailbox = self.clone();
+        let mut signals = self.channel.subscribe();
+        tokio::spawn(async move {
+            if mailbox.keys.own().is_some() {
+                let _ = mailbox.channel.send(Signal::Fetch { sender: mailbox.channel.device_id().to_string() }).await;
+            }
+            loop {
+                match signals.recv().await {
+                    Ok(Signal::Deposit { blob, .. }) => mailbox.deliver(&blob).await,
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    async fn deliver(&self, blob: &str) {
+        let Some(secret_key) = self.keys.own() else {
+            println!("[QUIC] Dropping mailbox item: no sealing key set");
+            return;
+        };
+        let Ok(sealed) = BASE64.decode(blob) else { return };
+        let (sender, expires_at, message) =
+            match open(&sealed, self.channel.device_id(), &secret_key, &self.trust_store) {
+                Ok(opened) => opened,
+                Err(e) => {
+                    println!("[QUIC] Rejected mailbox item: {}", e);
+                    return;
+                }
+            };
+        let SyncMessage::ClipboardItem { item_id, content_type, timestamp, payload } = message else { return };
+
+        let digest: [u8; 32] = Sha256::digest(&sealed).into();
+        {
+            let mut opened = self.opened.lock().unwrap();
+            let now = unix_secs();
+            opened.retain(|_, expiry| *expiry > now);
+            if opened.insert(digest, expires_at).is_some() {
+                return;
+            }
+        }
+        println!("[QUIC] Opened mailbox item {} from {}", item_id, sender);
+        let item = ReceivedItem { peer_id: sender, item_id, content_type, timestamp, payload, requires_ack: false };
+        let _ = self.incoming.send(item).await;
+    }
+
+    /// Seal a broadcast item for each paired device matching `filter` that isn't connected, and deposit it
+    ///
+    /// Devices without a sealing key are left out. As with live broadcasts,
+    /// the payload is first encrypted with the key shared with the device.
+    pub(crate) async fn deposit(
+        &self,
+        item: &BroadcastItem,
+        keys: &HashMap<String, Vec<u8>>,
+        filter: &BroadcastFilter,
+        connected: &[String],
+    ) -> HashMap<String, BroadcastOutcome> {
+        let mut outcomes = HashMap::new();
+        let expires_at = unix_secs() + self.ttl.as_secs();
+        for device_id in self.trust_store.paired() {
+            if !filter.matches(&device_id) || connected.contains(&device_id) {
+                continue;
+            }
+            let Some(recipient_key) = self.keys.peer(&device_id) else { continue };
+            let Some(key) = keys.get(&device_id) else {
+                outcomes.insert(device_id, BroadcastOutcome::NoKey);
+                continue;
+            };
+            let message = match encrypt_bytes(item.payload.clone(), key.clone()) {
+                Ok(ciphertext) => item.message(ciphertext),
+                Err(e) => {
+                    outcomes.insert(device_id, BroadcastOutcome::Failed { error: e });
+                    continue;
+                }
+            };
+            let outcome = match self.deposit_one(&device_id, &recipient_key, &message, expires_at).await {
+                Ok(()) => BroadcastOutcome::Deposited,
+                Err(e) => {
+                    println!("[QUIC] Depositing item {} for {} failed: {}", item.item_id, device_id, e);
+                    BroadcastOutcome::Failed { error: e.to_string() }
+                }
+            };
+            outcomes.insert(device_id, outcome);
+        }
+        outcomes
+    }
+
+    async fn deposit_one(
+        &self,
+        device_id: &str,
+        recipient_key: &[u8; 32],
+        message: &SyncMessage,
+        expires_at: u64,
+    ) -> Result<(), TransportError> {
+        let sealed = seal(&self.identity, device_id, recipient_key, message, expires_at)?;
+        if sealed.len() > MAX_SEALED_SIZE {
+            return Err(TransportError::Connection(format!(
+                "Sealed item is {} bytes, the mailbox takes at most {}",
+                sealed.len(),
+                MAX_SEALED_SIZE
+            )));
+        }
+        let signal = Signal::Deposit {
+            sender: self.channel.device_id().to_string(),
+            target: device_id.to_string(),
+            blob: BASE64.encode(&sealed),
+            ttl_secs: self.ttl.as_secs(),
+        };
+        self.channel.send(signal).await.map_err(|e| TransportError::Connection(e.to_string()))?;
+        println!("[QUIC] Deposited an item for offline device {}", device_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::generate_keypair;
+
+    #[test]
+    fn test_seal_and_open() {
+        let laptop = DeviceIdentity::generate("laptop".to_string()).unwrap();
+        let stranger = DeviceIdentity::generate("stranger".to_string()).unwrap();
+        let (secret, public) = generate_keypair();
+        let secret: [u8; 32] = secret.try_into().unwrap();
+        let public: [u8; 32] = public.try_into().unwrap();
+        let trust_store = TrustStore::new();
+        trust_store.pin("laptop", &laptop.fingerprint()).unwrap();
+
+        let message = SyncMessage::clipboard_item("text/plain".to_string(), b"ciphertext".to_vec());
+        let expires_at = unix_secs() + 60;
+        let sealed = seal(&laptop, "phone", &public, &message, expires_at).unwrap();
+        assert_eq!(open(&sealed, "phone", &secret, &trust_store).unwrap(), ("laptop".to_string(), expires_at, message.clone()));
+
+        assert!(open(&sealed, "tablet", &secret, &trust_store).is_err(), "Addressed to another device");
+        let (other_secret, _) = generate_keypair();
+        assert!(open(&sealed, "phone", &other_secret.try_into().unwrap(), &trust_store).is_err(), "Sealed to another key");
+
+        // Locate the ciphertext and signature by parsing the sealed item
+        let offset_of = |field: &[u8]| field.as_ptr() as usize - sealed.as_ptr() as usize;
+        let mut r = Reader::new(&sealed[SEAL_MAGIC.len() + 1..]);
+        r.string().unwrap();
+        r.string().unwrap();
+        r.u64().unwrap();
+        r.bytes().unwrap();
+        r.bytes().unwrap();
+        let ciphertext = offset_of(r.bytes().unwrap());
+        let signature = offset_of(r.bytes().unwrap());
+        let error = |sealed: &[u8]| match open(sealed, "phone", &secret, &trust_store) {
+            Err(TransportError::Protocol(e)) => e,
+            other => panic!("Expected a rejected item, got {:?}", other),
+        };
+
+        let mut tampered = sealed.clone();
+        tampered[ciphertext] ^= 1;
+        assert!(error(&tampered).contains("bad signature"), "Tampered ciphertext");
+        let mut tampered = sealed.clone();
+        tampered[signature] ^= 1;
+        assert!(error(&tampered).contains("bad signature"), "Tampered signature");
+        // Re-signed by the sender, a tampered ciphertext still fails to decrypt
+        let mut resigned = sealed[..signature - 4].to_vec();
+        resigned[ciphertext] ^= 1;
+        let signature = laptop.sign(&resigned).unwrap();
+        put_bytes(&mut resigned, &signature);
+        assert!(error(&resigned).contains("can't be opened"), "Tampered before signing");
+
+        let expired = seal(&laptop, "phone", &public, &message, unix_secs() - 1).unwrap();
+        assert!(open(&expired, "phone", &secret, &trust_store).is_err(), "Expired");
+        let unpaired = seal(&stranger, "phone", &public, &message, expires_at).unwrap();
+        assert!(open(&unpaired, "phone", &secret, &trust_store).is_err(), "From an unpaired device");
+        let first_use = TrustStore::new();
+        first_use.record("laptop", &laptop.fingerprint());
+        assert!(open(&sealed, "phone", &secret, &first_use).is_err(), "From a device only pinned on first use");
+    }
+}
@@ -1,3 +1,43 @@
+pub mod address;
+pub mod broadcast;
+mod candidates;
+pub mod datagram;
+pub mod delivery;
+pub mod events;
+mod happy_eyeballs;
+mod holepunch;
+pub mod identity;
+mod mailbox;
+pub mod options;
+mod peers;
+pub mod protocol;
 pub mod quic;
+pub mod receiver;
+pub mod relay;
+pub mod rendezvous;
+mod shutdown;
+mod socket;
+mod spool;
+pub mod stats;
+pub mod stun;
+pub mod supervisor;
+pub mod transfer;
+pub mod trust;
+pub use address::*;
+pub use broadcast::*;
+pub use datagram::*;
+pub use delivery::*;
+pub use events::*;
+pub use identity::*;
+pub use options::*;
+pub use protocol::*;
 pub use quic::*;
+pub use receiver::*;
+pub use relay::*;
+pub use rendezvous::*;
+pub use stats::*;
+pub use stun::*;
+pub use supervisor::*;
+pub use transfer::*;
+pub use trust::*;
 
@@ -0,0 +1,231 @@
+//! Transport Options for SyncMist
+//!
+//! QUIC settings that differ between deployments, such as a phone that should
+//! wake the radio rarely and a desktop on a fast LAN.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use quinn::congestion::{BbrConfig, CubicConfig, NewRenoConfig};
+use quinn::{IdleTimeout, TransportConfig, VarInt};
+use socket2::{Domain, Protocol, Socket, Type};
+
+use super::address::parse_ip;
+use super::protocol::MAX_FRAME_SIZE;
+use super::quic::TransportError;
+
+/// Bytes of datagrams buffered in each direction per connection
+const DATAGRAM_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Congestion control algorithm used for outgoing data
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CongestionController {
+    Cubic,
+    NewReno,
+    Bbr,
+}
+
+/// Tunable settings for a [`super::QuicTransport`]
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransportOptions {
+    /// Local IP address to bind, e.g. `::` (the default) or `0.0.0.0`
+    pub bind_address: String,
+    /// When bound to an IPv6 address, also send and receive IPv4
+    pub dual_stack: bool,
+    /// Interval between keep-alive packets in milliseconds, 0 to disable
+    pub keep_alive_interval_ms: u64,
+    /// Close connections idle for this many milliseconds, 0 to never time out
+    pub idle_timeout_ms: u64,
+    /// Streams of each direction a peer may have open at once
+    pub max_concurrent_streams: u32,
+    /// Largest message accepted from or sent to a peer, in bytes
+    pub max_message_size: u64,
+    /// Bytes a peer may send ahead of what we have read, per connection and per stream
+    pub receive_window: u64,
+    pub congestion_controller: CongestionController,
+    /// Largest chunked transfer accepted from a peer, in bytes
+    pub max_transfer_size: u64,
+    /// Directory received transfers are saved to, empty for a `syncmist` folder in the temp directory
+    pub transfer_directory: String,
+    /// Directory interrupted transfers are kept in, empty for `syncmist/spool` in the temp directory
+    pub spool_directory: String,
+    /// Interval between datagram heartbeats that measure link quality, 0 to disable
+    pub heartbeat_interval_ms: u64,
+    /// Interval between attempts to replace a relayed connection with a direct one, 0 to disable
+    pub direct_upgrade_interval_ms: u64,
+    /// How long the signaling hub keeps items sealed for offline devices, 0 to not leave any
+    pub mailbox_ttl_ms: u64,
+}
+
+impl Default for TransportOptions {
+    fn default() -> Self {
+        Self::desktop()
+    }
+}
+
+impl TransportOptions {
+    /// Defaults suited to desktops on a LAN
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn desktop() -> Self {
+        Self {
+            bind_address: "::".to_string(),
+            dual_stack: true,
+            keep_alive_interval_ms: 15_000,
+            idle_timeout_ms: 60_000,
+            max_concurrent_streams: 100,
+            max_message_size: MAX_FRAME_SIZE as u64,
+            receive_window: 8 * 1024 * 1024,
+            congestion_controller: CongestionController::Cubic,
+            max_transfer_size: 4 * 1024 * 1024 * 1024,
+            transfer_directory: String::new(),
+            spool_directory: String::new(),
+            heartbeat_interval_ms: 1_000,
+            direct_upgrade_interval_ms: 30_000,
+            mailbox_ttl_ms: 7 * 24 * 60 * 60 * 1000,
+        }
+    }
+
+    /// Defaults suited to phones: fewer keep-alives and smaller buffers
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn mobile() -> Self {
+        Self {
+            keep_alive_interval_ms: 25_000,
+            idle_timeout_ms: 120_000,
+            max_concurrent_streams: 32,
+            receive_window: 2 * 1024 * 1024,
+            congestion_controller: CongestionController::Bbr,
+            max_transfer_size: 512 * 1024 * 1024,
+            heartbeat_interval_ms: 5_000,
+            direct_upgrade_interval_ms: 60_000,
+            ..Self::desktop()
+        }
+    }
+
+    /// QUIC transport parameters for both accepted and dialed connections
+    pub(crate) fn transport_config(&self) -> Arc<TransportConfig> {
+        let mut config = TransportConfig::default();
+        config.keep_alive_interval(non_zero_millis(self.keep_alive_interval_ms));
+        config.max_idle_timeout(non_zero_millis(self.idle_timeout_ms).map(|timeout| {
+            IdleTimeout::try_from(timeout).unwrap_or_else(|_| VarInt::MAX.into())
+        }));
+        let streams = VarInt::from_u32(self.max_concurrent_streams);
+        config.max_concurrent_bidi_streams(streams);
+        config.max_concurrent_uni_streams(streams);
+        config.datagram_receive_buffer_size(Some(DATAGRAM_BUFFER_SIZE));
+        config.datagram_send_buffer_size(DATAGRAM_BUFFER_SIZE);
+        let window = VarInt::from_u64(self.receive_window).unwrap_or(VarInt::MAX);
+        config.receive_window(window);
+        config.stream_receive_window(window);
+        match self.congestion_controller {
+            CongestionController::Cubic => config.congestion_controller_factory(Arc::new(CubicConfig::default())),
+            CongestionController::NewReno => config.congestion_controller_factory(Arc::new(NewRenoConfig::default())),
+            CongestionController::Bbr => config.congestion_controller_factory(Arc::new(BbrConfig::default())),
+        };
+        Arc::new(config)
+    }
+
+    /// Largest message size as a length usable for buffers
+    pub(crate) fn max_message_size(&self) -> usize {
+        usize::try_from(self.max_message_size).unwrap_or(usize::MAX)
+    }
+
+    /// Directory received transfers are saved to
+    pub(crate) fn transfer_directory(&self) -> PathBuf {
+        if self.transfer_directory.is_empty() {
+            std::env::temp_dir().join("syncmist")
+        } else {
+            PathBuf::from(&self.transfer_directory)
+        }
+    }
+
+    /// Directory interrupted transfers are kept in
+    pub(crate) fn spool_directory(&self) -> PathBuf {
+        if self.spool_directory.is_empty() {
+            std::env::temp_dir().join("syncmist").join("spool")
+        } else {
+            PathBuf::from(&self.spool_directory)
+        }
+    }
+
+    /// Bind a UDP socket on the configured address
+    ///
+    /// Binding the IPv6 wildcard falls back to the IPv4 wildcard on hosts without IPv6.
+    pub(crate) fn bind_socket(&self, port: u16) -> Result<std::net::UdpSocket, TransportError> {
+        let (ip, scope_id) = parse_ip(&self.bind_address)?;
+        let addr = match ip {
+            IpAddr::V6(v6) => SocketAddr::V6(SocketAddrV6::new(v6, port, 0, scope_id)),
+            IpAddr::V4(_) => SocketAddr::new(ip, port),
+        };
+
+        match bind_udp(addr, self.dual_stack) {
+            Err(e) if ip == IpAddr::V6(Ipv6Addr::UNSPECIFIED) => {
+                println!("[QUIC] IPv6 unavailable ({}), binding IPv4 only", e);
+                bind_udp(SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), port), false)
+            }
+            result => result,
+        }
+    }
+}
+
+/// Create a non-blocking UDP socket bound to `addr`
+fn bind_udp(addr: SocketAddr, dual_stack: bool) -> Result<std::net::UdpSocket, TransportError> {
+    let socket = Socket::new(Domain::for_address(addr), Type::DGRAM, Some(Protocol::UDP))
+        .map_err(|e| TransportError::Io(format!("Failed to create socket: {}", e)))?;
+    if addr.is_ipv6() {
+        socket
+            .set_only_v6(!dual_stack)
+            .map_err(|e| TransportError::Io(format!("Failed to configure dual-stack socket: {}", e)))?;
+    }
+    socket
+        .bind(&addr.into())
+        .map_err(|e| TransportError::Io(format!("Failed to bind {}: {}", addr, e)))?;
+    socket
+        .set_nonblocking(true)
+        .map_err(|e| TransportError::Io(format!("Failed to configure socket: {}", e)))?;
+    Ok(socket.into())
+}
+
+fn non_zero_millis(millis: u64) -> Option<Duration> {
+    (millis > 0).then(|| Duration::from_millis(millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bind_socket_addresses() {
+        let options = TransportOptions { bind_address: "0.0.0.0".to_string(), ..TransportOptions::default() };
+        let socket = options.bind_socket(0).expect("IPv4 bind should succeed");
+        assert!(socket.local_addr().unwrap().is_ipv4());
+
+        // The dual-stack default binds the IPv6 wildcard, or IPv4 where IPv6 is unavailable
+        assert!(TransportOptions::default().bind_socket(0).is_ok());
+
+        let options = TransportOptions { bind_address: "::1".to_string(), ..TransportOptions::default() };
+        if let Ok(socket) = options.bind_socket(0) {
+            assert!(socket.local_addr().unwrap().is_ipv6());
+        }
+
+        let options = TransportOptions { bind_address: "not-an-ip".to_string(), ..TransportOptions::default() };
+        assert!(matches!(options.bind_socket(0), Err(TransportError::Connection(_))));
+    }
+
+    #[test]
+    fn test_out_of_range_values_are_clamped() {
+        let options = TransportOptions {
+            keep_alive_interval_ms: 0,
+            idle_timeout_ms: u64::MAX,
+            receive_window: u64::MAX,
+            max_message_size: u64::MAX,
+            ..TransportOptions::mobile()
+        };
+        // Must not panic on values QUIC can't represent
+        let _ = options.transport_config();
+        assert!(options.max_message_size() > 0);
+    }
+}
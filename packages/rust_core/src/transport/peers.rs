@@ -0,0 +1,346 @@
+//! Peer Connection Registry for SyncMist
+//!
+//! Connections are keyed by the device id proven in the TLS handshake, so a
+//! device that reconnects from a new address or port keeps the same peer id.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use quinn::Connection;
+use rustls::pki_types::CertificateDer;
+use tokio::sync::{broadcast, mpsc, Mutex, MutexGuard};
+
+use super::datagram::Links;
+use super::delivery::PendingAcks;
+use super::events::{publish, spawn_migration_watch, ConnectionEvent, DisconnectReason, EVENT_CAPACITY};
+use super::identity::device_id_from_cert;
+use super::protocol::{close_code, close_connection};
+use super::receiver::{spawn_receiver, ReceivedItem};
+use super::stats::PeerStats;
+use super::transfer::Transfers;
+
+/// Two connections to the same device established within this window in
+/// opposite directions are treated as a simultaneous open rather than a reconnect
+const SIMULTANEOUS_OPEN_WINDOW: Duration = Duration::from_secs(5);
+
+/// Connections accepted from one device within this window count towards its limit
+const ACCEPT_WINDOW: Duration = Duration::from_secs(10);
+
+/// Connections accepted from one device per [`ACCEPT_WINDOW`] before it is refused as rate limited
+const MAX_ACCEPTS_PER_WINDOW: usize = 10;
+
+/// A live connection to a peer device
+pub(crate) struct PeerEntry {
+    pub(crate) connection: Connection,
+    /// Whether we dialed this connection
+    pub(crate) outbound: bool,
+    /// Wire protocol version agreed in the Hello exchange
+    pub(crate) protocol_version: u16,
+    pub(crate) established: Instant,
+}
+
+impl PeerEntry {
+    pub(crate) fn new(connection: Connection, outbound: bool, protocol_version: u16) -> Self {
+        Self {
+            connection,
+            outbound,
+            protocol_version,
+            established: Instant::now(),
+        }
+    }
+}
+
+/// Shared registry of live connections
+///
+/// Cloned into the accept loop and per-connection tasks. The lock is only held
+/// for map operations, never across network I/O.
+#[derive(Clone)]
+pub(crate) struct PeerRegistry {
+    peers: Arc<Mutex<HashMap<String, PeerEntry>>>,
+    incoming: mpsc::Sender<ReceivedItem>,
+    events: broadcast::Sender<ConnectionEvent>,
+    max_message_size: usize,
+    transfers: Transfers,
+    acks: PendingAcks,
+    links: Links,
+    accepts: AcceptLimiter,
+}
+
+impl PeerRegistry {
+    pub(crate) fn new(
+        incoming: mpsc::Sender<ReceivedItem>,
+        max_message_size: usize,
+        transfers: Transfers,
+        links: Links,
+    ) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            peers: Arc::new(Mutex::new(HashMap::new())),
+            incoming,
+            events,
+            max_message_size,
+            transfers,
+            acks: PendingAcks::default(),
+            links,
+            accepts: AcceptLimiter::default(),
+        }
+    }
+
+    pub(crate) async fn lock(&self) -> MutexGuard<'_, HashMap<String, PeerEntry>> {
+        self.peers.lock().await
+    }
+
+    /// Connection to a peer, cloned so the lock is released before it is used
+    pub(crate) async fn connection(&self, peer_id: &str) -> Option<Connection> {
+        self.peers.lock().await.get(peer_id).map(|peer| peer.connection.clone())
+    }
+
+    /// Statistics of the connection to a peer
+    pub(crate) async fn stats(&self, peer_id: &str) -> Option<PeerStats> {
+        self.peers.lock().await.get(peer_id).map(|peer| PeerStats::new(peer_id, peer))
+    }
+
+    /// Statistics of all live connections, ordered by peer id
+    pub(crate) async fn all_stats(&self) -> Vec<PeerStats> {
+        let mut stats: Vec<PeerStats> = {
+            let peers = self.peers.lock().await;
+            peers.iter().map(|(peer_id, peer)| PeerStats::new(peer_id, peer)).collect()
+        };
+        stats.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+        stats
+    }
+
+    /// All live connections, taken under a single lock
+    pub(crate) async fn connections(&self) -> Vec<(String, Connection)> {
+        let peers = self.peers.lock().await;
+        peers.iter().map(|(peer_id, peer)| (peer_id.clone(), peer.connection.clone())).collect()
+    }
+
+    /// Register a connection and start receiving from it
+    ///
+    /// Returns `false` if the connection was closed as a duplicate.
+    pub(crate) async fn register(
+        &self,
+        local_device_id: &str,
+        peer_id: &str,
+        connection: Connection,
+        outbound: bool,
+        protocol_version: u16,
+    ) -> bool {
+        let entry = PeerEntry::new(connection.clone(), outbound, protocol_version);
+        let kept = {
+            let mut peers = self.peers.lock().await;
+            insert_peer(&mut peers, Some(local_device_id), peer_id, entry)
+        };
+        if kept {
+            self.publish(ConnectionEvent::PeerConnected {
+                peer_id: peer_id.to_string(),
+                address: connection.remote_address().to_string(),
+                outbound,
+            });
+            spawn_migration_watch(peer_id.to_string(), connection.clone(), self.events.clone());
+            self.transfers.resume(peer_id, &connection);
+            self.links.spawn(peer_id.to_string(), connection.clone());
+            spawn_receiver(peer_id.to_string(), connection, self.clone());
+        }
+        kept
+    }
+
+    /// Remove a peer whose connection closed, unless it was already replaced
+    pub(crate) async fn remove_closed(
+        &self,
+        peer_id: &str,
+        connection: &Connection,
+        reason: DisconnectReason,
+        by_peer: bool,
+    ) {
+        let removed = {
+            let mut peers = self.peers.lock().await;
+            let current = peers.get(peer_id).map(|peer| peer.connection.stable_id());
+            current == Some(connection.stable_id()) && peers.remove(peer_id).is_some()
+        };
+        if removed {
+            self.publish(ConnectionEvent::PeerDisconnected { peer_id: peer_id.to_string(), reason, by_peer });
+        }
+    }
+
+    pub(crate) fn incoming(&self) -> mpsc::Sender<ReceivedItem> {
+        self.incoming.clone()
+    }
+
+    /// Largest message accepted from or sent to peers
+    pub(crate) fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Chunked transfers in progress with any peer
+    pub(crate) fn transfers(&self) -> &Transfers {
+        &self.transfers
+    }
+
+    /// Received items whose sender waits for the app's decision
+    pub(crate) fn acks(&self) -> &PendingAcks {
+        &self.acks
+    }
+
+    /// Heartbeats and presence of connected peers
+    pub(crate) fn links(&self) -> &Links {
+        &self.links
+    }
+
+    /// Note a connection accepted from `peer_id`, returning `false` if the device connects too often
+    pub(crate) fn admit(&self, peer_id: &str) -> bool {
+        self.accepts.admit(peer_id, Instant::now())
+    }
+
+    pub(crate) fn publish(&self, event: ConnectionEvent) {
+        publish(&self.events, event);
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<ConnectionEvent> {
+        self.events.subscribe()
+    }
+}
+
+/// Times of recent connections accepted from each device
+///
+/// A device stuck in a reconnect loop would otherwise make us redo the Hello
+/// exchange and replace its connection over and over.
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Default)]
+struct AcceptLimiter {
+    accepted: Arc<std::sync::Mutex<HashMap<String, Vec<Instant>>>>,
+}
+
+impl AcceptLimiter {
+    fn admit(&self, peer_id: &str, now: Instant) -> bool {
+        let mut accepted = self.accepted.lock().unwrap();
+        accepted.retain(|_, times| {
+            times.retain(|time| now.duration_since(*time) < ACCEPT_WINDOW);
+            !times.is_empty()
+        });
+        let times = accepted.entry(peer_id.to_string()).or_default();
+        if times.len() >= MAX_ACCEPTS_PER_WINDOW {
+            return false;
+        }
+        times.push(now);
+        true
+    }
+}
+
+/// Device id from the certificate a peer presented in the TLS handshake
+pub(crate) fn authenticated_device_id(connection: &Connection) -> Option<String> {
+    let certs = connection
+        .peer_identity()?
+        .downcast::<Vec<CertificateDer<'static>>>()
+        .ok()?;
+    device_id_from_cert(certs.first()?.as_ref())
+}
+
+/// Peer id for a connection: the authenticated device id, else the device id we
+/// dialed, else the remote address for peers without a device certificate
+pub(crate) fn peer_id_for(connection: &Connection, expected_device_id: Option<&str>) -> String {
+    authenticated_device_id(connection)
+        .or_else(|| expected_device_id.map(str::to_string))
+        .unwrap_or_else(|| connection.remote_address().to_string())
+}
+
+/// Add a connection to the registry, reconciling with any existing connection to the same device
+///
+/// A newer connection normally replaces the old one (the device reconnected or
+/// moved). When both devices dial each other at the same time, both sides keep
+/// the connection dialed by the device with the smaller id so they agree on one.
+///
+/// Returns `false` if the new connection was closed as a duplicate.
+pub(crate) fn insert_peer(
+    peers: &mut HashMap<String, PeerEntry>,
+    local_device_id: Option<&str>,
+    peer_id: &str,
+    entry: PeerEntry,
+) -> bool {
+    let Some(existing) = peers.get(peer_id) else {
+        peers.insert(peer_id.to_string(), entry);
+        return true;
+    };
+
+    let existing_alive = existing.connection.close_reason().is_none();
+    if keep_existing(existing_alive, existing.outbound, existing.established.elapsed(), entry.outbound, local_device_id, peer_id) {
+        println!("[QUIC] Closing duplicate connection to {}", peer_id);
+        close_connection(&entry.connection, close_code::REPLACED);
+        return false;
+    }
+
+    let old_addr = existing.connection.remote_address();
+    let new_addr = entry.connection.remote_address();
+    if old_addr != new_addr {
+        println!("[QUIC] Device {} moved from {} to {}", peer_id, old_addr, new_addr);
+    }
+    if let Some(old) = peers.insert(peer_id.to_string(), entry) {
+        close_connection(&old.connection, close_code::REPLACED);
+    }
+    true
+}
+
+/// Whether an existing connection wins over a newly established one
+fn keep_existing(
+    existing_alive: bool,
+    existing_outbound: bool,
+    existing_age: Duration,
+    new_outbound: bool,
+    local_device_id: Option<&str>,
+    peer_id: &str,
+) -> bool {
+    let simultaneous_open =
+        existing_alive && existing_outbound != new_outbound && existing_age < SIMULTANEOUS_OPEN_WINDOW;
+    if !simultaneous_open {
+        return false;
+    }
+    match local_device_id {
+        // Keep the connection dialed by the smaller device id
+        Some(local) => existing_outbound == (local < peer_id),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRESH: Duration = Duration::from_millis(100);
+    const STALE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_newer_connection_replaces_reconnect() {
+        // Same direction: the device reconnected, newest wins
+        assert!(!keep_existing(true, false, FRESH, false, Some("a"), "b"));
+        assert!(!keep_existing(true, true, FRESH, true, Some("a"), "b"));
+        // Existing connection already closed
+        assert!(!keep_existing(false, true, FRESH, false, Some("a"), "b"));
+        // Opposite direction but long after: peer restarted and dialed us
+        assert!(!keep_existing(true, true, STALE, false, Some("a"), "b"));
+    }
+
+    #[test]
+    fn test_accept_limit() {
+        let limiter = AcceptLimiter::default();
+        let start = Instant::now();
+        for _ in 0..MAX_ACCEPTS_PER_WINDOW {
+            assert!(limiter.admit("a", start));
+        }
+        assert!(!limiter.admit("a", start), "One connection too many within the window");
+        assert!(limiter.admit("b", start), "Other devices have their own limit");
+        assert!(limiter.admit("a", start + ACCEPT_WINDOW), "Earlier connections age out");
+    }
+
+    #[test]
+    fn test_simultaneous_open_tie_break() {
+        // Local "a" < peer "b": both sides keep the connection "a" dialed
+        assert!(keep_existing(true, true, FRESH, false, Some("a"), "b"));
+        assert!(!keep_existing(true, false, FRESH, true, Some("a"), "b"));
+
+        // Local "b" > peer "a": keep the connection the peer dialed
+        assert!(keep_existing(true, false, FRESH, true, Some("b"), "a"));
+        assert!(!keep_existing(true, true, FRESH, false, Some("b"), "a"));
+    }
+}
@@ -0,0 +1,735 @@
+//! SyncMist Wire Protocol
+//!
+//! Every message travels as a frame: a one byte message type, a four byte
+//! big-endian payload length, then the payload. Integers in payloads are
+//! big-endian and strings/byte arrays are prefixed with a four byte length.
+//!
+//! Peers exchange [`SyncMessage::Hello`] on a bidirectional stream right after
+//! the QUIC handshake to agree on a protocol version. Chunked transfers also
+//! use a bidirectional stream each, see [`super::transfer`]. Heartbeats and
+//! presence go in QUIC datagrams of one frame each, see [`super::datagram`].
+
+use std::time::Duration;
+
+use quinn::{Connection, ReadError, ReadExactError, RecvStream, SendStream, VarInt, WriteError};
+
+use super::datagram::Activity;
+use super::peers::authenticated_device_id;
+use super::quic::TransportError;
+
+/// Highest protocol version this build speaks
+pub const PROTOCOL_VERSION: u16 = 1;
+
+/// Lowest protocol version this build still accepts
+pub const MIN_PROTOCOL_VERSION: u16 = 1;
+
+/// ALPN protocol id negotiated in the QUIC handshake
+pub(crate) const ALPN: &[u8] = b"syncmist";
+
+/// Largest frame payload accepted when decoding
+pub const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// Time allowed for the Hello exchange after the QUIC handshake
+const HELLO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time allowed for a refused hello to reach the peer before the connection is closed
+const HELLO_ERROR_FLUSH: Duration = Duration::from_secs(1);
+
+/// Stream error code for a stream abandoned because of a failure
+pub(crate) const STREAM_FAILED: VarInt = VarInt::from_u32(0);
+
+/// Stream error code for a stream abandoned at the user's request
+pub(crate) const STREAM_CANCELLED: VarInt = VarInt::from_u32(1);
+
+/// Application error codes sent when closing a connection
+///
+/// Received codes are reported as a [`super::events::DisconnectReason`].
+pub mod close_code {
+    /// No particular reason; older builds use it for every close
+    pub const UNSPECIFIED: u32 = 0;
+    /// The device is shutting down on purpose
+    pub const SHUTTING_DOWN: u32 = 1;
+    /// The user disconnected from the peer
+    pub const USER_DISCONNECT: u32 = 2;
+    /// The peer was unpaired or its pairing revoked
+    pub const UNPAIRED: u32 = 3;
+    /// The devices share no protocol version
+    pub const VERSION_MISMATCH: u32 = 4;
+    /// The peer connects more often than allowed
+    pub const RATE_LIMITED: u32 = 5;
+    /// A newer connection to the same device took over
+    pub const REPLACED: u32 = 6;
+    /// The peer broke the wire protocol
+    pub const PROTOCOL_VIOLATION: u32 = 7;
+    /// Another attempt to connect to the same device won, so this one was abandoned before Hello
+    pub const CANCELLED: u32 = 8;
+}
+
+/// Close a connection with an application close code and its matching reason phrase
+pub(crate) fn close_connection(connection: &Connection, code: u32) {
+    let phrase: &[u8] = match code {
+        close_code::SHUTTING_DOWN => b"shutdown",
+        close_code::USER_DISCONNECT => b"disconnect",
+        close_code::UNPAIRED => b"unpaired",
+        close_code::VERSION_MISMATCH => b"version mismatch",
+        close_code::RATE_LIMITED => b"rate limited",
+        close_code::REPLACED => b"replaced",
+        close_code::PROTOCOL_VIOLATION => b"protocol",
+        close_code::CANCELLED => b"cancelled",
+        _ => b"",
+    };
+    connection.close(code.into(), phrase);
+}
+
+/// Size of the type + length frame header
+const HEADER_SIZE: usize = 5;
+
+const TYPE_HELLO: u8 = 0x01;
+const TYPE_CLIPBOARD_ITEM: u8 = 0x02;
+const TYPE_ACK: u8 = 0x03;
+const TYPE_PING: u8 = 0x04;
+const TYPE_PONG: u8 = 0x05;
+const TYPE_HISTORY_REQUEST: u8 = 0x06;
+const TYPE_ERROR: u8 = 0x07;
+const TYPE_TRANSFER_OFFER: u8 = 0x08;
+const TYPE_TRANSFER_ACCEPT: u8 = 0x09;
+const TYPE_TRANSFER_CHUNK: u8 = 0x0a;
+const TYPE_TRANSFER_END: u8 = 0x0b;
+const TYPE_TRANSFER_ACK: u8 = 0x0c;
+const TYPE_PRESENCE: u8 = 0x0d;
+
+/// Protocol encoding/decoding errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[flutter_rust_bridge::frb]
+pub enum ProtocolError {
+    /// Frame or field ended early
+    Truncated,
+    /// Frame carries a message type this build doesn't know
+    UnknownMessageType(u8),
+    /// Frame payload exceeds the allowed size
+    FrameTooLarge { size: u64, max: u64 },
+    /// String field is not valid UTF-8
+    InvalidUtf8,
+    /// Payload has bytes left over after the message was decoded
+    TrailingBytes,
+    /// Peers share no protocol version
+    UnsupportedVersion { local_min: u16, local_max: u16, remote_min: u16, remote_max: u16 },
+    /// Peer sent a different message than the protocol requires at this point
+    UnexpectedMessage(String),
+    /// Hello names a different device than the peer's certificate
+    DeviceMismatch { hello: String, certificate: String },
+}
+
+impl std::fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProtocolError::Truncated => write!(f, "Truncated frame"),
+            ProtocolError::UnknownMessageType(t) => write!(f, "Unknown message type: {:#04x}", t),
+            ProtocolError::FrameTooLarge { size, max } => {
+                write!(f, "Frame too large: {} bytes (max {})", size, max)
+            }
+            ProtocolError::InvalidUtf8 => write!(f, "Invalid UTF-8 in string field"),
+            ProtocolError::TrailingBytes => write!(f, "Trailing bytes after message"),
+            ProtocolError::UnsupportedVersion { local_min, local_max, remote_min, remote_max } => write!(
+                f,
+                "No common protocol version: we support {}-{}, peer supports {}-{}",
+                local_min, local_max, remote_min, remote_max
+            ),
+            ProtocolError::UnexpectedMessage(m) => write!(f, "Unexpected message: {}", m),
+            ProtocolError::DeviceMismatch { hello, certificate } => write!(
+                f,
+                "Hello names device {} but the certificate belongs to {}",
+                hello, certificate
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProtocolError {}
+
+impl From<ProtocolError> for TransportError {
+    fn from(e: ProtocolError) -> Self {
+        TransportError::Protocol(e.to_string())
+    }
+}
+
+/// Error codes carried by [`SyncMessage::Error`]
+pub mod error_code {
+    /// Peers share no protocol version
+    pub const VERSION_MISMATCH: u16 = 1;
+    /// Malformed or unexpected message
+    pub const PROTOCOL_VIOLATION: u16 = 2;
+    /// Message type is understood but not supported by this peer
+    pub const UNSUPPORTED: u16 = 3;
+    /// Transfer is larger than the receiver accepts
+    pub const TOO_LARGE: u16 = 4;
+    /// Received content doesn't match the sender's hash
+    pub const HASH_MISMATCH: u16 = 5;
+    /// Receiver could not store the transfer
+    pub const TRANSFER_FAILED: u16 = 6;
+    /// Receiver did not apply a clipboard item sent with an acknowledgement
+    pub const REJECTED: u16 = 7;
+}
+
+/// A message exchanged between SyncMist peers
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[flutter_rust_bridge::frb]
+pub enum SyncMessage {
+    /// Version negotiation, sent by both sides when a connection opens
+    Hello {
+        device_id: String,
+        min_version: u16,
+        max_version: u16,
+    },
+    /// A clipboard entry (payload is already end-to-end encrypted)
+    ClipboardItem {
+        item_id: u64,
+        content_type: String,
+        timestamp: u64,
+        payload: Vec<u8>,
+    },
+    /// Acknowledges receipt of a clipboard item
+    Ack { item_id: u64 },
+    /// Liveness probe
+    Ping { nonce: u64 },
+    /// Reply to a [`SyncMessage::Ping`]
+    Pong { nonce: u64 },
+    /// Ask a peer for clipboard items newer than `since` (unix millis)
+    HistoryRequest { since: u64, limit: u32 },
+    /// Report a failure to the peer
+    Error { code: u16, message: String },
+    /// Opens a chunked transfer of `size` bytes
+    TransferOffer {
+        transfer_id: u64,
+        name: String,
+        content_type: String,
+        size: u64,
+    },
+    /// Receiver accepts a transfer and asks for content from `offset`
+    TransferAccept { transfer_id: u64, offset: u64 },
+    /// Transfer content starting at `offset`
+    TransferChunk { offset: u64, data: Vec<u8> },
+    /// Ends a transfer with the SHA-256 hash of the whole content
+    TransferEnd { sha256: Vec<u8> },
+    /// Receiver has stored transfer content up to `offset` durably
+    TransferAck { transfer_id: u64, offset: u64 },
+    /// What the user is doing on the sending device
+    Presence { activity: Activity },
+}
+
+impl SyncMessage {
+    /// Hello advertising the versions this build supports
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn hello(device_id: String) -> Self {
+        SyncMessage::Hello {
+            device_id,
+            min_version: MIN_PROTOCOL_VERSION,
+            max_version: PROTOCOL_VERSION,
+        }
+    }
+
+    /// Clipboard item with a fresh random id, stamped with the current time
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn clipboard_item(content_type: String, payload: Vec<u8>) -> Self {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        SyncMessage::ClipboardItem { item_id: rand::random(), content_type, timestamp, payload }
+    }
+
+    /// Short name of the message type, used in logs and errors
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SyncMessage::Hello { .. } => "Hello",
+            SyncMessage::ClipboardItem { .. } => "ClipboardItem",
+            SyncMessage::Ack { .. } => "Ack",
+            SyncMessage::Ping { .. } => "Ping",
+            SyncMessage::Pong { .. } => "Pong",
+            SyncMessage::HistoryRequest { .. } => "HistoryRequest",
+            SyncMessage::Error { .. } => "Error",
+            SyncMessage::TransferOffer { .. } => "TransferOffer",
+            SyncMessage::TransferAccept { .. } => "TransferAccept",
+            SyncMessage::TransferChunk { .. } => "TransferChunk",
+            SyncMessage::TransferEnd { .. } => "TransferEnd",
+            SyncMessage::TransferAck { .. } => "TransferAck",
+            SyncMessage::Presence { .. } => "Presence",
+        }
+    }
+
+    /// Encode the message as a complete frame
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut payload = Vec::new();
+        let message_type = match self {
+            SyncMessage::Hello { device_id, min_version, max_version } => {
+                put_u16(&mut payload, *min_version);
+                put_u16(&mut payload, *max_version);
+                put_bytes(&mut payload, device_id.as_bytes());
+                TYPE_HELLO
+            }
+            SyncMessage::ClipboardItem { item_id, content_type, timestamp, payload: data } => {
+                put_u64(&mut payload, *item_id);
+                put_bytes(&mut payload, content_type.as_bytes());
+                put_u64(&mut payload, *timestamp);
+                put_bytes(&mut payload, data);
+                TYPE_CLIPBOARD_ITEM
+            }
+            SyncMessage::Ack { item_id } => {
+                put_u64(&mut payload, *item_id);
+                TYPE_ACK
+            }
+            SyncMessage::Ping { nonce } => {
+                put_u64(&mut payload, *nonce);
+                TYPE_PING
+            }
+            SyncMessage::Pong { nonce } => {
+                put_u64(&mut payload, *nonce);
+                TYPE_PONG
+            }
+            SyncMessage::HistoryRequest { since, limit } => {
+                put_u64(&mut payload, *since);
+                put_u32(&mut payload, *limit);
+                TYPE_HISTORY_REQUEST
+            }
+            SyncMessage::Error { code, message } => {
+                put_u16(&mut payload, *code);
+                put_bytes(&mut payload, message.as_bytes());
+                TYPE_ERROR
+            }
+            SyncMessage::TransferOffer { transfer_id, name, content_type, size } => {
+                put_u64(&mut payload, *transfer_id);
+                put_bytes(&mut payload, name.as_bytes());
+                put_bytes(&mut payload, content_type.as_bytes());
+                put_u64(&mut payload, *size);
+                TYPE_TRANSFER_OFFER
+            }
+            SyncMessage::TransferAccept { transfer_id, offset } => {
+                put_u64(&mut payload, *transfer_id);
+                put_u64(&mut payload, *offset);
+                TYPE_TRANSFER_ACCEPT
+            }
+            SyncMessage::TransferChunk { offset, data } => {
+                put_u64(&mut payload, *offset);
+                put_bytes(&mut payload, data);
+                TYPE_TRANSFER_CHUNK
+            }
+            SyncMessage::TransferEnd { sha256 } => {
+                put_bytes(&mut payload, sha256);
+                TYPE_TRANSFER_END
+            }
+            SyncMessage::TransferAck { transfer_id, offset } => {
+                put_u64(&mut payload, *transfer_id);
+                put_u64(&mut payload, *offset);
+                TYPE_TRANSFER_ACK
+            }
+            SyncMessage::Presence { activity } => {
+                payload.push(activity.code());
+                TYPE_PRESENCE
+            }
+        };
+
+        let mut frame = Vec::with_capacity(HEADER_SIZE + payload.len());
+        frame.push(message_type);
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&payload);
+        frame
+    }
+
+    /// Decode a complete frame produced by [`SyncMessage::encode`]
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn decode(frame: &[u8]) -> Result<Self, ProtocolError> {
+        let (message_type, len) = decode_header(frame, MAX_FRAME_SIZE)?;
+        let payload = &frame[HEADER_SIZE..];
+        if payload.len() < len {
+            return Err(ProtocolError::Truncated);
+        }
+        if payload.len() > len {
+            return Err(ProtocolError::TrailingBytes);
+        }
+        Self::decode_payload(message_type, payload)
+    }
+
+    fn decode_payload(message_type: u8, payload: &[u8]) -> Result<Self, ProtocolError> {
+        let mut r = Reader::new(payload);
+        let message = match message_type {
+            TYPE_HELLO => {
+                let min_version = r.u16()?;
+                let max_version = r.u16()?;
+                SyncMessage::Hello { device_id: r.string()?, min_version, max_version }
+            }
+            TYPE_CLIPBOARD_ITEM => SyncMessage::ClipboardItem {
+                item_id: r.u64()?,
+                content_type: r.string()?,
+                timestamp: r.u64()?,
+                payload: r.bytes()?.to_vec(),
+            },
+            TYPE_ACK => SyncMessage::Ack { item_id: r.u64()? },
+            TYPE_PING => SyncMessage::Ping { nonce: r.u64()? },
+            TYPE_PONG => SyncMessage::Pong { nonce: r.u64()? },
+            TYPE_HISTORY_REQUEST => SyncMessage::HistoryRequest { since: r.u64()?, limit: r.u32()? },
+            TYPE_ERROR => SyncMessage::Error { code: r.u16()?, message: r.string()? },
+            TYPE_TRANSFER_OFFER => SyncMessage::TransferOffer {
+                transfer_id: r.u64()?,
+                name: r.string()?,
+                content_type: r.string()?,
+                size: r.u64()?,
+            },
+            TYPE_TRANSFER_ACCEPT => SyncMessage::TransferAccept { transfer_id: r.u64()?, offset: r.u64()? },
+            TYPE_TRANSFER_CHUNK => SyncMessage::TransferChunk { offset: r.u64()?, data: r.bytes()?.to_vec() },
+            TYPE_TRANSFER_END => SyncMessage::TransferEnd { sha256: r.bytes()?.to_vec() },
+            TYPE_TRANSFER_ACK => SyncMessage::TransferAck { transfer_id: r.u64()?, offset: r.u64()? },
+            TYPE_PRESENCE => SyncMessage::Presence { activity: Activity::from_code(r.u8()?) },
+            other => return Err(ProtocolError::UnknownMessageType(other)),
+        };
+        if !r.is_empty() {
+            return Err(ProtocolError::TrailingBytes);
+        }
+        Ok(message)
+    }
+}
+
+/// Pick the highest protocol version both peers support
+#[flutter_rust_bridge::frb(ignore)]
+pub fn negotiate_version(remote_min: u16, remote_max: u16) -> Result<u16, ProtocolError> {
+    let version = PROTOCOL_VERSION.min(remote_max);
+    if version < MIN_PROTOCOL_VERSION.max(remote_min) {
+        return Err(ProtocolError::UnsupportedVersion {
+            local_min: MIN_PROTOCOL_VERSION,
+            local_max: PROTOCOL_VERSION,
+            remote_min,
+            remote_max,
+        });
+    }
+    Ok(version)
+}
+
+/// Write one message frame to a QUIC stream
+///
+/// Messages whose payload exceeds `max_size` are refused without sending anything.
+pub(crate) async fn write_message(send: &mut SendStream, message: &SyncMessage, max_size: usize) -> Result<(), TransportError> {
+    let frame = message.encode();
+    let size = frame.len() - HEADER_SIZE;
+    if size > max_size {
+        return Err(ProtocolError::FrameTooLarge { size: size as u64, max: max_size as u64 }.into());
+    }
+    send.write_all(&frame).await.map_err(|e| match e {
+        WriteError::Stopped(code) if code == STREAM_CANCELLED => {
+            TransportError::Cancelled("Peer stopped the stream".to_string())
+        }
+        e => TransportError::Io(format!("Failed to send {}: {}", message.kind(), e)),
+    })
+}
+
+/// Send one message on a new unidirectional stream
+///
+/// Returns the finished stream, whose [`SendStream::stopped`] resolves once the
+/// peer has acknowledged the message.
+pub(crate) async fn send_on_new_stream(
+    connection: &Connection,
+    message: &SyncMessage,
+    max_size: usize,
+) -> Result<SendStream, TransportError> {
+    let mut send = connection
+        .open_uni()
+        .await
+        .map_err(|e| TransportError::Connection(format!("Failed to open stream: {}", e)))?;
+    write_message(&mut send, message, max_size).await?;
+    send.finish()
+        .map_err(|e| TransportError::Io(format!("Failed to finish stream: {}", e)))?;
+    Ok(send)
+}
+
+/// Read one message frame from a QUIC stream
+///
+/// The payload length is checked against `max_size` before anything is allocated.
+pub(crate) async fn read_message(recv: &mut RecvStream, max_size: usize) -> Result<SyncMessage, TransportError> {
+    let mut header = [0u8; HEADER_SIZE];
+    recv.read_exact(&mut header)
+        .await
+        .map_err(|e| read_error("header", e))?;
+    let (message_type, len) = decode_header(&header, max_size)?;
+
+    let mut payload = vec![0u8; len];
+    recv.read_exact(&mut payload)
+        .await
+        .map_err(|e| read_error("payload", e))?;
+    Ok(SyncMessage::decode_payload(message_type, &payload)?)
+}
+
+fn read_error(part: &str, e: ReadExactError) -> TransportError {
+    match e {
+        ReadExactError::ReadError(ReadError::Reset(code)) if code == STREAM_CANCELLED => {
+            TransportError::Cancelled("Peer reset the stream".to_string())
+        }
+        e => TransportError::Io(format!("Failed to read frame {}: {}", part, e)),
+    }
+}
+
+/// Hello exchange as the dialing side. Returns the negotiated protocol version.
+///
+/// On failure the connection is closed with [`close_code::VERSION_MISMATCH`] or
+/// [`close_code::PROTOCOL_VIOLATION`].
+pub(crate) async fn hello_initiator(connection: &Connection, device_id: String) -> Result<u16, TransportError> {
+    let mut code = close_code::PROTOCOL_VIOLATION;
+    let result = with_hello_timeout(async {
+        let (mut send, mut recv) = connection.open_bi()
+            .await
+            .map_err(|e| TransportError::Connection(format!("Failed to open hello stream: {}", e)))?;
+        write_message(&mut send, &SyncMessage::hello(device_id), MAX_FRAME_SIZE).await?;
+        let _ = send.finish();
+
+        match read_message(&mut recv, MAX_FRAME_SIZE).await? {
+            SyncMessage::Hello { device_id: named, min_version, max_version } => {
+                check_hello_device(connection, &named)?;
+                let negotiated = negotiate_version(min_version, max_version);
+                if let Err(ProtocolError::UnsupportedVersion { .. }) = negotiated {
+                    code = close_code::VERSION_MISMATCH;
+                }
+                Ok(negotiated?)
+            }
+            SyncMessage::Error { code: error, message } => {
+                if error == error_code::VERSION_MISMATCH {
+                    code = close_code::VERSION_MISMATCH;
+                }
+                Err(TransportError::Protocol(format!("Peer refused hello ({}): {}", error, message)))
+            }
+            other => Err(ProtocolError::UnexpectedMessage(other.kind().to_string()).into()),
+        }
+    })
+    .await;
+    if result.is_err() {
+        close_connection(connection, code);
+    }
+    result
+}
+
+/// Hello exchange as the accepting side. Returns the negotiated protocol version.
+///
+/// Peers without a common version, or whose Hello names another device than
+/// their certificate, are sent an error before the call fails, and the
+/// connection is closed as in [`hello_initiator`].
+pub(crate) async fn hello_responder(connection: &Connection, device_id: String) -> Result<u16, TransportError> {
+    let mut code = close_code::PROTOCOL_VIOLATION;
+    let result = with_hello_timeout(async {
+        let (mut send, mut recv) = connection.accept_bi()
+            .await
+            .map_err(|e| TransportError::Connection(format!("Failed to accept hello stream: {}", e)))?;
+
+        let negotiated = match read_message(&mut recv, MAX_FRAME_SIZE).await? {
+            SyncMessage::Hello { device_id: named, min_version, max_version } => {
+                check_hello_device(connection, &named).and_then(|_| negotiate_version(min_version, max_version))
+            }
+            other => Err(ProtocolError::UnexpectedMessage(other.kind().to_string())),
+        };
+
+        let reply = match &negotiated {
+            Ok(_) => SyncMessage::hello(device_id),
+            Err(e @ ProtocolError::UnsupportedVersion { .. }) => {
+                code = close_code::VERSION_MISMATCH;
+                SyncMessage::Error { code: error_code::VERSION_MISMATCH, message: e.to_string() }
+            }
+            Err(e) => SyncMessage::Error { code: error_code::PROTOCOL_VIOLATION, message: e.to_string() },
+        };
+        write_message(&mut send, &reply, MAX_FRAME_SIZE).await?;
+        let _ = send.finish();
+        if negotiated.is_err() {
+            // Let the error reach the peer before the close discards it
+            let _ = tokio::time::timeout(HELLO_ERROR_FLUSH, send.stopped()).await;
+        }
+
+        Ok(negotiated?)
+    })
+    .await;
+    if result.is_err() {
+        close_connection(connection, code);
+    }
+    result
+}
+
+/// Make sure a Hello names the device its peer's certificate was issued to.
+/// Peers without a device certificate have nothing to check against.
+fn check_hello_device(connection: &Connection, named: &str) -> Result<(), ProtocolError> {
+    match authenticated_device_id(connection) {
+        Some(certificate) if certificate != named => {
+            Err(ProtocolError::DeviceMismatch { hello: named.to_string(), certificate })
+        }
+        _ => Ok(()),
+    }
+}
+
+async fn with_hello_timeout<F>(hello: F) -> Result<u16, TransportError>
+where
+    F: std::future::Future<Output = Result<u16, TransportError>>,
+{
+    tokio::time::timeout(HELLO_TIMEOUT, hello)
+        .await
+        .map_err(|_| TransportError::Protocol("Timed out waiting for hello".to_string()))?
+}
+
+fn decode_header(frame: &[u8], max_size: usize) -> Result<(u8, usize), ProtocolError> {
+    if frame.len() < HEADER_SIZE {
+        return Err(ProtocolError::Truncated);
+    }
+    let len = u32::from_be_bytes([frame[1], frame[2], frame[3], frame[4]]) as usize;
+    if len > max_size {
+        return Err(ProtocolError::FrameTooLarge { size: len as u64, max: max_size as u64 });
+    }
+    Ok((frame[0], len))
+}
+
+pub(crate) fn put_u16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&v.to_be_bytes());
+}
+
+pub(crate) fn put_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_be_bytes());
+}
+
+pub(crate) fn put_u64(out: &mut Vec<u8>, v: u64) {
+    out.extend_from_slice(&v.to_be_bytes());
+}
+
+pub(crate) fn put_bytes(out: &mut Vec<u8>, v: &[u8]) {
+    put_u32(out, v.len() as u32);
+    out.extend_from_slice(v);
+}
+
+/// Cursor over a frame payload
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ProtocolError> {
+        if self.data.len() < n {
+            return Err(ProtocolError::Truncated);
+        }
+        let (head, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(head)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, ProtocolError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, ProtocolError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, ProtocolError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, ProtocolError> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn bytes(&mut self) -> Result<&'a [u8], ProtocolError> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    pub(crate) fn string(&mut self) -> Result<String, ProtocolError> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(|_| ProtocolError::InvalidUtf8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_messages() -> Vec<SyncMessage> {
+        vec![
+            SyncMessage::hello("device-1".to_string()),
+            SyncMessage::ClipboardItem {
+                item_id: 42,
+                content_type: "text/plain".to_string(),
+                timestamp: 1_700_000_000_000,
+                payload: vec![1, 2, 3, 4],
+            },
+            SyncMessage::Ack { item_id: 42 },
+            SyncMessage::Ping { nonce: 7 },
+            SyncMessage::Pong { nonce: 7 },
+            SyncMessage::HistoryRequest { since: 123, limit: 50 },
+            SyncMessage::Error { code: error_code::UNSUPPORTED, message: "nope".to_string() },
+            SyncMessage::TransferOffer {
+                transfer_id: 9,
+                name: "photo.png".to_string(),
+                content_type: "image/png".to_string(),
+                size: 1 << 40,
+            },
+            SyncMessage::TransferAccept { transfer_id: 9, offset: 4096 },
+            SyncMessage::TransferChunk { offset: 4096, data: vec![0xaa; 16] },
+            SyncMessage::TransferEnd { sha256: vec![7; 32] },
+            SyncMessage::TransferAck { transfer_id: 9, offset: 1 << 20 },
+            SyncMessage::Presence { activity: Activity::Typing },
+        ]
+    }
+
+    #[test]
+    fn test_message_roundtrip() {
+        for message in all_messages() {
+            let frame = message.encode();
+            let decoded = SyncMessage::decode(&frame).unwrap();
+            assert_eq!(decoded, message, "{} should survive a roundtrip", message.kind());
+        }
+    }
+
+    #[test]
+    fn test_stable_encoding() {
+        // The wire format is a compatibility contract with other clients
+        assert_eq!(SyncMessage::Ack { item_id: 1 }.encode(), vec![0x03, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            SyncMessage::hello("ab".to_string()).encode(),
+            vec![0x01, 0, 0, 0, 10, 0, 1, 0, 1, 0, 0, 0, 2, b'a', b'b']
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(SyncMessage::decode(&[0x03, 0, 0]), Err(ProtocolError::Truncated));
+        assert_eq!(SyncMessage::decode(&[0xff, 0, 0, 0, 0]), Err(ProtocolError::UnknownMessageType(0xff)));
+
+        let mut frame = SyncMessage::Ping { nonce: 1 }.encode();
+        frame.push(0);
+        assert_eq!(SyncMessage::decode(&frame), Err(ProtocolError::TrailingBytes));
+        frame.truncate(frame.len() - 2);
+        assert_eq!(SyncMessage::decode(&frame), Err(ProtocolError::Truncated));
+
+        // Declared length beyond the limit is rejected without reading the payload
+        let huge = [0x02, 0xff, 0xff, 0xff, 0xff];
+        assert!(matches!(SyncMessage::decode(&huge), Err(ProtocolError::FrameTooLarge { .. })));
+
+        // String fields must be UTF-8
+        let bad_utf8 = [0x07, 0, 0, 0, 7, 0, 1, 0, 0, 0, 1, 0xff];
+        assert_eq!(SyncMessage::decode(&bad_utf8), Err(ProtocolError::InvalidUtf8));
+    }
+
+    #[test]
+    fn test_negotiate_version() {
+        assert_eq!(negotiate_version(1, 1), Ok(1));
+        assert_eq!(negotiate_version(1, PROTOCOL_VERSION + 5), Ok(PROTOCOL_VERSION));
+        assert!(matches!(
+            negotiate_version(PROTOCOL_VERSION + 1, PROTOCOL_VERSION + 2),
+            Err(ProtocolError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_protocol_error_into_transport_error() {
+        let err: TransportError = ProtocolError::Truncated.into();
+        assert!(matches!(err, TransportError::Protocol(_)));
+        assert!(err.to_string().contains("Truncated frame"));
+    }
+}
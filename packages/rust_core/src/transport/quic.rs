@@ -8,9 +8,39 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use futures::Stream;
-use quinn::{Connection, Endpoint, ServerConfig, ClientConfig, TransportConfig};
-use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
-use tokio::sync::Mutex;
+use quinn::{Endpoint, Incoming, ServerConfig, ClientConfig, TransportConfig};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio::sync::{broadcast, mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use super::address::resolve_addresses;
+use super::broadcast::{send_to_all, BroadcastFilter, BroadcastItem, BroadcastOutcome};
+use super::candidates::CandidateExchange;
+use super::datagram::{Activity, LinkEvent, LinkQuality, Links};
+use super::delivery::{send_with_ack, DeliveryResult};
+use super::events::{ConnectionEvent, DisconnectReason};
+use super::happy_eyeballs::{order_addresses, race};
+use super::identity::{DeviceIdentity, SERVER_NAME};
+use super::mailbox::{Mailbox, SealingKeys};
+use super::options::TransportOptions;
+use super::peers::{authenticated_device_id, peer_id_for, PeerRegistry};
+use super::protocol::{close_code, close_connection, hello_initiator, hello_responder, send_on_new_stream, SyncMessage, ALPN};
+use super::receiver::{ReceivedItem, INCOMING_CAPACITY};
+use super::relay::{self, is_relayed};
+use super::rendezvous::Rendezvous;
+use super::shutdown::Drain;
+use super::socket::RawSocket;
+use super::stun::{binding, classify, NatReport};
+use super::stats::{PeerStats, MIN_STATS_INTERVAL};
+use super::supervisor::{publish_state, spawn_supervisor, PeerState, SupervisedPeer};
+use super::transfer::{self, PendingTransfer, TransferEvent, TransferInfo, Transfers};
+use super::trust::{PairedClientVerifier, PinnedFingerprint, TofuCertVerifier, TrustStore};
+use crate::discovery::mdns::{MdnsDiscovery, PeerInfo};
+use crate::frb_generated::StreamSink;
+use crate::signaling::SignalingClient;
+
+/// Longest a closing transport waits for close frames to reach its peers
+const CLOSE_FLUSH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
 
 /// Transport layer errors
 #[derive(Debug)]
@@ -26,6 +56,18 @@ pub enum TransportError {
     NotConnected,
     /// Peer not found in connections
     PeerNotFound(String),
+    /// Peer presented a certificate that differs from its pinned fingerprint
+    FingerprintMismatch {
+        device_id: String,
+        expected: String,
+        actual: String,
+    },
+    /// Peer violated the wire protocol or shares no protocol version with us
+    Protocol(String),
+    /// Transfer was cancelled by us or the peer
+    Cancelled(String),
+    /// Peer refused a transfer
+    Rejected(String),
 }
 
 impl std::fmt::Display for TransportError {
@@ -36,6 +78,14 @@ impl std::fmt::Display for TransportError {
             TransportError::Tls(e) => write!(f, "TLS error: {}", e),
             TransportError::NotConnected => write!(f, "Not connected"),
             TransportError::PeerNotFound(id) => write!(f, "Peer not found: {}", id),
+            TransportError::FingerprintMismatch { device_id, expected, actual } => write!(
+                f,
+                "Certificate fingerprint mismatch for device {}: expected {}, got {}",
+                device_id, expected, actual
+            ),
+            TransportError::Protocol(e) => write!(f, "Protocol error: {}", e),
+            TransportError::Cancelled(e) => write!(f, "Cancelled: {}", e),
+            TransportError::Rejected(e) => write!(f, "Rejected by peer: {}", e),
         }
     }
 }
@@ -59,472 +109,3089 @@ pub fn generate_self_signed_cert() -> Result<(Vec<u8>, Vec<u8>), TransportError>
     Ok((cert_der, key_der))
 }
 
-/// Internal function to get certificate types for quinn
-fn get_cert_and_key() -> Result<(CertificateDer<'static>, PrivateKeyDer<'static>), TransportError> {
-    let (cert_der, key_der) = generate_self_signed_cert()?;
-    let cert = CertificateDer::from(cert_der);
-    let key = PrivateKeyDer::from(PrivatePkcs8KeyDer::from(key_der));
-    Ok((cert, key))
-}
-
-/// TOFU (Trust On First Use) Certificate Verifier
-///
-/// This verifier skips standard certificate verification for P2P connections.
-/// TODO: Add fingerprint pinning from pairing for enhanced security.
-#[derive(Debug)]
-struct TofuCertVerifier;
-
-impl rustls::client::danger::ServerCertVerifier for TofuCertVerifier {
-    fn verify_server_cert(
-        &self,
-        _end_entity: &CertificateDer<'_>,
-        _intermediates: &[CertificateDer<'_>],
-        _server_name: &rustls::pki_types::ServerName<'_>,
-        _ocsp_response: &[u8],
-        _now: rustls::pki_types::UnixTime,
-    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
-        // TOFU: Skip standard verification for P2P
-        // TODO: Add fingerprint pinning from pairing
-        println!("[QUIC] TOFU: Accepting certificate (fingerprint pinning TODO)");
-        Ok(rustls::client::danger::ServerCertVerified::assertion())
-    }
-
-    fn verify_tls12_signature(
-        &self,
-        _message: &[u8],
-        _cert: &CertificateDer<'_>,
-        _dss: &rustls::DigitallySignedStruct,
-    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
-        Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
-    }
-
-    fn verify_tls13_signature(
-        &self,
-        _message: &[u8],
-        _cert: &CertificateDer<'_>,
-        _dss: &rustls::DigitallySignedStruct,
-    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
-        Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
-    }
-
-    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
-        vec![
-            rustls::SignatureScheme::RSA_PKCS1_SHA256,
-            rustls::SignatureScheme::RSA_PKCS1_SHA384,
-            rustls::SignatureScheme::RSA_PKCS1_SHA512,
-            rustls::SignatureScheme::ECDSA_NISTP256_SHA256,
-            rustls::SignatureScheme::ECDSA_NISTP384_SHA384,
-            rustls::SignatureScheme::ECDSA_NISTP521_SHA512,
-            rustls::SignatureScheme::RSA_PSS_SHA256,
-            rustls::SignatureScheme::RSA_PSS_SHA384,
-            rustls::SignatureScheme::RSA_PSS_SHA512,
-            rustls::SignatureScheme::ED25519,
-        ]
-    }
-}
-
 /// QUIC Transport for P2P clipboard sync
 ///
 /// Manages QUIC connections for secure, low-latency data transfer between peers.
 #[flutter_rust_bridge::frb]
 pub struct QuicTransport {
     endpoint: Option<Endpoint>,
-    connections: Arc<Mutex<HashMap<String, Connection>>>,
-    is_server: bool,
+    options: TransportOptions,
+    registry: PeerRegistry,
+    identity: Option<DeviceIdentity>,
+    trust_store: TrustStore,
+    /// Items from all peers' receive tasks
+    incoming_rx: Arc<Mutex<mpsc::Receiver<ReceivedItem>>>,
+    /// Reconnect tasks of peers we keep connected to
+    supervised: Arc<std::sync::Mutex<HashMap<String, JoinHandle<()>>>>,
+    /// Peers found by mDNS, consulted when reconnecting
+    discovered: Option<Arc<Mutex<Vec<PeerInfo>>>>,
+    /// Address that last won the connection race to each device
+    preferred_addresses: Arc<std::sync::Mutex<HashMap<String, SocketAddr>>>,
+    /// AES-256 keys shared with paired devices, used to encrypt broadcasts
+    peer_keys: Arc<std::sync::Mutex<HashMap<String, Vec<u8>>>>,
+    /// Sends not yet acknowledged by their peer, waited for by [`QuicTransport::shutdown`]
+    drain: Drain,
+    /// Non-QUIC traffic on the endpoint's socket
+    raw: RawSocket,
+    /// Rendezvous service we are registered with, and its background task
+    rendezvous: Option<(Rendezvous, JoinHandle<()>)>,
+    /// Relay server to fall back to when no direct path works
+    relay: Arc<std::sync::Mutex<Option<SocketAddr>>>,
+    /// Our address as seen from outside the NAT, from STUN or the rendezvous service
+    public_address: Arc<std::sync::Mutex<Option<SocketAddr>>>,
+    /// Candidate exchange over a signaling hub, and its background task
+    signaling: Option<(CandidateExchange, JoinHandle<()>)>,
+    /// X25519 keys items for offline devices are sealed with
+    sealing_keys: SealingKeys,
+    /// Mailbox at the signaling hub, and its background task
+    mailbox: Option<(Mailbox, JoinHandle<()>)>,
 }
 
 impl Default for QuicTransport {
     fn default() -> Self {
-        Self::new()
+        Self::new(TransportOptions::default())
     }
 }
 
 impl QuicTransport {
     /// Create a new QUIC transport instance
+    ///
+    /// # Arguments
+    /// * `options` - Bind address and QUIC tuning, see [`TransportOptions::desktop`] and [`TransportOptions::mobile`]
     #[flutter_rust_bridge::frb]
-    pub fn new() -> Self {
+    pub fn new(options: TransportOptions) -> Self {
         println!("[QUIC] Creating new QuicTransport instance");
+        let (incoming_tx, incoming_rx) = mpsc::channel(INCOMING_CAPACITY);
         Self {
             endpoint: None,
-            connections: Arc::new(Mutex::new(HashMap::new())),
-            is_server: false,
+            registry: PeerRegistry::new(
+                incoming_tx,
+                options.max_message_size(),
+                Transfers::new(&options),
+                Links::new(&options),
+            ),
+            options,
+            identity: None,
+            trust_store: TrustStore::new(),
+            incoming_rx: Arc::new(Mutex::new(incoming_rx)),
+            supervised: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            discovered: None,
+            preferred_addresses: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            peer_keys: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            drain: Drain::default(),
+            raw: RawSocket::default(),
+            rendezvous: None,
+            relay: Arc::new(std::sync::Mutex::new(None)),
+            public_address: Arc::new(std::sync::Mutex::new(None)),
+            signaling: None,
+            sealing_keys: SealingKeys::default(),
+            mailbox: None,
+        }
+    }
+
+    /// Create a new QUIC transport with default options that uses a persistent device identity
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn with_identity(identity: DeviceIdentity) -> Self {
+        let mut transport = Self::new(TransportOptions::default());
+        transport.identity = Some(identity);
+        transport
+    }
+
+    /// Set the device identity used for subsequent servers and connections
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_identity(&mut self, identity: DeviceIdentity) {
+        println!("[QUIC] Using device identity {}", identity.device_id());
+        self.identity = Some(identity);
+    }
+
+    /// Certificate and key of this device's identity
+    ///
+    /// Falls back to an ephemeral identity, kept for the lifetime of this transport,
+    /// when none was configured.
+    fn cert_and_key(&mut self) -> Result<(CertificateDer<'static>, PrivateKeyDer<'static>), TransportError> {
+        if self.identity.is_none() {
+            println!("[QUIC] No device identity configured, generating an ephemeral one");
+            let device_id = format!("ephemeral-{:016x}", rand::random::<u64>());
+            self.identity = Some(DeviceIdentity::generate(device_id)?);
         }
+        let identity = self.identity.as_ref().ok_or(TransportError::NotConnected)?;
+        Ok(identity.cert_and_key())
     }
 
-    /// Start the QUIC server on the specified port
+    /// Start accepting connections on the specified port
+    ///
+    /// The transport has a single endpoint that both accepts and dials with
+    /// the same identity. If it already exists because a peer was dialed, it
+    /// moves to `port` unless `port` is 0; connections it dialed follow it, but
+    /// ones it accepted can't change their server address and are lost. Incoming
+    /// connections are accepted in the background, with handshakes running
+    /// concurrently; subscribe with [`QuicTransport::connection_events`] to
+    /// learn about new peers.
     ///
     /// # Arguments
-    /// * `port` - Port number to listen on
+    /// * `port` - Port number to listen on, 0 for any
     #[flutter_rust_bridge::frb]
     pub async fn start_server(&mut self, port: u16) -> Result<(), TransportError> {
         println!("[QUIC] Starting server on port {}", port);
         
-        let (cert, key) = get_cert_and_key()?;
+        let endpoint = match self.open_endpoint() {
+            Some(endpoint) => endpoint,
+            None => self.bind(port)?,
+        };
+        let current = endpoint.local_addr()
+            .map_err(|e| TransportError::Io(format!("Failed to read endpoint address: {}", e)))?;
+        if port != 0 && port != current.port() {
+            let socket = self.options.bind_socket(port)?;
+            self.raw.rebind(&endpoint, socket)
+                .map_err(|e| TransportError::Io(format!("Failed to move endpoint to port {}: {}", port, e)))?;
+        }
+        
+        println!("[QUIC] Server started successfully on port {}", self.local_port().unwrap_or(port));
+        Ok(())
+    }
+
+    /// Port the endpoint accepts and dials from, `None` before it is created
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn local_port(&self) -> Option<u16> {
+        let endpoint = self.endpoint.as_ref()?;
+        endpoint.local_addr().ok().map(|address| address.port())
+    }
+
+    /// Create the endpoint on `port` and start accepting connections on it
+    fn bind(&mut self, port: u16) -> Result<Endpoint, TransportError> {
+        let (cert, key) = self.cert_and_key()?;
         
-        // Configure server
+        // Configure server, only accepting clients whose certificates are pinned
+        let client_verifier = Arc::new(PairedClientVerifier::new(self.trust_store.clone()));
         let mut server_crypto = rustls::ServerConfig::builder()
-            .with_no_client_auth()
+            .with_client_cert_verifier(client_verifier)
             .with_single_cert(vec![cert], key)
             .map_err(|e| TransportError::Tls(format!("Server config error: {}", e)))?;
         
-        server_crypto.alpn_protocols = vec![b"syncmist".to_vec()];
+        server_crypto.alpn_protocols = vec![ALPN.to_vec()];
         
         let mut server_config = ServerConfig::with_crypto(Arc::new(
             quinn::crypto::rustls::QuicServerConfig::try_from(server_crypto)
                 .map_err(|e| TransportError::Tls(format!("QUIC server config error: {}", e)))?
         ));
+        server_config.transport_config(self.options.transport_config());
         
-        // Configure transport
-        let mut transport = TransportConfig::default();
-        transport.max_idle_timeout(Some(
-            std::time::Duration::from_secs(60).try_into().unwrap()
-        ));
-        server_config.transport_config(Arc::new(transport));
-        
-        let addr: SocketAddr = format!("0.0.0.0:{}", port)
-            .parse()
-            .map_err(|e| TransportError::Connection(format!("Invalid address: {}", e)))?;
-        
-        let endpoint = Endpoint::server(server_config, addr)
-            .map_err(|e| TransportError::Io(format!("Failed to create server endpoint: {}", e)))?;
+        let socket = self.options.bind_socket(port)?;
+        let endpoint = self.raw.endpoint(server_config, socket)
+            .map_err(|e| TransportError::Io(format!("Failed to create endpoint: {}", e)))?;
         
-        self.endpoint = Some(endpoint);
-        self.is_server = true;
-        
-        println!("[QUIC] Server started successfully on port {}", port);
-        Ok(())
+        tokio::spawn(accept_loop(endpoint.clone(), self.registry.clone(), self.local_device_id()));
+        self.endpoint = Some(endpoint.clone());
+        Ok(endpoint)
     }
 
-    /// Accept an incoming connection (server only)
+    /// Wait for the next incoming connection
+    ///
+    /// Connections are accepted by the background accept loop; this resolves
+    /// with the peer id of the next one to complete its handshake, or the error
+    /// of the next failed handshake. Clients must present a certificate for a
+    /// paired device; pin their fingerprints with [`QuicTransport::pin_fingerprint`]
+    /// beforehand.
     #[flutter_rust_bridge::frb]
     pub async fn accept_connection(&self) -> Result<String, TransportError> {
-        let endpoint = self.endpoint.as_ref().ok_or(TransportError::NotConnected)?;
+        let mut events = self.registry.subscribe();
+        if !self.is_running() {
+            return Err(TransportError::NotConnected);
+        }
         
         println!("[QUIC] Waiting for incoming connection...");
         
-        let incoming = endpoint.accept().await
-            .ok_or_else(|| TransportError::Connection("Endpoint closed".to_string()))?;
-        
-        let connection = incoming.await
-            .map_err(|e| TransportError::Connection(format!("Failed to accept connection: {}", e)))?;
-        
-        let peer_addr = connection.remote_address().to_string();
-        println!("[QUIC] Accepted connection from {}", peer_addr);
-        
-        // Store connection
-        let mut connections = self.connections.lock().await;
-        connections.insert(peer_addr.clone(), connection);
-        
-        Ok(peer_addr)
+        loop {
+            match events.recv().await {
+                Ok(ConnectionEvent::PeerConnected { peer_id, outbound: false, .. }) => return Ok(peer_id),
+                Ok(ConnectionEvent::HandshakeFailed { error, .. }) => return Err(TransportError::Connection(error)),
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => {
+                    return Err(TransportError::Connection("Endpoint closed".to_string()))
+                }
+            }
+        }
     }
 
-    /// Connect to a peer (client mode)
+    /// Connect to a peer
+    ///
+    /// The peer's certificate is not pinned because its device id is unknown;
+    /// prefer [`QuicTransport::connect_to_device`] when it is.
     ///
     /// # Arguments
-    /// * `addr` - IP address or hostname of the peer
+    /// * `addr` - IPv4 or IPv6 address of the peer, optionally with a zone (`fe80::1%eth0`)
     /// * `port` - Port number of the peer
     #[flutter_rust_bridge::frb]
     pub async fn connect_to_peer(&mut self, addr: &str, port: u16) -> Result<String, TransportError> {
-        println!("[QUIC] Connecting to peer {}:{}", addr, port);
-        
-        let (cert, key) = get_cert_and_key()?;
-        
-        // Configure client with TOFU verifier
-        let mut client_crypto = rustls::ClientConfig::builder()
-            .dangerous()
-            .with_custom_certificate_verifier(Arc::new(TofuCertVerifier))
-            .with_client_auth_cert(vec![cert], key)
-            .map_err(|e| TransportError::Tls(format!("Client cert error: {}", e)))?;
-        
-        client_crypto.alpn_protocols = vec![b"syncmist".to_vec()];
-        
-        let client_config = ClientConfig::new(Arc::new(
-            quinn::crypto::rustls::QuicClientConfig::try_from(client_crypto)
-                .map_err(|e| TransportError::Tls(format!("QUIC client config error: {}", e)))?
-        ));
-        
-        // Create client endpoint if not already created
-        if self.endpoint.is_none() {
-            let bind_addr: SocketAddr = "0.0.0.0:0".parse().unwrap();
-            let mut endpoint = Endpoint::client(bind_addr)
-                .map_err(|e| TransportError::Io(format!("Failed to create client endpoint: {}", e)))?;
-            endpoint.set_default_client_config(client_config);
-            self.endpoint = Some(endpoint);
-        }
-        
-        let endpoint = self.endpoint.as_ref().ok_or(TransportError::NotConnected)?;
-        
-        let server_addr: SocketAddr = format!("{}:{}", addr, port)
-            .parse()
-            .map_err(|e| TransportError::Connection(format!("Invalid address: {}", e)))?;
-        
-        let connection = endpoint
-            .connect(server_addr, "syncmist")
-            .map_err(|e| TransportError::Connection(format!("Connect error: {}", e)))?
-            .await
-            .map_err(|e| TransportError::Connection(format!("Connection failed: {}", e)))?;
-        
-        let peer_id = connection.remote_address().to_string();
-        println!("[QUIC] Connected to peer {}", peer_id);
-        
-        // Store connection
-        let mut connections = self.connections.lock().await;
-        connections.insert(peer_id.clone(), connection);
-        
-        Ok(peer_id)
+        self.connect(addr, port, None).await
     }
 
-    /// Send data to a specific peer
+    /// Connect to a known device, pinning its certificate fingerprint
+    ///
+    /// The first successful connection records the fingerprint of the device's
+    /// certificate; later connections presenting a different certificate fail
+    /// with [`TransportError::FingerprintMismatch`].
     ///
     /// # Arguments
-    /// * `peer_id` - The peer identifier (address:port)
-    /// * `data` - Data to send
+    /// * `device_id` - Device id of the peer (from mDNS or pairing)
+    /// * `addr` - IPv4 or IPv6 address of the peer, optionally with a zone (`fe80::1%eth0`)
+    /// * `port` - Port number of the peer
     #[flutter_rust_bridge::frb]
-    pub async fn send_data(&self, peer_id: &str, data: Vec<u8>) -> Result<(), TransportError> {
-        println!("[QUIC] Sending {} bytes to peer {}", data.len(), peer_id);
-        
-        let connections = self.connections.lock().await;
-        let connection = connections.get(peer_id)
-            .ok_or_else(|| TransportError::PeerNotFound(peer_id.to_string()))?;
-        
-        let mut send = connection.open_uni().await
-            .map_err(|e| TransportError::Connection(format!("Failed to open stream: {}", e)))?;
-        
-        // Send length prefix (4 bytes, big-endian)
-        let len = data.len() as u32;
-        send.write_all(&len.to_be_bytes()).await
-            .map_err(|e| TransportError::Io(format!("Failed to send length: {}", e)))?;
-        
-        // Send data
-        send.write_all(&data).await
-            .map_err(|e| TransportError::Io(format!("Failed to send data: {}", e)))?;
-        
-        send.finish()
-            .map_err(|e| TransportError::Io(format!("Failed to finish stream: {}", e)))?;
+    pub async fn connect_to_device(
+        &mut self,
+        device_id: &str,
+        addr: &str,
+        port: u16,
+    ) -> Result<String, TransportError> {
+        self.connect(addr, port, Some(device_id.to_string())).await
+    }
+
+    async fn connect(
+        &mut self,
+        addr: &str,
+        port: u16,
+        device_id: Option<String>,
+    ) -> Result<String, TransportError> {
+        println!("[QUIC] Connecting to peer {} port {}", addr, port);
         
-        println!("[QUIC] Data sent successfully");
-        Ok(())
+        let dialer = self.dialer()?;
+        let addresses = resolve_addresses(addr, port)?;
+        dial_in_turn(&dialer, &addresses, device_id).await
     }
 
-    /// Receive data from peers as an async stream
+    /// Connect to a peer found by mDNS, racing its addresses
     ///
-    /// Returns a stream of (peer_id, data) tuples
-    #[flutter_rust_bridge::frb(ignore)]
-    pub fn receive_data(&self) -> impl Stream<Item = (String, Vec<u8>)> + '_ {
-        let connections = self.connections.clone();
+    /// Attempts start 250ms apart, local-network and IPv6 addresses first, and
+    /// the first handshake to complete wins; the others are cancelled. The
+    /// winning address is tried first next time. The peer's certificate
+    /// fingerprint is pinned as in [`QuicTransport::connect_to_device`].
+    #[flutter_rust_bridge::frb]
+    pub async fn connect_to_peer_info(&mut self, peer: PeerInfo) -> Result<String, TransportError> {
+        println!("[QUIC] Connecting to device {} ({} addresses)", peer.device_id, peer.addresses.len());
         
-        async_stream::stream! {
-            loop {
-                let conns = connections.lock().await;
-                for (peer_id, connection) in conns.iter() {
-                    // Try to accept a unidirectional stream
-                    match connection.accept_uni().await {
-                        Ok(mut recv) => {
-                            // Read length prefix
-                            let mut len_buf = [0u8; 4];
-                            if let Ok(()) = recv.read_exact(&mut len_buf).await {
-                                let len = u32::from_be_bytes(len_buf) as usize;
-                                
-                                // Read data
-                                let mut data = vec![0u8; len];
-                                if let Ok(()) = recv.read_exact(&mut data).await {
-                                    println!("[QUIC] Received {} bytes from {}", len, peer_id);
-                                    yield (peer_id.clone(), data);
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            println!("[QUIC] No data from {}: {}", peer_id, e);
-                        }
-                    }
+        let dialer = self.dialer()?;
+        let addresses: Vec<SocketAddr> = peer.addresses
+            .iter()
+            .filter_map(|addr| match resolve_addresses(addr, peer.port) {
+                Ok(resolved) => Some(resolved),
+                Err(e) => {
+                    println!("[QUIC] Skipping address of {}: {}", peer.device_id, e);
+                    None
                 }
-                drop(conns);
-                
-                // Small delay to prevent busy loop
-                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
-            }
+            })
+            .flatten()
+            .collect();
+        if addresses.is_empty() {
+            return Err(TransportError::Connection(format!("No usable address for device {}", peer.device_id)));
         }
+
+        let preferred = self.preferred_addresses.lock().unwrap().get(&peer.device_id).copied();
+        // Only prefer a remembered address the peer still advertises
+        let preferred = preferred.filter(|address| addresses.contains(address));
+        let ordered = order_addresses(&addresses, preferred);
+        let (address, handshake) = race(&dialer, &ordered, Some(peer.device_id.clone())).await?;
+        self.preferred_addresses.lock().unwrap().insert(peer.device_id, address);
+        Ok(dialer.register(handshake).await)
     }
 
-    /// Disconnect from a peer
-    #[flutter_rust_bridge::frb]
-    pub async fn disconnect(&self, peer_id: &str) -> Result<(), TransportError> {
-        println!("[QUIC] Disconnecting from peer {}", peer_id);
-        
-        let mut connections = self.connections.lock().await;
-        if let Some(connection) = connections.remove(peer_id) {
-            connection.close(0u32.into(), b"disconnect");
-            println!("[QUIC] Disconnected from {}", peer_id);
-            Ok(())
-        } else {
-            Err(TransportError::PeerNotFound(peer_id.to_string()))
-        }
+    /// Address that won the last [`QuicTransport::connect_to_peer_info`] race for a device
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_preferred_address(&self, device_id: String) -> Option<String> {
+        self.preferred_addresses
+            .lock()
+            .unwrap()
+            .get(&device_id)
+            .map(|address| address.to_string())
     }
 
-    /// Close the transport and all connections
-    #[flutter_rust_bridge::frb]
-    pub async fn close(&mut self) {
-        println!("[QUIC] Closing transport");
-        
-        let mut connections = self.connections.lock().await;
-        for (peer_id, connection) in connections.drain() {
-            connection.close(0u32.into(), b"shutdown");
-            println!("[QUIC] Closed connection to {}", peer_id);
+    /// The endpoint, unless [`QuicTransport::shutdown`] closed it
+    ///
+    /// What a shutdown left behind is cleared then, so a new endpoint can be bound.
+    fn open_endpoint(&mut self) -> Option<Endpoint> {
+        if self.drain.is_closed() {
+            self.reset();
         }
+        self.endpoint.clone()
+    }
+
+    /// Handle for dialing peers from background tasks
+    ///
+    /// Creates the identity and endpoint if they don't exist yet; an endpoint
+    /// created here listens on an OS-assigned port so peers can dial back.
+    fn dialer(&mut self) -> Result<Dialer, TransportError> {
+        let endpoint = match self.open_endpoint() {
+            Some(endpoint) => endpoint,
+            None => self.bind(0)?,
+        };
+        let identity = self.identity.clone().ok_or(TransportError::NotConnected)?;
         
-        if let Some(endpoint) = self.endpoint.take() {
-            endpoint.close(0u32.into(), b"shutdown");
+        Ok(Dialer {
+            endpoint,
+            transport_config: self.options.transport_config(),
+            identity,
+            trust_store: self.trust_store.clone(),
+            registry: self.registry.clone(),
+        })
+    }
+
+    /// Register with a rendezvous service so devices behind NATs can reach us
+    ///
+    /// Registration is sent from the QUIC socket, so the service sees the
+    /// public address our NAT maps it to, and is refreshed in the background.
+    /// Devices the service introduces are connected to by hole punching.
+    /// Returns our public address as the service saw it.
+    ///
+    /// # Arguments
+    /// * `server` - Address of the service, e.g. `rendezvous.example.com:3478`
+    #[flutter_rust_bridge::frb]
+    pub async fn register_rendezvous(&mut self, server: String) -> Result<String, TransportError> {
+        let dialer = self.dialer()?;
+        let server_addr = self.resolve_server(&server).await?;
+        let upgrade_interval = (self.options.direct_upgrade_interval_ms > 0)
+            .then(|| std::time::Duration::from_millis(self.options.direct_upgrade_interval_ms));
+
+        let (rendezvous, public) =
+            Rendezvous::register(server_addr, self.raw.clone(), dialer, self.relay.clone(), upgrade_interval).await?;
+        let task = rendezvous.spawn();
+        if let Some((_, previous)) = self.rendezvous.replace((rendezvous, task)) {
+            previous.abort();
         }
-        
-        println!("[QUIC] Transport closed");
+        *self.public_address.lock().unwrap() = Some(public);
+        Ok(public.to_string())
     }
 
-    /// Check if transport is running
+    /// Connect to a device through the rendezvous service, punching through NATs
+    ///
+    /// Both devices must be registered with the same service with
+    /// [`QuicTransport::register_rendezvous`] and have each other's
+    /// fingerprints pinned.
     #[flutter_rust_bridge::frb]
-    pub fn is_running(&self) -> bool {
-        self.endpoint.is_some()
+    pub async fn connect_via_rendezvous(&self, device_id: String) -> Result<String, TransportError> {
+        let (rendezvous, _) = self.rendezvous.as_ref().ok_or(TransportError::NotConnected)?;
+        println!("[QUIC] Connecting to device {} via rendezvous", device_id);
+        rendezvous.connect(&device_id).await
     }
 
-    /// Get list of connected peers
+    /// Use a relay server when no direct path to a device works
+    ///
+    /// Devices registered with a rendezvous service fall back to the relay
+    /// when hole punching fails. QUIC runs end to end through the relay, so it
+    /// only sees ciphertext.
+    ///
+    /// # Arguments
+    /// * `server` - Address of a `syncmist-relay` server, e.g. `relay.example.com:3479`
     #[flutter_rust_bridge::frb]
-    pub async fn get_connected_peers(&self) -> Vec<String> {
-        let connections = self.connections.lock().await;
-        connections.keys().cloned().collect()
+    pub async fn use_relay(&mut self, server: String) -> Result<(), TransportError> {
+        self.dialer()?;
+        let server_addr = self.resolve_server(&server).await?;
+        println!("[QUIC] Using relay {}", server_addr);
+        *self.relay.lock().unwrap() = Some(server_addr);
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Connect to a device through the relay set with [`QuicTransport::use_relay`]
+    ///
+    /// The device must connect to us through the same relay at about the same
+    /// time. When both are registered with a rendezvous service, the relayed
+    /// connection is replaced by a direct one once hole punching succeeds,
+    /// tried every [`TransportOptions::direct_upgrade_interval_ms`].
+    #[flutter_rust_bridge::frb]
+    pub async fn connect_via_relay(&mut self, device_id: String) -> Result<String, TransportError> {
+        let dialer = self.dialer()?;
+        let server = (*self.relay.lock().unwrap()).ok_or(TransportError::NotConnected)?;
+        println!("[QUIC] Connecting to device {} via relay", device_id);
+        let peer_id = relay::connect(&dialer, &self.raw, server, &device_id).await?;
+        if let Some((rendezvous, _)) = &self.rendezvous {
+            rendezvous.keep_upgrading(peer_id.clone());
+        }
+        Ok(peer_id)
+    }
 
-    #[test]
-    fn test_generate_certificate() {
-        // Verify certificate generation doesn't panic
-        let result = generate_self_signed_cert();
-        assert!(result.is_ok(), "Certificate generation should succeed");
-        
-        let (cert, key) = result.unwrap();
-        assert!(!cert.is_empty(), "Certificate should not be empty");
-        assert!(!key.is_empty(), "Private key should not be empty");
-        
-        // Verify certificate is valid DER format
-        assert!(cert.len() > 100, "Certificate should have reasonable size");
-        assert!(key.len() > 100, "Private key should have reasonable size");
+    /// Exchange connection candidates with paired devices through a signaling hub
+    ///
+    /// Paired devices that are, or come, online at the hub are connected to by
+    /// hole punching. Candidates include the public address learned with
+    /// [`QuicTransport::discover_nat`] or [`QuicTransport::register_rendezvous`],
+    /// so call one of those first when behind a NAT.
+    ///
+    /// The hub also becomes our mailbox: items left for us while we were
+    /// offline are fetched, verified and opened with the key from
+    /// [`QuicTransport::set_sealing_key`], and arrive like items from connected
+    /// peers. From then on [`QuicTransport::broadcast`] leaves items for offline
+    /// paired devices there.
+    ///
+    /// # Arguments
+    /// * `signaling` - Client connected to the hub as this device
+    #[flutter_rust_bridge::frb]
+    pub async fn use_signaling(&mut self, signaling: &SignalingClient) -> Result<(), TransportError> {
+        let dialer = self.dialer()?;
+        if signaling.device_id() != dialer.device_id() {
+            return Err(TransportError::Connection(format!(
+                "Signaling token is for {}, not {}",
+                signaling.device_id(),
+                dialer.device_id()
+            )));
+        }
+        let identity = self.identity.clone().ok_or(TransportError::NotConnected)?;
+        let mailbox = Mailbox::new(
+            signaling.channel(),
+            identity,
+            self.trust_store.clone(),
+            self.sealing_keys.clone(),
+            self.registry.incoming(),
+            std::time::Duration::from_millis(self.options.mailbox_ttl_ms),
+        );
+        let mailbox_task = mailbox.spawn();
+        if let Some((_, previous)) = self.mailbox.replace((mailbox, mailbox_task)) {
+            previous.abort();
+        }
+
+        let exchange = CandidateExchange::new(
+            signaling.channel(),
+            dialer,
+            self.raw.clone(),
+            self.trust_store.clone(),
+            self.public_address.clone(),
+        );
+        let task = exchange.spawn();
+        exchange.offer_to_online().await;
+        if let Some((_, previous)) = self.signaling.replace((exchange, task)) {
+            previous.abort();
+        }
+        Ok(())
     }
 
-    #[tokio::test]
-    async fn test_create_server() {
-        // Install crypto provider for rustls 0.23+
-        let _ = rustls::crypto::ring::default_provider().install_default();
-        
-        // Verify server starts on port (using port 0 for OS-assigned port)
-        let mut transport = QuicTransport::new();
-        assert!(!transport.is_running(), "Transport should not be running initially");
-        
-        let result = transport.start_server(0).await;
-        assert!(result.is_ok(), "Server should start successfully");
-        assert!(transport.is_running(), "Transport should be running after server start");
-        assert!(transport.is_server, "Transport should be marked as server");
-        
-        // Clean up
-        transport.close().await;
+    /// Connect to a device at the signaling hub, punching through NATs
+    ///
+    /// The device must be online at the hub set with
+    /// [`QuicTransport::use_signaling`] and have our fingerprint pinned.
+    #[flutter_rust_bridge::frb]
+    pub async fn connect_via_signaling(&self, device_id: String) -> Result<String, TransportError> {
+        let (exchange, _) = self.signaling.as_ref().ok_or(TransportError::NotConnected)?;
+        println!("[QUIC] Connecting to device {} via signaling", device_id);
+        exchange.connect(&device_id).await
     }
 
-    #[tokio::test]
-    async fn test_create_client() {
-        // Install crypto provider for rustls 0.23+
-        let _ = rustls::crypto::ring::default_provider().install_default();
-        
-        // Verify client endpoint is created
-        let mut transport = QuicTransport::new();
-        assert!(!transport.is_running(), "Transport should not be running initially");
-        
-        // Note: This will fail to connect since there's no server, but it should create the endpoint
-        let result = transport.connect_to_peer("127.0.0.1", 1234).await;
-        
-        // We expect this to fail (no server listening), but the endpoint should be created
-        assert!(result.is_err(), "Connection should fail (no server listening)");
-        assert!(transport.is_running(), "Client endpoint should be created despite connection failure");
-        assert!(!transport.is_server, "Transport should not be marked as server");
-        
-        // Clean up
-        transport.close().await;
+    /// Resolve a server to an address the endpoint's socket can send to
+    async fn resolve_server(&self, server: &str) -> Result<SocketAddr, TransportError> {
+        let local = self.raw.local_addr()?;
+        tokio::net::lookup_host(server)
+            .await
+            .map_err(|e| TransportError::Connection(format!("Failed to resolve {}: {}", server, e)))?
+            .find(|address| local.is_ipv6() || address.is_ipv4())
+            .ok_or_else(|| TransportError::Connection(format!("No usable address for {}", server)))
     }
 
-    #[test]
-    fn test_transport_error_display() {
-        let err = TransportError::Connection("test".to_string());
-        assert!(err.to_string().contains("Connection error"));
-        
-        let err = TransportError::NotConnected;
-        assert_eq!(err.to_string(), "Not connected");
+    /// Learn our public address and NAT behaviour from STUN servers
+    ///
+    /// Binding requests are sent from the QUIC socket to every server at once,
+    /// so the mapped address is the one peers can reach QUIC at. At least two
+    /// servers on different addresses are needed to tell an
+    /// endpoint-independent NAT from a symmetric one.
+    ///
+    /// # Arguments
+    /// * `stun_servers` - Server addresses such as `stun.example.com:3478`
+    #[flutter_rust_bridge::frb]
+    pub async fn discover_nat(&mut self, stun_servers: Vec<String>) -> Result<NatReport, TransportError> {
+        self.dialer()?;
+        let local = self.raw.local_addr()?;
+        let mut servers = Vec::new();
+        for server in &stun_servers {
+            servers.push(self.resolve_server(server).await?);
+        }
+
+        let results = futures::future::join_all(servers.iter().map(|server| binding(&self.raw, *server))).await;
+        let mut mapped = Vec::new();
+        let mut last_error = TransportError::Connection("No STUN servers given".to_string());
+        for (server, result) in servers.iter().zip(results) {
+            match result {
+                Ok(address) => mapped.push(address),
+                Err(e) => {
+                    println!("[QUIC] STUN binding with {} failed: {}", server, e);
+                    last_error = e;
+                }
+            }
+        }
+        let first = *mapped.first().ok_or(last_error)?;
+
+        let local_addresses: Vec<SocketAddr> = if_addrs::get_if_addrs()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|interface| SocketAddr::new(interface.ip(), local.port()))
+            .collect();
+        let nat_type = classify(&local_addresses, &mapped);
+        println!("[QUIC] Mapped to {} behind {:?} NAT", first, nat_type);
+        *self.public_address.lock().unwrap() = Some(first);
+        Ok(NatReport { mapped_address: first.to_string(), nat_type })
+    }
+
+    /// Keep a connection to a device open, reconnecting whenever it drops
+    ///
+    /// Dials the device now if it isn't connected. After a disconnect the last
+    /// address that worked is tried first, then addresses from mDNS (see
+    /// [`QuicTransport::use_discovery`]), then `addresses`, with jittered
+    /// exponential backoff between rounds. Progress is reported as
+    /// [`ConnectionEvent::PeerStateChanged`] events. Supervision stops by itself
+    /// when the device unpairs us or shares no protocol version with us.
+    ///
+    /// # Arguments
+    /// * `device_id` - Device id of the peer
+    /// * `addresses` - Known IP addresses of the peer, IPv6 optionally with a zone
+    /// * `port` - Port number of the peer
+    #[flutter_rust_bridge::frb]
+    pub async fn keep_connected(
+        &mut self,
+        device_id: String,
+        addresses: Vec<String>,
+        port: u16,
+    ) -> Result<(), TransportError> {
+        let mut resolved = Vec::new();
+        for addr in &addresses {
+            resolved.extend(resolve_addresses(addr, port)?);
+        }
+        let dialer = self.dialer()?;
         
-        let err = TransportError::PeerNotFound("peer123".to_string());
-        assert!(err.to_string().contains("Peer not found: peer123"));
+        let peer = SupervisedPeer { device_id: device_id.clone(), addresses: resolved };
+        let task = spawn_supervisor(peer, dialer, self.registry.clone(), self.discovered.clone());
+        let previous = self.supervised.lock().unwrap().insert(device_id, task);
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+        Ok(())
     }
 
-    #[test]
-    fn test_quic_transport_new() {
-        let transport = QuicTransport::new();
-        assert!(!transport.is_running());
-        assert!(!transport.is_server);
+    /// Stop reconnecting to a device; an open connection is left as is
+    ///
+    /// Returns `true` if the device was supervised.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn stop_keeping_connected(&self, device_id: String) -> bool {
+        let task = self.supervised.lock().unwrap().remove(&device_id);
+        match task {
+            Some(task) if !task.is_finished() => {
+                task.abort();
+                publish_state(&self.registry, &device_id, PeerState::Stopped, 0, std::time::Duration::ZERO);
+                true
+            }
+            _ => false,
+        }
     }
 
-    #[tokio::test]
-    async fn test_get_connected_peers_empty() {
-        let transport = QuicTransport::new();
-        let peers = transport.get_connected_peers().await;
-        assert!(peers.is_empty(), "Should have no connected peers initially");
+    /// Devices kept connected with [`QuicTransport::keep_connected`]
+    ///
+    /// Devices whose supervision ended on its own, because they unpaired us or
+    /// share no protocol version, are not included.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_supervised_peers(&self) -> Vec<String> {
+        let mut supervised = self.supervised.lock().unwrap();
+        supervised.retain(|_, task| !task.is_finished());
+        let mut peers: Vec<String> = supervised.keys().cloned().collect();
+        peers.sort();
+        peers
     }
 
-    #[tokio::test]
-    async fn test_disconnect_nonexistent_peer() {
-        let transport = QuicTransport::new();
-        let result = transport.disconnect("nonexistent").await;
-        assert!(result.is_err(), "Should fail to disconnect from nonexistent peer");
-        
-        if let Err(TransportError::PeerNotFound(peer_id)) = result {
-            assert_eq!(peer_id, "nonexistent");
-        } else {
-            panic!("Expected PeerNotFound error");
-        }
+    /// Use peers found by an mDNS browser as fresh addresses when reconnecting
+    ///
+    /// Applies to peers supervised after this call.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn use_discovery(&mut self, discovery: &MdnsDiscovery) {
+        self.discovered = Some(discovery.peers_handle());
     }
 
-    // Integration test: Server-Client communication
-    #[tokio::test]
+    /// Device id of our own identity
+    fn local_device_id(&self) -> String {
+        self.identity.as_ref().map(|identity| identity.device_id()).unwrap_or_default()
+    }
+
+    /// Get the device id proven by a peer's certificate during the handshake
+    #[flutter_rust_bridge::frb]
+    pub async fn get_peer_device_id(&self, peer_id: &str) -> Result<String, TransportError> {
+        let connections = self.registry.lock().await;
+        let peer = connections.get(peer_id)
+            .ok_or_else(|| TransportError::PeerNotFound(peer_id.to_string()))?;
+        authenticated_device_id(&peer.connection)
+            .ok_or_else(|| TransportError::Tls(format!("Peer {} did not present a device certificate", peer_id)))
+    }
+
+    /// Get the wire protocol version negotiated with a connected peer
+    #[flutter_rust_bridge::frb]
+    pub async fn get_peer_protocol_version(&self, peer_id: &str) -> Result<u16, TransportError> {
+        let connections = self.registry.lock().await;
+        let peer = connections.get(peer_id)
+            .ok_or_else(|| TransportError::PeerNotFound(peer_id.to_string()))?;
+        Ok(peer.protocol_version)
+    }
+
+    /// Get the current network address of a connected peer
+    ///
+    /// Reflects QUIC connection migration and reconnects from new addresses.
+    #[flutter_rust_bridge::frb]
+    pub async fn get_peer_address(&self, peer_id: &str) -> Result<String, TransportError> {
+        let connections = self.registry.lock().await;
+        let peer = connections.get(peer_id)
+            .ok_or_else(|| TransportError::PeerNotFound(peer_id.to_string()))?;
+        Ok(peer.connection.remote_address().to_string())
+    }
+
+    /// Whether a peer is reached through a relay rather than directly
+    #[flutter_rust_bridge::frb]
+    pub async fn is_peer_relayed(&self, peer_id: &str) -> Result<bool, TransportError> {
+        let connection = self.registry.connection(peer_id).await
+            .ok_or_else(|| TransportError::PeerNotFound(peer_id.to_string()))?;
+        Ok(is_relayed(connection.remote_address()))
+    }
+
+    /// RTT, congestion window, traffic counters, path address and age of a peer's connection
+    #[flutter_rust_bridge::frb]
+    pub async fn get_peer_stats(&self, peer_id: &str) -> Result<PeerStats, TransportError> {
+        self.registry.stats(peer_id).await
+            .ok_or_else(|| TransportError::PeerNotFound(peer_id.to_string()))
+    }
+
+    /// Send statistics of all connected peers to a Dart stream every `interval_ms`
+    ///
+    /// Each update lists every peer connected at the time, ordered by peer id.
+    /// Intervals below 100ms are raised to 100ms. Stops when the Dart stream is
+    /// cancelled.
+    #[flutter_rust_bridge::frb]
+    pub async fn peer_stats(&self, sink: StreamSink<Vec<PeerStats>>, interval_ms: u64) {
+        let registry = self.registry.clone();
+        let interval = std::time::Duration::from_millis(interval_ms).max(MIN_STATS_INTERVAL);
+        tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(interval);
+            loop {
+                ticks.tick().await;
+                if sink.add(registry.all_stats().await).is_err() {
+                    println!("[QUIC] Stats stream closed by listener");
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Pin a device's certificate fingerprint ahead of the first connection
+    ///
+    /// Used with the fingerprint carried in a QR pairing payload. Accepts hex
+    /// with or without `:` separators and replaces any existing pin. This pairs
+    /// the device, which lets it connect to us; fingerprints pinned on first
+    /// use when dialing don't.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn pin_fingerprint(&self, device_id: String, fingerprint: String) -> Result<(), TransportError> {
+        self.trust_store.pin(&device_id, &fingerprint)
+    }
+
+    /// Forget a device's pinned fingerprint
+    ///
+    /// Returns `true` if a fingerprint was pinned for the device.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn forget_fingerprint(&self, device_id: String) -> bool {
+        println!("[QUIC] Forgetting certificate fingerprint for device {}", device_id);
+        self.trust_store.forget(&device_id)
+    }
+
+    /// List all pinned certificate fingerprints
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn list_pinned_fingerprints(&self) -> Vec<PinnedFingerprint> {
+        self.trust_store.list()
+    }
+
+    /// Send data to a specific peer
+    ///
+    /// The data is wrapped in a [`SyncMessage::ClipboardItem`].
+    ///
+    /// # Arguments
+    /// * `peer_id` - The peer identifier (device id)
+    /// * `data` - Data to send
+    #[flutter_rust_bridge::frb]
+    pub async fn send_data(&self, peer_id: &str, data: Vec<u8>) -> Result<(), TransportError> {
+        let message = SyncMessage::clipboard_item("application/octet-stream".to_string(), data);
+        self.send_message(peer_id, message).await
+    }
+
+    /// Send a protocol message to a specific peer on its own stream
+    #[flutter_rust_bridge::frb]
+    pub async fn send_message(&self, peer_id: &str, message: SyncMessage) -> Result<(), TransportError> {
+        println!("[QUIC] Sending {} to peer {}", message.kind(), peer_id);
+        
+        let sending = self.drain.begin()?;
+        let connection = self.peer_connection(peer_id).await?;
+        let send = send_on_new_stream(&connection, &message, self.options.max_message_size()).await?;
+        sending.release_when_acknowledged(send);
+        
+        println!("[QUIC] Data sent successfully");
+        Ok(())
+    }
+
+    /// Set the key shared with a device, see [`crate::crypto::derive_shared_secret`]
+    ///
+    /// [`QuicTransport::broadcast`] encrypts items for the device with this key.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_peer_key(&self, device_id: String, key: Vec<u8>) {
+        self.peer_keys.lock().unwrap().insert(device_id, key);
+    }
+
+    /// Set our X25519 secret key, the one whose public key paired devices seal items with
+    ///
+    /// Use the key pair from [`crate::crypto::generate_keypair`] exchanged
+    /// during pairing. Set it before [`QuicTransport::use_signaling`], which
+    /// only fetches our mailbox when it can open what it gets.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_sealing_key(&self, secret_key: Vec<u8>) -> Result<(), TransportError> {
+        self.sealing_keys.set_own(&secret_key)
+    }
+
+    /// Set a paired device's X25519 public key, used to seal items left for it while offline
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn set_peer_public_key(&self, device_id: String, public_key: Vec<u8>) -> Result<(), TransportError> {
+        self.sealing_keys.set_peer(&device_id, &public_key)
+    }
+
+    /// Forget the key shared with a device, e.g. after unpairing
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn forget_peer_key(&self, device_id: String) -> bool {
+        self.peer_keys.lock().unwrap().remove(&device_id).is_some()
+    }
+
+    /// Send data to every connected device, or those matching `filter`, at once
+    ///
+    /// The data is encrypted with each device's key from
+    /// [`QuicTransport::set_peer_key`], once per distinct key, and sent as the
+    /// same [`SyncMessage::ClipboardItem`] to all of them. Returns what happened
+    /// for each device; one failing doesn't affect the others.
+    ///
+    /// With [`QuicTransport::use_signaling`], paired devices that aren't
+    /// connected but have a key from [`QuicTransport::set_peer_public_key`] get
+    /// the item sealed and left at the hub, see [`BroadcastOutcome::Deposited`].
+    ///
+    /// # Arguments
+    /// * `data` - Plaintext to send
+    /// * `filter` - Devices to include or leave out
+    #[flutter_rust_bridge::frb]
+    pub async fn broadcast(&self, data: Vec<u8>, filter: BroadcastFilter) -> HashMap<String, BroadcastOutcome> {
+        let connections = self.registry.connections().await;
+        let connected: Vec<String> = connections.iter().map(|(peer_id, _)| peer_id.clone()).collect();
+        let keys = self.peer_keys.lock().unwrap().clone();
+        let item = BroadcastItem::new("application/octet-stream".to_string(), data);
+        let mut outcomes =
+            send_to_all(connections, &keys, &filter, &item, self.options.max_message_size(), &self.drain).await;
+        if let Some((mailbox, _)) = self.mailbox.as_ref().filter(|_| self.options.mailbox_ttl_ms > 0) {
+            outcomes.extend(mailbox.deposit(&item, &keys, &filter, &connected).await);
+        }
+        outcomes
+    }
+
+    /// Send data to a peer and wait until it confirms applying it
+    ///
+    /// Like [`QuicTransport::send_data`], but the item goes on a bidirectional
+    /// stream and the peer's app answers it with
+    /// [`QuicTransport::acknowledge_item`] or [`QuicTransport::reject_item`].
+    ///
+    /// # Arguments
+    /// * `peer_id` - The peer identifier (device id)
+    /// * `data` - Data to send
+    /// * `timeout_ms` - How long to wait for the answer, including sending the data
+    #[flutter_rust_bridge::frb]
+    pub async fn send_data_acked(
+        &self,
+        peer_id: &str,
+        data: Vec<u8>,
+        timeout_ms: u64,
+    ) -> Result<DeliveryResult, TransportError> {
+        let message = SyncMessage::clipboard_item("application/octet-stream".to_string(), data);
+        self.send_message_acked(peer_id, message, timeout_ms).await
+    }
+
+    /// Send a [`SyncMessage::ClipboardItem`] and wait until the peer confirms applying it
+    ///
+    /// The item id identifies the answer, so reuse it when resending an item.
+    /// Fails only if the item could not be sent; a peer that doesn't answer in
+    /// time gives [`DeliveryResult::TimedOut`].
+    #[flutter_rust_bridge::frb]
+    pub async fn send_message_acked(
+        &self,
+        peer_id: &str,
+        message: SyncMessage,
+        timeout_ms: u64,
+    ) -> Result<DeliveryResult, TransportError> {
+        println!("[QUIC] Sending {} to peer {} with acknowledgement", message.kind(), peer_id);
+
+        let _sending = self.drain.begin()?;
+        let connection = self.peer_connection(peer_id).await?;
+        let timeout = std::time::Duration::from_millis(timeout_ms);
+        let result = send_with_ack(&connection, &message, self.options.max_message_size(), timeout).await?;
+
+        println!("[QUIC] Delivery to {}: {:?}", peer_id, result);
+        Ok(result)
+    }
+
+    /// Confirm that a received item with `requires_ack` was applied
+    ///
+    /// Returns `false` if the sender is no longer waiting for it.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn acknowledge_item(&self, peer_id: String, item_id: u64) -> bool {
+        self.registry.acks().resolve(&peer_id, item_id, Ok(()))
+    }
+
+    /// Tell the sender of a received item with `requires_ack` why it wasn't applied
+    ///
+    /// Returns `false` if the sender is no longer waiting for it.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reject_item(&self, peer_id: String, item_id: u64, reason: String) -> bool {
+        self.registry.acks().resolve(&peer_id, item_id, Err(reason))
+    }
+
+    /// Send a file to a peer in chunks
+    ///
+    /// Completes once the peer has stored the file and verified its SHA-256
+    /// hash, returning the transfer id. Progress is reported as
+    /// [`TransferEvent`]s, see [`QuicTransport::transfer_events`]. If the
+    /// connection drops, this fails but the transfer is kept in the spool and
+    /// continues from the last acknowledged offset when the peer reconnects.
+    ///
+    /// # Arguments
+    /// * `peer_id` - The peer identifier (device id)
+    /// * `path` - File to send; the peer saves it under the same file name
+    /// * `content_type` - MIME type of the file
+    #[flutter_rust_bridge::frb]
+    pub async fn send_file(&self, peer_id: &str, path: String, content_type: String) -> Result<u64, TransportError> {
+        let _sending = self.drain.begin()?;
+        let connection = self.peer_connection(peer_id).await?;
+        transfer::send_file(&connection, peer_id, self.registry.transfers(), path, content_type).await
+    }
+
+    /// Send a payload too large for [`QuicTransport::send_data`], such as a clipboard image, in chunks
+    ///
+    /// Behaves like [`QuicTransport::send_file`]; the peer saves the payload as
+    /// `name`. A copy is kept in the spool until the peer has all of it.
+    #[flutter_rust_bridge::frb]
+    pub async fn send_large_data(
+        &self,
+        peer_id: &str,
+        name: String,
+        content_type: String,
+        data: Vec<u8>,
+    ) -> Result<u64, TransportError> {
+        let _sending = self.drain.begin()?;
+        let connection = self.peer_connection(peer_id).await?;
+        let info = TransferInfo { name, content_type, size: data.len() as u64 };
+        transfer::send_data(&connection, peer_id, self.registry.transfers(), info, data).await
+    }
+
+    /// Send `size` bytes read from `reader` to a peer in chunks
+    ///
+    /// Unlike files, content from a reader can't be resumed after a disconnect.
+    #[flutter_rust_bridge::frb(ignore)]
+    pub async fn send_reader<R: tokio::io::AsyncRead + Unpin>(
+        &self,
+        peer_id: &str,
+        reader: R,
+        name: String,
+        content_type: String,
+        size: u64,
+    ) -> Result<u64, TransportError> {
+        let _sending = self.drain.begin()?;
+        let connection = self.peer_connection(peer_id).await?;
+        let info = TransferInfo { name, content_type, size };
+        transfer::send_reader(&connection, peer_id, self.registry.transfers(), reader, info).await
+    }
+
+    /// Cancel a transfer in either direction
+    ///
+    /// A running transfer is cancelled for the peer too. An interrupted one is
+    /// removed from the spool; for incoming transfers the sender may still
+    /// offer it again. Returns `false` if there is no such transfer.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn cancel_transfer(&self, transfer_id: u64) -> bool {
+        println!("[QUIC] Cancelling transfer {}", transfer_id);
+        self.registry.transfers().cancel(transfer_id)
+    }
+
+    /// Transfers that are running or waiting in the spool for their peer to reconnect
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_pending_transfers(&self) -> Vec<PendingTransfer> {
+        self.registry.transfers().pending()
+    }
+
+    /// Connection to a peer, cloned so the registry lock is not held while using it
+    async fn peer_connection(&self, peer_id: &str) -> Result<quinn::Connection, TransportError> {
+        self.registry.connection(peer_id).await
+            .ok_or_else(|| TransportError::PeerNotFound(peer_id.to_string()))
+    }
+
+    /// Receive data from peers as an async stream
+    ///
+    /// Returns a stream of (peer_id, data) tuples. Items are delivered once, to
+    /// whichever stream or sink reads them first.
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn receive_data(&self) -> impl Stream<Item = (String, Vec<u8>)> + '_ {
+        let incoming = self.incoming_rx.clone();
+        
+        async_stream::stream! {
+            loop {
+                let item = incoming.lock().await.recv().await;
+                match item {
+                    Some(item) => yield (item.peer_id, item.payload),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Forward clipboard items received from all peers to a Dart stream
+    ///
+    /// Each item carries the id of the peer that sent it. Forwarding stops when
+    /// the Dart stream is cancelled.
+    #[flutter_rust_bridge::frb]
+    pub async fn receive_items(&self, sink: StreamSink<ReceivedItem>) {
+        let incoming = self.incoming_rx.clone();
+        tokio::spawn(async move {
+            loop {
+                let item = incoming.lock().await.recv().await;
+                let Some(item) = item else { break };
+                if sink.add(item).is_err() {
+                    println!("[QUIC] Receive stream closed by listener");
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Forward connection events to a Dart stream
+    ///
+    /// Events published before the stream is opened are not replayed; call
+    /// this before starting the server or dialing peers. A listener that falls
+    /// far behind skips the oldest events.
+    #[flutter_rust_bridge::frb]
+    pub async fn connection_events(&self, sink: StreamSink<ConnectionEvent>) {
+        let mut events = self.registry.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => {
+                        if sink.add(event).is_err() {
+                            println!("[QUIC] Event stream closed by listener");
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        println!("[QUIC] Event listener lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Subscribe to connection events from Rust
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ConnectionEvent> {
+        self.registry.subscribe()
+    }
+
+    /// Forward transfer progress in both directions to a Dart stream
+    ///
+    /// Like [`QuicTransport::connection_events`], only events published after
+    /// the stream is opened are delivered.
+    #[flutter_rust_bridge::frb]
+    pub async fn transfer_events(&self, sink: StreamSink<TransferEvent>) {
+        let mut events = self.registry.transfers().subscribe();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => {
+                        if sink.add(event).is_err() {
+                            println!("[QUIC] Transfer event stream closed by listener");
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        println!("[QUIC] Transfer event listener lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Subscribe to transfer events from Rust
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn subscribe_transfers(&self) -> broadcast::Receiver<TransferEvent> {
+        self.registry.transfers().subscribe()
+    }
+
+    /// Tell all connected peers what the user is doing
+    ///
+    /// Sent as a datagram, so it costs no stream; peers connecting later are
+    /// told when they connect.
+    #[flutter_rust_bridge::frb]
+    pub async fn set_activity(&self, activity: Activity) {
+        let connections: Vec<quinn::Connection> =
+            self.registry.connections().await.into_iter().map(|(_, connection)| connection).collect();
+        self.registry.links().set_activity(activity, &connections);
+    }
+
+    /// Round-trip time, loss and activity of a connected peer, measured with datagram heartbeats
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn get_link_quality(&self, peer_id: String) -> Option<LinkQuality> {
+        self.registry.links().quality(&peer_id)
+    }
+
+    /// Forward link quality and activity updates to a Dart stream
+    ///
+    /// A quality update arrives for each peer every heartbeat interval, see
+    /// [`TransportOptions::heartbeat_interval_ms`].
+    #[flutter_rust_bridge::frb]
+    pub async fn link_events(&self, sink: StreamSink<LinkEvent>) {
+        let mut events = self.registry.links().subscribe();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => {
+                        if sink.add(event).is_err() {
+                            println!("[QUIC] Link event stream closed by listener");
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        println!("[QUIC] Link event listener lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
+    /// Subscribe to link events from Rust
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn subscribe_links(&self) -> broadcast::Receiver<LinkEvent> {
+        self.registry.links().subscribe()
+    }
+
+    /// Disconnect from a peer
+    ///
+    /// Also stops reconnecting to it if it was kept connected.
+    #[flutter_rust_bridge::frb]
+    pub async fn disconnect(&self, peer_id: &str) -> Result<(), TransportError> {
+        println!("[QUIC] Disconnecting from peer {}", peer_id);
+        
+        self.stop_keeping_connected(peer_id.to_string());
+        let removed = self.registry.lock().await.remove(peer_id);
+        if let Some(peer) = removed {
+            close_connection(&peer.connection, close_code::USER_DISCONNECT);
+            self.registry.publish(ConnectionEvent::PeerDisconnected {
+                peer_id: peer_id.to_string(),
+                reason: DisconnectReason::UserDisconnect,
+                by_peer: false,
+            });
+            println!("[QUIC] Disconnected from {}", peer_id);
+            Ok(())
+        } else {
+            Err(TransportError::PeerNotFound(peer_id.to_string()))
+        }
+    }
+
+    /// Unpair a device, dropping its pinned fingerprint and key and closing any connection to it
+    ///
+    /// The device sees the close as [`DisconnectReason::Unpaired`]. Returns
+    /// `true` if the device was pinned, keyed, supervised or connected.
+    #[flutter_rust_bridge::frb]
+    pub async fn unpair(&self, device_id: String) -> bool {
+        println!("[QUIC] Unpairing device {}", device_id);
+        let supervised = self.stop_keeping_connected(device_id.clone());
+        let pinned = self.trust_store.forget(&device_id);
+        let keyed = self.peer_keys.lock().unwrap().remove(&device_id).is_some();
+        let sealed = self.sealing_keys.forget_peer(&device_id);
+        let removed = self.registry.lock().await.remove(&device_id);
+        let connected = removed.is_some();
+        if let Some(peer) = removed {
+            close_connection(&peer.connection, close_code::UNPAIRED);
+            self.registry.publish(ConnectionEvent::PeerDisconnected {
+                peer_id: device_id,
+                reason: DisconnectReason::Unpaired,
+                by_peer: false,
+            });
+        }
+        supervised || pinned || keyed || sealed || connected
+    }
+
+    /// Close the transport and all connections
+    ///
+    /// Anything not yet delivered is dropped; use [`QuicTransport::shutdown`]
+    /// to deliver it first.
+    #[flutter_rust_bridge::frb]
+    pub async fn close(&mut self) {
+        println!("[QUIC] Closing transport");
+        self.stop_supervising();
+        self.close_connections().await;
+        self.reset();
+        println!("[QUIC] Transport closed");
+    }
+
+    /// Shut down gracefully, delivering what was already sent
+    ///
+    /// New sends and incoming connections are refused while sends in flight,
+    /// including chunked transfers, get up to `timeout_ms` to be acknowledged
+    /// by their peers. Transfers still running then are interrupted and resume
+    /// from the spool later. Connections are closed with a code peers report
+    /// as a shutdown rather than a lost connection.
+    ///
+    /// Takes `&self` so sends already waiting on the transport are not blocked
+    /// behind it. The transport can be started again afterwards.
+    #[flutter_rust_bridge::frb]
+    pub async fn shutdown(&self, timeout_ms: u64) {
+        println!("[QUIC] Shutting down, waiting up to {}ms for pending sends", timeout_ms);
+        self.drain.close();
+        self.stop_supervising();
+        if let Some(endpoint) = &self.endpoint {
+            endpoint.set_server_config(None);
+        }
+
+        if !self.drain.wait_idle(std::time::Duration::from_millis(timeout_ms)).await {
+            println!("[QUIC] {} sends still pending at the deadline, dropping them", self.drain.pending());
+        }
+        self.close_connections().await;
+        println!("[QUIC] Transport shut down");
+    }
+
+    fn stop_supervising(&self) {
+        let supervised: Vec<_> = self.supervised.lock().unwrap().drain().collect();
+        for (_, task) in supervised {
+            task.abort();
+        }
+    }
+
+    async fn close_connections(&self) {
+        if let Some((_, task)) = &self.rendezvous {
+            task.abort();
+        }
+        if let Some((_, task)) = &self.signaling {
+            task.abort();
+        }
+        if let Some((_, task)) = &self.mailbox {
+            task.abort();
+        }
+        let peers: Vec<_> = self.registry.lock().await.drain().collect();
+        for (peer_id, peer) in peers {
+            close_connection(&peer.connection, close_code::SHUTTING_DOWN);
+            println!("[QUIC] Closed connection to {}", peer_id);
+            self.registry.publish(ConnectionEvent::PeerDisconnected {
+                peer_id,
+                reason: DisconnectReason::ShuttingDown,
+                by_peer: false,
+            });
+        }
+
+        if let Some(endpoint) = &self.endpoint {
+            endpoint.close(close_code::SHUTTING_DOWN.into(), b"shutdown");
+            // Give the close frames a moment to reach peers
+            let _ = tokio::time::timeout(CLOSE_FLUSH_TIMEOUT, endpoint.wait_idle()).await;
+        }
+    }
+
+    /// Forget the closed endpoint and the services that ran on it
+    fn reset(&mut self) {
+        self.rendezvous = None;
+        self.signaling = None;
+        self.mailbox = None;
+        self.endpoint = None;
+        self.raw = RawSocket::default();
+        self.drain = Drain::default();
+    }
+
+    /// Check if transport is running
+    #[flutter_rust_bridge::frb]
+    pub fn is_running(&self) -> bool {
+        self.endpoint.is_some() && !self.drain.is_closed()
+    }
+
+    /// Get list of connected peers
+    #[flutter_rust_bridge::frb]
+    pub async fn get_connected_peers(&self) -> Vec<String> {
+        let connections = self.registry.lock().await;
+        connections.keys().cloned().collect()
+    }
+}
+
+/// Everything needed to open outbound connections, detached from the transport
+#[derive(Clone)]
+pub(crate) struct Dialer {
+    endpoint: Endpoint,
+    transport_config: Arc<TransportConfig>,
+    identity: DeviceIdentity,
+    trust_store: TrustStore,
+    registry: PeerRegistry,
+}
+
+/// An outbound connection that completed the TLS and Hello handshakes but isn't registered yet
+pub(crate) struct Handshake {
+    pub(crate) peer_id: String,
+    connection: quinn::Connection,
+    protocol_version: u16,
+}
+
+impl Dialer {
+    pub(crate) fn device_id(&self) -> String {
+        self.identity.device_id()
+    }
+
+    pub(crate) fn registry(&self) -> &PeerRegistry {
+        &self.registry
+    }
+
+    /// Connect to a peer, run the Hello exchange and register the connection
+    ///
+    /// When `device_id` is given the peer must present that device's
+    /// certificate, and its fingerprint is pinned on first use.
+    pub(crate) async fn dial(&self, server_addr: SocketAddr, device_id: Option<String>) -> Result<String, TransportError> {
+        let handshake = self.handshake(server_addr, device_id).await?;
+        Ok(self.register(handshake).await)
+    }
+
+    /// Register a completed handshake as a live peer connection and return its peer id
+    pub(crate) async fn register(&self, handshake: Handshake) -> String {
+        let Handshake { peer_id, connection, protocol_version } = handshake;
+        self.registry
+            .register(&self.identity.device_id(), &peer_id, connection, true, protocol_version)
+            .await;
+        peer_id
+    }
+
+    /// Connect to a peer and run the Hello exchange without registering the connection
+    ///
+    /// Dropping the result (or the future) abandons the connection.
+    pub(crate) async fn handshake(&self, server_addr: SocketAddr, device_id: Option<String>) -> Result<Handshake, TransportError> {
+        let connection = self.connect(server_addr, device_id.clone()).await?;
+        self.hello(server_addr, connection, device_id.as_deref()).await
+    }
+
+    /// Complete only the TLS handshake with a peer
+    ///
+    /// The peer doesn't register the connection until [`Dialer::hello`] runs on it.
+    pub(crate) async fn connect(&self, server_addr: SocketAddr, device_id: Option<String>) -> Result<quinn::Connection, TransportError> {
+        let (cert, key) = self.identity.cert_and_key();
+        
+        // Configure client with TOFU verifier
+        let verifier = Arc::new(TofuCertVerifier::new(self.trust_store.clone(), device_id));
+        let mut client_crypto = rustls::ClientConfig::builder()
+            .dangerous()
+            .with_custom_certificate_verifier(verifier.clone())
+            .with_client_auth_cert(vec![cert], key)
+            .map_err(|e| TransportError::Tls(format!("Client cert error: {}", e)))?;
+        
+        client_crypto.alpn_protocols = vec![ALPN.to_vec()];
+        
+        let mut client_config = ClientConfig::new(Arc::new(
+            quinn::crypto::rustls::QuicClientConfig::try_from(client_crypto)
+                .map_err(|e| TransportError::Tls(format!("QUIC client config error: {}", e)))?
+        ));
+        client_config.transport_config(self.transport_config.clone());
+        
+        let connecting = self.endpoint
+            .connect_with(client_config, server_addr, SERVER_NAME)
+            .map_err(|e| TransportError::Connection(format!("Connect error: {}", e)))?;
+        let connection = match connecting.await {
+            Ok(connection) => connection,
+            Err(e) => {
+                let error = verifier
+                    .take_rejection()
+                    .unwrap_or_else(|| TransportError::Connection(format!("Connection failed: {}", e)));
+                self.registry.publish(ConnectionEvent::HandshakeFailed {
+                    address: server_addr.to_string(),
+                    error: error.to_string(),
+                });
+                return Err(error);
+            }
+        };
+        Ok(connection)
+    }
+
+    /// Run the Hello exchange on a connection from [`Dialer::connect`]
+    pub(crate) async fn hello(
+        &self,
+        server_addr: SocketAddr,
+        connection: quinn::Connection,
+        expected_device_id: Option<&str>,
+    ) -> Result<Handshake, TransportError> {
+        let peer_id = peer_id_for(&connection, expected_device_id);
+        println!("[QUIC] Connected to peer {} ({})", peer_id, connection.remote_address());
+        
+        let protocol_version = match hello_initiator(&connection, self.identity.device_id()).await {
+            Ok(version) => version,
+            Err(e) => {
+                println!("[QUIC] Hello with {} failed: {}", peer_id, e);
+                self.registry.publish(ConnectionEvent::HandshakeFailed {
+                    address: server_addr.to_string(),
+                    error: e.to_string(),
+                });
+                return Err(e);
+            }
+        };
+        println!("[QUIC] Negotiated protocol v{} with {}", protocol_version, peer_id);
+        
+        Ok(Handshake { peer_id, connection, protocol_version })
+    }
+}
+
+/// Dial addresses one after another until one connects
+async fn dial_in_turn(dialer: &Dialer, addresses: &[SocketAddr], device_id: Option<String>) -> Result<String, TransportError> {
+    let mut last_error = TransportError::Connection("No address to connect to".to_string());
+    for address in addresses {
+        match dialer.dial(*address, device_id.clone()).await {
+            Ok(peer_id) => return Ok(peer_id),
+            Err(e @ TransportError::FingerprintMismatch { .. }) => return Err(e),
+            Err(e) => {
+                println!("[QUIC] Connecting to {} failed: {}", address, e);
+                last_error = e;
+            }
+        }
+    }
+    Err(last_error)
+}
+
+/// Accept incoming connections until the endpoint is closed
+async fn accept_loop(endpoint: Endpoint, registry: PeerRegistry, local_device_id: String) {
+    println!("[QUIC] Accept loop started");
+    while let Some(incoming) = endpoint.accept().await {
+        tokio::spawn(accept_incoming(incoming, registry.clone(), local_device_id.clone()));
+    }
+    println!("[QUIC] Accept loop stopped");
+}
+
+/// Complete the TLS and Hello handshakes of one incoming connection and register it
+async fn accept_incoming(incoming: Incoming, registry: PeerRegistry, local_device_id: String) {
+    let address = incoming.remote_address();
+    let failed = |error: String| {
+        println!("[QUIC] Handshake with {} failed: {}", address, error);
+        registry.publish(ConnectionEvent::HandshakeFailed { address: address.to_string(), error });
+    };
+
+    let connection = match incoming.await {
+        Ok(connection) => connection,
+        Err(e) => return failed(format!("Failed to accept connection: {}", e)),
+    };
+    
+    let peer_id = peer_id_for(&connection, None);
+    if !registry.admit(&peer_id) {
+        close_connection(&connection, close_code::RATE_LIMITED);
+        return failed(format!("{} connects too often", peer_id));
+    }
+    println!("[QUIC] Accepted connection from {} ({})", peer_id, address);
+    
+    let protocol_version = match hello_responder(&connection, local_device_id.clone()).await {
+        Ok(version) => version,
+        Err(e) => return failed(e.to_string()),
+    };
+    println!("[QUIC] Negotiated protocol v{} with {}", protocol_version, peer_id);
+    
+    registry.register(&local_device_id, &peer_id, connection, false, protocol_version).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::relay::RelayServer;
+    use crate::signaling::test_hub::TestHub;
+    use crate::transport::rendezvous::RendezvousServer;
+    use crate::transport::stun::{NatType, StunServer};
+
+    #[test]
+    fn test_generate_certificate() {
+        // Verify certificate generation doesn't panic
+        let result = generate_self_signed_cert();
+        assert!(result.is_ok(), "Certificate generation should succeed");
+        
+        let (cert, key) = result.unwrap();
+        assert!(!cert.is_empty(), "Certificate should not be empty");
+        assert!(!key.is_empty(), "Private key should not be empty");
+        
+        // Verify certificate is valid DER format
+        assert!(cert.len() > 100, "Certificate should have reasonable size");
+        assert!(key.len() > 100, "Private key should have reasonable size");
+    }
+
+    #[tokio::test]
+    async fn test_create_server() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        
+        // Verify server starts on port (using port 0 for OS-assigned port)
+        let mut transport = QuicTransport::new(TransportOptions::default());
+        assert!(!transport.is_running(), "Transport should not be running initially");
+        
+        let result = transport.start_server(0).await;
+        assert!(result.is_ok(), "Server should start successfully");
+        assert!(transport.is_running(), "Transport should be running after server start");
+        assert!(transport.local_port().is_some_and(|port| port != 0), "Server should listen on a port");
+        
+        // Clean up
+        transport.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_client() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        
+        // Verify client endpoint is created
+        let mut transport = QuicTransport::new(TransportOptions::default());
+        assert!(!transport.is_running(), "Transport should not be running initially");
+        
+        // Note: This will fail to connect since there's no server, but it should create the endpoint
+        let result = transport.connect_to_peer("127.0.0.1", 1234).await;
+        
+        // We expect this to fail (no server listening), but the endpoint should be created
+        assert!(result.is_err(), "Connection should fail (no server listening)");
+        assert!(transport.is_running(), "Client endpoint should be created despite connection failure");
+        assert!(transport.local_port().is_some(), "Client endpoint should accept connections too");
+        
+        // Clean up
+        transport.close().await;
+    }
+
+    #[test]
+    fn test_transport_error_display() {
+        let err = TransportError::Connection("test".to_string());
+        assert!(err.to_string().contains("Connection error"));
+        
+        let err = TransportError::NotConnected;
+        assert_eq!(err.to_string(), "Not connected");
+        
+        let err = TransportError::PeerNotFound("peer123".to_string());
+        assert!(err.to_string().contains("Peer not found: peer123"));
+    }
+
+    #[test]
+    fn test_transport_error_encodes_for_dart() {
+        use crate::frb_generated::SseEncode;
+        use flutter_rust_bridge::for_generated::SseSerializer;
+
+        // Every variant must cross the bridge; a stale encoder panics here
+        let errors = vec![
+            TransportError::Connection("c".to_string()),
+            TransportError::Io("i".to_string()),
+            TransportError::Tls("t".to_string()),
+            TransportError::NotConnected,
+            TransportError::PeerNotFound("peer".to_string()),
+            TransportError::FingerprintMismatch {
+                device_id: "dev".to_string(),
+                expected: "aa".to_string(),
+                actual: "bb".to_string(),
+            },
+            TransportError::Protocol("p".to_string()),
+            TransportError::Cancelled("x".to_string()),
+            TransportError::Rejected("r".to_string()),
+        ];
+
+        for (tag, err) in errors.into_iter().enumerate() {
+            let mut serializer = SseSerializer::new();
+            err.sse_encode(&mut serializer);
+            let bytes = serializer.cursor.into_inner();
+            let encoded_tag = i32::from_ne_bytes(bytes[..4].try_into().unwrap());
+            assert_eq!(encoded_tag, tag as i32, "Variant should carry its own tag");
+        }
+    }
+
+    #[test]
+    fn test_quic_transport_new() {
+        let transport = QuicTransport::new(TransportOptions::default());
+        assert!(!transport.is_running());
+        assert_eq!(transport.local_port(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_connected_peers_empty() {
+        let transport = QuicTransport::new(TransportOptions::default());
+        let peers = transport.get_connected_peers().await;
+        assert!(peers.is_empty(), "Should have no connected peers initially");
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_nonexistent_peer() {
+        let transport = QuicTransport::new(TransportOptions::default());
+        let result = transport.disconnect("nonexistent").await;
+        assert!(result.is_err(), "Should fail to disconnect from nonexistent peer");
+        
+        if let Err(TransportError::PeerNotFound(peer_id)) = result {
+            assert_eq!(peer_id, "nonexistent");
+        } else {
+            panic!("Expected PeerNotFound error");
+        }
+    }
+
+    // Integration test: Server-Client communication
+    #[tokio::test]
     async fn test_server_client_integration() {
         // Install crypto provider for rustls 0.23+
         let _ = rustls::crypto::ring::default_provider().install_default();
-        
-        // Create and start server
-        let mut server = QuicTransport::new();
-        let result = server.start_server(0).await;
-        assert!(result.is_ok(), "Server should start successfully");
-        
-        // Get the actual port the server is listening on
-        if let Some(ref endpoint) = server.endpoint {
-            if let Ok(server_addr) = endpoint.local_addr() {
-                let server_port = server_addr.port();
-                println!("Server listening on port {}", server_port);
-                
-                // Create client and attempt to connect
-                let mut client = QuicTransport::new();
-                
-                // Try to connect (this may fail due to timing, but tests the client creation)
-                let _connect_result = tokio::time::timeout(
-                    std::time::Duration::from_millis(500),
-                    client.connect_to_peer("127.0.0.1", server_port)
-                ).await;
-                
-                // Clean up
-                client.close().await;
+        
+        // Create and start server
+        let mut server = QuicTransport::new(TransportOptions::default());
+        let result = server.start_server(0).await;
+        assert!(result.is_ok(), "Server should start successfully");
+        
+        // Get the actual port the server is listening on
+        if let Some(ref endpoint) = server.endpoint {
+            if let Ok(server_addr) = endpoint.local_addr() {
+                let server_port = server_addr.port();
+                println!("Server listening on port {}", server_port);
+                
+                // Create client and attempt to connect
+                let mut client = QuicTransport::new(TransportOptions::default());
+                
+                // Try to connect (this may fail due to timing, but tests the client creation)
+                let _connect_result = tokio::time::timeout(
+                    std::time::Duration::from_millis(500),
+                    client.connect_to_peer("127.0.0.1", server_port)
+                ).await;
+                
+                // Clean up
+                client.close().await;
+            }
+        }
+        
+        // Clean up
+        server.close().await;
+        
+        // Note: This test verifies the setup works, even if connection times out
+        println!("Integration test completed - server and client created successfully");
+    }
+
+    // Integration test: certificate pinning across server restarts
+    #[tokio::test]
+    async fn test_connect_to_device_pins_fingerprint() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let identity = DeviceIdentity::generate("server-device".to_string()).unwrap();
+        let mut server = QuicTransport::with_identity(identity.clone());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        // First connection trusts and records the server certificate
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity.clone());
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let (_, result) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_device("server-device", "127.0.0.1", port)
+        );
+        assert!(result.is_ok(), "First connection should succeed: {:?}", result.err());
+        let pins = client.list_pinned_fingerprints();
+        assert_eq!(pins.len(), 1, "Fingerprint should be recorded on first use");
+        assert_eq!(pins[0].device_id, "server-device");
+        assert_eq!(pins[0].fingerprint, identity.fingerprint());
+        server.close().await;
+
+        // A restarted server with the same identity is still trusted
+        let mut server = QuicTransport::with_identity(identity);
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+        let (_, result) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_device("server-device", "127.0.0.1", port)
+        );
+        assert!(result.is_ok(), "Reconnecting to the same identity should succeed");
+        server.close().await;
+
+        // A different key claiming the same device id must be rejected
+        let forged = DeviceIdentity::generate("server-device".to_string()).unwrap();
+        let mut impostor = QuicTransport::with_identity(forged);
+        impostor.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        impostor.start_server(0).await.expect("Server should start");
+        let port = impostor.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let (_, result) = tokio::join!(
+            impostor.accept_connection(),
+            client.connect_to_device("server-device", "127.0.0.1", port)
+        );
+        match result {
+            Err(TransportError::FingerprintMismatch { device_id, expected, .. }) => {
+                assert_eq!(device_id, "server-device");
+                assert_eq!(expected, pins[0].fingerprint);
+            }
+            other => panic!("Expected FingerprintMismatch, got {:?}", other),
+        }
+
+        // Forgetting the pin allows trusting the new certificate
+        assert!(client.forget_fingerprint("server-device".to_string()));
+        let (_, result) = tokio::join!(
+            impostor.accept_connection(),
+            client.connect_to_device("server-device", "127.0.0.1", port)
+        );
+        assert!(result.is_ok(), "Connection should succeed after forgetting pin");
+
+        client.close().await;
+        impostor.close().await;
+    }
+
+    // Integration test: device id is taken from the certificate when not known up front
+    #[tokio::test]
+    async fn test_connect_to_peer_pins_certificate_device_id() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let identity = DeviceIdentity::generate("named-device".to_string()).unwrap();
+        let mut server = QuicTransport::with_identity(identity.clone());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let (_, result) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        assert!(result.is_ok(), "Connection should succeed: {:?}", result.err());
+        assert_eq!(
+            client.list_pinned_fingerprints(),
+            vec![PinnedFingerprint {
+                device_id: "named-device".to_string(),
+                fingerprint: identity.fingerprint(),
+            }]
+        );
+
+        // Expecting a different device at this address fails the handshake
+        let (_, result) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_device("other-device", "127.0.0.1", port)
+        );
+        assert!(matches!(result, Err(TransportError::Tls(_))), "Unexpected device should be rejected");
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: mutual TLS only admits paired devices
+    #[tokio::test]
+    async fn test_server_requires_paired_client() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let server_identity = DeviceIdentity::generate("server-device".to_string()).unwrap();
+        let server_fingerprint = server_identity.fingerprint();
+        let mut server = QuicTransport::with_identity(server_identity);
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        // An unpaired device is rejected during the handshake
+        let mut stranger = QuicTransport::with_identity(DeviceIdentity::generate("stranger".to_string()).unwrap());
+        let (accepted, _) = tokio::join!(
+            server.accept_connection(),
+            stranger.connect_to_peer("127.0.0.1", port)
+        );
+        assert!(accepted.is_err(), "Server should reject an unpaired client");
+        assert!(server.get_connected_peers().await.is_empty());
+
+        // Dialing the stranger pins it on first use, which doesn't pair it
+        stranger.pin_fingerprint("server-device".to_string(), server_fingerprint).unwrap();
+        stranger.start_server(0).await.expect("Stranger should start");
+        let stranger_port = stranger.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+        let (accepted, connected) = tokio::join!(
+            stranger.accept_connection(),
+            server.connect_to_peer("127.0.0.1", stranger_port)
+        );
+        accepted.expect("Stranger should accept its paired server");
+        server.disconnect(&connected.unwrap()).await.unwrap();
+        assert!(server.list_pinned_fingerprints().iter().any(|pin| pin.device_id == "stranger"));
+        let (accepted, _) = tokio::join!(
+            server.accept_connection(),
+            stranger.connect_to_peer("127.0.0.1", port)
+        );
+        assert!(accepted.is_err(), "Server should reject a device it only pinned on first use");
+
+        // A paired device is accepted and its device id is surfaced
+        let client_identity = DeviceIdentity::generate("paired-device".to_string()).unwrap();
+        server.pin_fingerprint("paired-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        let peer_id = accepted.expect("Server should accept a paired client");
+        assert_eq!(server.get_peer_device_id(&peer_id).await.unwrap(), "paired-device");
+        assert_eq!(client.get_peer_device_id(&connected.unwrap()).await.unwrap(), "server-device");
+
+        stranger.close().await;
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: a Hello must name the device its certificate belongs to
+    #[tokio::test]
+    async fn test_hello_device_mismatch_rejected() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let dialer = client.dialer().unwrap();
+        let address: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+        // The paired client claims to be another device in its Hello
+        let connection = dialer.connect(address, None).await.expect("TLS handshake should succeed");
+        let (accepted, hello) = tokio::join!(
+            server.accept_connection(),
+            hello_initiator(&connection, "server-device".to_string())
+        );
+        let error = accepted.expect_err("Server should refuse the Hello").to_string();
+        assert!(error.contains("certificate belongs to client-device"), "Unexpected error: {}", error);
+        assert!(hello.is_err(), "Client should see the refusal");
+        assert!(server.get_connected_peers().await.is_empty());
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: a device reconnecting from a new port keeps its peer id
+    #[tokio::test]
+    async fn test_reconnect_keyed_by_device_id() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("roaming-device".to_string()).unwrap();
+        server.pin_fingerprint("roaming-device".to_string(), client_identity.fingerprint()).unwrap();
+
+        let mut first = QuicTransport::with_identity(client_identity.clone());
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            first.connect_to_peer("127.0.0.1", port)
+        );
+        assert_eq!(accepted.unwrap(), "roaming-device", "Peer should be keyed by device id");
+        assert_eq!(connected.unwrap(), "server-device");
+        let first_addr = server.get_peer_address("roaming-device").await.unwrap();
+        let old = first.peer_connection("server-device").await.unwrap();
+
+        // Same device, new endpoint and therefore a new source port
+        let mut second = QuicTransport::with_identity(client_identity);
+        let (accepted, _) = tokio::join!(
+            server.accept_connection(),
+            second.connect_to_peer("127.0.0.1", port)
+        );
+        assert_eq!(accepted.unwrap(), "roaming-device");
+        assert_eq!(server.get_connected_peers().await, vec!["roaming-device".to_string()]);
+        let second_addr = server.get_peer_address("roaming-device").await.unwrap();
+        assert_ne!(first_addr, second_addr, "Address should follow the newest connection");
+
+        // The replaced connection is closed by the server
+        let closed = tokio::time::timeout(std::time::Duration::from_secs(5), old.closed()).await;
+        assert!(closed.is_ok(), "Replaced connection should be closed");
+
+        // Sending by device id reaches the new connection
+        assert!(server.send_data("roaming-device", b"hello".to_vec()).await.is_ok());
+
+        first.close().await;
+        second.close().await;
+        server.close().await;
+    }
+
+    // Integration test: clipboard data travels as typed protocol messages
+    #[tokio::test]
+    async fn test_send_and_receive_protocol_message() {
+        use futures::StreamExt;
+
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        let client_id = accepted.unwrap();
+        let server_id = connected.unwrap();
+        assert_eq!(server.get_peer_protocol_version(&client_id).await.unwrap(), crate::transport::protocol::PROTOCOL_VERSION);
+        assert_eq!(client.get_peer_protocol_version(&server_id).await.unwrap(), crate::transport::protocol::PROTOCOL_VERSION);
+
+        client.send_data(&server_id, b"clipboard".to_vec()).await.unwrap();
+        let received = {
+            let stream = server.receive_data();
+            futures::pin_mut!(stream);
+            tokio::time::timeout(std::time::Duration::from_secs(5), stream.next()).await
+        };
+        assert_eq!(received.unwrap(), Some((client_id, b"clipboard".to_vec())));
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: an idle peer does not hold up data from another peer
+    #[tokio::test]
+    async fn test_idle_peer_does_not_block_receive() {
+        use futures::StreamExt;
+
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let mut clients = Vec::new();
+        for name in ["idle-device", "busy-device"] {
+            let identity = DeviceIdentity::generate(name.to_string()).unwrap();
+            server.pin_fingerprint(name.to_string(), identity.fingerprint()).unwrap();
+            let mut client = QuicTransport::with_identity(identity);
+            let (accepted, connected) = tokio::join!(
+                server.accept_connection(),
+                client.connect_to_peer("127.0.0.1", port)
+            );
+            assert_eq!(accepted.unwrap(), name);
+            assert_eq!(connected.unwrap(), "server-device");
+            clients.push(client);
+        }
+
+        // Only the second peer sends anything
+        {
+            let stream = server.receive_data();
+            futures::pin_mut!(stream);
+            for i in 0..3u8 {
+                clients[1].send_data("server-device", vec![i]).await.unwrap();
+                let received = tokio::time::timeout(std::time::Duration::from_secs(2), stream.next()).await;
+                assert_eq!(
+                    received.expect("Data should not wait on the idle peer"),
+                    Some(("busy-device".to_string(), vec![i]))
+                );
+            }
+        }
+
+        // A closed connection is removed from the registry by its receive task
+        clients[0].close().await;
+        let mut removed = false;
+        for _ in 0..50 {
+            if server.get_connected_peers().await == vec!["busy-device".to_string()] {
+                removed = true;
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        assert!(removed, "Closed peer should be removed");
+
+        clients[1].close().await;
+        server.close().await;
+    }
+
+    // Integration test: the background accept loop publishes connectivity events
+    #[tokio::test]
+    async fn test_accept_loop_publishes_events() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        async fn next_event(events: &mut broadcast::Receiver<ConnectionEvent>) -> ConnectionEvent {
+            tokio::time::timeout(std::time::Duration::from_secs(5), events.recv())
+                .await
+                .expect("Event should arrive")
+                .unwrap()
+        }
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        let mut events = server.subscribe_events();
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        // Handshakes complete concurrently without anyone calling accept_connection
+        let mut clients = Vec::new();
+        for name in ["device-a", "device-b"] {
+            let identity = DeviceIdentity::generate(name.to_string()).unwrap();
+            server.pin_fingerprint(name.to_string(), identity.fingerprint()).unwrap();
+            clients.push(QuicTransport::with_identity(identity));
+        }
+        let (a, b) = clients.split_at_mut(1);
+        let (a, b) = tokio::join!(
+            a[0].connect_to_peer("127.0.0.1", port),
+            b[0].connect_to_peer("127.0.0.1", port)
+        );
+        assert!(a.is_ok() && b.is_ok(), "Both clients should connect");
+        let mut connected = Vec::new();
+        for _ in 0..2 {
+            match next_event(&mut events).await {
+                ConnectionEvent::PeerConnected { peer_id, outbound, .. } => {
+                    assert!(!outbound, "Accepted connections are inbound");
+                    connected.push(peer_id);
+                }
+                other => panic!("Expected PeerConnected, got {:?}", other),
+            }
+        }
+        connected.sort();
+        assert_eq!(connected, vec!["device-a".to_string(), "device-b".to_string()]);
+
+        // Moving the client to a new socket is reported as a migration
+        let socket = TransportOptions::default().bind_socket(0).unwrap();
+        clients[0].endpoint.as_ref().unwrap().rebind(socket).unwrap();
+        clients[0].send_data("server-device", b"ping".to_vec()).await.unwrap();
+        match next_event(&mut events).await {
+            ConnectionEvent::ConnectionMigrated { peer_id, old_address, new_address } => {
+                assert_eq!(peer_id, "device-a");
+                assert_ne!(old_address, new_address);
+            }
+            other => panic!("Expected ConnectionMigrated, got {:?}", other),
+        }
+
+        // An unpaired client fails the handshake
+        let mut stranger = QuicTransport::with_identity(DeviceIdentity::generate("stranger".to_string()).unwrap());
+        assert!(stranger.connect_to_peer("127.0.0.1", port).await.is_err());
+        assert!(matches!(next_event(&mut events).await, ConnectionEvent::HandshakeFailed { .. }));
+
+        // A client going away is reported with the reason it gave
+        clients[1].close().await;
+        match next_event(&mut events).await {
+            ConnectionEvent::PeerDisconnected { peer_id, reason, by_peer } => {
+                assert_eq!(peer_id, "device-b");
+                assert_eq!(reason, DisconnectReason::ShuttingDown);
+                assert!(by_peer);
+            }
+            other => panic!("Expected PeerDisconnected, got {:?}", other),
+        }
+        assert_eq!(server.get_connected_peers().await, vec!["device-a".to_string()]);
+
+        stranger.close().await;
+        clients[0].close().await;
+        server.close().await;
+    }
+
+    // Integration test: items sent with an acknowledgement report what the peer's app did
+    #[tokio::test]
+    async fn test_delivery_acknowledgements() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        accepted.unwrap();
+        let server_id = connected.unwrap();
+
+        let incoming = server.incoming_rx.clone();
+        let next_item = || async {
+            let item = tokio::time::timeout(std::time::Duration::from_secs(5), incoming.lock().await.recv()).await;
+            item.expect("Item should arrive").expect("Channel open")
+        };
+
+        // Applied
+        let (result, item) = tokio::join!(client.send_data_acked(&server_id, b"one".to_vec(), 5_000), async {
+            let item = next_item().await;
+            assert!(item.requires_ack);
+            assert!(server.acknowledge_item(item.peer_id.clone(), item.item_id));
+            item
+        });
+        assert_eq!(item.payload, b"one");
+        assert_eq!(result.unwrap(), DeliveryResult::Delivered { item_id: item.item_id });
+
+        // Rejected with a reason
+        let (result, item) = tokio::join!(client.send_data_acked(&server_id, b"two".to_vec(), 5_000), async {
+            let item = next_item().await;
+            assert!(server.reject_item(item.peer_id.clone(), item.item_id, "clipboard locked".to_string()));
+            item
+        });
+        assert_eq!(
+            result.unwrap(),
+            DeliveryResult::Rejected { item_id: item.item_id, reason: "clipboard locked".to_string() }
+        );
+
+        // Never answered: the sender times out and the receiver stops waiting
+        let (result, item) = tokio::join!(client.send_data_acked(&server_id, b"three".to_vec(), 300), next_item());
+        assert_eq!(result.unwrap(), DeliveryResult::TimedOut { item_id: item.item_id });
+        let mut forgotten = false;
+        for _ in 0..50 {
+            if server.registry.acks().waiting.lock().unwrap().is_empty() {
+                forgotten = true;
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert!(forgotten, "Receiver should stop waiting once the sender gives up");
+        assert!(!server.acknowledge_item(item.peer_id, item.item_id));
+
+        // Plain sends don't expect an answer, and only clipboard items can be acknowledged
+        client.send_data(&server_id, b"four".to_vec()).await.unwrap();
+        assert!(!next_item().await.requires_ack);
+        let ping = client.send_message_acked(&server_id, SyncMessage::Ping { nonce: 1 }, 1_000).await;
+        assert!(matches!(ping, Err(TransportError::Protocol(_))));
+
+        // The timeout also covers waiting for a stream while the peer's stream limit is used up
+        let connection = client.peer_connection(&server_id).await.unwrap();
+        let mut held = Vec::new();
+        while let Ok(streams) = tokio::time::timeout(std::time::Duration::from_millis(100), connection.open_bi()).await {
+            held.push(streams.unwrap());
+        }
+        let result = client.send_data_acked(&server_id, b"five".to_vec(), 300).await.unwrap();
+        assert!(matches!(result, DeliveryResult::TimedOut { .. }), "Got {:?}", result);
+        for (mut send, _) in held {
+            let _ = send.reset(crate::transport::protocol::STREAM_CANCELLED);
+        }
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: one broadcast reaches every matching peer, encrypted with its key
+    #[tokio::test]
+    async fn test_broadcast() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let mut clients = Vec::new();
+        for name in ["phone", "tablet", "laptop"] {
+            let identity = DeviceIdentity::generate(name.to_string()).unwrap();
+            server.pin_fingerprint(name.to_string(), identity.fingerprint()).unwrap();
+            let mut client = QuicTransport::with_identity(identity);
+            let (accepted, connected) = tokio::join!(
+                server.accept_connection(),
+                client.connect_to_peer("127.0.0.1", port)
+            );
+            assert_eq!(accepted.unwrap(), name);
+            connected.unwrap();
+            clients.push(client);
+        }
+
+        // Phone and tablet share a key, the laptop has none
+        let key = crate::crypto::generate_key();
+        server.set_peer_key("phone".to_string(), key.clone());
+        server.set_peer_key("tablet".to_string(), key.clone());
+
+        let outcomes = server.broadcast(b"clipboard".to_vec(), BroadcastFilter::default()).await;
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(outcomes["phone"], BroadcastOutcome::Sent);
+        assert_eq!(outcomes["tablet"], BroadcastOutcome::Sent);
+        assert_eq!(outcomes["laptop"], BroadcastOutcome::NoKey);
+
+        let mut received = Vec::new();
+        for client in &clients[..2] {
+            let item = tokio::time::timeout(std::time::Duration::from_secs(5), client.incoming_rx.lock().await.recv())
+                .await
+                .expect("Broadcast should arrive")
+                .unwrap();
+            assert_eq!(item.peer_id, "server-device");
+            assert_eq!(crate::crypto::decrypt_bytes(item.payload.clone(), key.clone()).unwrap(), b"clipboard");
+            received.push(item);
+        }
+        assert_eq!(received[0].item_id, received[1].item_id, "Peers get the same item");
+        assert_eq!(received[0].payload, received[1].payload, "Encrypted once for a shared key");
+
+        // Filtered: only the phone, plus a device that isn't connected
+        let filter = BroadcastFilter {
+            include: vec!["phone".to_string(), "watch".to_string(), "tablet".to_string()],
+            exclude: vec!["tablet".to_string()],
+        };
+        let outcomes = server.broadcast(b"again".to_vec(), filter).await;
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes["phone"], BroadcastOutcome::Sent);
+        assert_eq!(outcomes["watch"], BroadcastOutcome::NotConnected);
+
+        assert!(server.forget_peer_key("phone".to_string()));
+        let filter = BroadcastFilter { include: vec!["phone".to_string()], exclude: Vec::new() };
+        assert_eq!(server.broadcast(b"after unpairing".to_vec(), filter).await["phone"], BroadcastOutcome::NoKey);
+
+        for mut client in clients {
+            client.close().await;
+        }
+        server.close().await;
+    }
+
+    // Integration test: datagram heartbeats measure the link and carry presence
+    #[tokio::test]
+    async fn test_datagram_heartbeats_and_presence() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let options = TransportOptions { heartbeat_interval_ms: 50, ..TransportOptions::default() };
+        let mut server = QuicTransport::new(options.clone());
+        server.set_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::new(options);
+        client.set_identity(client_identity);
+        let mut server_links = server.subscribe_links();
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        let client_id = accepted.unwrap();
+        let server_id = connected.unwrap();
+
+        // Heartbeats are answered, giving an RTT without any loss
+        let mut measured = None;
+        for _ in 0..100 {
+            if let Some(quality) = server.get_link_quality(client_id.clone()) {
+                if quality.rtt_ms.is_some() {
+                    measured = Some(quality);
+                    break;
+                }
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        let quality = measured.expect("Heartbeat replies should give an RTT");
+        assert_eq!(quality.loss, 0.0);
+        assert!(quality.last_heard_ms < 1_000);
+        assert!(client.get_link_quality(server_id.clone()).is_some());
+        assert!(server.get_link_quality("stranger".to_string()).is_none());
+
+        client.set_activity(Activity::Copying).await;
+        let changed = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                match server_links.recv().await.unwrap() {
+                    LinkEvent::ActivityChanged { peer_id, activity } => break (peer_id, activity),
+                    LinkEvent::QualityChanged { quality } => assert_eq!(quality.peer_id, client_id),
+                }
+            }
+        })
+        .await
+        .expect("Activity should arrive");
+        assert_eq!(changed, (client_id.clone(), Activity::Copying));
+        assert_eq!(server.get_link_quality(client_id.clone()).unwrap().activity, Activity::Copying);
+
+        // The link state goes away with the connection
+        client.close().await;
+        let mut removed = false;
+        for _ in 0..50 {
+            if server.get_link_quality(client_id.clone()).is_none() {
+                removed = true;
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        assert!(removed, "Closed peer's link should be removed");
+
+        server.close().await;
+    }
+
+    // Integration test: connection statistics reflect traffic on the connection
+    #[tokio::test]
+    async fn test_peer_stats() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        let client_id = accepted.unwrap();
+        let server_id = connected.unwrap();
+
+        let before = client.get_peer_stats(&server_id).await.unwrap();
+        assert_eq!(before.peer_id, server_id);
+        assert_eq!(before.address, client.get_peer_address(&server_id).await.unwrap());
+        assert!(before.congestion_window > 0);
+        assert!(before.mtu >= 1200);
+        assert!(before.rtt_ms > 0.0);
+
+        client.send_data(&server_id, vec![7; 64 * 1024]).await.unwrap();
+        let received = tokio::time::timeout(std::time::Duration::from_secs(5), server.incoming_rx.lock().await.recv()).await;
+        assert_eq!(received.unwrap().unwrap().payload.len(), 64 * 1024);
+
+        let after = client.get_peer_stats(&server_id).await.unwrap();
+        assert!(after.bytes_sent >= before.bytes_sent + 64 * 1024);
+        assert!(after.packets_sent > before.packets_sent);
+        assert!(after.connected_ms >= before.connected_ms);
+        let server_side = server.get_peer_stats(&client_id).await.unwrap();
+        assert!(server_side.bytes_received >= 64 * 1024);
+
+        assert_eq!(server.registry.all_stats().await.len(), 1);
+        assert!(matches!(client.get_peer_stats("stranger").await, Err(TransportError::PeerNotFound(_))));
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: a graceful shutdown delivers items still in flight and is reported as such
+    #[tokio::test]
+    async fn test_graceful_shutdown_drains_sends() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        let client_id = accepted.unwrap();
+        let server_id = connected.unwrap();
+        let mut server_events = server.subscribe_events();
+
+        // Each send returns once its data is queued, long before it is delivered
+        const ITEMS: usize = 20;
+        for i in 0..ITEMS {
+            client.send_data(&server_id, vec![i as u8; 512 * 1024]).await.unwrap();
+        }
+
+        // Shutting down only borrows the transport, so a send can race it and is refused
+        let (_, late) = tokio::join!(client.shutdown(10_000), client.send_data(&server_id, vec![0xff; 16]));
+        assert!(late.is_err(), "Sends after the shutdown started should be refused");
+        assert!(!client.is_running());
+        assert!(client.get_connected_peers().await.is_empty());
+
+        let mut received = Vec::new();
+        while received.len() < ITEMS {
+            let item = tokio::time::timeout(std::time::Duration::from_secs(5), server.incoming_rx.lock().await.recv())
+                .await
+                .expect("Every item sent before the shutdown should arrive")
+                .unwrap();
+            received.push(item.payload[0] as usize);
+        }
+        received.sort();
+        assert_eq!(received, (0..ITEMS).collect::<Vec<_>>());
+
+        let reason = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                if let ConnectionEvent::PeerDisconnected { peer_id, reason, .. } = server_events.recv().await.unwrap() {
+                    assert_eq!(peer_id, client_id);
+                    break reason;
+                }
+            }
+        })
+        .await
+        .expect("Server should see the client go");
+        assert_eq!(reason, DisconnectReason::ShuttingDown);
+
+        // A shut down transport can connect again
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        accepted.unwrap();
+        client.send_data(&connected.unwrap(), vec![1]).await.expect("Sends should work again");
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: one endpoint both dials and accepts, and keeps its connections when it starts serving
+    #[tokio::test]
+    async fn test_symmetric_endpoint() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let a_identity = DeviceIdentity::generate("device-a".to_string()).unwrap();
+        let b_identity = DeviceIdentity::generate("device-b".to_string()).unwrap();
+        let (a_fingerprint, b_fingerprint) = (a_identity.fingerprint(), b_identity.fingerprint());
+        let mut a = QuicTransport::with_identity(a_identity);
+        let mut b = QuicTransport::with_identity(b_identity);
+        // Paired both ways, since pinning A on first use wouldn't let it dial B
+        a.pin_fingerprint("device-b".to_string(), b_fingerprint).unwrap();
+        b.pin_fingerprint("device-a".to_string(), a_fingerprint).unwrap();
+        a.start_server(0).await.unwrap();
+        let a_port = a.local_port().unwrap();
+
+        // B only ever dialed, and starting its server afterwards moves the endpoint without dropping that connection
+        assert_eq!(b.connect_to_device("device-a", "127.0.0.1", a_port).await.unwrap(), "device-a");
+        let b_port = b.local_port().expect("Dialing creates the endpoint");
+        b.start_server(0).await.unwrap();
+        assert_eq!(b.local_port(), Some(b_port), "Port 0 keeps the current port");
+        let port = std::net::UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        b.start_server(port).await.unwrap();
+        assert_eq!(b.local_port(), Some(port));
+        b.send_data("device-a", b"moved".to_vec()).await.unwrap();
+        let item = tokio::time::timeout(std::time::Duration::from_secs(5), a.incoming_rx.lock().await.recv())
+            .await
+            .expect("Item should arrive over the migrated connection")
+            .unwrap();
+        assert_eq!(item.payload, b"moved");
+
+        // A can dial B back on the same endpoint
+        b.disconnect("device-a").await.unwrap();
+        let mut b_events = b.subscribe_events();
+        assert_eq!(a.connect_to_device("device-b", "127.0.0.1", port).await.unwrap(), "device-b");
+        loop {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(5), b_events.recv()).await.unwrap().unwrap();
+            if let ConnectionEvent::PeerConnected { peer_id, outbound, .. } = event {
+                assert_eq!(peer_id, "device-a");
+                assert!(!outbound, "A dialed B");
+                break;
             }
         }
-        
-        // Clean up
+
+        b.close().await;
+        a.close().await;
+    }
+
+    // Integration test: devices introduced by a rendezvous service punch a direct connection
+    #[tokio::test]
+    async fn test_hole_punch_via_rendezvous() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let service = RendezvousServer::bind("127.0.0.1:0").await.unwrap();
+        let server = service.local_addr().to_string();
+
+        let a_identity = DeviceIdentity::generate("device-a".to_string()).unwrap();
+        let b_identity = DeviceIdentity::generate("device-b".to_string()).unwrap();
+        let mut a = QuicTransport::new(TransportOptions::default());
+        let mut b = QuicTransport::new(TransportOptions::default());
+        a.pin_fingerprint("device-b".to_string(), b_identity.fingerprint()).unwrap();
+        b.pin_fingerprint("device-a".to_string(), a_identity.fingerprint()).unwrap();
+        a.set_identity(a_identity);
+        b.set_identity(b_identity);
+
+        assert!(matches!(a.connect_via_rendezvous("device-b".to_string()).await, Err(TransportError::NotConnected)));
+        let public = a.register_rendezvous(server.clone()).await.unwrap();
+        assert_eq!(public, format!("127.0.0.1:{}", a.local_port().unwrap()), "Service reports the socket QUIC uses");
+        b.register_rendezvous(server).await.unwrap();
+
+        // B asks, but A has the smaller id and dials
+        assert_eq!(b.connect_via_rendezvous("device-a".to_string()).await.unwrap(), "device-a");
+        b.send_data("device-a", b"punched".to_vec()).await.unwrap();
+        let item = tokio::time::timeout(std::time::Duration::from_secs(5), a.incoming_rx.lock().await.recv())
+            .await
+            .expect("Item should arrive over the punched connection")
+            .unwrap();
+        assert_eq!(item.payload, b"punched");
+        assert_eq!(a.get_connected_peers().await, vec!["device-b".to_string()]);
+
+        assert!(matches!(
+            a.connect_via_rendezvous("device-x".to_string()).await,
+            Err(TransportError::PeerNotFound(id)) if id == "device-x"
+        ));
+
+        b.close().await;
+        a.close().await;
+    }
+
+    // Integration test: devices connect through a relay, then upgrade to a punched connection
+    #[tokio::test]
+    async fn test_relay_with_direct_upgrade() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let relay = RelayServer::bind("127.0.0.1:0").await.unwrap();
+        let service = RendezvousServer::bind("127.0.0.1:0").await.unwrap();
+
+        let a_identity = DeviceIdentity::generate("device-a".to_string()).unwrap();
+        let b_identity = DeviceIdentity::generate("device-b".to_string()).unwrap();
+        let options = TransportOptions { direct_upgrade_interval_ms: 200, ..TransportOptions::default() };
+        let mut a = QuicTransport::new(options.clone());
+        let mut b = QuicTransport::new(options);
+        a.pin_fingerprint("device-b".to_string(), b_identity.fingerprint()).unwrap();
+        b.pin_fingerprint("device-a".to_string(), a_identity.fingerprint()).unwrap();
+        a.set_identity(a_identity);
+        b.set_identity(b_identity);
+
+        assert!(matches!(a.connect_via_relay("device-b".to_string()).await, Err(TransportError::NotConnected)));
+        for transport in [&mut a, &mut b] {
+            transport.use_relay(relay.local_addr().to_string()).await.unwrap();
+            transport.register_rendezvous(service.local_addr().to_string()).await.unwrap();
+        }
+
+        let (a_result, b_result) =
+            tokio::join!(a.connect_via_relay("device-b".to_string()), b.connect_via_relay("device-a".to_string()));
+        assert_eq!(a_result.unwrap(), "device-b");
+        assert_eq!(b_result.unwrap(), "device-a");
+        assert!(b.is_peer_relayed("device-a").await.unwrap());
+        let address: SocketAddr = b.get_peer_address("device-a").await.unwrap().parse().unwrap();
+        assert_eq!(address.ip().to_canonical().to_string().split('.').next(), Some("240"));
+
+        b.send_data("device-a", b"relayed".to_vec()).await.unwrap();
+        let item = tokio::time::timeout(std::time::Duration::from_secs(5), a.incoming_rx.lock().await.recv())
+            .await
+            .expect("Item should arrive through the relay")
+            .unwrap();
+        assert_eq!(item.payload, b"relayed");
+
+        // A, the dialing side, punches a direct path that replaces the relayed connection
+        let upgraded = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+            while a.is_peer_relayed("device-b").await.unwrap_or(true) || b.is_peer_relayed("device-a").await.unwrap_or(true) {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            }
+        });
+        upgraded.await.expect("Connection should move to a direct path");
+        let address: SocketAddr = b.get_peer_address("device-a").await.unwrap().parse().unwrap();
+        assert_eq!(address.ip().to_canonical().to_string(), "127.0.0.1");
+
+        b.send_data("device-a", b"direct".to_vec()).await.unwrap();
+        let item = tokio::time::timeout(std::time::Duration::from_secs(5), a.incoming_rx.lock().await.recv())
+            .await
+            .expect("Item should arrive over the direct connection")
+            .unwrap();
+        assert_eq!(item.payload, b"direct");
+
+        b.close().await;
+        a.close().await;
+    }
+
+    // Integration test: paired devices exchange candidates through a signaling hub and punch a connection
+    #[tokio::test]
+    async fn test_connect_via_signaling() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let hub = TestHub::bind().await;
+        let stun = StunServer::bind("127.0.0.1:0").await.unwrap();
+
+        let a_identity = DeviceIdentity::generate("device-a".to_string()).unwrap();
+        let b_identity = DeviceIdentity::generate("device-b".to_string()).unwrap();
+        let mut a = QuicTransport::new(TransportOptions::default());
+        let mut b = QuicTransport::new(TransportOptions::default());
+        a.pin_fingerprint("device-b".to_string(), b_identity.fingerprint()).unwrap();
+        b.pin_fingerprint("device-a".to_string(), a_identity.fingerprint()).unwrap();
+        a.set_identity(a_identity);
+        b.set_identity(b_identity);
+
+        let a_signaling = SignalingClient::connect(hub.url(), hub.token("device-a", 3600)).await.unwrap();
+        let b_signaling = SignalingClient::connect(hub.url(), hub.token("device-b", 3600)).await.unwrap();
+        assert!(matches!(a.connect_via_signaling("device-b".to_string()).await, Err(TransportError::NotConnected)));
+        assert!(a.use_signaling(&b_signaling).await.is_err(), "Token names another device");
+
+        // The public address is the only candidate on loopback
+        for transport in [&mut a, &mut b] {
+            transport.discover_nat(vec![stun.local_addr().to_string()]).await.unwrap();
+        }
+        a.use_signaling(&a_signaling).await.unwrap();
+        b.use_signaling(&b_signaling).await.unwrap();
+
+        let online = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while b_signaling.online_peers().is_empty() {
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            }
+        });
+        online.await.expect("Devices should see each other at the hub");
+        assert_eq!(b.connect_via_signaling("device-a".to_string()).await.unwrap(), "device-a");
+        assert!(!b.is_peer_relayed("device-a").await.unwrap());
+
+        b.send_data("device-a", b"signaled".to_vec()).await.unwrap();
+        let item = tokio::time::timeout(std::time::Duration::from_secs(5), a.incoming_rx.lock().await.recv())
+            .await
+            .expect("Item should arrive over the punched connection")
+            .unwrap();
+        assert_eq!(item.payload, b"signaled");
+
+        assert!(matches!(
+            a.connect_via_signaling("device-x".to_string()).await,
+            Err(TransportError::PeerNotFound(id)) if id == "device-x"
+        ));
+
+        b.close().await;
+        a.close().await;
+    }
+
+    // Integration test: an item broadcast while a paired device is offline waits for it at the hub
+    #[tokio::test]
+    async fn test_mailbox_via_signaling() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let hub = TestHub::bind().await;
+        let a_identity = DeviceIdentity::generate("device-a".to_string()).unwrap();
+        let b_identity = DeviceIdentity::generate("device-b".to_string()).unwrap();
+        let mut a = QuicTransport::new(TransportOptions::default());
+        let mut b = QuicTransport::new(TransportOptions::default());
+        a.pin_fingerprint("device-b".to_string(), b_identity.fingerprint()).unwrap();
+        b.pin_fingerprint("device-a".to_string(), a_identity.fingerprint()).unwrap();
+        a.set_identity(a_identity);
+        b.set_identity(b_identity);
+
+        // What pairing leaves each device with
+        let (a_secret, a_public) = crate::crypto::generate_keypair();
+        let (b_secret, b_public) = crate::crypto::generate_keypair();
+        let shared = crate::crypto::derive_shared_secret(a_secret.clone(), b_public.clone()).unwrap();
+        a.set_sealing_key(a_secret).unwrap();
+        b.set_sealing_key(b_secret).unwrap();
+        a.set_peer_public_key("device-b".to_string(), b_public).unwrap();
+        b.set_peer_public_key("device-a".to_string(), a_public).unwrap();
+        a.set_peer_key("device-b".to_string(), shared.clone());
+        b.set_peer_key("device-a".to_string(), shared.clone());
+        assert!(a.set_peer_public_key("device-c".to_string(), vec![0; 5]).is_err());
+
+        let a_signaling = SignalingClient::connect(hub.url(), hub.token("device-a", 3600)).await.unwrap();
+        let outcomes = a.broadcast(b"left for later".to_vec(), BroadcastFilter::default()).await;
+        assert!(outcomes.is_empty(), "Without a hub nothing is deposited");
+        a.use_signaling(&a_signaling).await.unwrap();
+        let outcomes = a.broadcast(b"left for later".to_vec(), BroadcastFilter::default()).await;
+        assert_eq!(outcomes.get("device-b"), Some(&BroadcastOutcome::Deposited));
+
+        // B comes online and finds the item in its mailbox
+        let b_signaling = SignalingClient::connect(hub.url(), hub.token("device-b", 3600)).await.unwrap();
+        b.use_signaling(&b_signaling).await.unwrap();
+        let item = tokio::time::timeout(std::time::Duration::from_secs(5), b.incoming_rx.lock().await.recv())
+            .await
+            .expect("Item should arrive from the mailbox")
+            .unwrap();
+        assert_eq!(item.peer_id, "device-a");
+        assert_eq!(crate::crypto::decrypt_bytes(item.payload, shared).unwrap(), b"left for later");
+
+        b.close().await;
+        a.close().await;
+    }
+
+    // Integration test: STUN requests leave from the QUIC socket
+    #[tokio::test]
+    async fn test_discover_nat() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let first = StunServer::bind("127.0.0.1:0").await.unwrap();
+        let second = StunServer::bind("127.0.0.1:0").await.unwrap();
+        let mut transport = QuicTransport::with_identity(DeviceIdentity::generate("laptop".to_string()).unwrap());
+        transport.start_server(0).await.unwrap();
+
+        let report = transport
+            .discover_nat(vec![first.local_addr().to_string(), second.local_addr().to_string()])
+            .await
+            .unwrap();
+        assert_eq!(report.mapped_address, format!("127.0.0.1:{}", transport.local_port().unwrap()));
+        assert_eq!(report.nat_type, NatType::Open, "Loopback has no NAT");
+        assert!(transport.discover_nat(Vec::new()).await.is_err());
+
+        transport.close().await;
+    }
+
+    // Hole punching across real NATs; run by scripts/netns-hole-punch.sh in each network namespace
+    #[tokio::test]
+    #[ignore]
+    async fn test_hole_punch_netns() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let var = |name: &str| std::env::var(name).unwrap_or_else(|_| panic!("{} is set by the script", name));
+        let role = var("SYNCMIST_NETNS_ROLE");
+        let rendezvous = var("SYNCMIST_RENDEZVOUS");
+        if role == "rendezvous" {
+            let _service = RendezvousServer::bind(&rendezvous).await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+            return;
+        }
+
+        // Peers share their identities through a directory to pin each other
+        let dir = std::path::PathBuf::from(var("SYNCMIST_NETNS_DIR"));
+        let path = |role: &str| dir.join(format!("device-{}.identity", role)).to_string_lossy().to_string();
+        let other = if role == "a" { "b" } else { "a" };
+        let identity = DeviceIdentity::load_or_generate(path(&role), format!("device-{}", role)).unwrap();
+        let peer = loop {
+            match DeviceIdentity::load(path(other)) {
+                Ok(peer) => break peer,
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(100)).await,
+            }
+        };
+
+        let mut transport = QuicTransport::with_identity(identity);
+        transport.pin_fingerprint(peer.device_id(), peer.fingerprint()).unwrap();
+        let public = transport.register_rendezvous(rendezvous).await.unwrap();
+        println!("Device {} is reachable at {}", role, public);
+
+        if role == "b" {
+            assert_eq!(transport.connect_via_rendezvous(peer.device_id()).await.unwrap(), peer.device_id());
+            transport.send_data(&peer.device_id(), b"across the NATs".to_vec()).await.unwrap();
+        } else {
+            let item = tokio::time::timeout(std::time::Duration::from_secs(30), transport.incoming_rx.lock().await.recv())
+                .await
+                .expect("B should connect and send")
+                .unwrap();
+            assert_eq!(item.payload, b"across the NATs");
+        }
+        transport.shutdown(5000).await;
+    }
+
+    // Integration test: the peer learns why a connection was closed
+    #[tokio::test]
+    async fn test_disconnect_reasons() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let mut server_events = server.subscribe_events();
+        let mut client_events = client.subscribe_events();
+
+        async fn disconnected(events: &mut broadcast::Receiver<ConnectionEvent>) -> (DisconnectReason, bool) {
+            tokio::time::timeout(std::time::Duration::from_secs(5), async {
+                loop {
+                    if let ConnectionEvent::PeerDisconnected { reason, by_peer, .. } = events.recv().await.unwrap() {
+                        break (reason, by_peer);
+                    }
+                }
+            })
+            .await
+            .expect("Disconnect should be reported")
+        }
+
+        // The client disconnecting on purpose
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        let server_id = connected.unwrap();
+        accepted.unwrap();
+        client.disconnect(&server_id).await.unwrap();
+        assert_eq!(disconnected(&mut client_events).await, (DisconnectReason::UserDisconnect, false));
+        assert_eq!(disconnected(&mut server_events).await, (DisconnectReason::UserDisconnect, true));
+
+        // The server unpairing the client
+        let (accepted, connected) = tokio::join!(
+            server.accept_connection(),
+            client.connect_to_peer("127.0.0.1", port)
+        );
+        let client_id = accepted.unwrap();
+        connected.unwrap();
+        assert!(server.unpair(client_id.clone()).await);
+        assert_eq!(disconnected(&mut server_events).await, (DisconnectReason::Unpaired, false));
+        assert_eq!(disconnected(&mut client_events).await, (DisconnectReason::Unpaired, true));
+        assert!(server.list_pinned_fingerprints().is_empty(), "Unpairing forgets the fingerprint");
+        assert!(!server.unpair(client_id).await, "Nothing left to unpair");
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: a supervised peer is redialed after its connection drops, unless it unpaired us
+    #[tokio::test]
+    async fn test_keep_connected_reconnects() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        async fn next_state(events: &mut broadcast::Receiver<ConnectionEvent>) -> PeerState {
+            loop {
+                let event = tokio::time::timeout(std::time::Duration::from_secs(10), events.recv())
+                    .await
+                    .expect("State change should arrive")
+                    .unwrap();
+                if let ConnectionEvent::PeerStateChanged { peer_id, state, .. } = event {
+                    assert_eq!(peer_id, "server-device");
+                    return state;
+                }
+            }
+        }
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let mut events = client.subscribe_events();
+
+        client
+            .keep_connected("server-device".to_string(), vec!["127.0.0.1".to_string()], port)
+            .await
+            .expect("Supervision should start");
+        assert_eq!(client.get_supervised_peers(), vec!["server-device".to_string()]);
+        assert_eq!(next_state(&mut events).await, PeerState::Connected);
+
+        // The server drops the client; the supervisor notices and dials back
+        server.disconnect("client-device").await.unwrap();
+        assert_eq!(next_state(&mut events).await, PeerState::Reconnecting);
+        assert_eq!(next_state(&mut events).await, PeerState::Connected);
+        assert_eq!(client.get_connected_peers().await, vec!["server-device".to_string()]);
+
+        assert!(client.stop_keeping_connected("server-device".to_string()));
+        assert_eq!(next_state(&mut events).await, PeerState::Stopped);
+        assert!(client.get_supervised_peers().is_empty());
+
+        // A peer refusing us for connecting too often is redialed only after a backoff delay
+        client
+            .keep_connected("server-device".to_string(), vec!["127.0.0.1".to_string()], port)
+            .await
+            .expect("Supervision should start");
+        let connection = server.registry.connection("client-device").await.unwrap();
+        close_connection(&connection, close_code::RATE_LIMITED);
+        let backoff = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                if let Ok(ConnectionEvent::PeerStateChanged { state: PeerState::Reconnecting, attempt, retry_in_ms, .. }) =
+                    events.recv().await
+                {
+                    break (attempt, retry_in_ms);
+                }
+            }
+        })
+        .await
+        .expect("Client should start reconnecting");
+        assert!(backoff.0 == 1 && backoff.1 > 0, "Redial should wait, got {:?}", backoff);
+        assert_eq!(next_state(&mut events).await, PeerState::Connected);
+
+        // A peer unpairing us is not redialed
+        assert!(server.unpair("client-device".to_string()).await);
+        assert_eq!(next_state(&mut events).await, PeerState::Stopped);
+        assert!(client.get_supervised_peers().is_empty());
+        assert!(!client.stop_keeping_connected("server-device".to_string()));
+
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: chunked transfers with hash check, size limit and cancellation
+    #[tokio::test]
+    async fn test_chunked_transfers() {
+        use crate::transport::protocol::{error_code, read_message, write_message};
+        use crate::transport::transfer::CHUNK_SIZE;
+        use tokio::io::AsyncWriteExt;
+
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let directory = std::env::temp_dir().join(format!("syncmist-transfers-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        let options = TransportOptions {
+            max_transfer_size: 1024 * 1024,
+            transfer_directory: directory.to_string_lossy().to_string(),
+            ..TransportOptions::default()
+        };
+        let mut server = QuicTransport::new(options);
+        server.set_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::with_identity(client_identity);
+        let (_, connected) = tokio::join!(server.accept_connection(), client.connect_to_peer("127.0.0.1", port));
+        connected.unwrap();
+
+        async fn next_final(events: &mut broadcast::Receiver<TransferEvent>) -> TransferEvent {
+            loop {
+                let event = tokio::time::timeout(std::time::Duration::from_secs(5), events.recv())
+                    .await
+                    .expect("Transfer should end")
+                    .unwrap();
+                if !matches!(event, TransferEvent::Started { .. } | TransferEvent::Progress { .. }) {
+                    return event;
+                }
+            }
+        }
+
+        // A file spanning several chunks arrives intact under its own name
+        let source = std::env::temp_dir().join(format!("syncmist-source-{}.bin", std::process::id()));
+        let content: Vec<u8> = (0..CHUNK_SIZE * 3 + 123).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&source, &content).unwrap();
+        let mut server_events = server.subscribe_transfers();
+        let mut client_events = client.subscribe_transfers();
+        let transfer_id = client
+            .send_file("server-device", source.to_string_lossy().to_string(), "application/octet-stream".to_string())
+            .await
+            .expect("Transfer should succeed");
+        let TransferEvent::Completed { transfer_id: id, peer_id, path, sha256 } = next_final(&mut server_events).await else {
+            panic!("Receiver should complete the transfer");
+        };
+        assert_eq!((id, peer_id.as_str()), (transfer_id, "client-device"));
+        assert_eq!(std::fs::read(&path).unwrap(), content);
+        assert!(path.ends_with(source.file_name().unwrap().to_str().unwrap()));
+        assert!(matches!(
+            next_final(&mut client_events).await,
+            TransferEvent::Completed { sha256: ref sent, .. } if *sent == sha256
+        ));
+
+        // Transfers over the receiver's limit are refused before any content is sent
+        let result = client
+            .send_large_data("server-device", "big.bin".to_string(), "image/png".to_string(), vec![0; 1024 * 1024 + 1])
+            .await;
+        assert!(matches!(result, Err(TransportError::Rejected(_))), "Got {:?}", result);
+        assert!(matches!(next_final(&mut server_events).await, TransferEvent::Failed { .. }));
+        assert!(matches!(next_final(&mut client_events).await, TransferEvent::Failed { .. }));
+
+        // The sender cancels a transfer whose content stalls
+        let (mut writer, reader) = tokio::io::duplex(CHUNK_SIZE);
+        writer.write_all(&[1; 1000]).await.unwrap();
+        let sending = client.send_reader("server-device", reader, "stalled.bin".to_string(), String::new(), 4096);
+        let cancelling = async {
+            let TransferEvent::Started { transfer_id, .. } = server_events.recv().await.unwrap() else {
+                panic!("Receiver should start the transfer");
+            };
+            assert!(client.cancel_transfer(transfer_id));
+        };
+        let (result, _) = tokio::join!(sending, cancelling);
+        assert!(matches!(result, Err(TransportError::Cancelled(_))), "Got {:?}", result);
+        assert!(matches!(next_final(&mut server_events).await, TransferEvent::Cancelled { by_peer: true, .. }));
+        assert!(matches!(next_final(&mut client_events).await, TransferEvent::Cancelled { by_peer: false, .. }));
+
+        // The receiver cancels one too
+        let (_writer, reader) = tokio::io::duplex(CHUNK_SIZE);
+        let sending = client.send_reader("server-device", reader, "stalled.bin".to_string(), String::new(), 4096);
+        let cancelling = async {
+            let TransferEvent::Started { transfer_id, .. } = server_events.recv().await.unwrap() else {
+                panic!("Receiver should start the transfer");
+            };
+            assert!(server.cancel_transfer(transfer_id));
+        };
+        let (result, _) = tokio::join!(sending, cancelling);
+        assert!(matches!(result, Err(TransportError::Cancelled(_))), "Got {:?}", result);
+        assert!(matches!(next_final(&mut server_events).await, TransferEvent::Cancelled { by_peer: false, .. }));
+        let leftovers: Vec<_> = std::fs::read_dir(&directory)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().extension().is_some_and(|extension| extension == "part"))
+            .collect();
+        assert!(leftovers.is_empty(), "Cancelled transfers should not leave part files");
+
+        // Content not matching its hash is refused as a hash mismatch, other violations are not
+        async fn refusal(connection: &quinn::Connection, transfer_id: u64, content: Vec<SyncMessage>) -> u16 {
+            let (mut send, mut recv) = connection.open_bi().await.unwrap();
+            let offer = SyncMessage::TransferOffer { transfer_id, name: "raw.bin".to_string(), content_type: String::new(), size: 3 };
+            write_message(&mut send, &offer, 4096).await.unwrap();
+            assert!(matches!(read_message(&mut recv, 4096).await.unwrap(), SyncMessage::TransferAccept { offset: 0, .. }));
+            for message in content {
+                write_message(&mut send, &message, 4096).await.unwrap();
+            }
+            match read_message(&mut recv, 4096).await.unwrap() {
+                SyncMessage::Error { code, .. } => code,
+                other => panic!("Receiver should refuse the transfer, got {:?}", other),
+            }
+        }
+        let connection = client.registry.connection("server-device").await.unwrap();
+        let wrong_hash = vec![
+            SyncMessage::TransferChunk { offset: 0, data: vec![1, 2, 3] },
+            SyncMessage::TransferEnd { sha256: vec![0; 32] },
+        ];
+        assert_eq!(refusal(&connection, 901, wrong_hash).await, error_code::HASH_MISMATCH);
+        assert!(matches!(next_final(&mut server_events).await, TransferEvent::Failed { .. }));
+        let skipped = vec![SyncMessage::TransferChunk { offset: 1, data: vec![2, 3] }];
+        assert_eq!(refusal(&connection, 902, skipped).await, error_code::PROTOCOL_VIOLATION);
+        assert!(matches!(next_final(&mut server_events).await, TransferEvent::Failed { .. }));
+
+        let _ = std::fs::remove_file(&source);
+        let _ = std::fs::remove_dir_all(&directory);
+        client.close().await;
+        server.close().await;
+    }
+
+    // Integration test: a transfer cut off mid-stream resumes from the acknowledged offset after reconnecting
+    #[tokio::test]
+    async fn test_interrupted_transfer_resumes() {
+        use crate::transport::transfer::ACK_INTERVAL;
+
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        /// Forward datagrams to `server` until `budget` bytes came from the client, then drop everything
+        async fn cutting_proxy(server: SocketAddr, budget: usize) -> (u16, JoinHandle<()>) {
+            let outer = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            let inner = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            inner.connect(server).await.unwrap();
+            let port = outer.local_addr().unwrap().port();
+            let task = tokio::spawn(async move {
+                let (mut client, mut forwarded) = (None, 0);
+                let (mut up, mut down) = (vec![0u8; 65536], vec![0u8; 65536]);
+                loop {
+                    tokio::select! {
+                        Ok((n, source)) = outer.recv_from(&mut up) => {
+                            client = Some(source);
+                            forwarded += n;
+                            if forwarded <= budget {
+                                let _ = inner.send(&up[..n]).await;
+                            }
+                        }
+                        Ok(n) = inner.recv(&mut down) => {
+                            if let Some(client) = client.filter(|_| forwarded <= budget) {
+                                let _ = outer.send_to(&down[..n], client).await;
+                            }
+                        }
+                    }
+                }
+            });
+            (port, task)
+        }
+
+        async fn interrupted(events: &mut broadcast::Receiver<TransferEvent>) -> u64 {
+            loop {
+                let event = tokio::time::timeout(std::time::Duration::from_secs(10), events.recv())
+                    .await
+                    .expect("Transfer should be interrupted")
+                    .unwrap();
+                match event {
+                    TransferEvent::Interrupted { transferred, .. } => return transferred,
+                    TransferEvent::Started { .. } | TransferEvent::Progress { .. } => {}
+                    other => panic!("Unexpected event {:?}", other),
+                }
+            }
+        }
+
+        let root = std::env::temp_dir().join(format!("syncmist-resume-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let options = |name: &str| TransportOptions {
+            keep_alive_interval_ms: 200,
+            idle_timeout_ms: 1_000,
+            transfer_directory: root.join(name).join("received").to_string_lossy().to_string(),
+            spool_directory: root.join(name).join("spool").to_string_lossy().to_string(),
+            ..TransportOptions::default()
+        };
+        let content: Vec<u8> = (0..ACK_INTERVAL * 4 + 5).map(|i| (i % 253) as u8).collect();
+        let source = root.join("screenshot.png");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(&source, &content).unwrap();
+
+        let mut server = QuicTransport::new(options("server"));
+        server.set_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let port = server.endpoint.as_ref().unwrap().local_addr().unwrap().port();
+        let server_addr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+        let mut client = QuicTransport::new(options("client"));
+        client.set_identity(client_identity);
+        let mut server_events = server.subscribe_transfers();
+        let mut client_events = client.subscribe_transfers();
+
+        // The path goes dead once a bit more than the first acknowledged stretch went through
+        let (proxy_port, proxy) = cutting_proxy(server_addr, ACK_INTERVAL as usize * 3 / 2).await;
+        let (_, connected) = tokio::join!(server.accept_connection(), client.connect_to_peer("127.0.0.1", proxy_port));
+        connected.unwrap();
+        let sending = client.send_file("server-device", source.to_string_lossy().to_string(), "image/png".to_string());
+        let (sent, received, acknowledged) =
+            tokio::join!(sending, interrupted(&mut server_events), interrupted(&mut client_events));
+        assert!(sent.is_err(), "Sender should see the connection go");
+        assert_eq!(received, ACK_INTERVAL, "Receiver should keep what it acknowledged");
+        assert!(acknowledged <= received);
+        proxy.abort();
+        let pending = server.get_pending_transfers();
+        assert_eq!((pending.len(), pending[0].transferred), (1, ACK_INTERVAL));
+        assert_eq!(client.get_pending_transfers().len(), 1);
+
+        // Reconnecting is enough to continue the transfer
+        let (_, connected) = tokio::join!(server.accept_connection(), client.connect_to_peer("127.0.0.1", port));
+        connected.unwrap();
+        let mut started_at = None;
+        let path = loop {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(15), server_events.recv())
+                .await
+                .expect("Transfer should resume")
+                .unwrap();
+            match event {
+                TransferEvent::Started { offset, .. } => started_at = Some(offset),
+                TransferEvent::Completed { path, .. } => break path,
+                TransferEvent::Progress { .. } => {}
+                other => panic!("Unexpected event {:?}", other),
+            }
+        };
+        assert_eq!(started_at, Some(ACK_INTERVAL), "Receiver should continue after the acknowledged bytes");
+        assert_eq!(std::fs::read(&path).unwrap(), content);
+        loop {
+            if let TransferEvent::Completed { .. } = client_events.recv().await.unwrap() {
+                break;
+            }
+        }
+        assert!(client.get_pending_transfers().is_empty(), "Completed transfer should leave the spool");
+        assert!(server.get_pending_transfers().is_empty());
+
+        // Discarding an interrupted transfer removes it from the spool
+        client.disconnect("server-device").await.unwrap();
+        let (proxy_port, proxy) = cutting_proxy(server_addr, ACK_INTERVAL as usize * 3 / 2).await;
+        let (_, connected) = tokio::join!(server.accept_connection(), client.connect_to_peer("127.0.0.1", proxy_port));
+        connected.unwrap();
+        let sending = client.send_file("server-device", source.to_string_lossy().to_string(), "image/png".to_string());
+        let (sent, _) = tokio::join!(sending, interrupted(&mut client_events));
+        assert!(sent.is_err());
+        proxy.abort();
+        let transfer_id = client.get_pending_transfers()[0].transfer_id;
+        assert!(client.cancel_transfer(transfer_id));
+        assert!(client.get_pending_transfers().is_empty());
+        assert!(!client.cancel_transfer(transfer_id));
+
+        client.close().await;
+        server.close().await;
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    // Integration test: the default dual-stack endpoint serves IPv4 and IPv6 peers
+    #[tokio::test]
+    async fn test_dual_stack_connections() {
+        // Install crypto provider for rustls 0.23+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+
+        let mut server = QuicTransport::with_identity(DeviceIdentity::generate("server-device".to_string()).unwrap());
+        server.start_server(0).await.expect("Server should start");
+        let local = server.endpoint.as_ref().unwrap().local_addr().unwrap();
+        if !local.is_ipv6() {
+            println!("IPv6 unavailable, skipping");
+            server.close().await;
+            return;
+        }
+
+        let client_identity = DeviceIdentity::generate("client-device".to_string()).unwrap();
+        server.pin_fingerprint("client-device".to_string(), client_identity.fingerprint()).unwrap();
+
+        // IPv6 literal, then IPv4 through the same IPv6 socket
+        for addr in ["::1", "[::1]", "127.0.0.1"] {
+            let mut client = QuicTransport::with_identity(client_identity.clone());
+            let (accepted, connected) = tokio::join!(
+                server.accept_connection(),
+                client.connect_to_peer(addr, local.port())
+            );
+            assert_eq!(connected.unwrap(), "server-device", "Connecting via {} should succeed", addr);
+            assert_eq!(accepted.unwrap(), "client-device");
+            client.close().await;
+        }
+
+        // A PeerInfo from mDNS races its addresses, skipping unusable ones and
+        // not waiting for an unreachable one to time out
+        let peer = PeerInfo {
+            device_id: "server-device".to_string(),
+            device_name: "Server".to_string(),
+            addresses: vec!["not-an-ip".to_string(), "fd00::dead".to_string(), "::1".to_string()],
+            port: local.port(),
+            discovered_at: 0,
+        };
+        let mut client = QuicTransport::with_identity(client_identity);
+        let started = std::time::Instant::now();
+        let (_, connected) = tokio::join!(server.accept_connection(), client.connect_to_peer_info(peer.clone()));
+        assert_eq!(connected.unwrap(), "server-device");
+        assert!(started.elapsed() < std::time::Duration::from_secs(5), "Race should not wait for the unreachable address");
+        assert!(client.get_peer_address("server-device").await.unwrap().starts_with("[::1]"));
+        let preferred = format!("[::1]:{}", local.port());
+        assert_eq!(client.get_preferred_address("server-device".to_string()), Some(preferred.clone()));
+
+        // The remembered address wins again on the next connect
+        client.disconnect("server-device").await.unwrap();
+        let (_, connected) = tokio::join!(server.accept_connection(), client.connect_to_peer_info(peer));
+        assert_eq!(connected.unwrap(), "server-device");
+        assert_eq!(client.get_preferred_address("server-device".to_string()), Some(preferred));
+
+        // An attempt that lost the race is closed before Hello, so the server
+        // never registers it over the winner
+        let dialer = client.dialer().unwrap();
+        let (failed, _) = tokio::join!(server.accept_connection(), async {
+            let address = format!("[::1]:{}", local.port()).parse().unwrap();
+            let loser = dialer.connect(address, Some("server-device".to_string())).await.unwrap();
+            close_connection(&loser, close_code::CANCELLED);
+        });
+        assert!(failed.is_err(), "Cancelled attempt should fail its Hello on the server");
+        assert_eq!(client.get_connected_peers().await, vec!["server-device".to_string()], "Winning connection should survive");
+        assert_eq!(server.get_connected_peers().await, vec!["client-device".to_string()]);
+
+        client.close().await;
         server.close().await;
-        
-        // Note: This test verifies the setup works, even if connection times out
-        println!("Integration test completed - server and client created successfully");
     }
 }
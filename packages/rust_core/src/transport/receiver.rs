@@ -0,0 +1,125 @@
+//! Inbound Message Dispatch for SyncMist
+//!
+//! Every registered connection gets its own receive task that accepts streams as
+//! they arrive and forwards clipboard items, tagged with the peer id, into a
+//! single channel shared by all peers. An idle peer never delays another.
+//! Bidirectional streams carry chunked transfers and clipboard items whose
+//! sender waits for an acknowledgement.
+
+use quinn::{Connection, RecvStream, SendStream};
+use super::delivery::receive_with_ack;
+use super::events::DisconnectReason;
+use super::peers::PeerRegistry;
+use super::protocol::{error_code, read_message, send_on_new_stream, write_message, SyncMessage};
+use super::transfer::{receive_transfer, TransferInfo};
+
+/// Number of received items buffered before receive tasks wait for the app
+pub(crate) const INCOMING_CAPACITY: usize = 256;
+
+/// A clipboard item received from a peer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedItem {
+    /// Peer the item came from (device id)
+    pub peer_id: String,
+    pub item_id: u64,
+    pub content_type: String,
+    /// Milliseconds since the Unix epoch, as set by the sender
+    pub timestamp: u64,
+    pub payload: Vec<u8>,
+    /// Whether the sender waits for [`super::quic::QuicTransport::acknowledge_item`]
+    /// or [`super::quic::QuicTransport::reject_item`]
+    pub requires_ack: bool,
+}
+
+/// Spawn the receive task for a newly registered connection
+///
+/// The task ends when the connection closes, removing the peer from the
+/// registry unless it has already been replaced by a newer connection.
+pub(crate) fn spawn_receiver(peer_id: String, connection: Connection, registry: PeerRegistry) {
+    tokio::spawn(async move {
+        let (reason, by_peer) = loop {
+            // Read each stream independently so a large or slow message doesn't hold up the next
+            let closed = tokio::select! {
+                stream = connection.accept_uni() => stream
+                    .map(|recv| tokio::spawn(handle_stream(peer_id.clone(), connection.clone(), recv, registry.clone()))),
+                stream = connection.accept_bi() => stream
+                    .map(|(send, recv)| tokio::spawn(handle_bi_stream(peer_id.clone(), send, recv, registry.clone()))),
+            };
+            if let Err(e) = closed {
+                println!("[QUIC] Connection to {} closed: {}", peer_id, e);
+                break DisconnectReason::from_connection_error(&e);
+            }
+        };
+
+        registry.remove_closed(&peer_id, &connection, reason, by_peer).await;
+    });
+}
+
+/// Read one message from a stream and dispatch it
+async fn handle_stream(
+    peer_id: String,
+    connection: Connection,
+    mut recv: RecvStream,
+    registry: PeerRegistry,
+) {
+    let message = match read_message(&mut recv, registry.max_message_size()).await {
+        Ok(message) => message,
+        Err(e) => {
+            println!("[QUIC] Bad message from {}: {}", peer_id, e);
+            return;
+        }
+    };
+
+    match message {
+        SyncMessage::ClipboardItem { item_id, content_type, timestamp, payload } => {
+            println!("[QUIC] Received {} bytes from {}", payload.len(), peer_id);
+            let item = ReceivedItem { peer_id, item_id, content_type, timestamp, payload, requires_ack: false };
+            // Fails only once the transport has been dropped
+            let _ = registry.incoming().send(item).await;
+        }
+        SyncMessage::Ping { nonce } => {
+            let pong = SyncMessage::Pong { nonce };
+            if let Err(e) = send_on_new_stream(&connection, &pong, registry.max_message_size()).await {
+                println!("[QUIC] Failed to answer ping from {}: {}", peer_id, e);
+            }
+        }
+        other => {
+            println!("[QUIC] Ignoring {} from {}", other.kind(), peer_id);
+        }
+    }
+}
+
+/// Read the first message of a bidirectional stream and hand the stream to its handler
+async fn handle_bi_stream(peer_id: String, mut send: SendStream, mut recv: RecvStream, registry: PeerRegistry) {
+    let message = match read_message(&mut recv, registry.max_message_size()).await {
+        Ok(message) => message,
+        Err(e) => {
+            println!("[QUIC] Bad message from {}: {}", peer_id, e);
+            return;
+        }
+    };
+
+    match message {
+        SyncMessage::TransferOffer { transfer_id, name, content_type, size } => {
+            let offer = TransferInfo { name, content_type, size };
+            let transfers = registry.transfers().clone();
+            receive_transfer(peer_id, send, recv, transfers, offer, transfer_id).await;
+        }
+        SyncMessage::ClipboardItem { item_id, content_type, timestamp, payload } => {
+            println!("[QUIC] Received {} bytes from {}, awaiting acknowledgement", payload.len(), peer_id);
+            let item = ReceivedItem { peer_id: peer_id.clone(), item_id, content_type, timestamp, payload, requires_ack: true };
+            let acks = registry.acks().clone();
+            receive_with_ack(peer_id, send, item, registry.incoming(), acks, registry.max_message_size()).await;
+        }
+        other => {
+            println!("[QUIC] Refusing {} stream from {}", other.kind(), peer_id);
+            let refusal = SyncMessage::Error {
+                code: error_code::UNSUPPORTED,
+                message: format!("{} is not expected on a bidirectional stream", other.kind()),
+            };
+            let _ = write_message(&mut send, &refusal, registry.max_message_size()).await;
+            let _ = send.finish();
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! Relay for SyncMist
+//!
+//! Some NATs, symmetric ones and carrier-grade NAT in particular, can't be
+//! punched through. Devices behind them still sync through a relay: both join
+//! a session on the relay server named after the pair of device ids, and the
+//! relay forwards each datagram one of them sends to the other. QUIC runs end
+//! to end over the relayed path, so the relay only ever sees QUIC packets,
+//! encrypted with keys it doesn't have.
+//!
+//! Quinn addresses a relayed peer by a virtual address in the reserved
+//! `240.0.0.0/4` range; the [shared socket](super::socket) wraps packets for it
+//! in relay frames and unwraps the relay's frames back.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use sha2::{Digest, Sha256};
+use tokio::net::UdpSocket;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::events::wait_for_peer;
+use super::quic::{Dialer, TransportError};
+use super::socket::RawSocket;
+
+/// First bytes of every relay frame, below `0x40` so it isn't taken for QUIC
+const RELAY_MAGIC: &[u8; 4] = b"\x00SMY";
+/// Magic, kind and session id
+const RELAY_HEADER_SIZE: usize = RELAY_MAGIC.len() + 1 + 16;
+
+/// Device to relay: join a session, repeated to stay in it
+const JOIN: u8 = 0;
+/// Relay to device: the session's member count after a join
+const JOINED: u8 = 1;
+/// Either way: a datagram for the other member
+pub(crate) const DATA: u8 = 2;
+/// Relay to device: both members are active, so the join was refused
+const FULL: u8 = 3;
+
+/// Time between join requests while waiting for the peer
+const JOIN_INTERVAL: Duration = Duration::from_millis(250);
+/// Time allowed for both devices to join and complete the handshake
+pub(crate) const RELAY_TIMEOUT: Duration = Duration::from_secs(15);
+/// Members the relay forgets without traffic
+const SESSION_TTL: Duration = Duration::from_secs(60);
+/// Members heard from within this window can't be displaced by a join,
+/// longer than the keep-alive interval of a relayed connection
+const ACTIVE_WINDOW: Duration = Duration::from_secs(30);
+
+/// Name of the relay session shared by two devices
+pub(crate) fn session_id(a: &str, b: &str) -> [u8; 16] {
+    let (first, second) = if a < b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update(b"syncmist relay\0");
+    hasher.update(first.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(second.as_bytes());
+    hasher.finalize()[..16].try_into().unwrap()
+}
+
+/// Address quinn uses for the peer at the other end of a session
+pub(crate) fn virtual_address(session: &[u8; 16]) -> SocketAddr {
+    let ip = Ipv4Addr::new(240, session[0], session[1], session[2]);
+    let port = u16::from_be_bytes([session[3], session[4]]).max(1);
+    SocketAddr::new(IpAddr::V4(ip), port)
+}
+
+/// Whether a peer address is a relay session rather than a direct path
+pub(crate) fn is_relayed(address: SocketAddr) -> bool {
+    matches!(address.ip().to_canonical(), IpAddr::V4(v4) if v4.octets()[0] == 240)
+}
+
+pub(crate) fn encode(kind: u8, session: &[u8; 16], payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(RELAY_HEADER_SIZE + payload.len());
+    out.extend_from_slice(RELAY_MAGIC);
+    out.push(kind);
+    out.extend_from_slice(session);
+    out.extend_from_slice(payload);
+    out
+}
+
+/// Kind, session and payload of a relay frame, `None` for anything else
+pub(crate) fn decode(datagram: &[u8]) -> Option<(u8, [u8; 16], &[u8])> {
+    let rest = datagram.strip_prefix(RELAY_MAGIC)?;
+    let (&kind, rest) = rest.split_first()?;
+    let session = rest.get(..16)?.try_into().ok()?;
+    Some((kind, session, &rest[16..]))
+}
+
+/// Join the session for `peer_id` on the relay and connect to the peer through it
+///
+/// The peer must do the same. As with hole punching, the device with the
+/// smaller id dials and the other waits for it.
+pub(crate) async fn connect(
+    dialer: &Dialer,
+    raw: &RawSocket,
+    server: SocketAddr,
+    peer_id: &str,
+) -> Result<String, TransportError> {
+    let local_id = dialer.device_id();
+    let session = session_id(&local_id, peer_id);
+    let address = raw.add_route(server, session);
+    let deadline = tokio::time::Instant::now() + RELAY_TIMEOUT;
+    println!("[QUIC] Relaying to {} through {}", peer_id, server);
+
+    let mut events = dialer.registry().subscribe();
+    let result = tokio::time::timeout_at(deadline, async {
+        let peer_joined = join(raw, server, &session).await?;
+        if local_id.as_str() < peer_id {
+            // Packets sent before the peer joins would be dropped
+            if !peer_joined {
+                while !join(raw, server, &session).await? {
+                    tokio::time::sleep(JOIN_INTERVAL).await;
+                }
+            }
+            dialer.dial(address, Some(peer_id.to_string())).await
+        } else if wait_for_peer(&mut events, peer_id, false).await {
+            Ok(peer_id.to_string())
+        } else {
+            Err(TransportError::NotConnected)
+        }
+    });
+    result
+        .await
+        .map_err(|_| TransportError::Connection(format!("Relayed connection to {} timed out", peer_id)))?
+}
+
+/// Join a session, returning whether the other device is already in it
+async fn join(raw: &RawSocket, server: SocketAddr, session: &[u8; 16]) -> Result<bool, TransportError> {
+    let mut received = raw.subscribe();
+    let request = encode(JOIN, session, &[]);
+    loop {
+        raw.send_to(&request, server).await?;
+        let answer = tokio::time::timeout(JOIN_INTERVAL, async {
+            loop {
+                match received.recv().await {
+                    Ok((_, datagram)) => match decode(&datagram) {
+                        Some((JOINED, joined, members)) if joined == *session => {
+                            return members.first().map(|members| (JOINED, *members))
+                        }
+                        Some((FULL, full, _)) if full == *session => return Some((FULL, 0)),
+                        _ => continue,
+                    },
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        match answer.await {
+            Ok(Some((JOINED, members))) => return Ok(members >= 2),
+            // Two other addresses are active in the session; keep asking in case one was ours before a NAT rebinding
+            Ok(Some(_)) => tokio::time::sleep(JOIN_INTERVAL).await,
+            Ok(None) => return Err(TransportError::NotConnected),
+            Err(_) => continue,
+        }
+    }
+}
+
+/// A relay server, run by the `syncmist-relay` binary and in tests
+#[flutter_rust_bridge::frb(ignore)]
+pub struct RelayServer {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl RelayServer {
+    /// Start the relay on a UDP address such as `0.0.0.0:3479`
+    pub async fn bind(address: &str) -> Result<Self, TransportError> {
+        let socket = UdpSocket::bind(address)
+            .await
+            .map_err(|e| TransportError::Io(format!("Failed to bind relay {}: {}", address, e)))?;
+        let address = socket.local_addr().map_err(|e| TransportError::Io(e.to_string()))?;
+        println!("[QUIC] Relay listening on {}", address);
+        Ok(Self { address, task: tokio::spawn(serve(socket)) })
+    }
+
+    /// Address the relay listens on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+}
+
+impl Drop for RelayServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve(socket: UdpSocket) {
+    // Members of each session with when they were last heard from
+    let mut sessions: HashMap<[u8; 16], Vec<(SocketAddr, Instant)>> = HashMap::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let Ok((len, from)) = socket.recv_from(&mut buf).await else { continue };
+        let Some((kind, session, _)) = decode(&buf[..len]) else { continue };
+
+        match kind {
+            JOIN => {
+                let members = sessions.entry(session).or_default();
+                members.retain(|(address, seen)| *address != from && seen.elapsed() < SESSION_TTL);
+                members.sort_by_key(|(_, seen)| *seen);
+                // A session is for two devices; a rejoining device replaces its stale address.
+                // Anyone who knows both device ids can name the session, so an active member stays.
+                let answer = if members.len() >= 2 && members[0].1.elapsed() < ACTIVE_WINDOW {
+                    encode(FULL, &session, &[])
+                } else {
+                    if members.len() >= 2 {
+                        members.remove(0);
+                    }
+                    members.push((from, Instant::now()));
+                    encode(JOINED, &session, &[members.len() as u8])
+                };
+                let _ = socket.send_to(&answer, from).await;
+            }
+            DATA => {
+                let Some(members) = sessions.get_mut(&session) else { continue };
+                let Some(sender) = members.iter_mut().find(|(address, _)| *address == from) else { continue };
+                sender.1 = Instant::now();
+                // Forwarded as is: the frame names the session for the receiver
+                for (address, _) in members.iter().filter(|(address, _)| *address != from) {
+                    let _ = socket.send_to(&buf[..len], address).await;
+                }
+            }
+            _ => {}
+        }
+        sessions.retain(|_, members| members.iter().any(|(_, seen)| seen.elapsed() < SESSION_TTL));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sessions_and_addresses() {
+        let session = session_id("laptop", "phone");
+        assert_eq!(session, session_id("phone", "laptop"), "Both devices name the same session");
+        assert_ne!(session, session_id("laptop", "tablet"));
+
+        let address = virtual_address(&session);
+        assert!(is_relayed(address));
+        assert!(is_relayed("[::ffff:240.1.2.3]:9".parse().unwrap()), "Mapped by a dual-stack socket");
+        assert!(!is_relayed("203.0.113.7:4000".parse().unwrap()));
+
+        let frame = encode(DATA, &session, b"packet");
+        assert_eq!(decode(&frame), Some((DATA, session, &b"packet"[..])));
+        assert_eq!(decode(&frame[..10]), None);
+    }
+
+    #[tokio::test]
+    async fn test_join_keeps_active_members() {
+        let relay = RelayServer::bind("127.0.0.1:0").await.unwrap();
+        let server = relay.local_addr();
+        let session = session_id("laptop", "phone");
+        let join = |socket: UdpSocket| async move {
+            socket.send_to(&encode(JOIN, &session, &[]), server).await.unwrap();
+            let mut buf = [0u8; 64];
+            let len = socket.recv(&mut buf).await.unwrap();
+            let (kind, _, members) = decode(&buf[..len]).unwrap();
+            (kind, members.to_vec(), socket)
+        };
+        let bind = || async { UdpSocket::bind("127.0.0.1:0").await.unwrap() };
+
+        let (kind, members, laptop) = join(bind().await).await;
+        assert_eq!((kind, members), (JOINED, vec![1]));
+        let (kind, members, _phone) = join(bind().await).await;
+        assert_eq!((kind, members), (JOINED, vec![2]));
+
+        let (kind, _, _) = join(bind().await).await;
+        assert_eq!(kind, FULL, "A third address can't displace active members");
+        let (kind, members, _) = join(laptop).await;
+        assert_eq!((kind, members), (JOINED, vec![2]), "A member can rejoin from its own address");
+    }
+}
@@ -0,0 +1,439 @@
+//! Rendezvous for SyncMist
+//!
+//! A rendezvous service introduces devices that can't reach each other
+//! directly. Devices register from their QUIC socket, so the service sees the
+//! public address their NAT maps it to, and tell it their local addresses. When
+//! one asks to connect to another, the service sends each of them the other's
+//! addresses and both start [hole punching](super::holepunch).
+//!
+//! The service only relays addresses. It is not trusted: the QUIC handshake
+//! that follows still requires each device's pinned certificate.
+//!
+//! When punching fails and a relay is configured, devices fall back to a
+//! [relayed](super::relay) connection. The device that dialed it keeps asking
+//! the service for introductions, and a punched connection replaces the
+//! relayed one as soon as there is a direct path.
+
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::net::UdpSocket;
+use tokio::sync::{broadcast, oneshot};
+use tokio::task::JoinHandle;
+
+use super::holepunch::{punch, PUNCH_TIMEOUT};
+use super::protocol::{put_bytes, ProtocolError, Reader};
+use super::quic::{Dialer, TransportError};
+use super::relay::{self, is_relayed, RELAY_TIMEOUT};
+use super::socket::RawSocket;
+
+/// First bytes of every rendezvous packet, below `0x40` so it isn't taken for QUIC
+const RENDEZVOUS_MAGIC: &[u8; 4] = b"\x00SMR";
+
+/// Time between retransmissions of an unanswered request
+const RETRY_INTERVAL: Duration = Duration::from_millis(500);
+/// Requests sent before giving up on the service
+const MAX_ATTEMPTS: u32 = 10;
+/// Time between registrations, keeping the NAT mapping and the registration alive
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(15);
+/// Registrations the service keeps without a refresh
+const REGISTRATION_TTL: Duration = Duration::from_secs(60);
+
+/// A rendezvous packet
+#[derive(Clone, Debug, PartialEq)]
+enum Packet {
+    /// Device to service: announce the device and its local addresses
+    Register { device_id: String, candidates: Vec<SocketAddr> },
+    /// Service to device: the address the registration came from
+    Registered { address: SocketAddr },
+    /// Device to service: ask to be introduced to `target`
+    Connect { device_id: String, target: String },
+    /// Service to device: start punching to `peer_id` at `candidates`
+    Introduce { peer_id: String, candidates: Vec<SocketAddr> },
+    /// Service to device: `target` is not registered
+    Unknown { target: String },
+}
+
+impl Packet {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = RENDEZVOUS_MAGIC.to_vec();
+        match self {
+            Packet::Register { device_id, candidates } => {
+                out.push(0);
+                put_bytes(&mut out, device_id.as_bytes());
+                put_addresses(&mut out, candidates);
+            }
+            Packet::Registered { address } => {
+                out.push(1);
+                put_addresses(&mut out, std::slice::from_ref(address));
+            }
+            Packet::Connect { device_id, target } => {
+                out.push(2);
+                put_bytes(&mut out, device_id.as_bytes());
+                put_bytes(&mut out, target.as_bytes());
+            }
+            Packet::Introduce { peer_id, candidates } => {
+                out.push(3);
+                put_bytes(&mut out, peer_id.as_bytes());
+                put_addresses(&mut out, candidates);
+            }
+            Packet::Unknown { target } => {
+                out.push(4);
+                put_bytes(&mut out, target.as_bytes());
+            }
+        }
+        out
+    }
+
+    /// Decode a packet, `None` for anything that isn't one
+    fn decode(datagram: &[u8]) -> Option<Self> {
+        let mut r = Reader::new(datagram.strip_prefix(RENDEZVOUS_MAGIC)?);
+        let packet = Self::decode_body(&mut r).ok()?;
+        r.is_empty().then_some(packet)
+    }
+
+    fn decode_body(r: &mut Reader) -> Result<Self, ProtocolError> {
+        Ok(match r.u8()? {
+            0 => Packet::Register { device_id: r.string()?, candidates: addresses(r)? },
+            1 => Packet::Registered {
+                address: addresses(r)?.pop().ok_or(ProtocolError::Truncated)?,
+            },
+            2 => Packet::Connect { device_id: r.string()?, target: r.string()? },
+            3 => Packet::Introduce { peer_id: r.string()?, candidates: addresses(r)? },
+            4 => Packet::Unknown { target: r.string()? },
+            other => return Err(ProtocolError::UnknownMessageType(other)),
+        })
+    }
+}
+
+fn put_addresses(out: &mut Vec<u8>, addresses: &[SocketAddr]) {
+    out.push(addresses.len().min(u8::MAX as usize) as u8);
+    for address in addresses.iter().take(u8::MAX as usize) {
+        put_bytes(out, address.to_string().as_bytes());
+    }
+}
+
+fn addresses(r: &mut Reader) -> Result<Vec<SocketAddr>, ProtocolError> {
+    let count = r.u8()?;
+    (0..count)
+        .map(|_| {
+            let address = r.string()?;
+            address.parse().map_err(|_| ProtocolError::UnexpectedMessage(format!("bad address {}", address)))
+        })
+        .collect()
+}
+
+/// Address with IPv4-mapped IPv6 addresses turned back into IPv4
+fn canonical(address: SocketAddr) -> SocketAddr {
+    SocketAddr::new(address.ip().to_canonical(), address.port())
+}
+
+/// Addresses of this machine's interfaces with the endpoint's port
+///
+/// Loopback and link-local addresses are left out; peers on the same link
+/// find each other through mDNS instead.
+pub(crate) fn local_candidates(local: SocketAddr) -> Vec<SocketAddr> {
+    let interfaces = if_addrs::get_if_addrs().unwrap_or_default();
+    interfaces
+        .into_iter()
+        .map(|interface| interface.ip())
+        .filter(|ip| !ip.is_loopback() && !ip.is_unspecified())
+        .filter(|ip| match ip {
+            IpAddr::V4(v4) => !v4.is_link_local(),
+            IpAddr::V6(v6) => local.is_ipv6() && (v6.segments()[0] & 0xffc0) != 0xfe80,
+        })
+        .map(|ip| SocketAddr::new(ip, local.port()))
+        .collect()
+}
+
+type Waiter = oneshot::Sender<Result<String, TransportError>>;
+
+/// Registration with a rendezvous service, shared with its background task
+#[derive(Clone)]
+pub(crate) struct Rendezvous {
+    server: SocketAddr,
+    raw: RawSocket,
+    dialer: Dialer,
+    /// Pending [`Rendezvous::connect`] calls by device id
+    waiting: Arc<Mutex<HashMap<String, Waiter>>>,
+    /// Devices being punched to, so repeated introductions are ignored
+    punching: Arc<Mutex<HashSet<String>>>,
+    /// Relay to fall back to when punching fails, shared with the transport
+    relay: Arc<Mutex<Option<SocketAddr>>>,
+    /// Time between attempts to replace relayed connections, `None` to keep them
+    upgrade_interval: Option<Duration>,
+    /// Relayed devices we are trying to reach directly
+    upgrading: Arc<Mutex<HashSet<String>>>,
+}
+
+impl Rendezvous {
+    /// Register with the service at `server`, returning the public address it saw
+    pub(crate) async fn register(
+        server: SocketAddr,
+        raw: RawSocket,
+        dialer: Dialer,
+        relay: Arc<Mutex<Option<SocketAddr>>>,
+        upgrade_interval: Option<Duration>,
+    ) -> Result<(Self, SocketAddr), TransportError> {
+        let rendezvous = Self {
+            server,
+            raw,
+            dialer,
+            waiting: Arc::new(Mutex::new(HashMap::new())),
+            punching: Arc::new(Mutex::new(HashSet::new())),
+            relay,
+            upgrade_interval,
+            upgrading: Arc::new(Mutex::new(HashSet::new())),
+        };
+        let mut received = rendezvous.raw.subscribe();
+        let register = rendezvous.register_packet()?;
+        for _ in 0..MAX_ATTEMPTS {
+            rendezvous.send(&register).await?;
+            let answer = tokio::time::timeout(RETRY_INTERVAL, async {
+                loop {
+                    match received.recv().await {
+                        Ok((from, datagram)) if canonical(from) == canonical(server) => {
+                            if let Some(Packet::Registered { address }) = Packet::decode(&datagram) {
+                                return Some(address);
+                            }
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            });
+            if let Ok(Some(address)) = answer.await {
+                println!("[QUIC] Registered with rendezvous {} as {}", server, address);
+                return Ok((rendezvous, address));
+            }
+        }
+        Err(TransportError::Connection(format!("Rendezvous {} did not answer", server)))
+    }
+
+    fn register_packet(&self) -> Result<Packet, TransportError> {
+        Ok(Packet::Register {
+            device_id: self.dialer.device_id(),
+            candidates: local_candidates(self.raw.local_addr()?),
+        })
+    }
+
+    async fn send(&self, packet: &Packet) -> Result<(), TransportError> {
+        self.raw.send_to(&packet.encode(), self.server).await
+    }
+
+    /// Keep the registration alive and punch to devices the service introduces
+    pub(crate) fn spawn(&self) -> JoinHandle<()> {
+        let rendezvous = self.clone();
+        let mut received = self.raw.subscribe();
+        tokio::spawn(async move {
+            let mut keepalive = tokio::time::interval(KEEPALIVE_INTERVAL);
+            keepalive.tick().await;
+            loop {
+                tokio::select! {
+                    _ = keepalive.tick() => {
+                        if let Ok(register) = rendezvous.register_packet() {
+                            let _ = rendezvous.send(&register).await;
+                        }
+                    }
+                    datagram = received.recv() => match datagram {
+                        Ok((from, datagram)) if canonical(from) == canonical(rendezvous.server) => {
+                            match Packet::decode(&datagram) {
+                                Some(Packet::Introduce { peer_id, candidates }) => rendezvous.introduced(peer_id, candidates),
+                                Some(Packet::Unknown { target }) => rendezvous.finish(&target, Err(TransportError::PeerNotFound(target.clone()))),
+                                _ => {}
+                            }
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                }
+            }
+        })
+    }
+
+    fn introduced(&self, peer_id: String, candidates: Vec<SocketAddr>) {
+        if !self.punching.lock().unwrap().insert(peer_id.clone()) {
+            return;
+        }
+        println!("[QUIC] Rendezvous introduced {} at {:?}", peer_id, candidates);
+        let rendezvous = self.clone();
+        tokio::spawn(async move {
+            let mut result = punch(&rendezvous.dialer, &rendezvous.raw, &peer_id, &candidates).await;
+            if let Err(e) = &result {
+                println!("[QUIC] Hole punching to {} failed: {}", peer_id, e);
+                result = rendezvous.fall_back_to_relay(&peer_id).await.unwrap_or(result);
+            }
+            rendezvous.punching.lock().unwrap().remove(&peer_id);
+            rendezvous.finish(&peer_id, result);
+        });
+    }
+
+    /// Connect through the relay after punching failed, `None` if there is
+    /// no relay or the peer is already connected through it
+    async fn fall_back_to_relay(&self, peer_id: &str) -> Option<Result<String, TransportError>> {
+        let server = (*self.relay.lock().unwrap())?;
+        if self.dialer.registry().connection(peer_id).await.is_some() {
+            return None;
+        }
+        let result = relay::connect(&self.dialer, &self.raw, server, peer_id).await;
+        if result.is_ok() {
+            self.keep_upgrading(peer_id.to_string());
+        }
+        Some(result)
+    }
+
+    /// Keep trying to replace a relayed connection with a punched one
+    ///
+    /// Only the device that dials asks, so the two don't start competing
+    /// introductions. Stops once the connection is direct or gone.
+    pub(crate) fn keep_upgrading(&self, peer_id: String) {
+        let Some(interval) = self.upgrade_interval else { return };
+        if self.dialer.device_id() > peer_id || !self.upgrading.lock().unwrap().insert(peer_id.clone()) {
+            return;
+        }
+        let rendezvous = self.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let Some(connection) = rendezvous.dialer.registry().connection(&peer_id).await else { break };
+                if !is_relayed(connection.remote_address()) {
+                    break;
+                }
+                match rendezvous.connect(&peer_id).await {
+                    Ok(_) => println!("[QUIC] Connection to {} upgraded to a direct path", peer_id),
+                    Err(e) => println!("[QUIC] Connection to {} stays relayed: {}", peer_id, e),
+                }
+            }
+            rendezvous.upgrading.lock().unwrap().remove(&peer_id);
+        });
+    }
+
+    fn finish(&self, peer_id: &str, result: Result<String, TransportError>) {
+        if let Some(waiter) = self.waiting.lock().unwrap().remove(peer_id) {
+            let _ = waiter.send(result);
+        }
+    }
+
+    /// Ask the service to introduce us to `device_id` and punch a connection to it
+    pub(crate) async fn connect(&self, device_id: &str) -> Result<String, TransportError> {
+        let (waiter, result) = oneshot::channel();
+        self.waiting.lock().unwrap().insert(device_id.to_string(), waiter);
+        let connect = Packet::Connect { device_id: self.dialer.device_id(), target: device_id.to_string() };
+
+        let outcome = tokio::time::timeout(PUNCH_TIMEOUT + RELAY_TIMEOUT + RETRY_INTERVAL * MAX_ATTEMPTS, async {
+            tokio::pin!(result);
+            for _ in 0..MAX_ATTEMPTS {
+                // Stop asking once the service answered and punching started
+                if self.punching.lock().unwrap().contains(device_id) {
+                    break;
+                }
+                self.send(&connect).await?;
+                if let Ok(outcome) = tokio::time::timeout(RETRY_INTERVAL, &mut result).await {
+                    return outcome.map_err(|_| TransportError::NotConnected)?;
+                }
+            }
+            result.await.map_err(|_| TransportError::NotConnected)?
+        })
+        .await;
+
+        self.waiting.lock().unwrap().remove(device_id);
+        outcome.map_err(|_| TransportError::Connection(format!("Rendezvous with {} timed out", device_id)))?
+    }
+}
+
+/// A minimal rendezvous service, for tests and self-hosting
+#[flutter_rust_bridge::frb(ignore)]
+pub struct RendezvousServer {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+/// A device registered with the service
+struct Registration {
+    address: SocketAddr,
+    candidates: Vec<SocketAddr>,
+    refreshed: Instant,
+}
+
+impl RendezvousServer {
+    /// Start the service on a UDP address such as `0.0.0.0:3478`
+    pub async fn bind(address: &str) -> Result<Self, TransportError> {
+        let socket = UdpSocket::bind(address)
+            .await
+            .map_err(|e| TransportError::Io(format!("Failed to bind rendezvous {}: {}", address, e)))?;
+        let address = socket.local_addr().map_err(|e| TransportError::Io(e.to_string()))?;
+        println!("[QUIC] Rendezvous listening on {}", address);
+        Ok(Self { address, task: tokio::spawn(serve(socket)) })
+    }
+
+    /// Address the service listens on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+}
+
+impl Drop for RendezvousServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve(socket: UdpSocket) {
+    let mut registrations: HashMap<String, Registration> = HashMap::new();
+    let mut buf = vec![0u8; 2048];
+    loop {
+        let Ok((len, from)) = socket.recv_from(&mut buf).await else { continue };
+        let Some(packet) = Packet::decode(&buf[..len]) else { continue };
+        registrations.retain(|_, registration| registration.refreshed.elapsed() < REGISTRATION_TTL);
+
+        let replies = match packet {
+            Packet::Register { device_id, candidates } => {
+                let address = canonical(from);
+                registrations.insert(device_id, Registration { address, candidates, refreshed: Instant::now() });
+                vec![(from, Packet::Registered { address })]
+            }
+            Packet::Connect { device_id, target } => match (registrations.get(&device_id), registrations.get(&target)) {
+                (Some(requester), Some(peer)) => {
+                    println!("[QUIC] Rendezvous introducing {} and {}", device_id, target);
+                    let candidates = |registration: &Registration| {
+                        std::iter::once(registration.address).chain(registration.candidates.iter().copied()).collect()
+                    };
+                    vec![
+                        (peer.address, Packet::Introduce { peer_id: device_id, candidates: candidates(requester) }),
+                        (from, Packet::Introduce { peer_id: target, candidates: candidates(peer) }),
+                    ]
+                }
+                _ => vec![(from, Packet::Unknown { target })],
+            },
+            _ => Vec::new(),
+        };
+        for (to, reply) in replies {
+            let _ = socket.send_to(&reply.encode(), to).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packet_roundtrip() {
+        let packets = [
+            Packet::Register {
+                device_id: "laptop".to_string(),
+                candidates: vec!["192.168.1.5:4000".parse().unwrap(), "[2001:db8::5]:4000".parse().unwrap()],
+            },
+            Packet::Registered { address: "203.0.113.7:61000".parse().unwrap() },
+            Packet::Connect { device_id: "laptop".to_string(), target: "phone".to_string() },
+            Packet::Introduce { peer_id: "phone".to_string(), candidates: Vec::new() },
+            Packet::Unknown { target: "phone".to_string() },
+        ];
+        for packet in packets {
+            assert_eq!(Packet::decode(&packet.encode()), Some(packet));
+        }
+        assert_eq!(Packet::decode(b"\x00SMR\x09"), None);
+    }
+}
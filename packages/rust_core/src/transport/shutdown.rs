@@ -0,0 +1,110 @@
+//! Graceful Shutdown for SyncMist
+//!
+//! Closing a QUIC connection discards whatever has not been sent yet. Sends
+//! therefore hold a [`DrainGuard`] until the peer has acknowledged their stream,
+//! and a graceful shutdown refuses new sends, then waits for the outstanding
+//! guards before closing connections.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use quinn::SendStream;
+use tokio::sync::watch;
+
+use super::quic::TransportError;
+
+/// Sends in flight, and whether new ones are still accepted
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone)]
+pub(crate) struct Drain {
+    closing: Arc<AtomicBool>,
+    pending: Arc<watch::Sender<usize>>,
+}
+
+impl Default for Drain {
+    fn default() -> Self {
+        Self {
+            closing: Arc::new(AtomicBool::new(false)),
+            pending: Arc::new(watch::Sender::new(0)),
+        }
+    }
+}
+
+impl Drain {
+    /// Register a send, refused once shutdown has started
+    pub(crate) fn begin(&self) -> Result<DrainGuard, TransportError> {
+        if self.closing.load(Ordering::SeqCst) {
+            return Err(TransportError::Connection("Transport is shutting down".to_string()));
+        }
+        self.pending.send_modify(|pending| *pending += 1);
+        Ok(DrainGuard { pending: self.pending.clone() })
+    }
+
+    /// Refuse new sends from now on
+    pub(crate) fn close(&self) {
+        self.closing.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether new sends are refused
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closing.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn pending(&self) -> usize {
+        *self.pending.borrow()
+    }
+
+    /// Wait until no sends are in flight, returning `false` if `timeout` passed first
+    pub(crate) async fn wait_idle(&self, timeout: Duration) -> bool {
+        let mut pending = self.pending.subscribe();
+        let idle = tokio::time::timeout(timeout, pending.wait_for(|pending| *pending == 0)).await;
+        idle.is_ok()
+    }
+}
+
+/// Keeps a send counted as in flight until dropped
+pub(crate) struct DrainGuard {
+    pending: Arc<watch::Sender<usize>>,
+}
+
+impl DrainGuard {
+    /// Stay in flight until the peer has acknowledged all data on a finished stream
+    pub(crate) fn release_when_acknowledged(self, send: SendStream) {
+        tokio::spawn(async move {
+            let _ = send.stopped().await;
+            drop(self);
+        });
+    }
+}
+
+impl Drop for DrainGuard {
+    fn drop(&mut self) {
+        self.pending.send_modify(|pending| *pending -= 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_wait_for_guards() {
+        let drain = Drain::default();
+        assert!(drain.wait_idle(Duration::ZERO).await, "Nothing in flight");
+
+        let guard = drain.begin().unwrap();
+        drain.close();
+        assert!(drain.is_closed());
+        assert!(drain.begin().is_err(), "New sends are refused once closing");
+        assert_eq!(drain.pending(), 1);
+        assert!(!drain.wait_idle(Duration::from_millis(20)).await);
+
+        let waiting = tokio::spawn({
+            let drain = drain.clone();
+            async move { drain.wait_idle(Duration::from_secs(5)).await }
+        });
+        drop(guard);
+        assert!(waiting.await.unwrap());
+    }
+}
@@ -0,0 +1,306 @@
+//! Shared UDP Socket for SyncMist
+//!
+//! NAT traversal only works if everything leaves from the port QUIC uses: the
+//! mapping a rendezvous server observes, and the pinholes opened by probes,
+//! belong to that one socket. [`SharedSocket`] wraps the endpoint's socket and
+//! tells packets apart by their first byte, as in RFC 7983: QUIC always sets
+//! one of the top two bits (we never grease the fixed bit), while STUN and our
+//! own probe and rendezvous packets start below `0x40`. QUIC packets go to
+//! quinn; the rest are published to [`RawSocket`] subscribers.
+//!
+//! The socket also carries QUIC through [relay](super::relay) sessions: packets
+//! quinn sends to a session's virtual address are wrapped and sent to the
+//! relay, and the relay's data frames are unwrapped for quinn as if they came
+//! from that address.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io::{self, IoSliceMut};
+use std::net::{SocketAddr, SocketAddrV6};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{ready, Context, Poll};
+
+use quinn::udp::{RecvMeta, Transmit};
+use quinn::{AsyncUdpSocket, Endpoint, EndpointConfig, Runtime, ServerConfig, UdpPoller};
+use tokio::sync::broadcast;
+
+use super::quic::TransportError;
+use super::relay;
+
+/// Non-QUIC datagrams buffered per subscriber
+const RAW_CAPACITY: usize = 256;
+/// Unwrapped relay packets held for quinn before the oldest are dropped
+const RELAYED_CAPACITY: usize = 256;
+
+/// Whether a datagram is QUIC rather than STUN or one of our own packets
+pub(crate) fn is_quic(datagram: &[u8]) -> bool {
+    datagram.first().is_some_and(|first| first & 0xc0 != 0)
+}
+
+/// Address in the form a socket bound to `local` sends to and receives from
+///
+/// A dual-stack socket only takes IPv6 destinations, and reports IPv4 peers as
+/// IPv4-mapped addresses.
+fn for_socket(address: SocketAddr, local: SocketAddr) -> SocketAddr {
+    match address {
+        SocketAddr::V4(v4) if local.is_ipv6() => {
+            SocketAddr::V6(SocketAddrV6::new(v4.ip().to_ipv6_mapped(), v4.port(), 0, 0))
+        }
+        address => address,
+    }
+}
+
+fn canonical(address: SocketAddr) -> SocketAddr {
+    SocketAddr::new(address.ip().to_canonical(), address.port())
+}
+
+/// A relay session QUIC is tunnelled through
+#[derive(Clone, Copy)]
+struct Route {
+    server: SocketAddr,
+    session: [u8; 16],
+}
+
+/// Relay sessions by the virtual address quinn knows the peer by
+type Routes = Arc<Mutex<HashMap<SocketAddr, Route>>>;
+
+/// Sends and receives non-QUIC datagrams on the endpoint's socket
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone)]
+pub(crate) struct RawSocket {
+    /// Socket the endpoint currently uses, replaced on rebind
+    current: Arc<Mutex<Option<Arc<dyn AsyncUdpSocket>>>>,
+    received: broadcast::Sender<(SocketAddr, Vec<u8>)>,
+    routes: Routes,
+}
+
+impl Default for RawSocket {
+    fn default() -> Self {
+        let (received, _) = broadcast::channel(RAW_CAPACITY);
+        Self { current: Arc::new(Mutex::new(None)), received, routes: Arc::new(Mutex::new(HashMap::new())) }
+    }
+}
+
+impl RawSocket {
+    /// Create an endpoint on `socket` whose non-QUIC datagrams go to this handle
+    pub(crate) fn endpoint(&self, server_config: ServerConfig, socket: std::net::UdpSocket) -> io::Result<Endpoint> {
+        let runtime = quinn::default_runtime()
+            .ok_or_else(|| io::Error::other("no async runtime found"))?;
+        let shared = self.wrap(runtime.as_ref(), socket)?;
+        let mut config = EndpointConfig::default();
+        // Greased packets may clear the fixed bit and look like ours
+        config.grease_quic_bit(false);
+        Endpoint::new_with_abstract_socket(config, Some(server_config), shared, runtime)
+    }
+
+    /// Move an endpoint created by [`RawSocket::endpoint`] to another socket
+    pub(crate) fn rebind(&self, endpoint: &Endpoint, socket: std::net::UdpSocket) -> io::Result<()> {
+        let runtime = quinn::default_runtime()
+            .ok_or_else(|| io::Error::other("no async runtime found"))?;
+        let previous = self.current.lock().unwrap().clone();
+        let shared = self.wrap(runtime.as_ref(), socket)?;
+        endpoint.rebind_abstract(shared).inspect_err(|_| {
+            *self.current.lock().unwrap() = previous;
+        })
+    }
+
+    fn wrap(&self, runtime: &dyn Runtime, socket: std::net::UdpSocket) -> io::Result<Arc<dyn AsyncUdpSocket>> {
+        let inner = runtime.wrap_udp_socket(socket)?;
+        *self.current.lock().unwrap() = Some(inner.clone());
+        Ok(Arc::new(SharedSocket {
+            inner,
+            received: self.received.clone(),
+            routes: self.routes.clone(),
+            relayed: Mutex::new(VecDeque::new()),
+        }))
+    }
+
+    /// Tunnel QUIC for a relay session through `server`, returning the peer's virtual address
+    pub(crate) fn add_route(&self, server: SocketAddr, session: [u8; 16]) -> SocketAddr {
+        let address = relay::virtual_address(&session);
+        let route = Route { server: canonical(server), session };
+        self.routes.lock().unwrap().insert(address, route);
+        address
+    }
+
+    /// Non-QUIC datagrams received from now on, with their source address
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<(SocketAddr, Vec<u8>)> {
+        self.received.subscribe()
+    }
+
+    /// Address the socket is bound to
+    pub(crate) fn local_addr(&self) -> Result<SocketAddr, TransportError> {
+        let socket = self.current.lock().unwrap().clone().ok_or(TransportError::NotConnected)?;
+        socket.local_addr().map_err(|e| TransportError::Io(format!("Failed to read socket address: {}", e)))
+    }
+
+    /// Send one datagram to `destination`
+    pub(crate) async fn send_to(&self, datagram: &[u8], destination: SocketAddr) -> Result<(), TransportError> {
+        let socket = self.current.lock().unwrap().clone().ok_or(TransportError::NotConnected)?;
+        let local = socket.local_addr().map_err(|e| TransportError::Io(e.to_string()))?;
+        let destination = for_socket(destination, local);
+        let transmit = Transmit { destination, ecn: None, contents: datagram, segment_size: None, src_ip: None };
+
+        let mut poller = socket.clone().create_io_poller();
+        std::future::poll_fn(|cx| loop {
+            match socket.try_send(&transmit) {
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    ready!(poller.as_mut().poll_writable(cx))?;
+                }
+                result => return Poll::Ready(result),
+            }
+        })
+        .await
+        .map_err(|e| TransportError::Io(format!("Failed to send to {}: {}", destination, e)))
+    }
+}
+
+/// The endpoint's view of the socket, with non-QUIC datagrams taken out
+struct SharedSocket {
+    inner: Arc<dyn AsyncUdpSocket>,
+    received: broadcast::Sender<(SocketAddr, Vec<u8>)>,
+    routes: Routes,
+    /// QUIC packets unwrapped from relay frames, with their virtual source address
+    relayed: Mutex<VecDeque<(SocketAddr, Vec<u8>)>>,
+}
+
+impl SharedSocket {
+    /// Wrap each packet of a transmit to a relay session and send it to the relay
+    fn send_relayed(&self, transmit: &Transmit, route: Route) -> io::Result<()> {
+        let local = self.inner.local_addr()?;
+        let segment_size = transmit.segment_size.unwrap_or(transmit.contents.len()).max(1);
+        for packet in transmit.contents.chunks(segment_size) {
+            let frame = relay::encode(relay::DATA, &route.session, packet);
+            self.inner.try_send(&Transmit {
+                destination: for_socket(route.server, local),
+                ecn: transmit.ecn,
+                contents: &frame,
+                segment_size: None,
+                src_ip: None,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Take the QUIC packet out of a relay data frame, `false` if it isn't one
+    fn unwrap_relayed(&self, from: SocketAddr, datagram: &[u8]) -> bool {
+        let Some((relay::DATA, session, packet)) = relay::decode(datagram) else { return false };
+        let address = relay::virtual_address(&session);
+        let routed = self.routes.lock().unwrap().get(&address).is_some_and(|route| route.server == canonical(from));
+        if routed && is_quic(packet) {
+            let mut relayed = self.relayed.lock().unwrap();
+            if relayed.len() >= RELAYED_CAPACITY {
+                relayed.pop_front();
+            }
+            relayed.push_back((for_socket(address, from), packet.to_vec()));
+        }
+        routed
+    }
+
+    /// Move unwrapped relay packets into the free receive slots from `first` on
+    fn deliver_relayed(&self, bufs: &mut [IoSliceMut<'_>], meta: &mut [RecvMeta], first: usize) -> usize {
+        let mut relayed = self.relayed.lock().unwrap();
+        let mut filled = first;
+        while filled < bufs.len().min(meta.len()) {
+            let Some((address, packet)) = relayed.pop_front() else { break };
+            if packet.len() > bufs[filled].len() {
+                continue;
+            }
+            bufs[filled][..packet.len()].copy_from_slice(&packet);
+            meta[filled] = RecvMeta { addr: address, len: packet.len(), stride: packet.len(), ..RecvMeta::default() };
+            filled += 1;
+        }
+        filled
+    }
+}
+
+impl fmt::Debug for SharedSocket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSocket").field("inner", &self.inner).finish()
+    }
+}
+
+impl AsyncUdpSocket for SharedSocket {
+    fn create_io_poller(self: Arc<Self>) -> Pin<Box<dyn UdpPoller>> {
+        self.inner.clone().create_io_poller()
+    }
+
+    fn try_send(&self, transmit: &Transmit) -> io::Result<()> {
+        let route = self.routes.lock().unwrap().get(&canonical(transmit.destination)).copied();
+        match route {
+            Some(route) => self.send_relayed(transmit, route),
+            None => self.inner.try_send(transmit),
+        }
+    }
+
+    fn poll_recv(&self, cx: &mut Context, bufs: &mut [IoSliceMut<'_>], meta: &mut [RecvMeta]) -> Poll<io::Result<usize>> {
+        let delivered = self.deliver_relayed(bufs, meta, 0);
+        if delivered > 0 {
+            return Poll::Ready(Ok(delivered));
+        }
+        loop {
+            let count = ready!(self.inner.poll_recv(cx, bufs, meta))?;
+            let mut kept = 0;
+            for i in 0..count {
+                let len = meta[i].len;
+                if is_quic(&bufs[i][..len]) {
+                    if kept != i {
+                        let (head, tail) = bufs.split_at_mut(i);
+                        head[kept][..len].copy_from_slice(&tail[0][..len]);
+                        meta[kept] = meta[i];
+                    }
+                    kept += 1;
+                    continue;
+                }
+                // Offloaded receives can hold several datagrams from the same sender
+                for datagram in bufs[i][..len].chunks(meta[i].stride.max(1)) {
+                    if !self.unwrap_relayed(meta[i].addr, datagram) {
+                        let _ = self.received.send((meta[i].addr, datagram.to_vec()));
+                    }
+                }
+            }
+            let filled = self.deliver_relayed(bufs, meta, kept);
+            if !self.relayed.lock().unwrap().is_empty() {
+                // Ask quinn to poll again for the packets that didn't fit
+                cx.waker().wake_by_ref();
+            }
+            // Keep reading until quinn gets a packet or the socket has nothing more
+            if filled > 0 || count == 0 {
+                return Poll::Ready(Ok(filled));
+            }
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn max_transmit_segments(&self) -> usize {
+        self.inner.max_transmit_segments()
+    }
+
+    fn max_receive_segments(&self) -> usize {
+        self.inner.max_receive_segments()
+    }
+
+    fn may_fragment(&self) -> bool {
+        self.inner.may_fragment()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demultiplexing() {
+        // Long and short header QUIC packets
+        assert!(is_quic(&[0xc3, 0, 0, 0, 1]));
+        assert!(is_quic(&[0x41, 0x12]));
+        // STUN binding request and our own packets
+        assert!(!is_quic(&[0x00, 0x01, 0x00, 0x00, 0x21, 0x12, 0xa4, 0x42]));
+        assert!(!is_quic(b"\x00SMP"));
+        assert!(!is_quic(&[]));
+    }
+}
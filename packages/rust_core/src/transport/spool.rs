@@ -0,0 +1,259 @@
+//! Transfer Spool for SyncMist
+//!
+//! Chunked transfers are recorded in a local directory so they can continue
+//! after the connection drops or the app restarts. Each transfer has a small
+//! metadata file with the last offset the receiver acknowledged, next to the
+//! content: a `.part` file for incoming transfers and, for payloads that
+//! didn't come from a file, a `.data` copy for outgoing ones.
+//!
+//! Files are named after the peer as well as the transfer id. Ids of incoming
+//! transfers are chosen by the sender, so one peer reusing another's id must
+//! not reach that peer's records or content.
+
+use std::path::PathBuf;
+
+use sha2::{Digest, Sha256};
+
+use super::quic::TransportError;
+
+/// A transfer that may need to continue later
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SpoolEntry {
+    pub(crate) transfer_id: u64,
+    /// Whether we are sending
+    pub(crate) outbound: bool,
+    pub(crate) peer_id: String,
+    pub(crate) name: String,
+    pub(crate) content_type: String,
+    pub(crate) size: u64,
+    /// Bytes the receiver has stored durably
+    pub(crate) acked: u64,
+    /// File the content is read from, outgoing transfers only
+    pub(crate) source: String,
+}
+
+/// Directory of interrupted and running transfers
+#[derive(Clone)]
+pub(crate) struct Spool {
+    directory: PathBuf,
+}
+
+impl Spool {
+    pub(crate) fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Where an incoming transfer's content is written until it completes
+    pub(crate) fn part_path(&self, peer_id: &str, transfer_id: u64) -> PathBuf {
+        self.directory.join(format!("{}.part", stem(false, peer_id, transfer_id)))
+    }
+
+    /// Where an outgoing payload is kept until the peer has it
+    pub(crate) fn data_path(&self, transfer_id: u64) -> PathBuf {
+        self.directory.join(format!("out-{}.data", transfer_id))
+    }
+
+    fn meta_path(&self, outbound: bool, peer_id: &str, transfer_id: u64) -> PathBuf {
+        self.directory.join(format!("{}.meta", stem(outbound, peer_id, transfer_id)))
+    }
+
+    /// Create the spool directory if it doesn't exist
+    pub(crate) fn ensure_directory(&self) -> Result<(), TransportError> {
+        std::fs::create_dir_all(&self.directory)
+            .map_err(|e| TransportError::Io(format!("Failed to create {}: {}", self.directory.display(), e)))
+    }
+
+    /// Record a transfer, replacing any earlier record of it
+    pub(crate) fn save(&self, entry: &SpoolEntry) -> Result<(), TransportError> {
+        self.ensure_directory()?;
+        let path = self.meta_path(entry.outbound, &entry.peer_id, entry.transfer_id);
+        // Write then rename so a crash never leaves a half-written record
+        let temp = path.with_extension("meta.tmp");
+        std::fs::write(&temp, encode(entry))
+            .and_then(|_| std::fs::rename(&temp, &path))
+            .map_err(|e| TransportError::Io(format!("Failed to write {}: {}", path.display(), e)))
+    }
+
+    pub(crate) fn load(&self, outbound: bool, peer_id: &str, transfer_id: u64) -> Option<SpoolEntry> {
+        let text = std::fs::read_to_string(self.meta_path(outbound, peer_id, transfer_id)).ok()?;
+        decode(&text)
+            .filter(|entry| entry.outbound == outbound && entry.peer_id == peer_id && entry.transfer_id == transfer_id)
+    }
+
+    /// All recorded transfers in one direction, oldest id first
+    pub(crate) fn entries(&self, outbound: bool) -> Vec<SpoolEntry> {
+        let prefix = format!("{}-", direction(outbound));
+        let Ok(files) = std::fs::read_dir(&self.directory) else {
+            return Vec::new();
+        };
+        let mut entries: Vec<SpoolEntry> = files
+            .filter_map(|file| file.ok())
+            .filter_map(|file| {
+                let name = file.file_name().to_string_lossy().to_string();
+                if !name.starts_with(&prefix) || !name.ends_with(".meta") {
+                    return None;
+                }
+                let entry = decode(&std::fs::read_to_string(file.path()).ok()?)?;
+                let path = self.meta_path(entry.outbound, &entry.peer_id, entry.transfer_id);
+                (entry.outbound == outbound && path == file.path()).then_some(entry)
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.transfer_id);
+        entries
+    }
+
+    /// Forget a transfer and delete the content kept for it
+    pub(crate) fn remove(&self, outbound: bool, peer_id: &str, transfer_id: u64) {
+        let _ = std::fs::remove_file(self.meta_path(outbound, peer_id, transfer_id));
+        let content = if outbound { self.data_path(transfer_id) } else { self.part_path(peer_id, transfer_id) };
+        let _ = std::fs::remove_file(content);
+    }
+}
+
+/// `in-<peer>-<id>` or `out-<peer>-<id>`, with the peer id hashed so any
+/// device id makes a safe file name
+fn stem(outbound: bool, peer_id: &str, transfer_id: u64) -> String {
+    let digest = Sha256::digest(peer_id.as_bytes());
+    let peer: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}-{}-{}", direction(outbound), peer, transfer_id)
+}
+
+fn direction(outbound: bool) -> &'static str {
+    if outbound {
+        "out"
+    } else {
+        "in"
+    }
+}
+
+/// One `key=value` line per field, with `\` and newlines escaped
+fn encode(entry: &SpoolEntry) -> String {
+    let fields = [
+        ("transfer_id", entry.transfer_id.to_string()),
+        ("outbound", entry.outbound.to_string()),
+        ("peer_id", entry.peer_id.clone()),
+        ("name", entry.name.clone()),
+        ("content_type", entry.content_type.clone()),
+        ("size", entry.size.to_string()),
+        ("acked", entry.acked.to_string()),
+        ("source", entry.source.clone()),
+    ];
+    fields
+        .iter()
+        .map(|(key, value)| format!("{}={}\n", key, value.replace('\\', "\\\\").replace('\n', "\\n")))
+        .collect()
+}
+
+fn decode(text: &str) -> Option<SpoolEntry> {
+    let mut entry = SpoolEntry {
+        transfer_id: 0,
+        outbound: false,
+        peer_id: String::new(),
+        name: String::new(),
+        content_type: String::new(),
+        size: 0,
+        acked: 0,
+        source: String::new(),
+    };
+    let mut seen = 0;
+    for line in text.lines() {
+        let (key, value) = line.split_once('=')?;
+        let value = unescape(value);
+        match key {
+            "transfer_id" => entry.transfer_id = value.parse().ok()?,
+            "outbound" => entry.outbound = value.parse().ok()?,
+            "peer_id" => entry.peer_id = value,
+            "name" => entry.name = value,
+            "content_type" => entry.content_type = value,
+            "size" => entry.size = value.parse().ok()?,
+            "acked" => entry.acked = value.parse().ok()?,
+            "source" => entry.source = value,
+            _ => continue,
+        }
+        seen += 1;
+    }
+    (seen == 8 && entry.acked <= entry.size).then_some(entry)
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(transfer_id: u64, outbound: bool) -> SpoolEntry {
+        SpoolEntry {
+            transfer_id,
+            outbound,
+            peer_id: "phone".to_string(),
+            name: "odd\\name\nwith newline.png".to_string(),
+            content_type: "image/png".to_string(),
+            size: 10_000_000,
+            acked: 1_048_576,
+            source: "/tmp/screenshot.png".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record_roundtrip() {
+        let original = entry(42, true);
+        assert_eq!(decode(&encode(&original)), Some(original));
+
+        // Incomplete or inconsistent records are ignored
+        assert_eq!(decode("transfer_id=1\n"), None);
+        let mut inconsistent = entry(1, false);
+        inconsistent.acked = inconsistent.size + 1;
+        assert_eq!(decode(&encode(&inconsistent)), None);
+    }
+
+    #[test]
+    fn test_save_list_and_remove() {
+        let directory = std::env::temp_dir().join(format!("syncmist-spool-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        let spool = Spool::new(directory.clone());
+        assert!(spool.entries(true).is_empty(), "Missing directory means no entries");
+
+        spool.save(&entry(7, true)).unwrap();
+        spool.save(&entry(3, true)).unwrap();
+        spool.save(&entry(5, false)).unwrap();
+        std::fs::write(spool.part_path("phone", 5), b"partial").unwrap();
+
+        let ids: Vec<u64> = spool.entries(true).iter().map(|entry| entry.transfer_id).collect();
+        assert_eq!(ids, vec![3, 7]);
+        assert_eq!(spool.load(false, "phone", 5), Some(entry(5, false)));
+        assert_eq!(spool.load(true, "phone", 5), None);
+
+        // Another peer sending with the same id gets records and content of its own
+        let mut other = entry(5, false);
+        other.peer_id = "../laptop/x".to_string();
+        spool.save(&other).unwrap();
+        assert_ne!(spool.part_path("../laptop/x", 5), spool.part_path("phone", 5));
+        assert!(spool.part_path("../laptop/x", 5).starts_with(&directory));
+        assert_eq!(spool.load(false, "phone", 5), Some(entry(5, false)));
+        assert_eq!(spool.entries(false).len(), 2);
+        spool.remove(false, "../laptop/x", 5);
+        assert_eq!(spool.load(false, "../laptop/x", 5), None);
+        assert!(spool.part_path("phone", 5).exists(), "Other peers' content is left alone");
+
+        spool.remove(false, "phone", 5);
+        assert_eq!(spool.load(false, "phone", 5), None);
+        assert!(!spool.part_path("phone", 5).exists(), "Content should be removed with the record");
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,59 @@
+//! Connection Statistics for SyncMist
+//!
+//! Snapshots of quinn's per-connection counters, for diagnostics and the
+//! connection dashboard. Unlike the datagram heartbeats in
+//! [`super::datagram`], these cost nothing on the wire.
+
+use std::time::Duration;
+
+use super::peers::PeerEntry;
+
+/// Shortest interval accepted for periodic stats
+pub(crate) const MIN_STATS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Counters and path state of the connection to a peer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeerStats {
+    pub peer_id: String,
+    /// Current path address of the peer
+    pub address: String,
+    /// Smoothed round-trip time estimated by QUIC, in milliseconds
+    pub rtt_ms: f64,
+    /// Congestion window in bytes
+    pub congestion_window: u64,
+    /// UDP payload bytes sent and received, including QUIC overhead
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    /// UDP datagrams sent and received
+    pub packets_sent: u64,
+    pub packets_received: u64,
+    /// Packets QUIC declared lost and retransmitted
+    pub lost_packets: u64,
+    /// Times the congestion controller backed off
+    pub congestion_events: u64,
+    /// Path MTU in bytes
+    pub mtu: u16,
+    /// Milliseconds since the connection was registered
+    pub connected_ms: u64,
+}
+
+impl PeerStats {
+    pub(crate) fn new(peer_id: &str, peer: &PeerEntry) -> Self {
+        let stats = peer.connection.stats();
+        Self {
+            peer_id: peer_id.to_string(),
+            address: peer.connection.remote_address().to_string(),
+            rtt_ms: peer.connection.rtt().as_secs_f64() * 1000.0,
+            congestion_window: stats.path.cwnd,
+            bytes_sent: stats.udp_tx.bytes,
+            bytes_received: stats.udp_rx.bytes,
+            packets_sent: stats.udp_tx.datagrams,
+            packets_received: stats.udp_rx.datagrams,
+            lost_packets: stats.path.lost_packets,
+            congestion_events: stats.path.congestion_events,
+            mtu: stats.path.current_mtu,
+            connected_ms: peer.established.elapsed().as_millis() as u64,
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! STUN for SyncMist
+//!
+//! A minimal STUN (RFC 5389) binding client. Requests leave from the QUIC
+//! endpoint's socket, so the mapped address a server reports is the one peers
+//! outside our NAT reach QUIC at. Asking two servers tells the NAT's mapping
+//! behaviour apart (RFC 4787): an endpoint-independent NAT shows every server
+//! the same mapping and can be punched through, a symmetric one can't.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+
+use tokio::net::UdpSocket;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::quic::TransportError;
+use super::socket::RawSocket;
+
+const MAGIC_COOKIE: u32 = 0x2112_a442;
+const BINDING_REQUEST: u16 = 0x0001;
+const BINDING_SUCCESS: u16 = 0x0101;
+const MAPPED_ADDRESS: u16 = 0x0001;
+const XOR_MAPPED_ADDRESS: u16 = 0x0020;
+const HEADER_SIZE: usize = 20;
+
+/// Wait before the first retransmission, doubled after each
+const INITIAL_RTO: Duration = Duration::from_millis(250);
+/// Requests sent before a server is considered unreachable
+const MAX_REQUESTS: u32 = 5;
+
+/// How a NAT maps our socket to public addresses
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NatType {
+    /// The mapped address is our own: there is no NAT
+    Open,
+    /// Every destination sees the same mapped address, so hole punching works
+    EndpointIndependent,
+    /// Each destination sees a different mapped address; only a relay gets through
+    Symmetric,
+    /// Too few servers answered to tell
+    Unknown,
+}
+
+/// What STUN servers saw of our QUIC socket
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NatReport {
+    /// Public address and port of the QUIC socket, as seen by the first server that answered
+    pub mapped_address: String,
+    pub nat_type: NatType,
+}
+
+/// A STUN message; only what the binding exchange needs
+#[derive(Debug, PartialEq)]
+struct Message {
+    message_type: u16,
+    transaction_id: [u8; 12],
+    mapped_address: Option<SocketAddr>,
+}
+
+impl Message {
+    fn encode(&self) -> Vec<u8> {
+        let mut attributes = Vec::new();
+        if let Some(address) = self.mapped_address {
+            let value = xor_address(address, &self.transaction_id);
+            attributes.extend_from_slice(&XOR_MAPPED_ADDRESS.to_be_bytes());
+            attributes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            attributes.extend_from_slice(&value);
+        }
+        let mut out = Vec::with_capacity(HEADER_SIZE + attributes.len());
+        out.extend_from_slice(&self.message_type.to_be_bytes());
+        out.extend_from_slice(&(attributes.len() as u16).to_be_bytes());
+        out.extend_from_slice(&MAGIC_COOKIE.to_be_bytes());
+        out.extend_from_slice(&self.transaction_id);
+        out.extend_from_slice(&attributes);
+        out
+    }
+
+    /// Decode a STUN message, `None` for anything that isn't one
+    fn decode(datagram: &[u8]) -> Option<Self> {
+        if datagram.len() < HEADER_SIZE || datagram[0] & 0xc0 != 0 {
+            return None;
+        }
+        let message_type = u16::from_be_bytes([datagram[0], datagram[1]]);
+        let length = u16::from_be_bytes([datagram[2], datagram[3]]) as usize;
+        if datagram[4..8] != MAGIC_COOKIE.to_be_bytes() || datagram.len() != HEADER_SIZE + length {
+            return None;
+        }
+        let transaction_id: [u8; 12] = datagram[8..20].try_into().ok()?;
+
+        let mut mapped_address = None;
+        let mut attributes = &datagram[HEADER_SIZE..];
+        while attributes.len() >= 4 {
+            let kind = u16::from_be_bytes([attributes[0], attributes[1]]);
+            let len = u16::from_be_bytes([attributes[2], attributes[3]]) as usize;
+            let value = attributes.get(4..4 + len)?;
+            match kind {
+                XOR_MAPPED_ADDRESS => mapped_address = Some(unxor_address(value, &transaction_id)?),
+                // Only used by servers predating RFC 5389
+                MAPPED_ADDRESS if mapped_address.is_none() => mapped_address = Some(plain_address(value)?),
+                _ => {}
+            }
+            // Attributes are padded to a multiple of four bytes
+            attributes = attributes.get((4 + len).next_multiple_of(4)..).unwrap_or_default();
+        }
+        Some(Self { message_type, transaction_id, mapped_address })
+    }
+}
+
+/// Bytes XORed into an address: the cookie, followed by the transaction id for IPv6
+fn xor_key(transaction_id: &[u8; 12]) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    key[..4].copy_from_slice(&MAGIC_COOKIE.to_be_bytes());
+    key[4..].copy_from_slice(transaction_id);
+    key
+}
+
+fn xor_address(address: SocketAddr, transaction_id: &[u8; 12]) -> Vec<u8> {
+    let key = xor_key(transaction_id);
+    let port = address.port() ^ (MAGIC_COOKIE >> 16) as u16;
+    let (family, ip) = match address.ip().to_canonical() {
+        IpAddr::V4(v4) => (1u8, v4.octets().to_vec()),
+        IpAddr::V6(v6) => (2u8, v6.octets().to_vec()),
+    };
+    let mut out = vec![0, family];
+    out.extend_from_slice(&port.to_be_bytes());
+    out.extend(ip.iter().zip(key).map(|(byte, key)| byte ^ key));
+    out
+}
+
+fn unxor_address(value: &[u8], transaction_id: &[u8; 12]) -> Option<SocketAddr> {
+    let key = xor_key(transaction_id);
+    let plain: Vec<u8> = value
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| match i {
+            2 => byte ^ key[0],
+            3 => byte ^ key[1],
+            i if i >= 4 => byte ^ key[i - 4],
+            _ => *byte,
+        })
+        .collect();
+    plain_address(&plain)
+}
+
+fn plain_address(value: &[u8]) -> Option<SocketAddr> {
+    let port = u16::from_be_bytes([*value.get(2)?, *value.get(3)?]);
+    let ip = match (value.get(1)?, value.get(4..)?) {
+        (1, ip) if ip.len() == 4 => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(ip).ok()?)),
+        (2, ip) if ip.len() == 16 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(ip).ok()?)),
+        _ => return None,
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+/// Ask a STUN server for the address our socket is mapped to
+pub(crate) async fn binding(raw: &RawSocket, server: SocketAddr) -> Result<SocketAddr, TransportError> {
+    let request = Message { message_type: BINDING_REQUEST, transaction_id: rand::random(), mapped_address: None };
+    let encoded = request.encode();
+    let mut received = raw.subscribe();
+
+    let mut rto = INITIAL_RTO;
+    for _ in 0..MAX_REQUESTS {
+        raw.send_to(&encoded, server).await?;
+        let response = tokio::time::timeout(rto, async {
+            loop {
+                match received.recv().await {
+                    Ok((_, datagram)) => match Message::decode(&datagram) {
+                        Some(response) if response.transaction_id == request.transaction_id => return Some(response),
+                        _ => continue,
+                    },
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        match response.await {
+            Ok(Some(Message { message_type: BINDING_SUCCESS, mapped_address: Some(address), .. })) => {
+                return Ok(SocketAddr::new(address.ip().to_canonical(), address.port()));
+            }
+            Ok(Some(response)) => {
+                return Err(TransportError::Connection(format!(
+                    "STUN server {} answered with type {:#06x}",
+                    server, response.message_type
+                )));
+            }
+            Ok(None) => return Err(TransportError::NotConnected),
+            Err(_) => rto *= 2,
+        }
+    }
+    Err(TransportError::Connection(format!("STUN server {} did not answer", server)))
+}
+
+/// Classify a NAT from the addresses different servers saw
+///
+/// `local` holds our socket's addresses, one per interface.
+pub(crate) fn classify(local: &[SocketAddr], mapped: &[SocketAddr]) -> NatType {
+    match mapped {
+        [] => NatType::Unknown,
+        [first, ..] if local.contains(first) => NatType::Open,
+        [_] => NatType::Unknown,
+        [first, rest @ ..] if rest.iter().all(|address| address == first) => NatType::EndpointIndependent,
+        _ => NatType::Symmetric,
+    }
+}
+
+/// A minimal STUN server answering binding requests, for tests and self-hosting
+#[flutter_rust_bridge::frb(ignore)]
+pub struct StunServer {
+    address: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl StunServer {
+    /// Start the server on a UDP address such as `0.0.0.0:3478`
+    pub async fn bind(address: &str) -> Result<Self, TransportError> {
+        let socket = UdpSocket::bind(address)
+            .await
+            .map_err(|e| TransportError::Io(format!("Failed to bind STUN server {}: {}", address, e)))?;
+        let address = socket.local_addr().map_err(|e| TransportError::Io(e.to_string()))?;
+        println!("[QUIC] STUN server listening on {}", address);
+        let task = tokio::spawn(async move {
+            let mut buf = vec![0u8; 1500];
+            loop {
+                let Ok((len, from)) = socket.recv_from(&mut buf).await else { continue };
+                let Some(request) = Message::decode(&buf[..len]) else { continue };
+                if request.message_type != BINDING_REQUEST {
+                    continue;
+                }
+                let response = Message {
+                    message_type: BINDING_SUCCESS,
+                    transaction_id: request.transaction_id,
+                    mapped_address: Some(from),
+                };
+                let _ = socket.send_to(&response.encode(), from).await;
+            }
+        });
+        Ok(Self { address, task })
+    }
+
+    /// Address the server listens on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+}
+
+impl Drop for StunServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_roundtrip() {
+        for address in ["203.0.113.7:61000", "[2001:db8::7]:443"] {
+            let message = Message {
+                message_type: BINDING_SUCCESS,
+                transaction_id: rand::random(),
+                mapped_address: Some(address.parse().unwrap()),
+            };
+            assert_eq!(Message::decode(&message.encode()), Some(message));
+        }
+        assert_eq!(Message::decode(b"\x00SMR\x00\x00\x00\x00"), None);
+    }
+
+    #[test]
+    fn test_decode_rfc5769_response() {
+        // IPv4 sample response from RFC 5769 section 2.2, without the optional attributes
+        let mut response = vec![0x01, 0x01, 0x00, 0x0c, 0x21, 0x12, 0xa4, 0x42];
+        response.extend_from_slice(&[0xb7, 0xe7, 0xa7, 0x01, 0xbc, 0x34, 0xd6, 0x86, 0xfa, 0x87, 0xdf, 0xae]);
+        response.extend_from_slice(&[0x00, 0x20, 0x00, 0x08, 0x00, 0x01, 0xa1, 0x47, 0xe1, 0x12, 0xa6, 0x43]);
+        let message = Message::decode(&response).unwrap();
+        assert_eq!(message.mapped_address, Some("192.0.2.1:32853".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_classify() {
+        let local: Vec<SocketAddr> = vec!["192.168.1.5:4000".parse().unwrap()];
+        let public = |port: u16| SocketAddr::from(([203, 0, 113, 7], port));
+        assert_eq!(classify(&local, &[]), NatType::Unknown);
+        assert_eq!(classify(&local, &local), NatType::Open);
+        assert_eq!(classify(&local, &[public(61000)]), NatType::Unknown, "One server can't tell");
+        assert_eq!(classify(&local, &[public(61000), public(61000)]), NatType::EndpointIndependent);
+        assert_eq!(classify(&local, &[public(61000), public(61001)]), NatType::Symmetric);
+    }
+}
@@ -0,0 +1,229 @@
+//! Reconnect Supervisor for SyncMist
+//!
+//! Keeps connections to chosen peers alive. Each supervised peer gets a task
+//! that waits for its connection to close and re-dials the last address that
+//! worked, addresses freshly seen by mDNS, and the addresses it was given,
+//! backing off exponentially with jitter between rounds. A peer that closes
+//! for connecting too often is only dialed again after the backoff delay, and
+//! one that unpairs us or shares no protocol version is no longer supervised.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use super::address::resolve_addresses;
+use super::events::{ConnectionEvent, DisconnectReason};
+use super::peers::PeerRegistry;
+use super::quic::Dialer;
+use crate::discovery::mdns::PeerInfo;
+
+/// Delay before the first retry
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+/// Longest delay between retries
+const BACKOFF_MAX: Duration = Duration::from_secs(60);
+/// Time allowed for a single dial attempt
+const DIAL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Connection state of a supervised peer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PeerState {
+    /// A connection to the peer is open
+    Connected,
+    /// The peer is unreachable and will be dialed again after a delay
+    Reconnecting,
+    /// The peer is no longer supervised
+    Stopped,
+}
+
+/// Where to look for a supervised peer
+pub(crate) struct SupervisedPeer {
+    pub(crate) device_id: String,
+    /// Addresses given by the app, tried after fresher ones
+    pub(crate) addresses: Vec<SocketAddr>,
+}
+
+/// Start supervising a peer until the returned task is aborted
+pub(crate) fn spawn_supervisor(
+    peer: SupervisedPeer,
+    dialer: Dialer,
+    registry: PeerRegistry,
+    discovered: Option<Arc<Mutex<Vec<PeerInfo>>>>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        println!("[QUIC] Supervising connection to {}", peer.device_id);
+        let mut last_address = None;
+        let mut connected = false;
+        let mut attempt: u32 = 0;
+
+        loop {
+            // Watch the current connection, whichever side dialed it
+            if let Some(connection) = live_connection(&registry, &peer.device_id).await {
+                if !connected {
+                    connected = true;
+                    attempt = 0;
+                    publish_state(&registry, &peer.device_id, PeerState::Connected, 0, Duration::ZERO);
+                }
+                last_address = Some(connection.remote_address());
+                let error = connection.closed().await;
+                println!("[QUIC] Supervised connection to {} closed: {}", peer.device_id, error);
+                if live_connection(&registry, &peer.device_id).await.is_some() {
+                    continue;
+                }
+                connected = false;
+                match DisconnectReason::from_connection_error(&error).0 {
+                    reason @ (DisconnectReason::Unpaired | DisconnectReason::VersionMismatch) => {
+                        println!("[QUIC] Giving up on {}: {:?}", peer.device_id, reason);
+                        publish_state(&registry, &peer.device_id, PeerState::Stopped, 0, Duration::ZERO);
+                        return;
+                    }
+                    DisconnectReason::RateLimited => {
+                        attempt = attempt.saturating_add(1);
+                        let delay = backoff_delay(attempt, rand::random());
+                        publish_state(&registry, &peer.device_id, PeerState::Reconnecting, attempt, delay);
+                        tokio::time::sleep(delay).await;
+                    }
+                    _ => publish_state(&registry, &peer.device_id, PeerState::Reconnecting, 0, Duration::ZERO),
+                }
+                continue;
+            }
+
+            let mdns_addresses = match &discovered {
+                Some(discovered) => mdns_addresses(&discovered.lock().await, &peer.device_id),
+                None => Vec::new(),
+            };
+            for address in candidate_addresses(last_address, &mdns_addresses, &peer.addresses) {
+                println!("[QUIC] Dialing {} at {}", peer.device_id, address);
+                let result = tokio::time::timeout(DIAL_TIMEOUT, dialer.dial(address, Some(peer.device_id.clone()))).await;
+                match result {
+                    Ok(Ok(_)) => break,
+                    Ok(Err(e)) => println!("[QUIC] Reconnect to {} at {} failed: {}", peer.device_id, address, e),
+                    Err(_) => println!("[QUIC] Reconnect to {} at {} timed out", peer.device_id, address),
+                }
+            }
+            if live_connection(&registry, &peer.device_id).await.is_some() {
+                continue;
+            }
+
+            connected = false;
+            attempt = attempt.saturating_add(1);
+            let delay = backoff_delay(attempt, rand::random());
+            publish_state(&registry, &peer.device_id, PeerState::Reconnecting, attempt, delay);
+            tokio::time::sleep(delay).await;
+        }
+    })
+}
+
+/// Open connection to a peer, skipping one that closed but hasn't been removed yet
+async fn live_connection(registry: &PeerRegistry, peer_id: &str) -> Option<quinn::Connection> {
+    registry
+        .connection(peer_id)
+        .await
+        .filter(|connection| connection.close_reason().is_none())
+}
+
+/// Report a supervised peer's state to the app
+pub(crate) fn publish_state(registry: &PeerRegistry, peer_id: &str, state: PeerState, attempt: u32, retry_in: Duration) {
+    println!("[QUIC] Peer {} is {:?}", peer_id, state);
+    registry.publish(ConnectionEvent::PeerStateChanged {
+        peer_id: peer_id.to_string(),
+        state,
+        attempt,
+        retry_in_ms: retry_in.as_millis() as u64,
+    });
+}
+
+/// Delay before retry number `attempt` (starting at 1)
+///
+/// Doubles from [`BACKOFF_BASE`] up to [`BACKOFF_MAX`]; `jitter` in `[0, 1)`
+/// scales it to between half and all of that so peers don't retry in lockstep.
+fn backoff_delay(attempt: u32, jitter: f64) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16);
+    let delay = BACKOFF_BASE.saturating_mul(1 << exponent).min(BACKOFF_MAX);
+    delay.mul_f64(0.5 + jitter.clamp(0.0, 1.0) / 2.0)
+}
+
+/// Addresses mDNS currently advertises for a device
+fn mdns_addresses(discovered: &[PeerInfo], device_id: &str) -> Vec<SocketAddr> {
+    discovered
+        .iter()
+        .filter(|peer| peer.device_id == device_id)
+        .flat_map(|peer| {
+            peer.addresses
+                .iter()
+                .filter_map(|ip| resolve_addresses(ip, peer.port).ok())
+                .flatten()
+        })
+        .collect()
+}
+
+/// Addresses to dial in order: last known, then mDNS, then configured, without duplicates
+fn candidate_addresses(
+    last: Option<SocketAddr>,
+    mdns: &[SocketAddr],
+    configured: &[SocketAddr],
+) -> Vec<SocketAddr> {
+    let mut candidates = Vec::new();
+    for address in last.iter().chain(mdns).chain(configured) {
+        if !candidates.contains(address) {
+            candidates.push(*address);
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        assert_eq!(backoff_delay(1, 0.0), BACKOFF_BASE / 2);
+        assert_eq!(backoff_delay(3, 0.0), BACKOFF_BASE * 2);
+        assert_eq!(backoff_delay(100, 0.0), BACKOFF_MAX / 2, "Delay should be capped");
+        for attempt in 1..20 {
+            let delay = backoff_delay(attempt, 0.7);
+            assert!(delay <= BACKOFF_MAX, "Attempt {} waited {:?}", attempt, delay);
+            assert!(delay >= backoff_delay(attempt - 1, 0.7), "Delay should not shrink");
+        }
+    }
+
+    #[test]
+    fn test_candidate_addresses_order() {
+        let last: SocketAddr = "192.168.1.10:9876".parse().unwrap();
+        let fresh: SocketAddr = "192.168.1.20:9876".parse().unwrap();
+        let configured: SocketAddr = "10.0.0.5:9876".parse().unwrap();
+        assert_eq!(
+            candidate_addresses(Some(last), &[fresh, last], &[configured, fresh]),
+            vec![last, fresh, configured]
+        );
+    }
+
+    #[test]
+    fn test_mdns_addresses_for_device() {
+        let discovered = vec![
+            PeerInfo {
+                device_id: "phone".to_string(),
+                device_name: "Phone".to_string(),
+                addresses: vec!["192.168.1.20".to_string(), "fe80::1%2".to_string(), "bogus".to_string()],
+                port: 9000,
+                discovered_at: 0,
+            },
+            PeerInfo {
+                device_id: "laptop".to_string(),
+                device_name: "Laptop".to_string(),
+                addresses: vec!["192.168.1.30".to_string()],
+                port: 9876,
+                discovered_at: 0,
+            },
+        ];
+        assert_eq!(
+            mdns_addresses(&discovered, "phone"),
+            vec!["192.168.1.20:9000".parse().unwrap(), "[fe80::1%2]:9000".parse().unwrap()]
+        );
+        assert!(mdns_addresses(&discovered, "tablet").is_empty());
+    }
+}
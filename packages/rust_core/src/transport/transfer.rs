@@ -0,0 +1,905 @@
+//! Chunked Transfers for SyncMist
+//!
+//! Files and payloads too big for a single message travel on a bidirectional
+//! stream of their own: the sender offers the transfer with its size, the
+//! receiver checks the size against its limit before accepting, the content
+//! follows in chunks and a SHA-256 hash closes the stream. The receiver writes
+//! chunks straight to disk, so a transfer never has to fit in memory.
+//!
+//! The receiver acknowledges content every [`ACK_INTERVAL`] bytes once it is
+//! on disk, and both sides record the offset in the spool. When a transfer is
+//! interrupted by a lost connection, the sender offers it again with the same
+//! id after reconnecting and the receiver accepts from the acknowledged offset.
+//!
+//! Either side cancels by resetting or stopping the stream with
+//! [`STREAM_CANCELLED`], which the other side reports as a cancellation.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use quinn::{Connection, RecvStream, SendStream};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::sync::{broadcast, Notify};
+
+use super::events::EVENT_CAPACITY;
+use super::options::TransportOptions;
+use super::protocol::{error_code, read_message, write_message, ProtocolError, SyncMessage, STREAM_CANCELLED, STREAM_FAILED};
+use super::quic::TransportError;
+use super::spool::{Spool, SpoolEntry};
+
+/// Content bytes carried by each chunk
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Bytes received between acknowledgements, and the most a resumed transfer repeats
+pub const ACK_INTERVAL: u64 = 16 * CHUNK_SIZE as u64;
+
+/// Largest frame read on a transfer stream: a full chunk plus its fields
+const MAX_TRANSFER_FRAME: usize = CHUNK_SIZE + 1024;
+
+/// Minimum time between progress events for one transfer
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Time allowed for the receiver to verify and store a transfer after the last chunk
+const COMPLETION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Time a resumed transfer waits for the interrupted attempt to wind down
+const RESUME_WAIT: Duration = Duration::from_secs(10);
+
+/// Highest number tried when a received file's name is taken
+const MAX_NAME_SUFFIX: u32 = 9999;
+
+/// Progress of a chunked transfer
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferEvent {
+    /// A transfer was accepted by the receiver, possibly resuming an earlier attempt
+    Started {
+        transfer_id: u64,
+        peer_id: String,
+        name: String,
+        size: u64,
+        /// Whether we are sending
+        outbound: bool,
+        /// Bytes carried over from an interrupted attempt
+        offset: u64,
+    },
+    /// Bytes sent or received so far
+    Progress {
+        transfer_id: u64,
+        peer_id: String,
+        transferred: u64,
+        size: u64,
+    },
+    /// The receiver verified the content hash
+    Completed {
+        transfer_id: u64,
+        peer_id: String,
+        /// Where the content was saved, empty for outgoing transfers
+        path: String,
+        /// SHA-256 of the content as lowercase hex
+        sha256: String,
+    },
+    /// The transfer failed or was refused
+    Failed { transfer_id: u64, peer_id: String, error: String },
+    /// The transfer was cancelled
+    Cancelled {
+        transfer_id: u64,
+        peer_id: String,
+        /// Whether the peer cancelled rather than us
+        by_peer: bool,
+    },
+    /// The connection was lost; the transfer resumes when the peer reconnects
+    Interrupted {
+        transfer_id: u64,
+        peer_id: String,
+        /// Bytes the receiver has acknowledged
+        transferred: u64,
+    },
+}
+
+/// An interrupted or running transfer recorded in the spool
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingTransfer {
+    pub transfer_id: u64,
+    pub peer_id: String,
+    pub name: String,
+    pub size: u64,
+    /// Bytes the receiver has acknowledged
+    pub transferred: u64,
+    /// Whether we are sending
+    pub outbound: bool,
+}
+
+/// Transfers in progress and the settings that apply to them
+#[derive(Clone)]
+pub(crate) struct Transfers {
+    active: Arc<std::sync::Mutex<HashMap<u64, Arc<Notify>>>>,
+    events: broadcast::Sender<TransferEvent>,
+    max_size: u64,
+    directory: PathBuf,
+    spool: Spool,
+}
+
+/// Registration of a running transfer, removed when dropped
+struct ActiveTransfer {
+    transfer_id: u64,
+    cancel: Arc<Notify>,
+    transfers: Transfers,
+}
+
+impl Drop for ActiveTransfer {
+    fn drop(&mut self) {
+        self.transfers.active.lock().unwrap().remove(&self.transfer_id);
+    }
+}
+
+impl Transfers {
+    pub(crate) fn new(options: &TransportOptions) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            active: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            events,
+            max_size: options.max_transfer_size,
+            directory: options.transfer_directory(),
+            spool: Spool::new(options.spool_directory()),
+        }
+    }
+
+    /// Register a transfer, or `None` if one with the same id is running
+    fn start(&self, transfer_id: u64) -> Option<ActiveTransfer> {
+        let mut active = self.active.lock().unwrap();
+        if active.contains_key(&transfer_id) {
+            return None;
+        }
+        let cancel = Arc::new(Notify::new());
+        active.insert(transfer_id, cancel.clone());
+        Some(ActiveTransfer { transfer_id, cancel, transfers: self.clone() })
+    }
+
+    /// Register a transfer under a fresh id that isn't running or spooled
+    fn start_new(&self) -> ActiveTransfer {
+        loop {
+            let transfer_id = rand::random();
+            if self.spool.entries(true).iter().any(|entry| entry.transfer_id == transfer_id) {
+                continue;
+            }
+            if let Some(active) = self.start(transfer_id) {
+                return active;
+            }
+        }
+    }
+
+    /// Register a transfer once an earlier attempt with the same id has ended
+    ///
+    /// After a reconnect, the attempt on the old connection may not have
+    /// noticed yet that it is dead.
+    async fn start_when_free(&self, transfer_id: u64) -> Option<ActiveTransfer> {
+        let deadline = Instant::now() + RESUME_WAIT;
+        loop {
+            if let Some(active) = self.start(transfer_id) {
+                return Some(active);
+            }
+            if Instant::now() >= deadline {
+                return None;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    /// Cancel a running transfer or discard an interrupted one
+    ///
+    /// Returns `false` if there is no such transfer.
+    pub(crate) fn cancel(&self, transfer_id: u64) -> bool {
+        if let Some(cancel) = self.active.lock().unwrap().get(&transfer_id) {
+            // Stores a permit, so a cancel between two waits isn't lost
+            cancel.notify_one();
+            return true;
+        }
+        let mut found = false;
+        for outbound in [true, false] {
+            for entry in self.spool.entries(outbound).into_iter().filter(|entry| entry.transfer_id == transfer_id) {
+                self.spool.remove(outbound, &entry.peer_id, transfer_id);
+                self.publish(TransferEvent::Cancelled { transfer_id, peer_id: entry.peer_id, by_peer: false });
+                found = true;
+            }
+        }
+        found
+    }
+
+    /// Transfers recorded in the spool, outgoing first
+    pub(crate) fn pending(&self) -> Vec<PendingTransfer> {
+        [true, false]
+            .into_iter()
+            .flat_map(|outbound| self.spool.entries(outbound))
+            .map(|entry| PendingTransfer {
+                transfer_id: entry.transfer_id,
+                peer_id: entry.peer_id,
+                name: entry.name,
+                size: entry.size,
+                transferred: entry.acked,
+                outbound: entry.outbound,
+            })
+            .collect()
+    }
+
+    /// Continue interrupted outgoing transfers to a peer that just connected
+    pub(crate) fn resume(&self, peer_id: &str, connection: &Connection) {
+        for entry in self.spool.entries(true) {
+            if entry.peer_id != peer_id {
+                continue;
+            }
+            let transfers = self.clone();
+            let connection = connection.clone();
+            tokio::spawn(async move {
+                let Some(active) = transfers.start_when_free(entry.transfer_id).await else {
+                    println!("[QUIC] Transfer {} is still running, not resuming", entry.transfer_id);
+                    return;
+                };
+                // It may have completed or been cancelled while we waited
+                let Some(entry) = transfers.spool.load(true, &entry.peer_id, entry.transfer_id) else { return };
+                println!("[QUIC] Resuming transfer {} to {}", entry.transfer_id, entry.peer_id);
+                match tokio::fs::File::open(&entry.source).await {
+                    Ok(file) => {
+                        let _ = run_outgoing(&connection, &transfers, active, entry, file, true).await;
+                    }
+                    Err(e) => {
+                        transfers.spool.remove(true, &entry.peer_id, entry.transfer_id);
+                        let result = Err(TransportError::Io(format!("Failed to open {}: {}", entry.source, e)));
+                        finish(&transfers, &entry.peer_id, entry.transfer_id, String::new(), true, None, &result);
+                    }
+                }
+            });
+        }
+    }
+
+    fn publish(&self, event: TransferEvent) {
+        let _ = self.events.send(event);
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<TransferEvent> {
+        self.events.subscribe()
+    }
+}
+
+/// What a transfer is announced as
+pub(crate) struct TransferInfo {
+    pub(crate) name: String,
+    pub(crate) content_type: String,
+    pub(crate) size: u64,
+}
+
+/// Send a file to a peer, returning the transfer id once the peer verified it
+///
+/// If the connection drops, the transfer continues from the spool when the
+/// peer reconnects.
+pub(crate) async fn send_file(
+    connection: &Connection,
+    peer_id: &str,
+    transfers: &Transfers,
+    path: String,
+    content_type: String,
+) -> Result<u64, TransportError> {
+    let file = tokio::fs::File::open(&path)
+        .await
+        .map_err(|e| TransportError::Io(format!("Failed to open {}: {}", path, e)))?;
+    let size = file.metadata()
+        .await
+        .map_err(|e| TransportError::Io(format!("Failed to read {}: {}", path, e)))?
+        .len();
+    let name = Path::new(&path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let active = transfers.start_new();
+    let entry = new_entry(active.transfer_id, peer_id, TransferInfo { name, content_type, size }, path);
+    transfers.spool.save(&entry)?;
+    run_outgoing(connection, transfers, active, entry, file, true).await
+}
+
+/// Send an in-memory payload to a peer, keeping a copy in the spool until it arrives
+pub(crate) async fn send_data(
+    connection: &Connection,
+    peer_id: &str,
+    transfers: &Transfers,
+    info: TransferInfo,
+    data: Vec<u8>,
+) -> Result<u64, TransportError> {
+    let active = transfers.start_new();
+    let copy = transfers.spool.data_path(active.transfer_id);
+    transfers.spool.ensure_directory()?;
+    tokio::fs::write(&copy, &data)
+        .await
+        .map_err(|e| TransportError::Io(format!("Failed to write {}: {}", copy.display(), e)))?;
+    let entry = new_entry(active.transfer_id, peer_id, info, copy.to_string_lossy().to_string());
+    transfers.spool.save(&entry)?;
+    run_outgoing(connection, transfers, active, entry, std::io::Cursor::new(data), true).await
+}
+
+/// Send content from `reader` to a peer without recording it for resumption
+///
+/// The reader must yield exactly `info.size` bytes.
+pub(crate) async fn send_reader<R: AsyncRead + Unpin>(
+    connection: &Connection,
+    peer_id: &str,
+    transfers: &Transfers,
+    reader: R,
+    info: TransferInfo,
+) -> Result<u64, TransportError> {
+    let active = transfers.start_new();
+    let entry = new_entry(active.transfer_id, peer_id, info, String::new());
+    run_outgoing(connection, transfers, active, entry, reader, false).await
+}
+
+fn new_entry(transfer_id: u64, peer_id: &str, info: TransferInfo, source: String) -> SpoolEntry {
+    SpoolEntry {
+        transfer_id,
+        outbound: true,
+        peer_id: peer_id.to_string(),
+        name: info.name,
+        content_type: info.content_type,
+        size: info.size,
+        acked: 0,
+        source,
+    }
+}
+
+/// Run one attempt of an outgoing transfer and settle its spool record
+async fn run_outgoing<R: AsyncRead + Unpin>(
+    connection: &Connection,
+    transfers: &Transfers,
+    active: ActiveTransfer,
+    entry: SpoolEntry,
+    reader: R,
+    persistent: bool,
+) -> Result<u64, TransportError> {
+    let transfer_id = entry.transfer_id;
+    println!("[QUIC] Sending transfer {} ({}, {} bytes) to {}", transfer_id, entry.name, entry.size, entry.peer_id);
+
+    let mut cancelled_here = false;
+    let result = match connection.open_bi().await {
+        Ok((mut send, mut recv)) => {
+            // Notices the receiver cancelling even while the reader is stalled
+            let stopped = send.stopped();
+            let peer_cancelled = async move { matches!(stopped.await, Ok(Some(code)) if code == STREAM_CANCELLED) };
+            let result = tokio::select! {
+                _ = active.cancel.notified() => {
+                    cancelled_here = true;
+                    Err(TransportError::Cancelled("Transfer cancelled".to_string()))
+                }
+                true = peer_cancelled => Err(TransportError::Cancelled("Peer stopped the stream".to_string())),
+                result = send_content(&mut send, &mut recv, transfers, &entry, reader, persistent) => result,
+            };
+            if let Err(e) = &result {
+                let code = if matches!(e, TransportError::Cancelled(_)) { STREAM_CANCELLED } else { STREAM_FAILED };
+                let _ = send.reset(code);
+                let _ = recv.stop(code);
+            }
+            result
+        }
+        Err(e) => Err(TransportError::Connection(format!("Failed to open stream: {}", e))),
+    };
+
+    let resumable = match &result {
+        Err(e) if persistent && is_interruption(e) => {
+            transfers.spool.load(true, &entry.peer_id, transfer_id).map(|entry| entry.acked)
+        }
+        _ => None,
+    };
+    if resumable.is_none() {
+        transfers.spool.remove(true, &entry.peer_id, transfer_id);
+    }
+    finish(transfers, &entry.peer_id, transfer_id, String::new(), !cancelled_here, resumable, &result);
+    drop(active);
+    result.map(|_| transfer_id)
+}
+
+/// Offer, stream and close one outgoing transfer. Returns the content hash.
+async fn send_content<R: AsyncRead + Unpin>(
+    send: &mut SendStream,
+    recv: &mut RecvStream,
+    transfers: &Transfers,
+    entry: &SpoolEntry,
+    mut reader: R,
+    persistent: bool,
+) -> Result<String, TransportError> {
+    let transfer_id = entry.transfer_id;
+    let offer = SyncMessage::TransferOffer {
+        transfer_id,
+        name: entry.name.clone(),
+        content_type: entry.content_type.clone(),
+        size: entry.size,
+    };
+    write_message(send, &offer, MAX_TRANSFER_FRAME).await?;
+    let offset = match read_message(recv, MAX_TRANSFER_FRAME).await? {
+        SyncMessage::TransferAccept { transfer_id: id, offset } if id == transfer_id && offset <= entry.size => offset,
+        SyncMessage::Error { message, .. } => return Err(TransportError::Rejected(message)),
+        other => return Err(ProtocolError::UnexpectedMessage(other.kind().to_string()).into()),
+    };
+    transfers.publish(TransferEvent::Started {
+        transfer_id,
+        peer_id: entry.peer_id.clone(),
+        name: entry.name.clone(),
+        size: entry.size,
+        outbound: true,
+        offset,
+    });
+
+    let replies = read_replies(recv, transfers, entry.clone(), persistent);
+    tokio::pin!(replies);
+    let sha256 = tokio::select! {
+        result = write_content(send, transfers, entry, &mut reader, offset) => result?,
+        result = &mut replies => {
+            result?;
+            return Err(ProtocolError::UnexpectedMessage("Ack before the transfer ended".to_string()).into());
+        }
+    };
+    tokio::time::timeout(COMPLETION_TIMEOUT, replies)
+        .await
+        .map_err(|_| TransportError::Connection("Timed out waiting for the receiver to verify the transfer".to_string()))??;
+    Ok(sha256)
+}
+
+/// Stream content from `offset` on and end with the hash of all of it
+async fn write_content<R: AsyncRead + Unpin>(
+    send: &mut SendStream,
+    transfers: &Transfers,
+    entry: &SpoolEntry,
+    reader: &mut R,
+    offset: u64,
+) -> Result<String, TransportError> {
+    // The receiver already has the start; only its hash is needed
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut skipped = 0u64;
+    while skipped < offset {
+        let want = (offset - skipped).min(CHUNK_SIZE as u64) as usize;
+        let n = read_chunk(reader, &mut buf[..want]).await?;
+        if n == 0 {
+            return Err(TransportError::Io(format!("Content ended before the resume offset {}", offset)));
+        }
+        hasher.update(&buf[..n]);
+        skipped += n as u64;
+    }
+
+    let mut progress = Progress::new(transfers, &entry.peer_id, entry.transfer_id, entry.size);
+    let mut offset = offset;
+    loop {
+        let n = read_chunk(reader, &mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        if offset + n as u64 > entry.size {
+            return Err(TransportError::Io(format!("Content is longer than the announced {} bytes", entry.size)));
+        }
+        hasher.update(&buf[..n]);
+        let chunk = SyncMessage::TransferChunk { offset, data: buf[..n].to_vec() };
+        write_message(send, &chunk, MAX_TRANSFER_FRAME).await?;
+        offset += n as u64;
+        progress.report(offset);
+    }
+    if offset != entry.size {
+        return Err(TransportError::Io(format!("Content ended after {} of {} bytes", offset, entry.size)));
+    }
+
+    let sha256: Vec<u8> = hasher.finalize().to_vec();
+    write_message(send, &SyncMessage::TransferEnd { sha256: sha256.clone() }, MAX_TRANSFER_FRAME).await?;
+    send.finish()
+        .map_err(|e| TransportError::Io(format!("Failed to finish stream: {}", e)))?;
+    Ok(to_hex(&sha256))
+}
+
+/// Record the receiver's acknowledgements until it accepts or refuses the transfer
+async fn read_replies(
+    recv: &mut RecvStream,
+    transfers: &Transfers,
+    mut entry: SpoolEntry,
+    persistent: bool,
+) -> Result<(), TransportError> {
+    loop {
+        match read_message(recv, MAX_TRANSFER_FRAME).await? {
+            SyncMessage::TransferAck { transfer_id, offset } if transfer_id == entry.transfer_id => {
+                if persistent && offset > entry.acked && offset <= entry.size {
+                    entry.acked = offset;
+                    transfers.spool.save(&entry)?;
+                }
+            }
+            SyncMessage::Ack { item_id } if item_id == entry.transfer_id => return Ok(()),
+            SyncMessage::Error { message, .. } => return Err(TransportError::Rejected(message)),
+            other => return Err(ProtocolError::UnexpectedMessage(other.kind().to_string()).into()),
+        }
+    }
+}
+
+/// Receive a transfer offered on a bidirectional stream
+///
+/// An offer for a transfer interrupted earlier continues from the offset last
+/// acknowledged to the same peer.
+pub(crate) async fn receive_transfer(
+    peer_id: String,
+    mut send: SendStream,
+    mut recv: RecvStream,
+    transfers: Transfers,
+    offer: TransferInfo,
+    transfer_id: u64,
+) {
+    let refuse = |code: u16, message: String| SyncMessage::Error { code, message };
+    let active = if offer.size > transfers.max_size {
+        Err(refuse(
+            error_code::TOO_LARGE,
+            format!("Transfer of {} bytes exceeds the limit of {}", offer.size, transfers.max_size),
+        ))
+    } else {
+        transfers
+            .start_when_free(transfer_id)
+            .await
+            .ok_or_else(|| refuse(error_code::PROTOCOL_VIOLATION, format!("Transfer {} is already running", transfer_id)))
+    };
+    let active = match active {
+        Ok(active) => active,
+        Err(refusal) => {
+            println!("[QUIC] Refusing transfer {} from {}: {:?}", transfer_id, peer_id, refusal);
+            let _ = write_message(&mut send, &refusal, MAX_TRANSFER_FRAME).await;
+            let _ = send.finish();
+            if let SyncMessage::Error { message, .. } = refusal {
+                transfers.publish(TransferEvent::Failed { transfer_id, peer_id, error: message });
+            }
+            return;
+        }
+    };
+
+    let mut entry = transfers
+        .spool
+        .load(false, &peer_id, transfer_id)
+        .filter(|entry| entry.name == offer.name && entry.size == offer.size)
+        .unwrap_or(SpoolEntry {
+            transfer_id,
+            outbound: false,
+            peer_id: peer_id.clone(),
+            name: offer.name,
+            content_type: offer.content_type,
+            size: offer.size,
+            acked: 0,
+            source: String::new(),
+        });
+    println!(
+        "[QUIC] Receiving transfer {} ({}, {} bytes from offset {}) from {}",
+        transfer_id, entry.name, entry.size, entry.acked, peer_id
+    );
+
+    let part = transfers.spool.part_path(&peer_id, transfer_id);
+    let mut cancelled_here = false;
+    let result = tokio::select! {
+        _ = active.cancel.notified() => {
+            cancelled_here = true;
+            Err(TransportError::Cancelled("Transfer cancelled".to_string()))
+        }
+        result = receive_content(&mut send, &mut recv, &transfers, &mut entry, &part) => result,
+    };
+    let mut hash_mismatch = false;
+    let result = match result {
+        Ok((announced, actual)) if announced != actual => {
+            hash_mismatch = true;
+            Err(TransportError::Protocol(format!(
+                "Content hash mismatch: expected {}, got {}",
+                to_hex(&announced),
+                to_hex(&actual)
+            )))
+        }
+        Ok((_, actual)) => match save_part(&part, &transfers.directory, &entry.name).await {
+            Ok(path) => {
+                let _ = write_message(&mut send, &SyncMessage::Ack { item_id: transfer_id }, MAX_TRANSFER_FRAME).await;
+                let _ = send.finish();
+                Ok((path, to_hex(&actual)))
+            }
+            Err(e) => Err(e),
+        },
+        Err(e) => Err(e),
+    };
+
+    let resumable = match &result {
+        Err(e) if is_interruption(e) => Some(entry.acked),
+        _ => None,
+    };
+    if let Err(e) = &result {
+        match e {
+            TransportError::Cancelled(_) => {
+                let _ = send.reset(STREAM_CANCELLED);
+                let _ = recv.stop(STREAM_CANCELLED);
+            }
+            _ if resumable.is_some() => {
+                let _ = send.reset(STREAM_FAILED);
+                let _ = recv.stop(STREAM_FAILED);
+            }
+            e => {
+                let code = match e {
+                    _ if hash_mismatch => error_code::HASH_MISMATCH,
+                    TransportError::Protocol(_) => error_code::PROTOCOL_VIOLATION,
+                    _ => error_code::TRANSFER_FAILED,
+                };
+                let refusal = refuse(code, e.to_string());
+                let _ = write_message(&mut send, &refusal, MAX_TRANSFER_FRAME).await;
+                let _ = send.finish();
+                let _ = recv.stop(STREAM_FAILED);
+            }
+        }
+    }
+    if resumable.is_none() {
+        transfers.spool.remove(false, &peer_id, transfer_id);
+    }
+
+    drop(active);
+    let (path, result) = match result {
+        Ok((path, sha256)) => (path.to_string_lossy().to_string(), Ok(sha256)),
+        Err(e) => (String::new(), Err(e)),
+    };
+    finish(&transfers, &peer_id, transfer_id, path, !cancelled_here, resumable, &result);
+}
+
+/// Accept a transfer and write its chunks to `part`, acknowledging as they reach the disk
+///
+/// Returns the content hash announced by the sender and the hash of what was
+/// received, for the caller to compare.
+async fn receive_content(
+    send: &mut SendStream,
+    recv: &mut RecvStream,
+    transfers: &Transfers,
+    entry: &mut SpoolEntry,
+    part: &Path,
+) -> Result<(Vec<u8>, Vec<u8>), TransportError> {
+    let disk_error = |e: std::io::Error| TransportError::Io(format!("Failed to write {}: {}", part.display(), e));
+
+    // Anything past the last acknowledgement may not have reached the disk
+    transfers.spool.ensure_directory()?;
+    let mut file = tokio::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(part)
+        .await
+        .map_err(disk_error)?;
+    let on_disk = file.metadata().await.map_err(disk_error)?.len();
+    if on_disk < entry.acked {
+        entry.acked = 0;
+    }
+    file.set_len(entry.acked).await.map_err(disk_error)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut hashed = 0u64;
+    while hashed < entry.acked {
+        let n = file.read(&mut buf).await.map_err(disk_error)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        hashed += n as u64;
+    }
+    transfers.spool.save(entry)?;
+
+    let transfer_id = entry.transfer_id;
+    write_message(send, &SyncMessage::TransferAccept { transfer_id, offset: entry.acked }, MAX_TRANSFER_FRAME).await?;
+    transfers.publish(TransferEvent::Started {
+        transfer_id,
+        peer_id: entry.peer_id.clone(),
+        name: entry.name.clone(),
+        size: entry.size,
+        outbound: false,
+        offset: entry.acked,
+    });
+
+    let mut progress = Progress::new(transfers, &entry.peer_id, transfer_id, entry.size);
+    let mut received = entry.acked;
+    loop {
+        match read_message(recv, MAX_TRANSFER_FRAME).await? {
+            SyncMessage::TransferChunk { offset, data } => {
+                if offset != received || received + data.len() as u64 > entry.size {
+                    return Err(ProtocolError::UnexpectedMessage(format!(
+                        "chunk at {} (+{} bytes) after {} of {} bytes",
+                        offset,
+                        data.len(),
+                        received,
+                        entry.size
+                    ))
+                    .into());
+                }
+                file.write_all(&data).await.map_err(disk_error)?;
+                hasher.update(&data);
+                received += data.len() as u64;
+                progress.report(received);
+
+                if received - entry.acked >= ACK_INTERVAL {
+                    file.sync_data().await.map_err(disk_error)?;
+                    entry.acked = received;
+                    transfers.spool.save(entry)?;
+                    let ack = SyncMessage::TransferAck { transfer_id, offset: received };
+                    write_message(send, &ack, MAX_TRANSFER_FRAME).await?;
+                }
+            }
+            SyncMessage::TransferEnd { sha256 } => {
+                if received != entry.size {
+                    return Err(TransportError::Protocol(format!(
+                        "Transfer ended after {} of {} bytes",
+                        received, entry.size
+                    )));
+                }
+                file.flush().await.map_err(disk_error)?;
+                return Ok((sha256, hasher.finalize().to_vec()));
+            }
+            other => return Err(ProtocolError::UnexpectedMessage(other.kind().to_string()).into()),
+        }
+    }
+}
+
+/// Move a completed part file to its final name in `directory`
+async fn save_part(part: &Path, directory: &Path, name: &str) -> Result<PathBuf, TransportError> {
+    tokio::fs::create_dir_all(directory)
+        .await
+        .map_err(|e| TransportError::Io(format!("Failed to create {}: {}", directory.display(), e)))?;
+    let path = unique_path(directory, &file_name(name))?;
+    if tokio::fs::rename(part, &path).await.is_err() {
+        // The spool may be on another file system
+        tokio::fs::copy(part, &path)
+            .await
+            .map_err(|e| TransportError::Io(format!("Failed to save {}: {}", path.display(), e)))?;
+        let _ = tokio::fs::remove_file(part).await;
+    }
+    Ok(path)
+}
+
+/// Whether an error means the connection or stream was lost, so the transfer can resume
+fn is_interruption(e: &TransportError) -> bool {
+    matches!(e, TransportError::Io(_) | TransportError::Connection(_))
+}
+
+/// Publish the final event of a transfer attempt and log it
+///
+/// `resumable` holds the acknowledged offset when the transfer was interrupted
+/// and kept in the spool.
+fn finish(
+    transfers: &Transfers,
+    peer_id: &str,
+    transfer_id: u64,
+    path: String,
+    by_peer: bool,
+    resumable: Option<u64>,
+    result: &Result<String, TransportError>,
+) {
+    let peer_id = peer_id.to_string();
+    let event = match (result, resumable) {
+        (Ok(sha256), _) => {
+            println!("[QUIC] Transfer {} with {} completed (sha256 {})", transfer_id, peer_id, sha256);
+            TransferEvent::Completed { transfer_id, peer_id, path, sha256: sha256.clone() }
+        }
+        (Err(TransportError::Cancelled(_)), _) => {
+            println!("[QUIC] Transfer {} with {} cancelled", transfer_id, peer_id);
+            TransferEvent::Cancelled { transfer_id, peer_id, by_peer }
+        }
+        (Err(e), Some(transferred)) => {
+            println!("[QUIC] Transfer {} with {} interrupted after {} bytes: {}", transfer_id, peer_id, transferred, e);
+            TransferEvent::Interrupted { transfer_id, peer_id, transferred }
+        }
+        (Err(e), None) => {
+            println!("[QUIC] Transfer {} with {} failed: {}", transfer_id, peer_id, e);
+            TransferEvent::Failed { transfer_id, peer_id, error: e.to_string() }
+        }
+    };
+    transfers.publish(event);
+}
+
+/// Rate-limited progress events for one transfer
+struct Progress<'a> {
+    transfers: &'a Transfers,
+    peer_id: &'a str,
+    transfer_id: u64,
+    size: u64,
+    last: Instant,
+}
+
+impl<'a> Progress<'a> {
+    fn new(transfers: &'a Transfers, peer_id: &'a str, transfer_id: u64, size: u64) -> Self {
+        Self { transfers, peer_id, transfer_id, size, last: Instant::now() }
+    }
+
+    fn report(&mut self, transferred: u64) {
+        if transferred < self.size && self.last.elapsed() < PROGRESS_INTERVAL {
+            return;
+        }
+        self.last = Instant::now();
+        self.transfers.publish(TransferEvent::Progress {
+            transfer_id: self.transfer_id,
+            peer_id: self.peer_id.to_string(),
+            transferred,
+            size: self.size,
+        });
+    }
+}
+
+/// Fill `buf` from the reader, returning fewer bytes only at the end of the content
+async fn read_chunk<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> Result<usize, TransportError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader
+            .read(&mut buf[filled..])
+            .await
+            .map_err(|e| TransportError::Io(format!("Failed to read transfer content: {}", e)))?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
+
+/// File name for a received transfer, ignoring any directories in the announced name
+fn file_name(name: &str) -> String {
+    let name = name.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+    if name.is_empty() || name == "." || name == ".." {
+        "transfer".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// `directory/name`, or `directory/stem (n).ext` if that already exists
+///
+/// Fails rather than overwrite a file when every numbered name is taken.
+fn unique_path(directory: &Path, name: &str) -> Result<PathBuf, TransportError> {
+    let path = directory.join(name);
+    if !path.exists() {
+        return Ok(path);
+    }
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{}", extension)),
+        _ => (name, String::new()),
+    };
+    (1..=MAX_NAME_SUFFIX)
+        .map(|n| directory.join(format!("{} ({}){}", stem, n, extension)))
+        .find(|path| !path.exists())
+        .ok_or_else(|| TransportError::Io(format!("No free name for {} in {}", name, directory.display())))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name_strips_directories() {
+        assert_eq!(file_name("photo.png"), "photo.png");
+        assert_eq!(file_name("../../etc/passwd"), "passwd");
+        assert_eq!(file_name("C:\\Users\\me\\notes.txt"), "notes.txt");
+        assert_eq!(file_name(".."), "transfer");
+        assert_eq!(file_name("dir/"), "transfer");
+    }
+
+    #[test]
+    fn test_unique_path_avoids_existing_files() {
+        let directory = std::env::temp_dir().join(format!("syncmist-unique-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        assert_eq!(unique_path(&directory, "a.txt").unwrap(), directory.join("a.txt"));
+
+        std::fs::write(directory.join("a.txt"), b"x").unwrap();
+        std::fs::write(directory.join("a (1).txt"), b"x").unwrap();
+        assert_eq!(unique_path(&directory, "a.txt").unwrap(), directory.join("a (2).txt"));
+
+        std::fs::write(directory.join("README"), b"x").unwrap();
+        assert_eq!(unique_path(&directory, "README").unwrap(), directory.join("README (1)"));
+
+        // Every numbered name taken fails instead of reusing an existing file
+        std::fs::write(directory.join("full"), b"x").unwrap();
+        for n in 1..=MAX_NAME_SUFFIX {
+            std::fs::write(directory.join(format!("full ({})", n)), b"x").unwrap();
+        }
+        assert!(matches!(unique_path(&directory, "full"), Err(TransportError::Io(_))));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
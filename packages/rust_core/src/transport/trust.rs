@@ -0,0 +1,427 @@
+//! Certificate Fingerprint Pinning for SyncMist
+//!
+//! Records the SHA-256 fingerprint of each peer's end-entity certificate the first
+//! time we talk to it (Trust On First Use) and rejects later handshakes from the
+//! same device that present a different certificate. Devices pinned explicitly,
+//! from a pairing payload, are also paired: only they may connect to us.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::server::danger::{ClientCertVerified, ClientCertVerifier};
+use rustls::DistinguishedName;
+use sha2::{Digest, Sha256};
+
+use super::identity::device_id_from_cert;
+use super::quic::TransportError;
+
+/// Length of a hex encoded SHA-256 fingerprint
+const FINGERPRINT_HEX_LEN: usize = 64;
+
+/// A certificate fingerprint pinned for a device
+#[flutter_rust_bridge::frb]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PinnedFingerprint {
+    pub device_id: String,
+    /// Lowercase hex SHA-256 of the device's DER certificate
+    pub fingerprint: String,
+}
+
+/// Compute the SHA-256 fingerprint of a DER encoded certificate
+///
+/// Returns the digest as 64 lowercase hex characters.
+#[flutter_rust_bridge::frb(sync)]
+pub fn certificate_fingerprint(cert_der: Vec<u8>) -> String {
+    fingerprint_of(&cert_der)
+}
+
+pub(crate) fn fingerprint_of(cert_der: &[u8]) -> String {
+    Sha256::digest(cert_der)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Normalize a fingerprint as it may appear in a pairing payload
+///
+/// Accepts upper or lower case hex, optionally separated by `:` as printed by
+/// most certificate tools.
+pub(crate) fn normalize_fingerprint(fingerprint: &str) -> Result<String, TransportError> {
+    let normalized: String = fingerprint
+        .chars()
+        .filter(|c| *c != ':')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    if normalized.len() != FINGERPRINT_HEX_LEN || !normalized.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(TransportError::Tls(format!("Invalid certificate fingerprint: {}", fingerprint)));
+    }
+    Ok(normalized)
+}
+
+/// Device id to certificate fingerprint mapping shared by all handshakes
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TrustStore {
+    pins: Arc<Mutex<HashMap<String, String>>>,
+    /// Devices pinned by pairing rather than on first use
+    paired: Arc<Mutex<HashSet<String>>>,
+}
+
+impl TrustStore {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pin a fingerprint for a device and pair with it, replacing any previous pin
+    pub(crate) fn pin(&self, device_id: &str, fingerprint: &str) -> Result<(), TransportError> {
+        let fingerprint = normalize_fingerprint(fingerprint)?;
+        println!("[QUIC] Pinning certificate fingerprint for device {}", device_id);
+        self.pins.lock().unwrap().insert(device_id.to_string(), fingerprint);
+        self.paired.lock().unwrap().insert(device_id.to_string());
+        Ok(())
+    }
+
+    /// Remove the pin for a device and unpair it. Returns whether a pin existed.
+    pub(crate) fn forget(&self, device_id: &str) -> bool {
+        self.paired.lock().unwrap().remove(device_id);
+        self.pins.lock().unwrap().remove(device_id).is_some()
+    }
+
+    /// Whether a device was pinned by pairing, as opposed to on first use
+    pub(crate) fn is_paired(&self, device_id: &str) -> bool {
+        self.paired.lock().unwrap().contains(device_id)
+    }
+
+    /// Paired devices, ordered by device id
+    pub(crate) fn paired(&self) -> Vec<String> {
+        let mut paired: Vec<String> = self.paired.lock().unwrap().iter().cloned().collect();
+        paired.sort();
+        paired
+    }
+
+    pub(crate) fn get(&self, device_id: &str) -> Option<String> {
+        self.pins.lock().unwrap().get(device_id).cloned()
+    }
+
+    /// All pinned fingerprints, ordered by device id
+    pub(crate) fn list(&self) -> Vec<PinnedFingerprint> {
+        let pins = self.pins.lock().unwrap();
+        let mut list: Vec<PinnedFingerprint> = pins
+            .iter()
+            .map(|(device_id, fingerprint)| PinnedFingerprint {
+                device_id: device_id.clone(),
+                fingerprint: fingerprint.clone(),
+            })
+            .collect();
+        list.sort_by(|a, b| a.device_id.cmp(&b.device_id));
+        list
+    }
+
+    /// Check a presented fingerprint against the pin for a device
+    ///
+    /// Devices without a pin are accepted; use [`TrustStore::record`] once the
+    /// handshake has proven possession of the key.
+    pub(crate) fn check(&self, device_id: &str, fingerprint: &str) -> Result<(), TransportError> {
+        match self.get(device_id) {
+            Some(expected) if expected != fingerprint => Err(TransportError::FingerprintMismatch {
+                device_id: device_id.to_string(),
+                expected,
+                actual: fingerprint.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record a fingerprint for a device on first use. Existing pins are kept.
+    ///
+    /// The device is not paired by this; it can't connect to us until it is.
+    pub(crate) fn record(&self, device_id: &str, fingerprint: &str) {
+        let mut pins = self.pins.lock().unwrap();
+        if !pins.contains_key(device_id) {
+            println!("[QUIC] TOFU: Trusting device {} with fingerprint {}", device_id, fingerprint);
+            pins.insert(device_id.to_string(), fingerprint.to_string());
+        }
+    }
+}
+
+/// TOFU (Trust On First Use) Certificate Verifier
+///
+/// Skips CA validation for P2P connections and instead pins the peer's certificate
+/// fingerprint in the [`TrustStore`] under the device id we expect to reach, or
+/// the device id named in the certificate when we don't know which device answers.
+/// The fingerprint is only recorded once the handshake signature proves the peer
+/// holds the certificate's private key.
+#[derive(Debug)]
+pub(crate) struct TofuCertVerifier {
+    trust_store: TrustStore,
+    device_id: Option<String>,
+    provider: Arc<CryptoProvider>,
+    pinned_device: Mutex<Option<String>>,
+    rejection: Mutex<Option<TransportError>>,
+}
+
+impl TofuCertVerifier {
+    pub(crate) fn new(trust_store: TrustStore, device_id: Option<String>) -> Self {
+        Self {
+            trust_store,
+            device_id,
+            provider: Arc::new(rustls::crypto::ring::default_provider()),
+            pinned_device: Mutex::new(None),
+            rejection: Mutex::new(None),
+        }
+    }
+
+    /// The pinning error that caused the last rejected handshake, if any
+    pub(crate) fn take_rejection(&self) -> Option<TransportError> {
+        self.rejection.lock().unwrap().take()
+    }
+
+    fn reject(&self, error: TransportError) -> rustls::Error {
+        println!("[QUIC] TOFU: Rejecting certificate: {}", error);
+        *self.rejection.lock().unwrap() = Some(error);
+        rustls::Error::InvalidCertificate(rustls::CertificateError::ApplicationVerificationFailure)
+    }
+
+    fn record_if_pinning(&self, cert: &CertificateDer<'_>) {
+        if let Some(device_id) = self.pinned_device.lock().unwrap().as_deref() {
+            self.trust_store.record(device_id, &fingerprint_of(cert.as_ref()));
+        }
+    }
+}
+
+impl ServerCertVerifier for TofuCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let presented = device_id_from_cert(end_entity.as_ref());
+        let device_id = match (&self.device_id, presented) {
+            (Some(expected), Some(presented)) if *expected != presented => {
+                return Err(self.reject(TransportError::Tls(format!(
+                    "Peer identified as device {}, expected {}",
+                    presented, expected
+                ))));
+            }
+            (Some(expected), _) => expected.clone(),
+            (None, Some(presented)) => presented,
+            (None, None) => {
+                println!("[QUIC] TOFU: No device id for peer, accepting certificate without pinning");
+                return Ok(ServerCertVerified::assertion());
+            }
+        };
+
+        let fingerprint = fingerprint_of(end_entity.as_ref());
+        if let Err(e) = self.trust_store.check(&device_id, &fingerprint) {
+            return Err(self.reject(e));
+        }
+        *self.pinned_device.lock().unwrap() = Some(device_id);
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        let valid = rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )?;
+        self.record_if_pinning(cert);
+        Ok(valid)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        let valid = rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )?;
+        self.record_if_pinning(cert);
+        Ok(valid)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.provider.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Client certificate verifier for the QUIC server (mutual TLS)
+///
+/// Only paired devices may connect: the client certificate must name a device id
+/// paired through the [`TrustStore`], and match its pinned fingerprint. Unlike
+/// the server side there is no trust on first use, and servers we pinned on
+/// first use may not connect back.
+#[derive(Debug)]
+pub(crate) struct PairedClientVerifier {
+    trust_store: TrustStore,
+    provider: Arc<CryptoProvider>,
+}
+
+impl PairedClientVerifier {
+    pub(crate) fn new(trust_store: TrustStore) -> Self {
+        Self {
+            trust_store,
+            provider: Arc::new(rustls::crypto::ring::default_provider()),
+        }
+    }
+}
+
+impl ClientCertVerifier for PairedClientVerifier {
+    fn root_hint_subjects(&self) -> &[DistinguishedName] {
+        &[]
+    }
+
+    fn verify_client_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _now: UnixTime,
+    ) -> Result<ClientCertVerified, rustls::Error> {
+        let rejected = || rustls::Error::InvalidCertificate(rustls::CertificateError::ApplicationVerificationFailure);
+
+        let Some(device_id) = device_id_from_cert(end_entity.as_ref()) else {
+            println!("[QUIC] mTLS: Rejecting client certificate without a device id");
+            return Err(rejected());
+        };
+        if !self.trust_store.is_paired(&device_id) {
+            println!("[QUIC] mTLS: Rejecting unpaired device {}", device_id);
+            return Err(rejected());
+        }
+        if let Err(e) = self.trust_store.check(&device_id, &fingerprint_of(end_entity.as_ref())) {
+            println!("[QUIC] mTLS: Rejecting client certificate: {}", e);
+            return Err(rejected());
+        }
+
+        println!("[QUIC] mTLS: Verified client certificate for device {}", device_id);
+        Ok(ClientCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.provider.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FP_A: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const FP_B: &str = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+    #[test]
+    fn test_certificate_fingerprint() {
+        // SHA-256 of the empty input
+        assert_eq!(
+            certificate_fingerprint(Vec::new()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(certificate_fingerprint(vec![1, 2, 3]).len(), FINGERPRINT_HEX_LEN);
+    }
+
+    #[test]
+    fn test_normalize_fingerprint() {
+        let colon_separated = FP_A
+            .to_uppercase()
+            .as_bytes()
+            .chunks(2)
+            .map(|c| std::str::from_utf8(c).unwrap())
+            .collect::<Vec<_>>()
+            .join(":");
+        assert_eq!(normalize_fingerprint(&colon_separated).unwrap(), FP_A);
+
+        assert!(normalize_fingerprint("abc").is_err(), "Short fingerprint should be rejected");
+        assert!(normalize_fingerprint(&"zz".repeat(32)).is_err(), "Non-hex fingerprint should be rejected");
+    }
+
+    #[test]
+    fn test_trust_store_first_use() {
+        let store = TrustStore::new();
+
+        // Unknown devices are accepted and recorded
+        assert!(store.check("device-1", FP_A).is_ok());
+        store.record("device-1", FP_A);
+        assert_eq!(store.get("device-1").as_deref(), Some(FP_A));
+
+        // Recording again does not overwrite the original pin
+        store.record("device-1", FP_B);
+        assert_eq!(store.get("device-1").as_deref(), Some(FP_A));
+
+        match store.check("device-1", FP_B) {
+            Err(TransportError::FingerprintMismatch { device_id, expected, actual }) => {
+                assert_eq!(device_id, "device-1");
+                assert_eq!(expected, FP_A);
+                assert_eq!(actual, FP_B);
+            }
+            other => panic!("Expected FingerprintMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trust_store_pin_list_forget() {
+        let store = TrustStore::new();
+        store.pin("device-b", FP_B).unwrap();
+        store.pin("device-a", &FP_A.to_uppercase()).unwrap();
+        assert!(store.pin("device-c", "not-a-fingerprint").is_err());
+
+        let pins = store.list();
+        assert_eq!(pins.len(), 2);
+        assert_eq!(pins[0], PinnedFingerprint { device_id: "device-a".into(), fingerprint: FP_A.into() });
+        assert_eq!(pins[1].device_id, "device-b");
+
+        assert!(store.forget("device-a"));
+        assert!(!store.forget("device-a"), "Forgetting twice should report no pin");
+        assert_eq!(store.list().len(), 1);
+    }
+
+    #[test]
+    fn test_trust_store_pairing() {
+        let store = TrustStore::new();
+        store.record("server", FP_A);
+        assert!(!store.is_paired("server"), "Pinning on first use should not pair");
+
+        store.pin("phone", FP_B).unwrap();
+        store.pin("laptop", FP_A).unwrap();
+        assert!(store.is_paired("phone"));
+        assert_eq!(store.paired(), ["laptop", "phone"]);
+
+        store.forget("phone");
+        assert!(!store.is_paired("phone"));
+        assert_eq!(store.paired(), ["laptop"]);
+    }
+}